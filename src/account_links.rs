@@ -0,0 +1,35 @@
+//! Management of the links between the accounts of the same player across the realms.
+
+use crate::database::{AccountLink, AuditLogEntry};
+use crate::opts::{LinkAccountsAction, LinkAccountsOpts};
+use crate::prelude::*;
+
+#[instrument(skip_all)]
+pub async fn run(opts: LinkAccountsOpts) -> Result {
+    sentry::configure_scope(|scope| scope.set_tag("app", "link-accounts"));
+    let db = database::mongodb::open(&opts.internal.mongodb_uri).await?;
+    match opts.action {
+        LinkAccountsAction::Add { accounts } => {
+            let group_id = AccountLink::link(&db, &accounts).await?;
+            AuditLogEntry::cli("link-accounts.add", format!("{accounts:?} group={group_id}"))
+                .insert(&db)
+                .await?;
+            info!(?accounts, %group_id, "linked");
+        }
+        LinkAccountsAction::Remove {
+            account: (realm, account_id),
+        } => {
+            let is_removed = AccountLink::unlink(&db, realm, account_id).await?;
+            AuditLogEntry::cli("link-accounts.remove", format!("{realm}/{account_id}"))
+                .insert(&db)
+                .await?;
+            info!(?realm, account_id, is_removed, "unlinked");
+        }
+        LinkAccountsAction::List => {
+            for link in AccountLink::retrieve_all(&db).await? {
+                println!("{}\t{}/{}", link.group_id, link.realm, link.account_id);
+            }
+        }
+    }
+    Ok(())
+}
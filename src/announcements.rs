@@ -0,0 +1,53 @@
+//! Management of the site-wide announcements.
+
+use crate::database::mongodb::traits::Upsert;
+use crate::database::{Announcement, AuditLogEntry};
+use crate::opts::{AnnouncementsAction, AnnouncementsOpts};
+use crate::prelude::*;
+
+#[instrument(skip_all)]
+pub async fn run(opts: AnnouncementsOpts) -> Result {
+    sentry::configure_scope(|scope| scope.set_tag("app", "announcements"));
+    let db = database::mongodb::open(&opts.internal.mongodb_uri).await?;
+    match opts.action {
+        AnnouncementsAction::Add {
+            id,
+            markdown,
+            severity,
+            expire,
+        } => {
+            let now = now();
+            let announcement = Announcement {
+                id,
+                markdown,
+                severity,
+                created_at: now,
+                expires_at: now + Duration::from_std(expire)?,
+            };
+            announcement.upsert(&db).await?;
+            AuditLogEntry::cli("announcements.add", &announcement.id)
+                .insert(&db)
+                .await?;
+            info!(announcement.id, %announcement.expires_at, "published");
+        }
+        AnnouncementsAction::Remove { id } => {
+            let is_removed = Announcement::delete(&db, &id).await?;
+            AuditLogEntry::cli("announcements.remove", &id)
+                .insert(&db)
+                .await?;
+            info!(id, is_removed, "removed");
+        }
+        AnnouncementsAction::List => {
+            for announcement in Announcement::retrieve_all(&db).await? {
+                println!(
+                    "{}\t{}\t{}\t{}",
+                    announcement.id,
+                    announcement.severity.as_str(),
+                    announcement.expires_at,
+                    announcement.markdown,
+                );
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,102 @@
+//! Detection and review of the statistically implausible victory ratio jumps,
+//! which are likely to be account sharing or cheating.
+
+use futures::TryStreamExt;
+use tokio::time::sleep;
+
+use crate::database::mongodb::traits::Upsert;
+use crate::database::{AccountAnomaly, AccountIdProjection, AccountSnapshot, AuditLogEntry};
+use crate::helpers::lock::Lock;
+use crate::helpers::redis;
+use crate::opts::{AnomaliesAction, AnomaliesOpts, DetectAnomaliesOpts, InternalConnectionOpts};
+use crate::prelude::*;
+
+const LOCK_TTL: time::Duration = time::Duration::from_secs(60);
+
+#[instrument(skip_all)]
+pub async fn run(opts: AnomaliesOpts) -> Result {
+    sentry::configure_scope(|scope| scope.set_tag("app", "anomalies"));
+    match opts.action {
+        AnomaliesAction::Detect(detect_opts) => detect(&opts.internal, detect_opts).await?,
+        AnomaliesAction::List { realm } => {
+            let db = database::mongodb::open(&opts.internal.mongodb_uri).await?;
+            for anomaly in AccountAnomaly::retrieve_all(&db, realm).await? {
+                println!(
+                    "{}/{}\t{}\tunusual activity detected: {} wins in {} battles, z = {:.1}",
+                    anomaly.realm.to_str(),
+                    anomaly.account_id,
+                    anomaly.detected_at,
+                    anomaly.n_wins,
+                    anomaly.n_battles,
+                    anomaly.z_score,
+                );
+            }
+        }
+        AnomaliesAction::Dismiss {
+            account: (realm, account_id),
+        } => {
+            let db = database::mongodb::open(&opts.internal.mongodb_uri).await?;
+            let is_dismissed = AccountAnomaly::dismiss(&db, realm, account_id).await?;
+            AuditLogEntry::cli("anomalies.dismiss", format!("{realm}/{account_id}"))
+                .insert(&db)
+                .await?;
+            info!(?realm, account_id, is_dismissed, "dismissed");
+        }
+    }
+    Ok(())
+}
+
+/// Periodically tests the accounts, which have played within the window.
+/// Only one replica per realm is active at a time.
+async fn detect(internal: &InternalConnectionOpts, opts: DetectAnomaliesOpts) -> Result {
+    sentry::configure_scope(|scope| scope.set_tag("realm", opts.realm));
+
+    let db = database::mongodb::open(&internal.mongodb_uri).await?;
+    let redis = redis::connect(&internal.redis_uri, internal.redis_pool_size).await?;
+    let lock = Lock::new(redis, &format!("anomalies:{}", opts.realm.to_str()), LOCK_TTL);
+    let window = Duration::from_std(opts.window)?;
+    loop {
+        lock.run_exclusively(detect_once(&db, &opts, window)).await?;
+        sleep(opts.interval).await;
+    }
+}
+
+#[instrument(skip_all)]
+async fn detect_once(
+    db: &mongodb::Database,
+    opts: &DetectAnomaliesOpts,
+    window: Duration,
+) -> Result {
+    let start_instant = Instant::now();
+    let now = now();
+    let since = now - window;
+    let mut accounts = AccountIdProjection::retrieve_active_since(db, opts.realm, since).await?;
+    let mut n_accounts = 0;
+    let mut n_anomalies = 0;
+    while let Some(account) = accounts.try_next().await? {
+        n_accounts += 1;
+        let Some(prior) = AccountSnapshot::retrieve_latest(db, opts.realm, account.id, since).await?
+        else {
+            continue;
+        };
+        let Some(latest) = AccountSnapshot::retrieve_latest(db, opts.realm, account.id, now).await?
+        else {
+            continue;
+        };
+        let anomaly = AccountAnomaly::detect(
+            opts.realm,
+            account.id,
+            &prior.random_stats,
+            &(latest.random_stats - prior.random_stats),
+            opts.min_battles,
+            opts.min_z_score,
+        );
+        if let Some(anomaly) = anomaly {
+            warn!(anomaly.account_id, anomaly.z_score, "unusual activity detected");
+            anomaly.upsert(db).await?;
+            n_anomalies += 1;
+        }
+    }
+    info!(n_accounts, n_anomalies, elapsed = ?start_instant.elapsed(), "finished");
+    Ok(())
+}
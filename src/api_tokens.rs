@@ -0,0 +1,19 @@
+//! Issuance of the tokens for the protected API endpoints.
+
+use crate::database::{ApiToken, AuditLogEntry};
+use crate::opts::IssueApiTokenOpts;
+use crate::prelude::*;
+
+#[instrument(skip_all)]
+pub async fn issue(opts: IssueApiTokenOpts) -> Result {
+    sentry::configure_scope(|scope| scope.set_tag("app", "issue-api-token"));
+    let db = database::mongodb::open(&opts.internal.mongodb_uri).await?;
+    let (token, api_token) = ApiToken::generate(opts.name);
+    api_token.insert(&db).await?;
+    AuditLogEntry::cli("issue-api-token", &api_token.name)
+        .insert(&db)
+        .await?;
+    info!(name = api_token.name.as_str(), "issued");
+    println!("{token}");
+    Ok(())
+}
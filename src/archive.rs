@@ -0,0 +1,43 @@
+//! Archival of the long inactive accounts, which keeps the hot collection small
+//! and the crawler throughput high.
+//!
+//! The web application transparently restores an archived account on its first view.
+
+use futures::TryStreamExt;
+
+use crate::database::{AccountIdProjection, AccountSnapshot, ArchivedAccount};
+use crate::opts::ArchiveAccountsOpts;
+use crate::prelude::*;
+
+/// Compresses the inactive accounts' snapshots and moves the accounts into the archive.
+///
+/// Intended to be run periodically, for example, weekly.
+#[instrument(skip_all)]
+pub async fn run(opts: ArchiveAccountsOpts) -> Result {
+    sentry::configure_scope(|scope| {
+        scope.set_tag("app", "archive-accounts");
+        scope.set_tag("realm", opts.realm);
+    });
+
+    let db = database::mongodb::open(&opts.internal.mongodb_uri).await?;
+    let before = now() - Duration::from_std(opts.inactive_for)?;
+    info!(realm = ?opts.realm, %before, "archiving…");
+
+    let mut account_ids = AccountIdProjection::retrieve_inactive_before(&db, opts.realm, before)
+        .await?
+        .map_ok(|account| account.id)
+        .try_chunks(opts.batch_size);
+    let (mut n_archived, mut n_snapshots) = (0_u64, 0_usize);
+    while let Some(batch) = account_ids.try_next().await? {
+        for account_id in &batch {
+            n_snapshots +=
+                AccountSnapshot::compress_all(&db, opts.realm, *account_id, opts.zstd_level)
+                    .await?;
+        }
+        n_archived += ArchivedAccount::archive(&db, opts.realm, &batch, before).await?;
+        info!(n_archived, n_snapshots, "archived the batch");
+    }
+
+    info!(n_archived, n_snapshots, "finished");
+    Ok(())
+}
@@ -0,0 +1,20 @@
+//! Review of the audit log.
+
+use crate::database::AuditLogEntry;
+use crate::opts::AuditLogOpts;
+use crate::prelude::*;
+
+#[instrument(skip_all)]
+pub async fn run(opts: AuditLogOpts) -> Result {
+    sentry::configure_scope(|scope| scope.set_tag("app", "audit-log"));
+    let db = database::mongodb::open(&opts.internal.mongodb_uri).await?;
+    let entries = AuditLogEntry::retrieve_page(&db, opts.page as u64, opts.per_page as i64).await?;
+    for entry in entries {
+        let ip_addr = entry.ip_addr.map_or_else(|| "-".to_string(), |ip_addr| ip_addr.to_string());
+        println!(
+            "{}\t{}\t{}\t{}\t{}",
+            entry.timestamp, entry.actor, ip_addr, entry.action, entry.details,
+        );
+    }
+    Ok(())
+}
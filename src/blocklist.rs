@@ -0,0 +1,39 @@
+//! Management of the abusive viewers' blocklist.
+
+use crate::database::AuditLogEntry;
+use crate::helpers::redis;
+use crate::opts::{BlocklistAction, BlocklistOpts};
+use crate::prelude::*;
+use crate::web::blocklist;
+
+#[instrument(skip_all)]
+pub async fn run(opts: BlocklistOpts) -> Result {
+    sentry::configure_scope(|scope| scope.set_tag("app", "blocklist"));
+    let redis = redis::connect(&opts.internal.redis_uri, opts.internal.redis_pool_size).await?;
+    let db = database::mongodb::open(&opts.internal.mongodb_uri).await?;
+    match opts.action {
+        BlocklistAction::Add { target, expire } => {
+            blocklist::add(&redis, target, expire).await?;
+            AuditLogEntry::cli("blocklist.add", format!("{target} expire={expire:?}"))
+                .insert(&db)
+                .await?;
+            info!(%target, ?expire, "blocked");
+        }
+        BlocklistAction::Remove { target } => {
+            let is_removed = blocklist::remove(&redis, target).await?;
+            AuditLogEntry::cli("blocklist.remove", target.to_string())
+                .insert(&db)
+                .await?;
+            info!(%target, is_removed, "unblocked");
+        }
+        BlocklistAction::List => {
+            for entry in blocklist::list(&redis).await? {
+                match entry.expires_at {
+                    Some(expires_at) => println!("{}\t{}", entry.target, expires_at),
+                    None => println!("{}\tpermanent", entry.target),
+                }
+            }
+        }
+    }
+    Ok(())
+}
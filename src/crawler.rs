@@ -1,19 +1,27 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use fred::pool::RedisPool;
+use futures::stream::PollNext;
 use futures::{stream, Stream, StreamExt, TryStreamExt};
 use itertools::Itertools;
 use tokio::sync::Mutex;
 
 use self::crawled_data::CrawledData;
-use self::metrics::CrawlerMetrics;
+use self::metrics::{CrawlerMetrics, LagAlert};
+use self::webhook::{CrawlEvent, Webhook};
+use crate::database::mongodb::traits::Upsert;
+use crate::helpers::redis;
 use crate::opts::{CrawlAccountsOpts, CrawlerOpts, SharedCrawlerOpts};
 use crate::prelude::*;
+use crate::wargaming::usage::ApiUsage;
 use crate::wargaming::WargamingApi;
 use crate::{database, wargaming};
 
 mod crawled_data;
+pub mod fast_lane;
 mod metrics;
+mod webhook;
 
 pub struct Crawler {
     api: WargamingApi,
@@ -22,6 +30,11 @@ pub struct Crawler {
     metrics: Mutex<CrawlerMetrics>,
     n_buffered_batches: usize,
     heartbeat_url: Option<String>,
+    webhook: Option<Webhook>,
+    zstd_level: Option<i32>,
+
+    /// Set when the fast lane is enabled.
+    redis: Option<RedisPool>,
 }
 
 /// Runs the full-featured account crawler, that infinitely scans all the accounts
@@ -34,15 +47,39 @@ pub async fn run_crawler(opts: CrawlerOpts) -> Result {
         scope.set_tag("realm", opts.shared.realm);
     });
 
-    let crawler = Crawler::new(&opts.shared, opts.heartbeat_url).await?;
+    let shard = database::Shard::new(opts.shard_index, opts.shard_count)?;
+    let redis = if opts.fast_lane || opts.shared.connections.api_daily_budget.is_some() {
+        let internal = &opts.shared.connections.internal;
+        Some(redis::connect(&internal.redis_uri, internal.redis_pool_size).await?)
+    } else {
+        None
+    };
+    let mut crawler = Crawler::new(&opts.shared, opts.heartbeat_url, redis.clone()).await?;
+    if let Some(webhook_url) = opts.webhook_url {
+        info!("the webhook is enabled");
+        crawler.webhook = Some(Webhook::new(webhook_url)?);
+    }
     let accounts = database::Account::get_sampled_stream(
         crawler.db.clone(),
         opts.shared.realm,
         opts.sample_size,
         Duration::from_std(opts.min_offset)?,
         opts.offset_scale,
+        shard,
     )?;
-    crawler.run(Box::pin(accounts)).await
+    match redis.filter(|_| opts.fast_lane) {
+        Some(redis) => {
+            info!("the fast lane is enabled");
+            let fast_lane = fast_lane::stream(redis, crawler.db.clone(), opts.shared.realm);
+            let accounts = stream::select_with_strategy(
+                Box::pin(fast_lane),
+                Box::pin(accounts),
+                |_: &mut ()| PollNext::Left,
+            );
+            crawler.run(accounts).await
+        }
+        None => crawler.run(Box::pin(accounts)).await,
+    }
 }
 
 /// Performs a very slow one-time account scan.
@@ -57,27 +94,42 @@ pub async fn crawl_accounts(opts: CrawlAccountsOpts) -> Result {
     let accounts = stream::iter(opts.start_id..opts.end_id)
         .map(|account_id| database::Account::new(opts.shared.realm, account_id))
         .map(Ok);
-    let crawler = Crawler::new(&opts.shared, None).await?;
+    let crawler = Crawler::new(&opts.shared, None, None).await?;
     crawler.run(accounts).await
 }
 
 impl Crawler {
-    pub async fn new(opts: &SharedCrawlerOpts, heartbeat_url: Option<String>) -> Result<Self> {
-        let api = WargamingApi::new(
-            &opts.connections.application_id,
-            opts.connections.api_timeout,
+    pub async fn new(
+        opts: &SharedCrawlerOpts,
+        heartbeat_url: Option<String>,
+        redis: Option<RedisPool>,
+    ) -> Result<Self> {
+        let mut api = WargamingApi::new(
+            opts.connections.application_ids.clone(),
+            opts.connections.api_timeouts(),
             opts.connections.max_api_rps,
         )?;
+        if let Some(redis) = &redis {
+            api = api.with_usage(ApiUsage::new(redis.clone(), opts.connections.api_daily_budget));
+        }
         let internal = &opts.connections.internal;
         let db = database::mongodb::open(&internal.mongodb_uri).await?;
 
         let this = Self {
             realm: opts.realm,
-            metrics: Mutex::new(CrawlerMetrics::new(&api.request_counter, opts.log_interval)),
+            metrics: Mutex::new(
+                CrawlerMetrics::new(&api, opts.log_interval).with_lag_alert(
+                    opts.lag_alert_threshold
+                        .map(|threshold| LagAlert::new(threshold, opts.lag_alert_intervals)),
+                ),
+            ),
             api,
             db,
             n_buffered_batches: opts.buffering.n_batches,
             heartbeat_url,
+            webhook: None,
+            zstd_level: opts.zstd_level,
+            redis,
         };
         Ok(this)
     }
@@ -130,10 +182,35 @@ impl Crawler {
 
         let mut metrics = self.metrics.lock().await;
         metrics.add_batch(batch_len, matched_len);
-        let is_metrics_logged = metrics.check(&self.api.request_counter);
+        let is_metrics_logged = metrics.check(&self.api);
+        if is_metrics_logged {
+            let db = self.db.clone();
+            let realm = self.realm;
+            tokio::spawn(async move {
+                if let Err(error) = database::CrawlerHeartbeat::new(realm).upsert(&db).await {
+                    warn!("failed to store the heartbeat: {:#}", error);
+                }
+            });
+        }
+        if let (true, Some(redis)) = (is_metrics_logged, &self.redis) {
+            let redis = redis.clone();
+            let realm = self.realm;
+            tokio::spawn(async move {
+                match fast_lane::len(&redis, realm).await {
+                    Ok(fast_lane_len) => info!(fast_lane_len),
+                    Err(error) => warn!("failed to retrieve the fast lane length: {:#}", error),
+                }
+            });
+        }
         if let (true, Some(heartbeat_url)) = (is_metrics_logged, &self.heartbeat_url) {
             tokio::spawn(reqwest::get(heartbeat_url.clone()));
         }
+        if let (true, Some(webhook)) = (is_metrics_logged, &self.webhook) {
+            let (n_dropped, n_failed) = webhook.take_counters();
+            if n_dropped != 0 || n_failed != 0 {
+                warn!(n_dropped, n_failed, "webhook events have been lost");
+            }
+        }
     }
 
     /// Match the batch's accounts to the account infos fetched from the API.
@@ -204,13 +281,42 @@ impl Crawler {
         };
         debug!(n_tank_snapshots = tank_snapshots.len(), "crawled");
 
+        let mut account_snapshot = database::AccountSnapshot::new(
+            self.realm,
+            &account_info,
+            tank_last_battle_times,
+            account.base_snapshot_time,
+        );
+        if let Some(level) = self.zstd_level {
+            account_snapshot.compress(level)?;
+        }
+        let n_battles = account_info.stats.n_total_battles();
+        let n_new_battles = account
+            .activity
+            .map(|activity| n_battles.saturating_sub(activity.n_battles));
+        let hourly_battles = n_new_battles
+            .filter(|n_new_battles| *n_new_battles != 0)
+            .map(|n_new_battles| {
+                database::HourlyBattles::new(
+                    self.realm,
+                    account_info.last_battle_time,
+                    n_new_battles,
+                )
+            });
         let account = database::Account {
             id: account.id,
             realm: self.realm,
             last_battle_time: Some(account_info.last_battle_time),
+            base_snapshot_time: Some(account_snapshot.full_snapshot_time()),
+            activity: Some(database::Activity::updated(
+                account.activity,
+                account.last_battle_time,
+                n_battles,
+                account_info.last_battle_time,
+            )),
+            nickname: Some(account_info.nickname.clone()),
+            created_at: Some(account_info.created_at),
         };
-        let account_snapshot =
-            database::AccountSnapshot::new(self.realm, &account_info, tank_last_battle_times);
         let rating_snapshot = database::RatingSnapshot::new(self.realm, &account_info);
 
         Ok(CrawledData {
@@ -218,6 +324,8 @@ impl Crawler {
             account_snapshot,
             tank_snapshots,
             rating_snapshot,
+            hourly_battles,
+            n_new_battles,
         })
     }
 
@@ -227,6 +335,14 @@ impl Crawler {
         debug!(last_battle_time = ?crawled_data.account.last_battle_time, "updating account…");
 
         crawled_data.upsert(&self.db).await?;
+        if let Some(webhook) = &self.webhook {
+            webhook.send(CrawlEvent {
+                realm: self.realm,
+                account_id: crawled_data.account.id,
+                last_battle_time: crawled_data.account_snapshot.last_battle_time.timestamp(),
+                n_battles: crawled_data.n_new_battles,
+            });
+        }
 
         self.metrics
             .lock()
@@ -9,6 +9,12 @@ pub struct CrawledData {
     pub account_snapshot: database::AccountSnapshot,
     pub tank_snapshots: Vec<database::TankSnapshot>,
     pub rating_snapshot: Option<database::RatingSnapshot>,
+
+    /// Bucketed new battles, [`None`] on the first crawl or without the new battles.
+    pub hourly_battles: Option<database::HourlyBattles>,
+
+    /// New battles since the previous crawl, [`None`] on the first crawl.
+    pub n_new_battles: Option<u32>,
 }
 
 impl CrawledData {
@@ -29,6 +35,9 @@ impl CrawledData {
         if let Some(rating_snapshot) = &self.rating_snapshot {
             rating_snapshot.upsert(into).await?;
         }
+        if let Some(hourly_battles) = &self.hourly_battles {
+            hourly_battles.upsert(into).await?;
+        }
         self.account.upsert(into).await?;
         debug!(elapsed = ?start_instant.elapsed());
         Ok(())
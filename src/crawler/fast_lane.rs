@@ -0,0 +1,121 @@
+//! Queue of the accounts viewed on the website, which the crawler picks up
+//! ahead of the sampled ones, so that the viewers see the fresh data on refresh.
+
+use fred::pool::RedisPool;
+use fred::prelude::*;
+use fred::types::RedisKey;
+use futures::stream::{iter, try_unfold};
+use futures::{Stream, TryStreamExt};
+use tokio::time::sleep;
+
+use crate::prelude::*;
+
+/// Maximum queue length, so that the queue does not grow indefinitely without a crawler.
+const MAX_LENGTH: i64 = 10000;
+
+/// Interval between checking the empty queue.
+const POLL_INTERVAL: time::Duration = time::Duration::from_secs(1);
+
+/// Pushes the accounts into the queue.
+#[derive(Clone)]
+pub struct FastLane {
+    redis: RedisPool,
+
+    /// An account is pushed at most once within this interval,
+    /// and not at all, if it has been crawled within this interval.
+    throttle: time::Duration,
+}
+
+impl FastLane {
+    pub const fn new(redis: RedisPool, throttle: time::Duration) -> Self {
+        Self { redis, throttle }
+    }
+
+    /// Pushes the account into the queue, unless it has already been crawled or pushed recently.
+    ///
+    /// Only the crawler updates the last battle time, so the recent one means that
+    /// the account has been crawled after that.
+    #[instrument(
+        skip_all,
+        level = "debug",
+        fields(realm = ?account.realm, account_id = account.id),
+    )]
+    pub async fn push(&self, account: &database::Account) -> Result {
+        if is_crawled_recently(account.last_battle_time, self.throttle, now()) {
+            debug!(?account.last_battle_time, "crawled recently");
+            return Ok(());
+        }
+        let (realm, account_id) = (account.realm, account.id);
+        let expire = Some(Expiration::EX(self.throttle.as_secs() as i64));
+        let is_set: Option<String> = self
+            .redis
+            .set(throttle_key(realm, account_id), 1, expire, Some(SetOptions::NX), false)
+            .await?;
+        if is_set.is_some() {
+            let queue_key = queue_key(realm);
+            self.redis.rpush::<(), _, _>(&queue_key, account_id).await?;
+            self.redis.ltrim::<(), _>(&queue_key, -MAX_LENGTH, -1).await?;
+            debug!("pushed");
+        }
+        Ok(())
+    }
+}
+
+/// Pops the queued accounts and streams them, waiting for the new ones when the queue is empty.
+pub fn stream(
+    redis: RedisPool,
+    db: mongodb::Database,
+    realm: wargaming::Realm,
+) -> impl Stream<Item = Result<database::Account>> {
+    try_unfold((redis, db), move |(redis, db)| async move {
+        loop {
+            let account_ids: Vec<wargaming::AccountId> =
+                redis.lpop(queue_key(realm), Some(100)).await?;
+            if account_ids.is_empty() {
+                sleep(POLL_INTERVAL).await;
+                continue;
+            }
+            debug!(n_accounts = account_ids.len(), "popped");
+            let accounts = database::Account::retrieve_many(&db, realm, &account_ids).await?;
+            return Ok::<_, Error>(Some((iter(accounts.into_iter().map(Ok)), (redis, db))));
+        }
+    })
+    .try_flatten()
+}
+
+pub async fn len(redis: &RedisPool, realm: wargaming::Realm) -> Result<usize> {
+    Ok(redis.llen(queue_key(realm)).await?)
+}
+
+/// Clock skew makes the future last battle time count as recent.
+fn is_crawled_recently(
+    last_battle_time: Option<DateTime>,
+    within: time::Duration,
+    now: DateTime,
+) -> bool {
+    last_battle_time.is_some_and(|last_battle_time| {
+        (now - last_battle_time).to_std().map_or(true, |elapsed| elapsed < within)
+    })
+}
+
+fn queue_key(realm: wargaming::Realm) -> RedisKey {
+    RedisKey::from(format!("crawler:fast-lane:{}", realm.to_str()))
+}
+
+fn throttle_key(realm: wargaming::Realm, account_id: wargaming::AccountId) -> RedisKey {
+    RedisKey::from(format!("crawler:fast-lane:{}:{}", realm.to_str(), account_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_crawled_recently_ok() {
+        let (within, now) = (time::Duration::from_secs(900), now());
+        assert!(!is_crawled_recently(None, within, now));
+        assert!(is_crawled_recently(Some(now - Duration::minutes(5)), within, now));
+        assert!(is_crawled_recently(Some(now + Duration::minutes(1)), within, now));
+        assert!(!is_crawled_recently(Some(now - Duration::minutes(20)), within, now));
+    }
+}
@@ -1,8 +1,12 @@
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::Ordering;
 
 use crate::helpers::average::Average;
+use crate::helpers::histogram::Histogram;
 use crate::prelude::*;
 use crate::wargaming;
+use crate::wargaming::WargamingApi;
+
+const HOUR_SECS: f64 = 3600.0;
 
 pub struct CrawlerMetrics {
     log_interval: time::Duration,
@@ -12,28 +16,69 @@ pub struct CrawlerMetrics {
     start_request_count: u32,
     n_accounts: u32,
     last_account_id: wargaming::AccountId,
-    total_lag: Duration,
+
+    /// Account lags in seconds.
+    lags: Histogram,
+
+    lag_alert: Option<LagAlert>,
+}
+
+/// Tracks the consecutive metrics intervals, which exceed the account lag threshold.
+pub struct LagAlert {
+    threshold_secs: u64,
+    n_intervals: usize,
+    n_exceeded: usize,
+}
+
+impl LagAlert {
+    pub fn new(threshold: time::Duration, n_intervals: usize) -> Self {
+        info!(?threshold, n_intervals, "lag alert enabled");
+        Self {
+            threshold_secs: threshold.as_secs(),
+            n_intervals,
+            n_exceeded: 0,
+        }
+    }
+
+    /// Accounts for the interval's 95th percentile lag and tells whether to fire the alert.
+    /// The alert fires once per streak.
+    fn check(&mut self, lag_p95_secs: Option<u64>) -> bool {
+        if lag_p95_secs.is_some_and(|lag_secs| lag_secs > self.threshold_secs) {
+            self.n_exceeded += 1;
+            self.n_exceeded == self.n_intervals
+        } else {
+            self.n_exceeded = 0;
+            false
+        }
+    }
 }
 
 impl CrawlerMetrics {
-    pub fn new(request_counter: &AtomicU32, log_interval: time::Duration) -> Self {
+    pub fn new(api: &WargamingApi, log_interval: time::Duration) -> Self {
         info!(?log_interval);
         Self {
-            start_request_count: request_counter.load(Ordering::Relaxed),
+            start_request_count: api.request_counter.load(Ordering::Relaxed),
             n_accounts: 0,
             last_account_id: 0,
             reset_instant: Instant::now(),
             average_batch_fill_level: Average::default(),
             log_interval,
-            total_lag: Duration::zero(),
+            lags: Histogram::default(),
+            lag_alert: None,
         }
     }
 
+    pub const fn with_lag_alert(mut self, lag_alert: Option<LagAlert>) -> Self {
+        self.lag_alert = lag_alert;
+        self
+    }
+
     pub fn add_account(&mut self, account: &database::AccountSnapshot) {
         self.n_accounts += 1;
         self.last_account_id = account.account_id;
         if account.last_battle_time.timestamp() != 0 {
-            self.total_lag = self.total_lag + (now() - account.last_battle_time);
+            let lag_secs = (now() - account.last_battle_time).num_seconds();
+            self.lags.record(lag_secs.max(0) as u64);
         }
     }
 
@@ -42,12 +87,19 @@ impl CrawlerMetrics {
             .push(matched_len as f64 / batch_len as f64);
     }
 
-    pub fn check(&mut self, request_counter: &AtomicU32) -> bool {
+    pub fn check(&mut self, api: &WargamingApi) -> bool {
         let now = Instant::now();
         let elapsed = self.reset_instant.elapsed();
         if elapsed >= self.log_interval {
-            let request_counter = request_counter.load(Ordering::Relaxed);
+            let request_counter = api.request_counter.load(Ordering::Relaxed);
             self.log(request_counter, elapsed);
+            Self::log_latencies(api);
+            let errors = std::mem::take(&mut *api.errors.lock().unwrap());
+            info!(?errors);
+            let lag_p95_secs = self.lags.quantile(0.95);
+            if self.lag_alert.as_mut().is_some_and(|alert| alert.check(lag_p95_secs)) {
+                self.alert(elapsed, &errors);
+            }
             self.reset(request_counter, now);
             true
         } else {
@@ -64,24 +116,66 @@ impl CrawlerMetrics {
             rps = %format!("{:.1}", n_requests as f64 / elapsed_secs),
             fill = %format!("{:.1}%", self.average_batch_fill_level.average() * 100.0),
             apm = %format!("{:.0}", self.n_accounts as f64 / elapsed_mins),
-            lag_hrs = %format!("{:.1}", self.lag_hours()),
+            lag_p50_hrs = %format!("{:.1}", self.lag_hours(0.50)),
+            lag_p95_hrs = %format!("{:.1}", self.lag_hours(0.95)),
+            lag_p99_hrs = %format!("{:.1}", self.lag_hours(0.99)),
             id = self.last_account_id,
         );
     }
 
+    /// Reports the lag along with the throughput and the API errors, the error goes to Sentry.
+    fn alert(&self, elapsed: time::Duration, errors: &AHashMap<&'static str, u32>) {
+        let elapsed_mins = elapsed.as_secs_f64() / 60.0;
+        error!(
+            lag_p95_hrs = %format!("{:.1}", self.lag_hours(0.95)),
+            apm = %format!("{:.0}", self.n_accounts as f64 / elapsed_mins),
+            fill = %format!("{:.1}%", self.average_batch_fill_level.average() * 100.0),
+            ?errors,
+            "the account lag has exceeded the threshold for a while",
+        );
+    }
+
+    /// Logs and resets the API response time percentiles.
+    fn log_latencies(api: &WargamingApi) {
+        let latencies = std::mem::take(&mut *api.latencies.lock().unwrap());
+        for (path, histogram) in latencies {
+            info!(
+                path,
+                n_requests = histogram.len(),
+                p50_ms = histogram.quantile(0.50),
+                p95_ms = histogram.quantile(0.95),
+                p99_ms = histogram.quantile(0.99),
+            );
+        }
+    }
+
     fn reset(&mut self, request_counter: u32, now: Instant) {
         self.reset_instant = now;
         self.average_batch_fill_level = Default::default();
         self.start_request_count = request_counter;
         self.n_accounts = 0;
-        self.total_lag = Duration::zero();
+        self.lags = Histogram::default();
     }
 
-    fn lag_hours(&self) -> f64 {
-        if self.n_accounts != 0 {
-            (self.total_lag.num_seconds() as f64) / (self.n_accounts as f64) / 3600.0
-        } else {
-            0f64
-        }
+    fn lag_hours(&self, quantile: f64) -> f64 {
+        self.lags
+            .quantile(quantile)
+            .map_or(0.0, |lag_secs| lag_secs as f64 / HOUR_SECS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lag_alert_ok() {
+        let mut lag_alert = LagAlert::new(time::Duration::from_secs(3600), 2);
+        assert!(!lag_alert.check(Some(7200)));
+        assert!(!lag_alert.check(Some(1800)));
+        assert!(!lag_alert.check(Some(7200)));
+        assert!(lag_alert.check(Some(7200)));
+        assert!(!lag_alert.check(Some(7200)), "the alert must fire once per streak");
+        assert!(!lag_alert.check(None));
     }
 }
@@ -0,0 +1,105 @@
+//! Outgoing webhook, which gets an event for every crawled account with the new battles,
+//! so that the downstream systems could react in near real time.
+//!
+//! The events are sent in the background and dropped, when the receiver can't keep up,
+//! so that a slow receiver never slows down the crawler.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+use serde::Serialize;
+use tokio::sync::Semaphore;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct Webhook {
+    url: Arc<String>,
+    client: reqwest::Client,
+
+    /// Limits the number of the requests in flight.
+    permits: Arc<Semaphore>,
+
+    n_dropped: Arc<AtomicU32>,
+    n_failed: Arc<AtomicU32>,
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct CrawlEvent {
+    pub realm: wargaming::Realm,
+    pub account_id: wargaming::AccountId,
+
+    /// Timestamp of the account's last battle in seconds.
+    pub last_battle_time: i64,
+
+    /// New battles since the previous crawl, `null` on the first crawl.
+    pub n_battles: Option<u32>,
+}
+
+impl Webhook {
+    const MAX_IN_FLIGHT: usize = 100;
+
+    pub fn new(url: String) -> Result<Self> {
+        let client = reqwest::ClientBuilder::new()
+            .timeout(time::Duration::from_secs(10))
+            .build()?;
+        Ok(Self {
+            url: Arc::new(url),
+            client,
+            permits: Arc::new(Semaphore::new(Self::MAX_IN_FLIGHT)),
+            n_dropped: Arc::default(),
+            n_failed: Arc::default(),
+        })
+    }
+
+    /// Posts the event in the background, or drops it, if too many requests are in flight.
+    pub fn send(&self, event: CrawlEvent) {
+        let Ok(permit) = Arc::clone(&self.permits).try_acquire_owned() else {
+            self.n_dropped.fetch_add(1, Ordering::Relaxed);
+            return;
+        };
+        let this = self.clone();
+        tokio::spawn(async move {
+            if let Err(error) = this.post(&event).await {
+                debug!(?event, "failed to post the event: {:#}", error);
+                this.n_failed.fetch_add(1, Ordering::Relaxed);
+            }
+            drop(permit);
+        });
+    }
+
+    /// Resets the counters of the dropped and failed events since the previous call.
+    pub fn take_counters(&self) -> (u32, u32) {
+        (self.n_dropped.swap(0, Ordering::Relaxed), self.n_failed.swap(0, Ordering::Relaxed))
+    }
+
+    async fn post(&self, event: &CrawlEvent) -> Result {
+        self.client
+            .post(self.url.as_str())
+            .json(event)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_event_ok() -> Result {
+        let event = CrawlEvent {
+            realm: wargaming::Realm::Europe,
+            account_id: 503652977,
+            last_battle_time: 1660000000,
+            n_battles: Some(3),
+        };
+        assert_eq!(
+            serde_json::to_string(&event)?,
+            r#"{"realm":"eu","account_id":503652977,"last_battle_time":1660000000,"n_battles":3}"#,
+        );
+        Ok(())
+    }
+}
@@ -1,26 +1,44 @@
-use mongodb::Database;
+use mongodb::options::ClientOptions;
+use mongodb::{Client, Database};
 
 use crate::database::mongodb::traits::Indexes;
 use crate::prelude::*;
 
 pub mod models;
+pub mod query_budget;
 pub mod traits;
 
 #[instrument(level = "debug")]
 pub async fn open(uri: &str) -> Result<Database> {
     info!(uri, "connecting…");
-    let client = mongodb::Client::with_uri_str(uri)
+    let mut options = ClientOptions::parse(uri)
         .await
         .context("failed to parse the specified MongoDB URI")?;
+    options.command_event_handler = Some(Arc::new(query_budget::QueryBudgetHandler));
+    let client = Client::with_options(options)?;
     let database = client
         .default_database()
         .ok_or_else(|| anyhow!("MongoDB database name is not specified"))?;
 
     info!("ensuring indexes…");
     models::Account::ensure_indexes(&database).await?;
+    models::ArchivedAccount::ensure_indexes(&database).await?;
+    models::AccountAnomaly::ensure_indexes(&database).await?;
+    models::AccountLink::ensure_indexes(&database).await?;
+    models::AccountNote::ensure_indexes(&database).await?;
+    models::AccountRecords::ensure_indexes(&database).await?;
     models::AccountSnapshot::ensure_indexes(&database).await?;
+    models::Announcement::ensure_indexes(&database).await?;
+    models::AuditLogEntry::ensure_indexes(&database).await?;
     models::TankSnapshot::ensure_indexes(&database).await?;
     models::RatingSnapshot::ensure_indexes(&database).await?;
+    models::CrawlerHeartbeat::ensure_indexes(&database).await?;
+    models::HourlyBattles::ensure_indexes(&database).await?;
+    models::NicknameResolution::ensure_indexes(&database).await?;
+    models::TankSnapshotRollup::ensure_indexes(&database).await?;
+    models::TankSnapshotRollupState::ensure_indexes(&database).await?;
+    models::TankopediaSnapshot::ensure_indexes(&database).await?;
+    models::VehicleAttributes::ensure_indexes(&database).await?;
 
     info!("connected");
     Ok(database)
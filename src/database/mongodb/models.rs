@@ -1,11 +1,37 @@
 pub use self::account::*;
+pub use self::account_anomaly::*;
+pub use self::account_link::*;
+pub use self::account_records::*;
+pub use self::account_note::*;
 pub use self::account_snapshot::*;
+pub use self::announcement::*;
+pub use self::api_token::*;
+pub use self::audit_log_entry::*;
+pub use self::crawler_heartbeat::*;
+pub use self::hourly_battles::*;
+pub use self::nickname_resolution::*;
 pub use self::rating_snapshot::*;
 pub use self::root::*;
 pub use self::tank_snapshot::*;
+pub use self::tank_snapshot_rollup::*;
+pub use self::tankopedia_snapshot::*;
+pub use self::vehicle_attributes::*;
 
 mod account;
+mod account_anomaly;
+mod account_link;
+mod account_records;
+mod account_note;
 mod account_snapshot;
+mod announcement;
+mod api_token;
+mod audit_log_entry;
+mod crawler_heartbeat;
+mod hourly_battles;
+mod nickname_resolution;
 mod rating_snapshot;
 mod root;
 mod tank_snapshot;
+mod tank_snapshot_rollup;
+mod tankopedia_snapshot;
+mod vehicle_attributes;
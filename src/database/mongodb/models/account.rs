@@ -1,5 +1,6 @@
 use futures::stream::{iter, try_unfold};
 use futures::{Stream, TryStreamExt};
+use itertools::Itertools;
 use mongodb::bson::{doc, Document};
 use mongodb::options::*;
 use mongodb::{bson, Database, IndexModel};
@@ -8,6 +9,8 @@ use serde::{Deserialize, Serialize};
 use serde_with::TryFromInto;
 use statrs::distribution::Exp;
 
+pub use self::activity::*;
+pub use self::archive::*;
 pub use self::id_projection::*;
 pub use self::random::*;
 pub use self::rating::*;
@@ -15,6 +18,8 @@ pub use self::tank_last_battle_time::*;
 use crate::database::mongodb::traits::*;
 use crate::prelude::*;
 
+mod activity;
+mod archive;
 mod id_projection;
 mod random;
 mod rating;
@@ -34,6 +39,23 @@ pub struct Account {
     #[serde(rename = "lbts")]
     #[serde_as(as = "Option<bson::DateTime>")]
     pub last_battle_time: Option<DateTime>,
+
+    /// Last battle time of the latest full account snapshot,
+    /// which the following snapshots are delta-encoded against.
+    #[serde(rename = "bts", default)]
+    #[serde_as(as = "Option<bson::DateTime>")]
+    pub base_snapshot_time: Option<DateTime>,
+
+    #[serde(rename = "act", default, skip_serializing_if = "Option::is_none")]
+    pub activity: Option<Activity>,
+
+    /// Last crawled nickname, so that the profile could be shown while the API is down.
+    #[serde(rename = "nn", default, skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+
+    #[serde(rename = "cr", default, skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<bson::DateTime>")]
+    pub created_at: Option<DateTime>,
 }
 
 impl TypedDocument for Account {
@@ -42,7 +64,7 @@ impl TypedDocument for Account {
 
 #[async_trait]
 impl Indexes for Account {
-    type I = [IndexModel; 2];
+    type I = [IndexModel; 3];
 
     fn indexes() -> Self::I {
         [
@@ -53,6 +75,16 @@ impl Indexes for Account {
                 .keys(doc! { "rlm": 1, "aid": 1 })
                 .options(IndexOptions::builder().unique(true).build())
                 .build(),
+            // Differs from the plain one by the keys, which MongoDB before 5.0 requires:
+            IndexModel::builder()
+                .keys(doc! { "rlm": 1, "act.s": -1, "lbts": -1 })
+                .options(
+                    IndexOptions::builder()
+                        .name("rlm_1_act.s_-1_lbts_-1_active".to_string())
+                        .partial_filter_expression(Self::active_filter())
+                        .build(),
+                )
+                .build(),
         ]
     }
 }
@@ -63,10 +95,25 @@ impl Account {
             id: account_id,
             realm,
             last_battle_time: None,
+            base_snapshot_time: None,
+            activity: None,
+            nickname: None,
+            created_at: None,
         }
     }
+
+    /// Current activity level, [`None`] means the account hasn't been crawled yet.
+    pub fn activity_level(&self) -> Option<ActivityLevel> {
+        Some(self.activity?.level(self.last_battle_time?, now()))
+    }
+
+    fn active_filter() -> Document {
+        doc! { "act.s": { "$gte": Activity::ACTIVE_SCORE } }
+    }
 }
 
+impl InsertManyNew for Account {}
+
 #[async_trait]
 impl Upsert for Account {
     type Update = Document;
@@ -82,7 +129,44 @@ impl Upsert for Account {
     }
 }
 
+/// Deterministic subset of the account space, so that multiple crawlers do not overlap.
+///
+/// Account IDs are issued sequentially, hence, the remainder alone distributes them evenly.
+#[derive(Copy, Clone, Debug)]
+pub struct Shard {
+    index: u32,
+    count: u32,
+}
+
+impl Default for Shard {
+    fn default() -> Self {
+        Self { index: 0, count: 1 }
+    }
+}
+
+impl Shard {
+    pub fn new(index: u32, count: u32) -> Result<Self> {
+        if count == 0 {
+            bail!("the shard count must be positive");
+        }
+        if index >= count {
+            bail!("the shard index {} must be less than the shard count {}", index, count);
+        }
+        Ok(Self { index, count })
+    }
+
+    /// Adds the shard condition to the query filter.
+    fn apply(self, filter: &mut Document) {
+        if self.count != 1 {
+            filter.insert("aid", doc! { "$mod": [self.count as i64, self.index as i64] });
+        }
+    }
+}
+
 impl Account {
+    /// Up to this part of the sample is taken by the active accounts.
+    const ACTIVE_SAMPLE_DIVISOR: usize = 4;
+
     #[instrument(skip_all, level = "debug")]
     pub fn get_sampled_stream(
         database: Database,
@@ -90,8 +174,9 @@ impl Account {
         sample_size: usize,
         min_offset: Duration,
         offset_scale: time::Duration,
+        shard: Shard,
     ) -> Result<impl Stream<Item = Result<Self>>> {
-        info!(sample_size, %min_offset, ?offset_scale);
+        info!(sample_size, %min_offset, ?offset_scale, ?shard);
         let offset_scale_secs = offset_scale.as_secs_f64();
         let exp1 = Exp::new(1.0)?;
         let stream = try_unfold((1, database), move |(sample_number, database)| async move {
@@ -99,7 +184,8 @@ impl Account {
                 Duration::seconds((thread_rng().sample::<f64, _>(exp1) * offset_scale_secs) as i64);
             let before = Utc::now() - min_offset - offset;
             debug!(sample_number, ?before, "retrieving a sample…");
-            let sample = Account::retrieve_sample(&database, realm, before, sample_size).await?;
+            let sample =
+                Account::retrieve_sample(&database, realm, before, sample_size, shard).await?;
             debug!(sample_number, "retrieved");
             Ok::<_, Error>(Some((iter(sample.into_iter().map(Ok)), (sample_number + 1, database))))
         })
@@ -107,14 +193,67 @@ impl Account {
         Ok(stream)
     }
 
+    /// Estimated number of the tracked accounts, taken from the collection metadata.
+    #[instrument(skip_all, level = "debug")]
+    pub async fn estimated_count(in_: &Database) -> Result<u64> {
+        Ok(Self::collection(in_).estimated_document_count(None).await?)
+    }
+
+    /// Exact number of the realm's tracked accounts, counts the unique index.
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm))]
+    pub async fn count(in_: &Database, realm: wargaming::Realm) -> Result<u64> {
+        let filter = doc! { "rlm": realm.to_str() };
+        Ok(Self::collection(in_).count_documents(filter, None).await?)
+    }
+
+    /// Estimates the realm's current battles per hour from the activity scores
+    /// of the accounts, which have played within the last day.
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm))]
+    pub async fn estimate_battles_per_hour(
+        from: &Database,
+        realm: wargaming::Realm,
+        now: DateTime,
+    ) -> Result<f64> {
+        let tau_millis = Activity::TAU_DAYS * 86400.0 * 1000.0;
+        let current_score = doc! {
+            "$multiply": [
+                "$act.s",
+                { "$exp": { "$divide": [{ "$subtract": ["$lbts", now] }, tau_millis] } },
+            ],
+        };
+        let pipeline = [
+            doc! {
+                "$match": {
+                    "rlm": realm.to_str(),
+                    "lbts": { "$gte": now - Duration::days(1) },
+                    "act": { "$exists": true },
+                },
+            },
+            doc! { "$group": { "_id": null, "s": { "$sum": current_score } } },
+        ];
+        let result = Self::collection(from)
+            .aggregate(pipeline, None)
+            .await?
+            .try_next()
+            .await?;
+        match result {
+            Some(result) => Ok(result.get_f64("s")? / 24.0),
+            None => Ok(0.0),
+        }
+    }
+
     /// Ensures that the account exists in the database.
-    /// Does nothing if it exists, inserts – otherwise.
+    /// Does nothing if it exists, restores it from the archive or inserts the never crawled
+    /// stub – otherwise, so that the crawler picks up the account viewed on the website.
     #[instrument(skip_all, level = "debug", fields(realm = ?realm, account_id = account_id))]
     pub async fn ensure_exists(
         in_: &Database,
         realm: wargaming::Realm,
         account_id: wargaming::AccountId,
     ) -> Result {
+        if ArchivedAccount::restore(in_, realm, account_id).await? {
+            return Ok(());
+        }
         let filter = doc! { "rlm": realm.to_str(), "aid": account_id };
         let update = doc! { "$setOnInsert": { "lbts": null } };
         let options = UpdateOptions::builder().upsert(true).build();
@@ -131,6 +270,7 @@ impl Account {
         realm: wargaming::Realm,
         before: DateTime,
         sample_size: usize,
+        shard: Shard,
     ) -> Result<Vec<Account>> {
         debug!(sample_size, "retrieving…");
         let start_instant = Instant::now();
@@ -138,7 +278,8 @@ impl Account {
         // Retrieve new accounts:
         let mut accounts = {
             debug!("querying new accounts…");
-            let filter = doc! { "rlm": realm.to_str(), "lbts": null };
+            let mut filter = doc! { "rlm": realm.to_str(), "lbts": null };
+            shard.apply(&mut filter);
             let options = FindOptions::builder().limit(sample_size as i64).build();
             let new_accounts = Self::find_vec(from, filter, options).await?;
             debug!(
@@ -148,13 +289,36 @@ impl Account {
             new_accounts
         };
 
+        // Reserve a part of the sample for the active accounts:
+        if accounts.len() != sample_size {
+            debug!("querying active accounts…");
+            let mut filter = doc! {
+                "rlm": realm.to_str(),
+                "$and": [ { "lbts": { "$ne": null } }, { "lbts": { "$lte": before } } ],
+            };
+            filter.extend(Self::active_filter());
+            shard.apply(&mut filter);
+            let limit = (sample_size - accounts.len()).div_ceil(Self::ACTIVE_SAMPLE_DIVISOR);
+            let options = FindOptions::builder()
+                .sort(doc! { "lbts": -1 })
+                .limit(limit as i64)
+                .build();
+            let active_accounts = Self::find_vec(from, filter, options).await?;
+            debug!(
+                n_active_accounts = active_accounts.len(),
+                elapsed = ?start_instant.elapsed(),
+            );
+            accounts.extend(active_accounts);
+        }
+
         // Retrieve random selection of accounts:
         if accounts.len() != sample_size {
             debug!("querying random accounts…");
-            let filter = doc! {
+            let mut filter = doc! {
                 "rlm": realm.to_str(),
                 "$and": [ { "lbts": { "$ne": null } }, { "lbts": { "$lte": before } } ],
             };
+            shard.apply(&mut filter);
             let options = FindOptions::builder()
                 .sort(doc! { "lbts": -1 })
                 .limit((sample_size - accounts.len()) as i64)
@@ -167,9 +331,34 @@ impl Account {
             accounts.extend(random_accounts);
         };
 
+        // The active accounts may be sampled twice:
+        let accounts = accounts.into_iter().unique_by(|account| account.id).collect();
         Ok(accounts)
     }
 
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm, account_id = account_id))]
+    pub async fn retrieve(
+        from: &Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+    ) -> Result<Option<Account>> {
+        Self::collection(from)
+            .find_one(doc! { "rlm": realm.to_str(), "aid": account_id }, None)
+            .await
+            .with_context(|| format!("failed to retrieve the account #{account_id}"))
+    }
+
+    /// Retrieves the specified accounts, skipping the missing ones.
+    #[instrument(skip_all, level = "debug", fields(n_accounts = account_ids.len()))]
+    pub async fn retrieve_many(
+        from: &Database,
+        realm: wargaming::Realm,
+        account_ids: &[wargaming::AccountId],
+    ) -> Result<Vec<Account>> {
+        let filter = doc! { "rlm": realm.to_str(), "aid": { "$in": account_ids } };
+        Self::find_vec(from, filter, None).await
+    }
+
     pub async fn sample_account(from: &Database, realm: wargaming::Realm) -> Result<Account> {
         let filter = doc! {
             "rlm": realm.to_str(),
@@ -185,3 +374,36 @@ impl Account {
         Ok(account)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shard_new_error() {
+        assert!(Shard::new(0, 0).is_err());
+        assert!(Shard::new(2, 2).is_err());
+    }
+
+    #[test]
+    fn shard_apply_ok() -> Result {
+        let mut filter = doc! { "rlm": "eu" };
+        Shard::new(1, 3)?.apply(&mut filter);
+        assert_eq!(filter, doc! { "rlm": "eu", "aid": { "$mod": [3_i64, 1_i64] } });
+
+        let mut filter = doc! { "rlm": "eu" };
+        Shard::default().apply(&mut filter);
+        assert_eq!(filter, doc! { "rlm": "eu" });
+        Ok(())
+    }
+
+    #[test]
+    fn indexes_have_distinct_keys_ok() {
+        let indexes = Account::indexes();
+        for (i, left) in indexes.iter().enumerate() {
+            for right in &indexes[i + 1..] {
+                assert_ne!(left.keys, right.keys);
+            }
+        }
+    }
+}
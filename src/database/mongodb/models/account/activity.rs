@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use serde_with::TryFromInto;
+
+use crate::prelude::*;
+
+/// Exponentially decayed number of battles per day.
+///
+/// Every crawl decays the previous score by the time elapsed since the previous last battle
+/// and adds the new battles, so that a steady pace converges to the actual battles per day.
+#[serde_with::serde_as]
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Default)]
+pub struct Activity {
+    /// Battles per day as of the account's last battle time.
+    #[serde(rename = "s")]
+    pub score: f64,
+
+    /// Total battles as of the last crawl, which the next crawl's delta is counted against.
+    #[serde_as(as = "TryFromInto<i32>")]
+    #[serde(rename = "nb")]
+    pub n_battles: u32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ActivityLevel {
+    Active,
+    Casual,
+    Dormant,
+}
+
+impl Activity {
+    /// Decay time constant in days.
+    pub const TAU_DAYS: f64 = 7.0;
+
+    /// Accounts with the higher score are crawled with the priority.
+    pub const ACTIVE_SCORE: f64 = 5.0;
+
+    const CASUAL_SCORE: f64 = 0.5;
+
+    /// Updates the activity with the newly crawled account.
+    ///
+    /// The score is unknown on the first crawl, so it starts from zero.
+    pub fn updated(
+        previous: Option<Self>,
+        previous_last_battle_time: Option<DateTime>,
+        n_battles: u32,
+        last_battle_time: DateTime,
+    ) -> Self {
+        let score = match (previous, previous_last_battle_time) {
+            (Some(previous), Some(previous_last_battle_time)) => {
+                let n_new_battles = n_battles.saturating_sub(previous.n_battles);
+                Self::decay(previous.score, last_battle_time - previous_last_battle_time)
+                    + n_new_battles as f64 / Self::TAU_DAYS
+            }
+            _ => 0.0,
+        };
+        Self { score, n_battles }
+    }
+
+    /// Score as of now, given that the account hasn't played since its last battle time.
+    pub fn current_score(self, last_battle_time: DateTime, now: DateTime) -> f64 {
+        Self::decay(self.score, now - last_battle_time)
+    }
+
+    pub fn level(self, last_battle_time: DateTime, now: DateTime) -> ActivityLevel {
+        let score = self.current_score(last_battle_time, now);
+        if score >= Self::ACTIVE_SCORE {
+            ActivityLevel::Active
+        } else if score >= Self::CASUAL_SCORE {
+            ActivityLevel::Casual
+        } else {
+            ActivityLevel::Dormant
+        }
+    }
+
+    fn decay(score: f64, elapsed: Duration) -> f64 {
+        let elapsed_days = elapsed.num_seconds().max(0) as f64 / 86400.0;
+        score * (-elapsed_days / Self::TAU_DAYS).exp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn updated_converges_ok() {
+        let mut activity = Activity::updated(None, None, 100, Utc.timestamp_opt(0, 0).unwrap());
+        assert_eq!(activity.score, 0.0);
+
+        // 10 battles a day for half a year:
+        for day in 1..=180 {
+            activity = Activity::updated(
+                Some(activity),
+                Some(Utc.timestamp_opt((day - 1) * 86400, 0).unwrap()),
+                activity.n_battles + 10,
+                Utc.timestamp_opt(day * 86400, 0).unwrap(),
+            );
+        }
+        assert!((activity.score - 10.0).abs() < 1.0, "score: {}", activity.score);
+    }
+
+    #[test]
+    fn level_ok() {
+        let activity = Activity {
+            score: 10.0,
+            n_battles: 1000,
+        };
+        let last_battle_time = Utc.timestamp_opt(0, 0).unwrap();
+        assert_eq!(activity.level(last_battle_time, last_battle_time), ActivityLevel::Active);
+        let now = last_battle_time + Duration::days(7);
+        assert_eq!(activity.level(last_battle_time, now), ActivityLevel::Casual);
+        let now = last_battle_time + Duration::days(60);
+        assert_eq!(activity.level(last_battle_time, now), ActivityLevel::Dormant);
+    }
+}
@@ -0,0 +1,102 @@
+use mongodb::bson::{doc, Document};
+use mongodb::options::{IndexOptions, UpdateOptions};
+use mongodb::{bson, Database, IndexModel};
+use serde::{Deserialize, Serialize};
+
+use crate::database::mongodb::traits::{Indexes, TypedDocument};
+use crate::database::Account;
+use crate::prelude::*;
+use crate::wargaming;
+
+/// Account, which hasn't played for so long that it's been moved out of the hot collection,
+/// so that the crawler does not spend the requests on it.
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ArchivedAccount(pub Account);
+
+impl TypedDocument for ArchivedAccount {
+    const NAME: &'static str = "accounts_archive";
+}
+
+#[async_trait]
+impl Indexes for ArchivedAccount {
+    type I = [IndexModel; 1];
+
+    fn indexes() -> Self::I {
+        [IndexModel::builder()
+            .keys(doc! { "rlm": 1, "aid": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build()]
+    }
+}
+
+impl ArchivedAccount {
+    /// Moves the specified accounts, which are still inactive, into the archive.
+    ///
+    /// Returns the number of the archived accounts.
+    #[instrument(skip_all, level = "debug", fields(n_accounts = account_ids.len()))]
+    pub async fn archive(
+        db: &Database,
+        realm: wargaming::Realm,
+        account_ids: &[wargaming::AccountId],
+        before: DateTime,
+    ) -> Result<u64> {
+        let filter = doc! {
+            "rlm": realm.to_str(),
+            "aid": { "$in": account_ids },
+            "lbts": { "$lt": before },
+        };
+        let pipeline = [
+            doc! { "$match": filter.clone() },
+            doc! { "$project": { "_id": 0 } },
+            doc! {
+                "$merge": {
+                    "into": Self::NAME,
+                    "on": ["rlm", "aid"],
+                    "whenMatched": "replace",
+                    "whenNotMatched": "insert",
+                }
+            },
+        ];
+        Account::collection(db)
+            .aggregate(pipeline, None)
+            .await
+            .context("failed to copy the accounts into the archive")?;
+
+        // An account crawled in the meantime does not match anymore and stays in the hot collection.
+        let result = Account::collection(db)
+            .delete_many(filter, None)
+            .await
+            .context("failed to delete the archived accounts")?;
+        Ok(result.deleted_count)
+    }
+
+    /// Moves the account back from the archive, if it's there.
+    ///
+    /// Returns whether the account has been restored.
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm, account_id = account_id))]
+    pub async fn restore(
+        db: &Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+    ) -> Result<bool> {
+        let filter = doc! { "rlm": realm.to_str(), "aid": account_id };
+        let archived = Self::collection(db)
+            .find_one_and_delete(filter.clone(), None)
+            .await
+            .with_context(|| format!("failed to take the account #{account_id} from the archive"))?;
+        let Some(Self(account)) = archived else {
+            return Ok(false);
+        };
+
+        // Should the account be re-inserted in the meantime, the fresher one wins.
+        let update: Document = doc! { "$setOnInsert": bson::to_bson(&account)? };
+        let options = UpdateOptions::builder().upsert(true).build();
+        Account::collection(db)
+            .update_one(filter, update, options)
+            .await
+            .with_context(|| format!("failed to restore the account #{account_id}"))?;
+        info!(?realm, account_id, "restored from the archive");
+        Ok(true)
+    }
+}
@@ -47,4 +47,19 @@ impl AccountIdProjection {
             .build();
         Ok(Self::collection(from).find(filter, options).await?)
     }
+
+    /// Streams the accounts, which haven't played since the specified moment.
+    #[instrument(skip_all, level = "info", fields(realm = ?realm, before = ?before))]
+    pub async fn retrieve_inactive_before(
+        from: &Database,
+        realm: wargaming::Realm,
+        before: DateTime,
+    ) -> Result<impl Stream<Item = Result<Self, mongodb::error::Error>>> {
+        let filter = doc! { "rlm": realm.to_str(), "lbts": { "$lt": before } };
+        let options = FindOptions::builder()
+            .projection(doc! { "_id": 0, "aid": 1 })
+            .batch_size(10000)
+            .build();
+        Ok(Self::collection(from).find(filter, options).await?)
+    }
 }
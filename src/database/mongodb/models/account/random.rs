@@ -56,6 +56,14 @@ pub struct RandomStatsSnapshot {
     #[serde_as(as = "TryFromInto<i32>")]
     #[serde(default, rename = "spot", skip_serializing_if = "is_default")]
     pub n_spotted: u32,
+
+    #[serde_as(as = "TryFromInto<i64>")]
+    #[serde(default, rename = "dmgb", skip_serializing_if = "is_default")]
+    pub damage_blocked: u64,
+
+    #[serde_as(as = "TryFromInto<i64>")]
+    #[serde(default, rename = "dmga", skip_serializing_if = "is_default")]
+    pub damage_assisted: u64,
 }
 
 impl NBattles for RandomStatsSnapshot {
@@ -102,6 +110,8 @@ impl From<wargaming::BasicStats> for RandomStatsSnapshot {
             n_frags: statistics.frags,
             xp: statistics.xp,
             n_spotted: statistics.spotted,
+            damage_blocked: statistics.damage_blocked,
+            damage_assisted: statistics.damage_assisted,
         }
     }
 }
@@ -126,6 +136,8 @@ impl Sub<RandomStatsSnapshot> for RandomStatsSnapshot {
             n_frags: self.n_frags.saturating_sub(rhs.n_frags),
             xp: self.xp.saturating_sub(rhs.xp),
             n_spotted: self.n_spotted.saturating_sub(rhs.n_spotted),
+            damage_blocked: self.damage_blocked.saturating_sub(rhs.damage_blocked),
+            damage_assisted: self.damage_assisted.saturating_sub(rhs.damage_assisted),
         }
     }
 }
@@ -144,6 +156,9 @@ impl Sum for RandomStatsSnapshot {
             sum.damage_received += component.damage_received;
             sum.damage_dealt += component.damage_dealt;
             sum.n_win_and_survived += component.n_win_and_survived;
+            sum.n_spotted += component.n_spotted;
+            sum.damage_blocked += component.damage_blocked;
+            sum.damage_assisted += component.damage_assisted;
         }
         sum
     }
@@ -167,4 +182,28 @@ impl RandomStatsSnapshot {
     pub fn accuracy(&self) -> f64 {
         self.n_hits as f64 / self.n_shots as f64
     }
+
+    #[must_use]
+    #[inline]
+    pub fn spotted_per_battle(&self) -> f64 {
+        self.n_spotted as f64 / self.n_battles as f64
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn xp_per_battle(&self) -> f64 {
+        self.xp as f64 / self.n_battles as f64
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn damage_blocked_per_battle(&self) -> f64 {
+        self.damage_blocked as f64 / self.n_battles as f64
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn damage_assisted_per_battle(&self) -> f64 {
+        self.damage_assisted as f64 / self.n_battles as f64
+    }
 }
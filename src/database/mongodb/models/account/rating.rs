@@ -9,7 +9,7 @@ use crate::math::traits::*;
 use crate::wargaming;
 
 #[serde_with::serde_as]
-#[derive(Serialize, Deserialize, Copy, Clone)]
+#[derive(Serialize, Deserialize, Copy, Clone, Default)]
 pub struct RatingStatsSnapshot {
     #[serde(default, rename = "mm", skip_serializing_if = "is_default")]
     pub mm_rating: wargaming::MmRating,
@@ -19,6 +19,41 @@ impl From<&wargaming::TankStats> for TankLastBattleTime {
     }
 }
 
+/// Size of an encoded item: 4 bytes of the tank ID and 8 bytes of the timestamp in milliseconds.
+const ENCODED_ITEM_SIZE: usize = 12;
+
+impl TankLastBattleTime {
+    /// Encodes the items into the compact binary form and compresses them with zstd.
+    pub fn compress(items: &[Self], level: i32) -> Result<Vec<u8>> {
+        let mut buffer = Vec::with_capacity(items.len() * ENCODED_ITEM_SIZE);
+        for item in items {
+            buffer.extend_from_slice(&item.tank_id.to_le_bytes());
+            buffer.extend_from_slice(&item.last_battle_time.timestamp_millis().to_le_bytes());
+        }
+        zstd::encode_all(buffer.as_slice(), level).context("failed to compress")
+    }
+
+    pub fn decompress(compressed: &[u8]) -> Result<Vec<Self>> {
+        let buffer = zstd::decode_all(compressed).context("failed to decompress")?;
+        if buffer.len() % ENCODED_ITEM_SIZE != 0 {
+            bail!("invalid encoded length: {}", buffer.len());
+        }
+        buffer
+            .chunks_exact(ENCODED_ITEM_SIZE)
+            .map(|chunk| {
+                let (tank_id, timestamp) = chunk.split_at(4);
+                Ok(Self {
+                    tank_id: wargaming::TankId::from_le_bytes(tank_id.try_into()?),
+                    last_battle_time: Utc
+                        .timestamp_millis_opt(i64::from_le_bytes(timestamp.try_into()?))
+                        .single()
+                        .ok_or_else(|| anyhow!("invalid timestamp"))?,
+                })
+            })
+            .collect()
+    }
+}
+
 // TODO: use `serde_with::TryFromInto` instead.
 impl Serialize for TankLastBattleTime {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -37,3 +72,26 @@ impl<'de> Deserialize<'de> for TankLastBattleTime {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compress_ok() -> Result {
+        let items: Vec<TankLastBattleTime> = (1..=100)
+            .map(|tank_id| TankLastBattleTime {
+                tank_id,
+                last_battle_time: Utc.timestamp_opt(1660000000 + tank_id as i64, 0).unwrap(),
+            })
+            .collect();
+        let compressed = TankLastBattleTime::compress(&items, 3)?;
+        assert!(compressed.len() < items.len() * ENCODED_ITEM_SIZE);
+
+        let decompressed = TankLastBattleTime::decompress(&compressed)?;
+        assert_eq!(decompressed.len(), items.len());
+        assert_eq!(decompressed[99].tank_id, 100);
+        assert_eq!(decompressed[99].last_battle_time, items[99].last_battle_time);
+        Ok(())
+    }
+}
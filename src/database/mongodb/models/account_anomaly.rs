@@ -0,0 +1,170 @@
+//! Statistically implausible victory ratio jumps, flagged for a manual review.
+
+use mongodb::bson::{doc, Document};
+use mongodb::options::{FindOptions, IndexOptions};
+use mongodb::{bson, Database, IndexModel};
+use serde::{Deserialize, Serialize};
+
+use crate::database::mongodb::traits::{Indexes, TypedDocument, Upsert};
+use crate::database::RandomStatsSnapshot;
+use crate::prelude::*;
+use crate::wargaming;
+
+#[serde_with::serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct AccountAnomaly {
+    #[serde(rename = "rlm")]
+    pub realm: wargaming::Realm,
+
+    #[serde(rename = "aid")]
+    pub account_id: wargaming::AccountId,
+
+    #[serde(rename = "da")]
+    #[serde_as(as = "bson::DateTime")]
+    pub detected_at: DateTime,
+
+    #[serde(rename = "nb")]
+    pub n_battles: u32,
+
+    #[serde(rename = "nw")]
+    pub n_wins: u32,
+
+    /// Victory ratio before the window, which the window is tested against.
+    #[serde(rename = "pvr")]
+    pub prior_victory_ratio: f64,
+
+    #[serde(rename = "z")]
+    pub z_score: f64,
+}
+
+impl TypedDocument for AccountAnomaly {
+    const NAME: &'static str = "account_anomalies";
+}
+
+#[async_trait]
+impl Indexes for AccountAnomaly {
+    type I = [IndexModel; 2];
+
+    fn indexes() -> Self::I {
+        [
+            IndexModel::builder()
+                .keys(doc! { "rlm": 1, "aid": 1 })
+                .options(IndexOptions::builder().unique(true).build())
+                .build(),
+            IndexModel::builder().keys(doc! { "da": -1 }).build(),
+        ]
+    }
+}
+
+#[async_trait]
+impl Upsert for AccountAnomaly {
+    type Update = Document;
+
+    #[inline]
+    fn query(&self) -> Document {
+        doc! { "rlm": self.realm.to_str(), "aid": self.account_id }
+    }
+
+    /// Keeps the latest detection only.
+    #[inline]
+    fn update(&self) -> Result<Self::Update> {
+        Ok(doc! { "$set": bson::to_bson(self)? })
+    }
+}
+
+impl AccountAnomaly {
+    /// Prior victory ratio is clamped, so that the variance never collapses to zero.
+    const MIN_PRIOR_VICTORY_RATIO: f64 = 0.01;
+    const MAX_PRIOR_VICTORY_RATIO: f64 = 0.99;
+
+    /// Tests the window's victory ratio against the prior one with the binomial z-test.
+    ///
+    /// Returns the anomaly, if the window has enough battles and the z-score exceeds the threshold.
+    pub fn detect(
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+        prior: &RandomStatsSnapshot,
+        window: &RandomStatsSnapshot,
+        min_battles: u32,
+        min_z_score: f64,
+    ) -> Option<Self> {
+        if prior.n_battles == 0 || window.n_battles < min_battles {
+            return None;
+        }
+        let prior_victory_ratio = (prior.n_wins as f64 / prior.n_battles as f64)
+            .clamp(Self::MIN_PRIOR_VICTORY_RATIO, Self::MAX_PRIOR_VICTORY_RATIO);
+        let n_battles = window.n_battles as f64;
+        let expected_wins = n_battles * prior_victory_ratio;
+        let std_dev = (n_battles * prior_victory_ratio * (1.0 - prior_victory_ratio)).sqrt();
+        let z_score = (window.n_wins as f64 - expected_wins) / std_dev;
+        (z_score >= min_z_score).then(|| Self {
+            realm,
+            account_id,
+            detected_at: now(),
+            n_battles: window.n_battles,
+            n_wins: window.n_wins,
+            prior_victory_ratio,
+            z_score,
+        })
+    }
+
+    /// Retrieves the anomalies, the recently detected first.
+    #[instrument(skip_all, level = "debug")]
+    pub async fn retrieve_all(
+        from: &Database,
+        realm: Option<wargaming::Realm>,
+    ) -> Result<Vec<Self>> {
+        let filter = match realm {
+            Some(realm) => doc! { "rlm": realm.to_str() },
+            None => doc! {},
+        };
+        let options = FindOptions::builder().sort(doc! { "da": -1 }).build();
+        Self::find_vec(from, filter, options).await
+    }
+
+    /// Removes the reviewed anomaly, returns whether it existed.
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm, account_id = account_id))]
+    pub async fn dismiss(
+        from: &Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+    ) -> Result<bool> {
+        let result = Self::collection(from)
+            .delete_one(doc! { "rlm": realm.to_str(), "aid": account_id }, None)
+            .await
+            .context("failed to dismiss the anomaly")?;
+        Ok(result.deleted_count != 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(n_battles: u32, n_wins: u32) -> RandomStatsSnapshot {
+        RandomStatsSnapshot {
+            n_battles,
+            n_wins,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn detect_ok() {
+        let prior = stats(10000, 5000);
+        let anomaly =
+            AccountAnomaly::detect(wargaming::Realm::Europe, 1, &prior, &stats(100, 90), 50, 4.0)
+                .unwrap();
+        assert!((anomaly.z_score - 8.0).abs() < 1e-6, "z-score: {}", anomaly.z_score);
+        assert_eq!(anomaly.prior_victory_ratio, 0.5);
+    }
+
+    #[test]
+    fn detect_none() {
+        let prior = stats(10000, 5000);
+        let realm = wargaming::Realm::Europe;
+        assert!(AccountAnomaly::detect(realm, 1, &prior, &stats(100, 55), 50, 4.0).is_none());
+        assert!(AccountAnomaly::detect(realm, 1, &prior, &stats(20, 20), 50, 4.0).is_none());
+        assert!(AccountAnomaly::detect(realm, 1, &stats(0, 0), &stats(100, 90), 50, 4.0).is_none());
+    }
+}
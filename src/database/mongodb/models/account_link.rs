@@ -0,0 +1,169 @@
+//! Manual links between the accounts of the same player, who has migrated across the realms.
+
+use mongodb::bson::oid::ObjectId;
+use mongodb::bson::{doc, Document};
+use mongodb::options::{FindOptions, IndexOptions};
+use mongodb::{Database, IndexModel};
+use serde::{Deserialize, Serialize};
+
+use crate::database::mongodb::traits::{Indexes, TypedDocument, Upsert};
+use crate::prelude::*;
+use crate::wargaming;
+
+/// One document per linked account, the accounts of the same player share the group ID.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct AccountLink {
+    #[serde(rename = "rlm")]
+    pub realm: wargaming::Realm,
+
+    #[serde(rename = "aid")]
+    pub account_id: wargaming::AccountId,
+
+    #[serde(rename = "g")]
+    pub group_id: ObjectId,
+}
+
+impl TypedDocument for AccountLink {
+    const NAME: &'static str = "account_links";
+}
+
+#[async_trait]
+impl Indexes for AccountLink {
+    type I = [IndexModel; 2];
+
+    fn indexes() -> Self::I {
+        [
+            IndexModel::builder()
+                .keys(doc! { "rlm": 1, "aid": 1 })
+                .options(IndexOptions::builder().unique(true).build())
+                .build(),
+            IndexModel::builder().keys(doc! { "g": 1 }).build(),
+        ]
+    }
+}
+
+#[async_trait]
+impl Upsert for AccountLink {
+    type Update = Document;
+
+    #[inline]
+    fn query(&self) -> Document {
+        Self::filter(self.realm, self.account_id)
+    }
+
+    #[inline]
+    fn update(&self) -> Result<Self::Update> {
+        Ok(doc! { "$set": { "g": self.group_id } })
+    }
+}
+
+impl AccountLink {
+    fn filter(realm: wargaming::Realm, account_id: wargaming::AccountId) -> Document {
+        doc! { "rlm": realm.to_str(), "aid": account_id }
+    }
+
+    /// Links the accounts together, merging their existing groups, if any.
+    ///
+    /// # Returns
+    ///
+    /// The resulting group ID.
+    #[instrument(skip_all, level = "debug")]
+    pub async fn link(
+        to: &Database,
+        accounts: &[(wargaming::Realm, wargaming::AccountId)],
+    ) -> Result<ObjectId> {
+        let filters: Vec<Document> = accounts
+            .iter()
+            .map(|(realm, account_id)| Self::filter(*realm, *account_id))
+            .collect();
+        let existing = Self::find_vec(to, doc! { "$or": filters }, None).await?;
+        let group_id = existing
+            .first()
+            .map_or_else(ObjectId::new, |link| link.group_id);
+
+        for link in existing.iter().filter(|link| link.group_id != group_id) {
+            Self::collection(to)
+                .update_many(doc! { "g": link.group_id }, doc! { "$set": { "g": group_id } }, None)
+                .await
+                .context("failed to merge the account link groups")?;
+        }
+        for (realm, account_id) in accounts {
+            let link = Self {
+                realm: *realm,
+                account_id: *account_id,
+                group_id,
+            };
+            link.upsert(to).await?;
+        }
+        Ok(group_id)
+    }
+
+    /// Unlinks the account from its group.
+    ///
+    /// # Returns
+    ///
+    /// Whether the account has been linked.
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm, account_id = account_id))]
+    pub async fn unlink(
+        from: &Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+    ) -> Result<bool> {
+        let result = Self::collection(from)
+            .delete_one(Self::filter(realm, account_id), None)
+            .await
+            .context("failed to unlink the account")?;
+        Ok(result.deleted_count != 0)
+    }
+
+    /// Retrieves all the accounts linked with the specified one, including itself.
+    /// The result is empty, when the account is not linked.
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm, account_id = account_id))]
+    pub async fn retrieve_linked(
+        from: &Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+    ) -> Result<Vec<Self>> {
+        let link = Self::collection(from)
+            .find_one(Self::filter(realm, account_id), None)
+            .await
+            .context("failed to retrieve the account link")?;
+        match link {
+            Some(link) => Self::retrieve_group(from, link.group_id).await,
+            None => Ok(Vec::new()),
+        }
+    }
+
+    #[instrument(skip_all, level = "debug")]
+    async fn retrieve_group(from: &Database, group_id: ObjectId) -> Result<Vec<Self>> {
+        let options = FindOptions::builder().sort(doc! { "rlm": 1, "aid": 1 }).build();
+        Self::find_vec(from, doc! { "g": group_id }, options).await
+    }
+
+    /// Retrieves all the links, grouped together.
+    #[instrument(skip_all, level = "debug")]
+    pub async fn retrieve_all(from: &Database) -> Result<Vec<Self>> {
+        let options = FindOptions::builder().sort(doc! { "g": 1, "rlm": 1, "aid": 1 }).build();
+        Self::find_vec(from, None, options).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mongodb::bson;
+
+    use super::*;
+
+    #[test]
+    fn bson_round_trip_ok() -> Result {
+        let link = AccountLink {
+            realm: wargaming::Realm::Russia,
+            account_id: 1,
+            group_id: ObjectId::new(),
+        };
+        let document = bson::to_document(&link)?;
+        assert_eq!(document.get_str("rlm")?, "ru");
+        assert_eq!(bson::from_document::<AccountLink>(document)?, link);
+        Ok(())
+    }
+}
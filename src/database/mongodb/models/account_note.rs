@@ -0,0 +1,188 @@
+//! Private notes and tags, which the viewers attach to the accounts.
+
+use mongodb::bson::{doc, Document};
+use mongodb::options::{FindOptions, IndexOptions};
+use mongodb::{bson, Database, IndexModel};
+use serde::{Deserialize, Serialize};
+
+use crate::database::mongodb::traits::{Indexes, TypedDocument, Upsert};
+use crate::prelude::*;
+use crate::wargaming;
+
+#[serde_with::serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct AccountNote {
+    /// Opaque viewer ID from the signed cookie.
+    #[serde(rename = "vid")]
+    pub viewer_id: String,
+
+    #[serde(rename = "rlm")]
+    pub realm: wargaming::Realm,
+
+    #[serde(rename = "aid")]
+    pub account_id: wargaming::AccountId,
+
+    /// Nickname as of the note update, so that the notes page needs no API calls.
+    #[serde(rename = "nn")]
+    pub nickname: String,
+
+    #[serde(rename = "tags", default)]
+    pub tags: Vec<String>,
+
+    #[serde(rename = "t", default)]
+    pub text: String,
+
+    #[serde(rename = "ua")]
+    #[serde_as(as = "bson::DateTime")]
+    pub updated_at: DateTime,
+}
+
+impl TypedDocument for AccountNote {
+    const NAME: &'static str = "account_notes";
+}
+
+#[async_trait]
+impl Indexes for AccountNote {
+    type I = [IndexModel; 1];
+
+    fn indexes() -> Self::I {
+        [IndexModel::builder()
+            .keys(doc! { "vid": 1, "rlm": 1, "aid": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build()]
+    }
+}
+
+#[async_trait]
+impl Upsert for AccountNote {
+    type Update = Document;
+
+    #[inline]
+    fn query(&self) -> Document {
+        Self::filter(&self.viewer_id, self.realm, self.account_id)
+    }
+
+    #[inline]
+    fn update(&self) -> Result<Self::Update> {
+        Ok(doc! {
+            "$set": {
+                "nn": &self.nickname,
+                "tags": &self.tags,
+                "t": &self.text,
+                "ua": self.updated_at,
+            },
+        })
+    }
+}
+
+impl AccountNote {
+    pub const MAX_TEXT_LEN: usize = 1000;
+    pub const MAX_TAGS: usize = 10;
+    pub const MAX_TAG_LEN: usize = 32;
+
+    /// The viewer's notes page lists at most this many notes.
+    const MAX_RETRIEVED: i64 = 1000;
+
+    fn filter(
+        viewer_id: &str,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+    ) -> Document {
+        doc! { "vid": viewer_id, "rlm": realm.to_str(), "aid": account_id }
+    }
+
+    /// Parses the comma-separated tags, dropping the empty ones and the duplicates.
+    pub fn parse_tags(value: &str) -> Result<Vec<String>> {
+        let mut tags: Vec<String> = Vec::new();
+        for tag in value.split(',').map(str::trim).filter(|tag| !tag.is_empty()) {
+            if tag.chars().count() > Self::MAX_TAG_LEN {
+                bail!("a tag must be at most {} characters long", Self::MAX_TAG_LEN);
+            }
+            if !tags.iter().any(|existing| existing == tag) {
+                tags.push(tag.to_string());
+            }
+        }
+        if tags.len() > Self::MAX_TAGS {
+            bail!("at most {} tags are allowed", Self::MAX_TAGS);
+        }
+        Ok(tags)
+    }
+
+    /// Tells whether the note matches the case-insensitive search query.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.nickname.to_lowercase().contains(&query)
+            || self.text.to_lowercase().contains(&query)
+            || self.tags.iter().any(|tag| tag.to_lowercase().contains(&query))
+    }
+
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm, account_id = account_id))]
+    pub async fn retrieve(
+        from: &Database,
+        viewer_id: &str,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+    ) -> Result<Option<Self>> {
+        Self::collection(from)
+            .find_one(Self::filter(viewer_id, realm, account_id), None)
+            .await
+            .context("failed to retrieve the account note")
+    }
+
+    /// Retrieves the viewer's notes, the recently updated first.
+    #[instrument(skip_all, level = "debug")]
+    pub async fn retrieve_all(from: &Database, viewer_id: &str) -> Result<Vec<Self>> {
+        let options = FindOptions::builder()
+            .sort(doc! { "ua": -1 })
+            .limit(Self::MAX_RETRIEVED)
+            .build();
+        Self::find_vec(from, doc! { "vid": viewer_id }, options).await
+    }
+
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm, account_id = account_id))]
+    pub async fn delete(
+        from: &Database,
+        viewer_id: &str,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+    ) -> Result {
+        Self::collection(from)
+            .delete_one(Self::filter(viewer_id, realm, account_id), None)
+            .await
+            .context("failed to delete the account note")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_tags_ok() -> Result {
+        assert_eq!(
+            AccountNote::parse_tags("platoon mate, seal clubber,,platoon mate ")?,
+            vec!["platoon mate", "seal clubber"],
+        );
+        assert!(AccountNote::parse_tags(&"x".repeat(33)).is_err());
+        assert!(AccountNote::parse_tags("1,2,3,4,5,6,7,8,9,10,11").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn matches_ok() {
+        let note = AccountNote {
+            viewer_id: "viewer".to_string(),
+            realm: wargaming::Realm::Europe,
+            account_id: 1,
+            nickname: "eigenein".to_string(),
+            tags: vec!["Platoon mate".to_string()],
+            text: "Plays mediums".to_string(),
+            updated_at: now(),
+        };
+        assert!(note.matches("EIGEN"));
+        assert!(note.matches("platoon"));
+        assert!(note.matches("medium"));
+        assert!(!note.matches("seal"));
+    }
+}
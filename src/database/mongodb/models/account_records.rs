@@ -0,0 +1,274 @@
+//! Account's personal bests, computed from the daily snapshots.
+//!
+//! The records are computed on the first view and then recomputed nightly,
+//! as long as somebody keeps looking at them.
+
+use chrono::NaiveDate;
+use mongodb::bson::{doc, Document};
+use mongodb::options::{FindOptions, IndexOptions};
+use mongodb::{bson, Database, IndexModel};
+use serde::{Deserialize, Serialize};
+use serde_with::TryFromInto;
+
+use crate::database::mongodb::traits::{Indexes, TypedDocument, Upsert};
+use crate::database::AccountSnapshot;
+use crate::math::traits::*;
+use crate::prelude::*;
+use crate::wargaming;
+
+#[serde_with::serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct AccountRecords {
+    #[serde(rename = "rlm")]
+    pub realm: wargaming::Realm,
+
+    #[serde_as(as = "TryFromInto<i32>")]
+    #[serde(rename = "aid")]
+    pub account_id: wargaming::AccountId,
+
+    #[serde(rename = "t")]
+    #[serde_as(as = "bson::DateTime")]
+    pub computed_at: DateTime,
+
+    /// When the records got computed for a viewer, the nightly job stops
+    /// recomputing them after a while.
+    #[serde(rename = "va")]
+    #[serde_as(as = "bson::DateTime")]
+    pub viewed_at: DateTime,
+
+    /// Best win rate within a day with enough battles.
+    #[serde(rename = "vr", default)]
+    pub best_victory_ratio: Option<DayRecord>,
+
+    /// Highest average damage within a day.
+    #[serde(rename = "dmg", default)]
+    pub best_damage: Option<DayRecord>,
+
+    /// Longest run of the consecutive days with battles.
+    #[serde(rename = "stk", default)]
+    pub longest_streak: Option<Streak>,
+}
+
+#[serde_with::serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct DayRecord {
+    #[serde(rename = "d")]
+    #[serde_as(as = "bson::DateTime")]
+    pub date: DateTime,
+
+    #[serde(rename = "v")]
+    pub value: f64,
+
+    #[serde_as(as = "TryFromInto<i32>")]
+    #[serde(rename = "nb")]
+    pub n_battles: u32,
+}
+
+#[serde_with::serde_as]
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Streak {
+    /// The streak's first day.
+    #[serde(rename = "s")]
+    #[serde_as(as = "bson::DateTime")]
+    pub start: DateTime,
+
+    #[serde_as(as = "TryFromInto<i32>")]
+    #[serde(rename = "n")]
+    pub n_days: u32,
+}
+
+impl TypedDocument for AccountRecords {
+    const NAME: &'static str = "account_records";
+}
+
+impl AccountRecords {
+    /// Minimal battles within a day to count its win rate.
+    pub const MIN_BATTLES: u32 = 20;
+
+    /// Unviewed records are dropped after a month.
+    const TTL: time::Duration = time::Duration::from_secs(30 * 86400);
+
+    /// Computes the records from the latest snapshots of each day, the oldest first.
+    ///
+    /// The account's first day is skipped, because there's no baseline to subtract.
+    pub fn compute(
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+        daily_snapshots: &[AccountSnapshot],
+        now: DateTime,
+    ) -> Self {
+        let mut records = Self {
+            realm,
+            account_id,
+            computed_at: now,
+            viewed_at: now,
+            best_victory_ratio: None,
+            best_damage: None,
+            longest_streak: None,
+        };
+        let mut streak: Option<(NaiveDate, Streak)> = None;
+
+        for pair in daily_snapshots.windows(2) {
+            let stats = pair[1].random_stats - pair[0].random_stats;
+            if stats.n_battles == 0 {
+                continue;
+            }
+            let date = pair[1].last_battle_time.date_naive();
+            let midnight = Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap());
+            let record = |value| DayRecord {
+                date: midnight,
+                value,
+                n_battles: stats.n_battles,
+            };
+
+            if stats.n_battles >= Self::MIN_BATTLES {
+                Self::keep_best(&mut records.best_victory_ratio, record(stats.victory_ratio()));
+            }
+            Self::keep_best(&mut records.best_damage, record(stats.average_damage_dealt()));
+
+            streak = match streak {
+                Some((last_date, mut streak)) if last_date.succ_opt() == Some(date) => {
+                    streak.n_days += 1;
+                    Some((date, streak))
+                }
+                _ => Some((date, Streak { start: midnight, n_days: 1 })),
+            };
+            let (_, current) = streak.as_ref().unwrap();
+            if records
+                .longest_streak
+                .as_ref()
+                .is_none_or(|longest| current.n_days > longest.n_days)
+            {
+                records.longest_streak = Some(*current);
+            }
+        }
+
+        records
+    }
+
+    fn keep_best(best: &mut Option<DayRecord>, record: DayRecord) {
+        if best.as_ref().is_none_or(|best| record.value > best.value) {
+            *best = Some(record);
+        }
+    }
+
+    /// Tells whether the records have been computed since the last midnight.
+    pub fn is_fresh(&self, now: DateTime) -> bool {
+        self.computed_at.date_naive() == now.date_naive()
+    }
+
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm, account_id = account_id))]
+    pub async fn retrieve(
+        from: &Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+    ) -> Result<Option<Self>> {
+        let filter = doc! { "rlm": realm.to_str(), "aid": account_id };
+        Self::collection(from)
+            .find_one(filter, None)
+            .await
+            .with_context(|| format!("failed to retrieve the records of #{account_id}"))
+    }
+
+    /// Retrieves the records, which were computed before the specified time,
+    /// but viewed after the other one.
+    #[instrument(skip_all, level = "debug")]
+    pub async fn retrieve_stale(
+        from: &Database,
+        computed_before: DateTime,
+        viewed_since: DateTime,
+        limit: i64,
+    ) -> Result<Vec<Self>> {
+        let filter = doc! {
+            "t": { "$lt": computed_before },
+            "va": { "$gte": viewed_since },
+        };
+        let options = FindOptions::builder().limit(limit).build();
+        Self::find_vec(from, filter, options).await
+    }
+}
+
+#[async_trait]
+impl Indexes for AccountRecords {
+    type I = [IndexModel; 3];
+
+    fn indexes() -> Self::I {
+        [
+            IndexModel::builder()
+                .keys(doc! { "rlm": 1, "aid": 1 })
+                .options(IndexOptions::builder().unique(true).build())
+                .build(),
+            IndexModel::builder().keys(doc! { "t": 1 }).build(),
+            IndexModel::builder()
+                .keys(doc! { "va": 1 })
+                .options(IndexOptions::builder().expire_after(Self::TTL).build())
+                .build(),
+        ]
+    }
+}
+
+#[async_trait]
+impl Upsert for AccountRecords {
+    type Update = Document;
+
+    #[inline]
+    fn query(&self) -> Document {
+        doc! { "rlm": self.realm.to_str(), "aid": self.account_id }
+    }
+
+    #[inline]
+    fn update(&self) -> Result<Self::Update> {
+        Ok(doc! { "$set": bson::to_document(self)? })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::RandomStatsSnapshot;
+
+    fn snapshot(date: &str, n_battles: u32, n_wins: u32, damage_dealt: u64) -> AccountSnapshot {
+        let random_stats = RandomStatsSnapshot {
+            n_battles,
+            n_wins,
+            damage_dealt,
+            ..Default::default()
+        };
+        AccountSnapshot::for_test(format!("{date}T18:00:00Z").parse().unwrap(), random_stats)
+    }
+
+    #[test]
+    fn compute_ok() {
+        let snapshots = [
+            snapshot("2022-08-01", 100, 50, 100_000),
+            snapshot("2022-08-02", 130, 70, 130_000),
+            snapshot("2022-08-03", 135, 75, 140_000),
+            snapshot("2022-08-04", 160, 90, 165_000),
+            snapshot("2022-08-06", 161, 90, 166_000),
+        ];
+        let now = Utc.timestamp_opt(1660000000, 0).unwrap();
+        let records = AccountRecords::compute(wargaming::Realm::Europe, 1, &snapshots, now);
+
+        let best_victory_ratio = records.best_victory_ratio.unwrap();
+        assert_eq!(best_victory_ratio.date, "2022-08-02T00:00:00Z".parse::<DateTime>().unwrap());
+        assert_eq!(best_victory_ratio.n_battles, 30);
+
+        let best_damage = records.best_damage.unwrap();
+        assert_eq!(best_damage.date, "2022-08-03T00:00:00Z".parse::<DateTime>().unwrap());
+        assert_eq!(best_damage.value, 2000.0);
+
+        let longest_streak = records.longest_streak.unwrap();
+        assert_eq!(longest_streak.start, "2022-08-02T00:00:00Z".parse::<DateTime>().unwrap());
+        assert_eq!(longest_streak.n_days, 3);
+    }
+
+    #[test]
+    fn compute_empty_ok() {
+        let now = Utc.timestamp_opt(1660000000, 0).unwrap();
+        let records = AccountRecords::compute(wargaming::Realm::Europe, 1, &[], now);
+        assert!(records.best_victory_ratio.is_none());
+        assert!(records.longest_streak.is_none());
+        assert!(records.is_fresh(now));
+        assert!(!records.is_fresh(now + Duration::days(1)));
+    }
+}
@@ -1,5 +1,16 @@
-use mongodb::bson::{doc, Document};
-use mongodb::options::{FindOneOptions, IndexOptions};
+//! Account snapshots, one per crawled change of the account.
+//!
+//! A snapshot keeps the last battle times of the account's tanks to look up the tank snapshots.
+//! Since most of the tanks do not change between the battles, a snapshot may only contain
+//! the tanks changed since a preceding full snapshot – the base one.
+//!
+//! The tank list may also be compressed with zstd, see [`AccountSnapshot::compress`].
+
+use futures::{Stream, TryStreamExt};
+use itertools::Itertools;
+use mongodb::bson::spec::BinarySubtype;
+use mongodb::bson::{doc, Binary, Document};
+use mongodb::options::{FindOneOptions, FindOptions, IndexOptions};
 use mongodb::{bson, Database, IndexModel};
 use serde::{Deserialize, Serialize};
 use serde_with::TryFromInto;
@@ -9,6 +20,12 @@ use crate::database::{RandomStatsSnapshot, RatingStatsSnapshot, TankLastBattleTi
 use crate::prelude::*;
 use crate::wargaming;
 
+/// The tank last battle times are stored as the plain array.
+const SCHEMA_VERSION_PLAIN: u8 = 1;
+
+/// The tank last battle times are stored as the zstd-compressed binary.
+const SCHEMA_VERSION_COMPRESSED: u8 = 2;
+
 #[serde_with::serde_as]
 #[derive(Serialize, Deserialize, Clone)]
 pub struct AccountSnapshot {
@@ -29,8 +46,41 @@ pub struct AccountSnapshot {
     #[serde(flatten)]
     pub rating_stats: RatingStatsSnapshot,
 
-    #[serde(rename = "t")]
+    #[serde(rename = "sv", default = "default_schema_version")]
+    pub schema_version: u8,
+
+    /// Either all the tanks or, when [`Self::base_snapshot_time`] is set,
+    /// only the tanks played since the base snapshot.
+    ///
+    /// Empty in a compressed snapshot.
+    #[serde(rename = "t", default)]
     pub tank_last_battle_times: Vec<TankLastBattleTime>,
+
+    /// Compressed [`Self::tank_last_battle_times`].
+    #[serde(rename = "tz", default, skip_serializing_if = "Option::is_none")]
+    pub compressed_tank_last_battle_times: Option<Binary>,
+
+    /// Last battle time of the full snapshot, which this snapshot is delta-encoded against.
+    #[serde(rename = "bts", default, skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<bson::DateTime>")]
+    pub base_snapshot_time: Option<DateTime>,
+}
+
+/// Overall victory ratio of the account as of the end of the day.
+#[serde_with::serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct DailyVictoryRatio {
+    /// Last battle time of the day's latest snapshot.
+    #[serde(rename = "lbts")]
+    #[serde_as(as = "bson::DateTime")]
+    pub last_battle_time: DateTime,
+
+    #[serde(rename = "vr")]
+    pub victory_ratio: f64,
+}
+
+const fn default_schema_version() -> u8 {
+    SCHEMA_VERSION_PLAIN
 }
 
 impl TypedDocument for AccountSnapshot {
@@ -49,19 +99,121 @@ impl Indexes for AccountSnapshot {
 }
 
 impl AccountSnapshot {
+    /// Creates the snapshot, delta-encoded against the base snapshot, if any.
+    ///
+    /// Falls back to the full snapshot when the delta would contain
+    /// more than a half of the tanks, which also keeps the deltas from growing indefinitely.
     pub fn new(
         realm: wargaming::Realm,
         account_info: &wargaming::AccountInfo,
         tank_last_battle_times: Vec<TankLastBattleTime>,
+        base_snapshot_time: Option<DateTime>,
     ) -> Self {
-        Self {
+        let mut this = Self {
             realm,
             last_battle_time: account_info.last_battle_time,
             account_id: account_info.id,
             random_stats: account_info.stats.random.into(),
             rating_stats: account_info.stats.rating.into(),
+            schema_version: SCHEMA_VERSION_PLAIN,
             tank_last_battle_times,
+            compressed_tank_last_battle_times: None,
+            base_snapshot_time: None,
+        };
+        if let Some(base_snapshot_time) = base_snapshot_time {
+            this.encode_delta(base_snapshot_time);
+        }
+        this
+    }
+
+    fn encode_delta(&mut self, base_snapshot_time: DateTime) {
+        let changed = self
+            .tank_last_battle_times
+            .iter()
+            .filter(|item| item.last_battle_time > base_snapshot_time)
+            .copied()
+            .collect_vec();
+        if changed.len() * 2 <= self.tank_last_battle_times.len() {
+            self.tank_last_battle_times = changed;
+            self.base_snapshot_time = Some(base_snapshot_time);
+        }
+    }
+
+    /// Last battle time of the full snapshot, which the next snapshots should be encoded against.
+    pub fn full_snapshot_time(&self) -> DateTime {
+        self.base_snapshot_time.unwrap_or(self.last_battle_time)
+    }
+
+    /// Moves the tank last battle times into the compressed binary field.
+    pub fn compress(&mut self, level: i32) -> Result {
+        if self.schema_version == SCHEMA_VERSION_PLAIN {
+            let bytes = TankLastBattleTime::compress(&self.tank_last_battle_times, level)?;
+            self.compressed_tank_last_battle_times = Some(Binary {
+                subtype: BinarySubtype::Generic,
+                bytes,
+            });
+            self.tank_last_battle_times = Vec::new();
+            self.schema_version = SCHEMA_VERSION_COMPRESSED;
         }
+        Ok(())
+    }
+
+    /// Restores the plain tank last battle times of a compressed snapshot.
+    pub fn decompress(&mut self) -> Result {
+        match self.schema_version {
+            SCHEMA_VERSION_PLAIN => Ok(()),
+            SCHEMA_VERSION_COMPRESSED => {
+                let binary = self
+                    .compressed_tank_last_battle_times
+                    .take()
+                    .ok_or_else(|| anyhow!("missing compressed tank last battle times"))?;
+                self.tank_last_battle_times = TankLastBattleTime::decompress(&binary.bytes)?;
+                self.schema_version = SCHEMA_VERSION_PLAIN;
+                Ok(())
+            }
+            version => bail!("unsupported schema version {}", version),
+        }
+    }
+
+    /// Restores the full tank list from the base snapshot.
+    fn merge_base(&mut self, base: Self) {
+        let changed: AHashMap<wargaming::TankId, TankLastBattleTime> = self
+            .tank_last_battle_times
+            .drain(..)
+            .map(|item| (item.tank_id, item))
+            .collect();
+        let mut merged = base
+            .tank_last_battle_times
+            .into_iter()
+            .filter(|item| !changed.contains_key(&item.tank_id))
+            .collect_vec();
+        merged.extend(changed.into_values());
+        self.tank_last_battle_times = merged;
+        self.base_snapshot_time = None;
+    }
+
+    /// Reconstructs the full and plain snapshot, if this one is delta-encoded or compressed.
+    async fn reconstruct(mut self, from: &Database) -> Result<Self> {
+        self.decompress()?;
+        let base_snapshot_time = match self.base_snapshot_time {
+            Some(base_snapshot_time) => base_snapshot_time,
+            None => return Ok(self),
+        };
+        let filter = doc! {
+            "rlm": self.realm.to_str(),
+            "aid": self.account_id,
+            "lbts": base_snapshot_time,
+        };
+        let mut base = Self::collection(from)
+            .find_one(filter, None)
+            .await?
+            .with_context(|| format!("missing base snapshot at {base_snapshot_time}"))?;
+        base.decompress()?;
+        if base.base_snapshot_time.is_some() {
+            bail!("base snapshot at {base_snapshot_time} is not a full one");
+        }
+        self.merge_base(base);
+        Ok(self)
     }
 }
 
@@ -95,11 +247,338 @@ impl AccountSnapshot {
         let filter = doc! { "rlm": realm.to_str(), "aid": account_id, "lbts": { "$lte": before } };
         let options = FindOneOptions::builder().sort(doc! { "lbts": -1 }).build();
         let start_instant = Instant::now();
-        let this = Self::collection(from).find_one(filter, options).await?;
+        let this = match Self::collection(from).find_one(filter, options).await? {
+            Some(this) => Some(this.reconstruct(from).await?),
+            None => None,
+        };
         if let Some(this) = &this {
             debug!(?this.last_battle_time, "found");
         }
         debug!(elapsed_secs = start_instant.elapsed().as_secs_f32());
         Ok(this)
     }
+
+    /// Retrieves the last battle time of the latest snapshot, which tells how fresh the data is.
+    pub async fn retrieve_latest_time(
+        from: &Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+    ) -> Result<Option<DateTime>> {
+        let filter = doc! { "rlm": realm.to_str(), "aid": account_id };
+        Self::retrieve_time(from, filter, -1).await
+    }
+
+    /// Retrieves the last battle time of the latest snapshot strictly before the specified time.
+    pub async fn retrieve_previous_time(
+        from: &Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+        before: DateTime,
+    ) -> Result<Option<DateTime>> {
+        let filter = doc! { "rlm": realm.to_str(), "aid": account_id, "lbts": { "$lt": before } };
+        Self::retrieve_time(from, filter, -1).await
+    }
+
+    /// Retrieves the last battle time of the earliest snapshot at or after the specified time.
+    pub async fn retrieve_next_time(
+        from: &Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+        since: DateTime,
+    ) -> Result<Option<DateTime>> {
+        let filter = doc! { "rlm": realm.to_str(), "aid": account_id, "lbts": { "$gte": since } };
+        Self::retrieve_time(from, filter, 1).await
+    }
+
+    /// Retrieves only the last battle time of the first matched snapshot.
+    async fn retrieve_time(
+        from: &Database,
+        filter: Document,
+        order: i32,
+    ) -> Result<Option<DateTime>> {
+        let options = FindOneOptions::builder()
+            .sort(doc! { "lbts": order })
+            .projection(doc! { "_id": 0, "lbts": 1 })
+            .build();
+        let document = Self::collection(from)
+            .clone_with_type::<Document>()
+            .find_one(filter, options)
+            .await
+            .context("failed to retrieve the snapshot time")?;
+        match document {
+            Some(document) => Ok(Some(document.get_datetime("lbts")?.to_chrono())),
+            None => Ok(None),
+        }
+    }
+
+    /// Retrieves the snapshots after the specified time, the oldest first.
+    ///
+    /// The delta-encoded snapshots are not reconstructed, but decompressed.
+    #[instrument(skip_all, fields(account_id = account_id, since = ?since), err)]
+    pub async fn retrieve_since(
+        from: &Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+        since: DateTime,
+    ) -> Result<Vec<Self>> {
+        let filter = doc! { "rlm": realm.to_str(), "aid": account_id, "lbts": { "$gt": since } };
+        let options = FindOptions::builder().sort(doc! { "lbts": 1 }).build();
+        let start_instant = Instant::now();
+        let mut snapshots = Self::find_vec(from, filter, options).await?;
+        snapshots.iter_mut().try_for_each(Self::decompress)?;
+        debug!(n_snapshots = snapshots.len(), elapsed_secs = start_instant.elapsed().as_secs_f32());
+        Ok(snapshots)
+    }
+
+    /// Retrieves the most recent snapshots, the oldest first.
+    ///
+    /// The delta-encoded snapshots are not reconstructed, but decompressed.
+    #[instrument(skip_all, fields(account_id = account_id, limit = limit), err)]
+    pub async fn retrieve_recent(
+        from: &Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+        limit: i64,
+    ) -> Result<Vec<Self>> {
+        let filter = doc! { "rlm": realm.to_str(), "aid": account_id };
+        let options = FindOptions::builder()
+            .sort(doc! { "lbts": -1 })
+            .limit(limit)
+            .build();
+        let mut snapshots = Self::find_vec(from, filter, options).await?;
+        snapshots.iter_mut().try_for_each(Self::decompress)?;
+        snapshots.reverse();
+        Ok(snapshots)
+    }
+
+    /// Compresses the account's plain snapshots in place, returns their number.
+    #[instrument(skip_all, fields(account_id = account_id), err)]
+    pub async fn compress_all(
+        db: &Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+        level: i32,
+    ) -> Result<usize> {
+        let filter = doc! {
+            "rlm": realm.to_str(),
+            "aid": account_id,
+            "sv": { "$ne": SCHEMA_VERSION_COMPRESSED as i32 },
+        };
+        let snapshots = Self::find_vec(db, filter, None).await?;
+        let n_snapshots = snapshots.len();
+        for mut snapshot in snapshots {
+            snapshot.compress(level)?;
+            Self::collection(db)
+                .replace_one(snapshot.query(), &snapshot, None)
+                .await
+                .context("failed to replace the snapshot")?;
+        }
+        Ok(n_snapshots)
+    }
+
+    /// Streams all the realm's snapshots without the tanks, ordered by account
+    /// and then the oldest first.
+    ///
+    /// The sort is the exact reverse of the index, so that it does not happen in memory.
+    #[instrument(skip_all, fields(realm = ?realm))]
+    pub async fn retrieve_all_without_tanks(
+        from: &Database,
+        realm: wargaming::Realm,
+    ) -> Result<impl Stream<Item = Result<Self, mongodb::error::Error>>> {
+        let options = FindOptions::builder()
+            .sort(doc! { "rlm": -1, "aid": -1, "lbts": 1 })
+            .projection(doc! { "t": 0, "tz": 0 })
+            .build();
+        let cursor = Self::collection(from)
+            .find(doc! { "rlm": realm.to_str() }, options)
+            .await
+            .context("failed to query the account snapshots")?;
+        Ok(cursor)
+    }
+
+    /// Retrieves the latest snapshot of each day without the tanks, the oldest first.
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm, account_id = account_id))]
+    pub async fn retrieve_daily_closing(
+        from: &Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+    ) -> Result<Vec<Self>> {
+        let pipeline = [
+            doc! { "$match": { "rlm": realm.to_str(), "aid": account_id } },
+            doc! { "$sort": { "lbts": 1_i32 } },
+            doc! { "$project": { "t": 0_i32, "tz": 0_i32 } },
+            doc! {
+                "$group": {
+                    "_id": { "$dateToString": { "format": "%Y-%m-%d", "date": "$lbts" } },
+                    "root": { "$last": "$$ROOT" },
+                }
+            },
+            doc! { "$replaceRoot": { "newRoot": "$root" } },
+            doc! { "$sort": { "lbts": 1_i32 } },
+        ];
+        let start_instant = Instant::now();
+        let snapshots = Self::collection(from)
+            .clone_with_type::<Document>()
+            .aggregate(pipeline, None)
+            .await
+            .context("failed to aggregate the daily snapshots")?
+            .and_then(|document| async move { Ok(bson::from_document(document)?) })
+            .try_collect::<Vec<Self>>()
+            .await?;
+        debug!(n_days = snapshots.len(), elapsed_secs = start_instant.elapsed().as_secs_f32());
+        Ok(snapshots)
+    }
+
+    /// Aggregates the latest snapshot of each day into the overall victory ratios,
+    /// the oldest first.
+    #[instrument(skip_all, fields(account_id = account_id, since = ?since), err)]
+    pub async fn retrieve_daily_victory_ratios(
+        from: &Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+        since: DateTime,
+    ) -> Result<Vec<DailyVictoryRatio>> {
+        let filter = doc! { "rlm": realm.to_str(), "aid": account_id, "lbts": { "$gte": since } };
+        let pipeline = [
+            doc! { "$match": filter },
+            doc! { "$sort": { "lbts": 1_i32 } },
+            doc! {
+                "$group": {
+                    "_id": { "$dateToString": { "format": "%Y-%m-%d", "date": "$lbts" } },
+                    "lbts": { "$last": "$lbts" },
+                    "nb": { "$last": "$nb" },
+                    "nw": { "$last": "$nw" },
+                }
+            },
+            doc! { "$match": { "nb": { "$gt": 0_i32 } } },
+            doc! { "$sort": { "lbts": 1_i32 } },
+            doc! {
+                "$project": {
+                    "_id": 0_i32,
+                    "lbts": 1_i32,
+                    "vr": { "$divide": [{ "$ifNull": ["$nw", 0_i32] }, "$nb"] },
+                }
+            },
+        ];
+        let start_instant = Instant::now();
+        let ratios = Self::collection(from)
+            .clone_with_type::<Document>()
+            .aggregate(pipeline, None)
+            .await
+            .context("failed to aggregate the daily victory ratios")?
+            .and_then(|document| async move { Ok(bson::from_document(document)?) })
+            .try_collect::<Vec<DailyVictoryRatio>>()
+            .await?;
+        debug!(n_days = ratios.len(), elapsed_secs = start_instant.elapsed().as_secs_f32());
+        Ok(ratios)
+    }
+}
+
+#[cfg(test)]
+impl AccountSnapshot {
+    /// Plain snapshot of the European account #1 without any tanks.
+    pub fn for_test(last_battle_time: DateTime, random_stats: RandomStatsSnapshot) -> Self {
+        Self {
+            realm: wargaming::Realm::Europe,
+            last_battle_time,
+            account_id: 1,
+            random_stats,
+            rating_stats: RatingStatsSnapshot::default(),
+            schema_version: SCHEMA_VERSION_PLAIN,
+            tank_last_battle_times: Vec::new(),
+            compressed_tank_last_battle_times: None,
+            base_snapshot_time: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tank(tank_id: wargaming::TankId, timestamp: i64) -> TankLastBattleTime {
+        TankLastBattleTime {
+            tank_id,
+            last_battle_time: Utc.timestamp_opt(timestamp, 0).unwrap(),
+        }
+    }
+
+    fn snapshot(
+        timestamp: i64,
+        tank_last_battle_times: Vec<TankLastBattleTime>,
+    ) -> AccountSnapshot {
+        let last_battle_time = Utc.timestamp_opt(timestamp, 0).unwrap();
+        AccountSnapshot {
+            tank_last_battle_times,
+            ..AccountSnapshot::for_test(last_battle_time, RandomStatsSnapshot::default())
+        }
+    }
+
+    #[test]
+    fn encode_delta_ok() {
+        let base_snapshot_time = Utc.timestamp_opt(100, 0).unwrap();
+        let mut snapshot = snapshot(150, vec![tank(1, 50), tank(2, 90), tank(3, 150)]);
+        snapshot.encode_delta(base_snapshot_time);
+        assert_eq!(snapshot.base_snapshot_time, Some(base_snapshot_time));
+        assert_eq!(snapshot.full_snapshot_time(), base_snapshot_time);
+        assert_eq!(snapshot.tank_last_battle_times.len(), 1);
+        assert_eq!(snapshot.tank_last_battle_times[0].tank_id, 3);
+    }
+
+    #[test]
+    fn encode_delta_falls_back_to_full_ok() {
+        let mut snapshot = snapshot(150, vec![tank(1, 50), tank(2, 120), tank(3, 150)]);
+        snapshot.encode_delta(Utc.timestamp_opt(100, 0).unwrap());
+        assert_eq!(snapshot.base_snapshot_time, None);
+        assert_eq!(snapshot.full_snapshot_time(), Utc.timestamp_opt(150, 0).unwrap());
+        assert_eq!(snapshot.tank_last_battle_times.len(), 3);
+    }
+
+    #[test]
+    fn merge_base_ok() {
+        let base = snapshot(100, vec![tank(1, 50), tank(2, 100), tank(4, 10), tank(5, 20)]);
+        let mut snapshot = snapshot(
+            150,
+            vec![tank(1, 50), tank(2, 150), tank(3, 140), tank(4, 10), tank(5, 20)],
+        );
+        snapshot.encode_delta(base.last_battle_time);
+        assert_eq!(snapshot.tank_last_battle_times.len(), 2);
+        snapshot.merge_base(base);
+        assert_eq!(snapshot.base_snapshot_time, None);
+        let mut tanks = snapshot
+            .tank_last_battle_times
+            .iter()
+            .map(|item| (item.tank_id, item.last_battle_time.timestamp()))
+            .collect_vec();
+        tanks.sort_unstable();
+        assert_eq!(tanks, [(1, 50), (2, 150), (3, 140), (4, 10), (5, 20)]);
+    }
+
+    #[test]
+    fn compress_ok() -> Result {
+        let mut snapshot = snapshot(150, vec![tank(1, 50), tank(2, 150)]);
+        snapshot.compress(3)?;
+        assert!(snapshot.tank_last_battle_times.is_empty());
+
+        let document = bson::to_document(&snapshot)?;
+        assert_eq!(document.get_i32("sv")?, 2);
+        assert!(!document.contains_key("t") || document.get_array("t")?.is_empty());
+
+        let mut snapshot: AccountSnapshot = bson::from_document(document)?;
+        snapshot.decompress()?;
+        assert_eq!(snapshot.schema_version, SCHEMA_VERSION_PLAIN);
+        assert_eq!(snapshot.tank_last_battle_times.len(), 2);
+        assert_eq!(snapshot.tank_last_battle_times[1].tank_id, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn decompress_legacy_ok() -> Result {
+        let mut document = bson::to_document(&snapshot(150, vec![tank(1, 50)]))?;
+        document.remove("sv");
+        let mut snapshot: AccountSnapshot = bson::from_document(document)?;
+        snapshot.decompress()?;
+        assert_eq!(snapshot.tank_last_battle_times.len(), 1);
+        Ok(())
+    }
 }
@@ -0,0 +1,103 @@
+//! Site-wide announcements, for example, of the maintenance windows or the new features.
+
+use mongodb::bson::{doc, Document};
+use mongodb::options::FindOptions;
+use mongodb::{bson, Database, IndexModel};
+use serde::{Deserialize, Serialize};
+
+use crate::database::mongodb::traits::{Indexes, TypedDocument, Upsert};
+use crate::prelude::*;
+
+#[serde_with::serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Announcement {
+    /// Short slug, which is also remembered in the viewer's cookie upon the dismissal.
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    /// Markdown text, see [`crate::web::partials::Markdown`] for the supported subset.
+    #[serde(rename = "md")]
+    pub markdown: String,
+
+    #[serde(rename = "sev")]
+    pub severity: Severity,
+
+    #[serde(rename = "ca")]
+    #[serde_as(as = "bson::DateTime")]
+    pub created_at: DateTime,
+
+    #[serde(rename = "exp")]
+    #[serde_as(as = "bson::DateTime")]
+    pub expires_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    Danger,
+}
+
+impl Severity {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Danger => "danger",
+        }
+    }
+}
+
+impl TypedDocument for Announcement {
+    const NAME: &'static str = "announcements";
+}
+
+#[async_trait]
+impl Indexes for Announcement {
+    type I = [IndexModel; 1];
+
+    fn indexes() -> Self::I {
+        [IndexModel::builder().keys(doc! { "exp": -1 }).build()]
+    }
+}
+
+#[async_trait]
+impl Upsert for Announcement {
+    type Update = Document;
+
+    #[inline]
+    fn query(&self) -> Document {
+        doc! { "_id": &self.id }
+    }
+
+    #[inline]
+    fn update(&self) -> Result<Self::Update> {
+        Ok(doc! { "$set": bson::to_bson(self)? })
+    }
+}
+
+impl Announcement {
+    /// Retrieves the unexpired announcements, the newest first.
+    #[instrument(skip_all, level = "debug")]
+    pub async fn retrieve_active(from: &Database, now: DateTime) -> Result<Vec<Self>> {
+        let options = FindOptions::builder().sort(doc! { "ca": -1 }).build();
+        Self::find_vec(from, doc! { "exp": { "$gt": now } }, options).await
+    }
+
+    /// Retrieves all the announcements including the expired ones, the newest first.
+    #[instrument(skip_all, level = "debug")]
+    pub async fn retrieve_all(from: &Database) -> Result<Vec<Self>> {
+        let options = FindOptions::builder().sort(doc! { "ca": -1 }).build();
+        Self::find_vec(from, doc! {}, options).await
+    }
+
+    #[instrument(skip_all, level = "debug", fields(id = id))]
+    pub async fn delete(from: &Database, id: &str) -> Result<bool> {
+        let result = Self::collection(from)
+            .delete_one(doc! { "_id": id }, None)
+            .await
+            .context("failed to delete the announcement")?;
+        Ok(result.deleted_count != 0)
+    }
+}
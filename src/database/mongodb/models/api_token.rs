@@ -0,0 +1,79 @@
+//! Tokens for the protected API endpoints, issued to the community sites.
+
+use mongodb::bson::doc;
+use mongodb::{bson, Database};
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::database::mongodb::traits::TypedDocument;
+use crate::prelude::*;
+
+/// Only the token hash is stored, the token itself is shown once on issuance.
+#[serde_with::serde_as]
+#[derive(Serialize, Deserialize)]
+pub struct ApiToken {
+    /// Hex-encoded SHA-256 of the token.
+    #[serde(rename = "_id")]
+    pub hash: String,
+
+    /// Who the token was issued to.
+    #[serde(rename = "n")]
+    pub name: String,
+
+    #[serde(rename = "ca")]
+    #[serde_as(as = "bson::DateTime")]
+    pub created_at: DateTime,
+}
+
+impl TypedDocument for ApiToken {
+    const NAME: &'static str = "api_tokens";
+}
+
+impl ApiToken {
+    /// Generates a new token and returns it along with the document to store.
+    pub fn generate(name: String) -> (String, Self) {
+        let token = hex::encode(thread_rng().gen::<[u8; 32]>());
+        let this = Self {
+            hash: Self::hash(&token),
+            name,
+            created_at: now(),
+        };
+        (token, this)
+    }
+
+    fn hash(token: &str) -> String {
+        hex::encode(Sha256::digest(token.as_bytes()))
+    }
+
+    pub async fn insert(&self, into: &Database) -> Result {
+        Self::collection(into)
+            .insert_one(self, None)
+            .await
+            .context("failed to insert the API token")?;
+        Ok(())
+    }
+
+    /// Looks up the token, [`None`] means the token is invalid.
+    #[instrument(skip_all, level = "debug")]
+    pub async fn retrieve(from: &Database, token: &str) -> Result<Option<Self>> {
+        let filter = doc! { "_id": Self::hash(token) };
+        Self::collection(from)
+            .find_one(filter, None)
+            .await
+            .context("failed to retrieve the API token")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_ok() {
+        let (token, api_token) = ApiToken::generate("example.com".to_string());
+        assert_eq!(token.len(), 64);
+        assert_eq!(api_token.hash, ApiToken::hash(&token));
+        assert_ne!(api_token.hash, token);
+    }
+}
@@ -0,0 +1,114 @@
+//! Trail of the administrative and the notable viewer actions.
+
+use std::net::IpAddr;
+
+use mongodb::bson::doc;
+use mongodb::options::FindOptions;
+use mongodb::{bson, Database, IndexModel};
+use serde::{Deserialize, Serialize};
+
+use crate::database::mongodb::traits::{Indexes, TypedDocument};
+use crate::prelude::*;
+
+#[serde_with::serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct AuditLogEntry {
+    #[serde(rename = "ts")]
+    #[serde_as(as = "bson::DateTime")]
+    pub timestamp: DateTime,
+
+    /// Operating system user for the CLI, or `web` for the viewers.
+    #[serde(rename = "act")]
+    pub actor: String,
+
+    /// Viewer's IP address, missing for the CLI.
+    #[serde(rename = "ip", default, skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+    pub ip_addr: Option<IpAddr>,
+
+    /// Dotted action name, for example: `blocklist.add`.
+    #[serde(rename = "a")]
+    pub action: String,
+
+    /// Free-form description of the action's subject.
+    #[serde(rename = "d")]
+    pub details: String,
+}
+
+impl TypedDocument for AuditLogEntry {
+    const NAME: &'static str = "audit_log";
+}
+
+#[async_trait]
+impl Indexes for AuditLogEntry {
+    type I = [IndexModel; 1];
+
+    fn indexes() -> Self::I {
+        [IndexModel::builder().keys(doc! { "ts": -1 }).build()]
+    }
+}
+
+impl AuditLogEntry {
+    /// Action, which is performed with the command-line interface.
+    pub fn cli(action: impl Into<String>, details: impl Into<String>) -> Self {
+        Self {
+            timestamp: now(),
+            actor: std::env::var("USER").unwrap_or_else(|_| "unknown".to_string()),
+            ip_addr: None,
+            action: action.into(),
+            details: details.into(),
+        }
+    }
+
+    /// Action, which is performed by an anonymous viewer.
+    pub fn web(
+        ip_addr: Option<IpAddr>,
+        action: impl Into<String>,
+        details: impl Into<String>,
+    ) -> Self {
+        Self {
+            timestamp: now(),
+            actor: "web".to_string(),
+            ip_addr,
+            action: action.into(),
+            details: details.into(),
+        }
+    }
+
+    #[instrument(skip_all, level = "debug", fields(action = self.action))]
+    pub async fn insert(&self, into: &Database) -> Result {
+        Self::collection(into)
+            .insert_one(self, None)
+            .await
+            .context("failed to insert the audit log entry")?;
+        Ok(())
+    }
+
+    /// Retrieves the page of the entries, the most recent first. The pages start from `1`.
+    #[instrument(skip_all, level = "debug", fields(page = page, per_page = per_page))]
+    pub async fn retrieve_page(from: &Database, page: u64, per_page: i64) -> Result<Vec<Self>> {
+        let options = FindOptions::builder()
+            .sort(doc! { "ts": -1 })
+            .skip(page.saturating_sub(1) * per_page as u64)
+            .limit(per_page)
+            .build();
+        Self::find_vec(from, None, options).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip_ok() -> Result {
+        let entry = AuditLogEntry {
+            timestamp: Utc.timestamp_opt(1660000000, 0).unwrap(),
+            ..AuditLogEntry::web(Some("::1".parse()?), "refresh", "eu/42")
+        };
+        let document = bson::to_document(&entry)?;
+        assert_eq!(document.get_str("ip")?, "::1");
+        assert_eq!(bson::from_document::<AuditLogEntry>(document)?, entry);
+        Ok(())
+    }
+}
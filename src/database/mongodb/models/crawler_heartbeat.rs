@@ -0,0 +1,65 @@
+use mongodb::bson::{doc, Document};
+use mongodb::options::IndexOptions;
+use mongodb::{bson, Database, IndexModel};
+use serde::Deserialize;
+
+use crate::database::mongodb::traits::{Indexes, TypedDocument, Upsert};
+use crate::prelude::*;
+use crate::wargaming;
+
+/// The last time a crawler has reported its metrics, one document per realm.
+#[serde_with::serde_as]
+#[derive(Deserialize)]
+pub struct CrawlerHeartbeat {
+    #[serde(rename = "rlm")]
+    pub realm: wargaming::Realm,
+
+    #[serde(rename = "ts")]
+    #[serde_as(as = "bson::DateTime")]
+    pub timestamp: DateTime,
+}
+
+impl TypedDocument for CrawlerHeartbeat {
+    const NAME: &'static str = "crawler_heartbeats";
+}
+
+impl CrawlerHeartbeat {
+    pub fn new(realm: wargaming::Realm) -> Self {
+        Self {
+            realm,
+            timestamp: now(),
+        }
+    }
+
+    #[instrument(skip_all, level = "debug")]
+    pub async fn retrieve_all(from: &Database) -> Result<Vec<Self>> {
+        Self::find_vec(from, None, None).await
+    }
+}
+
+#[async_trait]
+impl Indexes for CrawlerHeartbeat {
+    type I = [IndexModel; 1];
+
+    fn indexes() -> Self::I {
+        [IndexModel::builder()
+            .keys(doc! { "rlm": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build()]
+    }
+}
+
+#[async_trait]
+impl Upsert for CrawlerHeartbeat {
+    type Update = Document;
+
+    #[inline]
+    fn query(&self) -> Document {
+        doc! { "rlm": self.realm.to_str() }
+    }
+
+    #[inline]
+    fn update(&self) -> Result<Self::Update> {
+        Ok(doc! { "$set": { "ts": self.timestamp } })
+    }
+}
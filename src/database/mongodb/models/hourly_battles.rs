@@ -0,0 +1,90 @@
+//! Battles, which the crawler has seen, bucketed by realm and hour.
+//!
+//! The battles are attributed to the hour of the account's last battle,
+//! which is accurate enough on the realm scale.
+
+use chrono::DurationRound;
+use mongodb::bson::{doc, Document};
+use mongodb::options::IndexOptions;
+use mongodb::{bson, Database, IndexModel};
+use serde::Deserialize;
+
+use crate::database::mongodb::traits::{Indexes, TypedDocument, Upsert};
+use crate::prelude::*;
+use crate::wargaming;
+
+#[serde_with::serde_as]
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct HourlyBattles {
+    #[serde(rename = "rlm")]
+    pub realm: wargaming::Realm,
+
+    /// Start of the hour.
+    #[serde(rename = "t")]
+    #[serde_as(as = "bson::DateTime")]
+    pub hour: DateTime,
+
+    #[serde(rename = "n")]
+    pub n_battles: i64,
+}
+
+impl TypedDocument for HourlyBattles {
+    const NAME: &'static str = "hourly_battles";
+}
+
+impl HourlyBattles {
+    /// Buckets are kept for a few weeks, which is enough to average out the weekly pattern.
+    const TTL: time::Duration = time::Duration::from_secs(35 * 86400);
+
+    pub fn new(realm: wargaming::Realm, last_battle_time: DateTime, n_battles: u32) -> Self {
+        Self {
+            realm,
+            hour: last_battle_time.duration_trunc(Duration::hours(1)).unwrap(),
+            n_battles: i64::from(n_battles),
+        }
+    }
+
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm, since = ?since))]
+    pub async fn retrieve_since(
+        from: &Database,
+        realm: wargaming::Realm,
+        since: DateTime,
+    ) -> Result<Vec<Self>> {
+        let filter = doc! { "rlm": realm.to_str(), "t": { "$gte": since } };
+        Self::find_vec(from, filter, None).await
+    }
+}
+
+#[async_trait]
+impl Indexes for HourlyBattles {
+    type I = [IndexModel; 2];
+
+    fn indexes() -> Self::I {
+        [
+            IndexModel::builder()
+                .keys(doc! { "rlm": 1, "t": 1 })
+                .options(IndexOptions::builder().unique(true).build())
+                .build(),
+            IndexModel::builder()
+                .keys(doc! { "t": 1 })
+                .options(IndexOptions::builder().expire_after(Self::TTL).build())
+                .build(),
+        ]
+    }
+}
+
+#[async_trait]
+impl Upsert for HourlyBattles {
+    type Update = Document;
+
+    #[inline]
+    fn query(&self) -> Document {
+        doc! { "rlm": self.realm.to_str(), "t": self.hour }
+    }
+
+    /// Adds up the battles instead of overwriting them.
+    #[inline]
+    fn update(&self) -> Result<Self::Update> {
+        Ok(doc! { "$inc": { "n": self.n_battles } })
+    }
+}
@@ -0,0 +1,87 @@
+//! Nicknames resolved to the account IDs, so that the `/{realm}/@{nickname}` links
+//! don't call the API on every click.
+
+use mongodb::bson::{doc, Document};
+use mongodb::options::IndexOptions;
+use mongodb::{bson, Database, IndexModel};
+use serde::{Deserialize, Serialize};
+use serde_with::TryFromInto;
+
+use crate::database::mongodb::traits::{Indexes, TypedDocument, Upsert};
+use crate::prelude::*;
+use crate::wargaming;
+
+#[serde_with::serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct NicknameResolution {
+    #[serde(rename = "rlm")]
+    pub realm: wargaming::Realm,
+
+    /// Lower-cased nickname.
+    #[serde(rename = "nn")]
+    pub nickname: String,
+
+    #[serde_as(as = "TryFromInto<i32>")]
+    #[serde(rename = "aid")]
+    pub account_id: wargaming::AccountId,
+
+    #[serde(rename = "t")]
+    #[serde_as(as = "bson::DateTime")]
+    pub resolved_at: DateTime,
+}
+
+impl TypedDocument for NicknameResolution {
+    const NAME: &'static str = "nickname_resolutions";
+}
+
+impl NicknameResolution {
+    /// The nicknames may be changed, or released and taken by other players.
+    const TTL: time::Duration = time::Duration::from_secs(86400);
+
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm, nickname = nickname))]
+    pub async fn retrieve(
+        from: &Database,
+        realm: wargaming::Realm,
+        nickname: &str,
+    ) -> Result<Option<wargaming::AccountId>> {
+        let filter = doc! { "rlm": realm.to_str(), "nn": nickname };
+        let resolution = Self::collection(from)
+            .find_one(filter, None)
+            .await
+            .with_context(|| format!("failed to retrieve the nickname `{nickname}`"))?;
+        Ok(resolution.map(|resolution| resolution.account_id))
+    }
+}
+
+#[async_trait]
+impl Indexes for NicknameResolution {
+    type I = [IndexModel; 2];
+
+    fn indexes() -> Self::I {
+        [
+            IndexModel::builder()
+                .keys(doc! { "rlm": 1, "nn": 1 })
+                .options(IndexOptions::builder().unique(true).build())
+                .build(),
+            IndexModel::builder()
+                .keys(doc! { "t": 1 })
+                .options(IndexOptions::builder().expire_after(Self::TTL).build())
+                .build(),
+        ]
+    }
+}
+
+#[async_trait]
+impl Upsert for NicknameResolution {
+    type Update = Document;
+
+    #[inline]
+    fn query(&self) -> Document {
+        doc! { "rlm": self.realm.to_str(), "nn": &self.nickname }
+    }
+
+    #[inline]
+    fn update(&self) -> Result<Self::Update> {
+        Ok(doc! { "$set": bson::to_document(self)? })
+    }
+}
@@ -6,11 +6,13 @@ use serde::Deserialize;
 use serde_with::TryFromInto;
 
 use crate::database::mongodb::traits::{Indexes, TypedDocument, Upsert};
+use crate::database::{AccountSnapshot, Granularity};
+use crate::helpers::sentry::ChildSpan;
 use crate::prelude::*;
 use crate::wargaming;
 
 #[serde_with::serde_as]
-#[derive(Deserialize)]
+#[derive(Deserialize, Debug)]
 pub struct RatingSnapshot {
     #[serde(rename = "rlm")]
     pub realm: wargaming::Realm,
@@ -43,19 +45,95 @@ impl TypedDocument for RatingSnapshot {
 
 impl RatingSnapshot {
     pub fn new(realm: wargaming::Realm, account_info: &wargaming::AccountInfo) -> Option<Self> {
-        let has_rating = account_info.stats.rating.current_season != 0
-            // This is bad, but there's no telling other than that.
-            && account_info.stats.rating.mm_rating.0 != 0.0;
+        Self::from_rating(
+            realm,
+            account_info.id,
+            account_info.last_battle_time,
+            account_info.stats.rating.current_season,
+            account_info.stats.rating.mm_rating,
+        )
+    }
+
+    /// Derives the snapshot from the historical account snapshot.
+    pub fn from_account_snapshot(snapshot: &AccountSnapshot) -> Option<Self> {
+        Self::from_rating(
+            snapshot.realm,
+            snapshot.account_id,
+            snapshot.last_battle_time,
+            snapshot.rating_stats.current_season,
+            snapshot.rating_stats.mm_rating,
+        )
+    }
+
+    fn from_rating(
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+        last_battle_time: DateTime,
+        season: u16,
+        mm_rating: wargaming::MmRating,
+    ) -> Option<Self> {
+        // This is bad, but there's no telling other than that.
+        let has_rating = season != 0 && mm_rating.0 != 0.0;
         has_rating.then(|| Self {
             realm,
-            account_id: account_info.id,
-            season: account_info.stats.rating.current_season,
+            account_id,
+            season,
             #[allow(deprecated)]
-            date: account_info.last_battle_time.date().and_hms(0, 0, 0),
-            open_rating: account_info.stats.rating.mm_rating,
-            close_rating: account_info.stats.rating.mm_rating,
+            date: last_battle_time.date().and_hms(0, 0, 0),
+            open_rating: mm_rating,
+            close_rating: mm_rating,
         })
     }
+
+    /// Tells whether the snapshots belong to the same account, season and day.
+    pub fn is_same_day(&self, other: &Self) -> bool {
+        self.realm == other.realm
+            && self.account_id == other.account_id
+            && self.season == other.season
+            && self.date == other.date
+    }
+
+    /// Merges the daily snapshots, the most recent first, into the weekly or monthly ones:
+    /// the bucket opens with its first rating and closes with its last one.
+    ///
+    /// The merged snapshot is dated with the bucket start.
+    pub fn downsample(snapshots: Vec<Self>, granularity: Granularity) -> Vec<Self> {
+        let mut merged: Vec<Self> = Vec::with_capacity(snapshots.len());
+        for mut snapshot in snapshots {
+            snapshot.date = granularity.bucket_start(snapshot.date);
+            match merged.last_mut() {
+                Some(last) if last.is_same_day(&snapshot) => {
+                    last.open_rating = snapshot.open_rating;
+                }
+                _ => merged.push(snapshot),
+            }
+        }
+        merged
+    }
+
+    /// Picks the finest granularity, which fits the snapshots into the limit.
+    /// The monthly snapshots are returned as is, even if there're still too many of them.
+    pub fn downsample_to(mut snapshots: Vec<Self>, max_points: usize) -> Vec<Self> {
+        for granularity in Granularity::ALL {
+            if snapshots.len() <= max_points {
+                break;
+            }
+            snapshots = Self::downsample(snapshots, granularity);
+        }
+        snapshots
+    }
+
+    /// Inserts the snapshot unless the day is already there, so that the crawled ratings win.
+    pub async fn insert_missing(&self, to: &Database) -> Result {
+        let update = doc! {
+            "$setOnInsert": { "op": self.open_rating.0, "cl": self.close_rating.0 },
+        };
+        Self::collection(to)
+            .update_one(self.query(), update, Self::upsert_options())
+            .await
+            .with_context(|| format!("failed to insert into `{}`", Self::NAME))?;
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -113,14 +191,65 @@ impl RatingSnapshot {
             "aid": account_id,
             "szn": season as i32
         };
+        let span = ChildSpan::start("db.find", || format!("{}: {}", Self::NAME, filter));
         let options = FindOptions::builder().sort(doc! { "dt": -1 }).build();
-        let snapshots = Self::collection(from)
+        let snapshots: Vec<Self> = Self::collection(from)
             .find(filter, options)
             .await
             .context("failed to query the ratings")?
             .try_collect()
             .await
             .context("failed to collect the ratings")?;
+        span.set_data("n_rows", snapshots.len());
+        span.finish();
         Ok(snapshots)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(date: &str, open: f64, close: f64) -> RatingSnapshot {
+        RatingSnapshot {
+            realm: wargaming::Realm::Europe,
+            account_id: 1,
+            season: 100,
+            date: format!("{date}T00:00:00Z").parse().unwrap(),
+            open_rating: open.into(),
+            close_rating: close.into(),
+        }
+    }
+
+    #[test]
+    fn downsample_weekly_ok() {
+        // 2022-08-15 is Monday.
+        let snapshots = vec![
+            snapshot("2022-08-16", 30.0, 35.0),
+            snapshot("2022-08-15", 20.0, 25.0),
+            snapshot("2022-08-14", 10.0, 15.0),
+            snapshot("2022-08-12", 5.0, 8.0),
+        ];
+        let merged = RatingSnapshot::downsample(snapshots, Granularity::Weekly);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].date, snapshot("2022-08-15", 0.0, 0.0).date);
+        assert_eq!(merged[0].open_rating.0, 20.0);
+        assert_eq!(merged[0].close_rating.0, 35.0);
+        assert_eq!(merged[1].date, snapshot("2022-08-08", 0.0, 0.0).date);
+        assert_eq!(merged[1].open_rating.0, 5.0);
+        assert_eq!(merged[1].close_rating.0, 15.0);
+    }
+
+    #[test]
+    fn downsample_to_ok() {
+        let snapshots = vec![snapshot("2022-08-16", 1.0, 2.0), snapshot("2022-08-15", 1.0, 2.0)];
+        assert_eq!(RatingSnapshot::downsample_to(snapshots, 2).len(), 2);
+
+        let snapshots = vec![
+            snapshot("2022-08-16", 1.0, 2.0),
+            snapshot("2022-08-15", 1.0, 2.0),
+            snapshot("2022-08-01", 1.0, 2.0),
+        ];
+        assert_eq!(RatingSnapshot::downsample_to(snapshots, 1).len(), 1);
+    }
+}
@@ -2,16 +2,21 @@ use std::ops::Sub;
 
 use futures::TryStreamExt;
 use itertools::{merge_join_by, EitherOrBoth, Itertools};
-use mongodb::bson::{doc, from_document, Document};
-use mongodb::options::IndexOptions;
-use mongodb::{bson, Database, IndexModel};
+use mongodb::bson::{doc, from_document, Bson, Document};
+use mongodb::options::{FindOptions, IndexOptions};
+use mongodb::{bson, Cursor, Database, IndexModel};
 use serde::{Deserialize, Serialize};
 use serde_with::TryFromInto;
-use tokio::spawn;
 use tokio::time::timeout;
 
-use crate::database::mongodb::traits::{Indexes, TypedDocument, Upsert};
-use crate::database::{RandomStatsSnapshot, Root, TankLastBattleTime};
+use crate::database::mongodb::query_budget::spawn;
+use crate::database::mongodb::traits::{Indexes, InsertManyNew, TypedDocument, Upsert};
+use crate::database::{
+    Granularity, RandomStatsSnapshot, Root, TankLastBattleTime, TankSnapshotRollup,
+    TankSnapshotRollupState,
+};
+use crate::helpers::sentry::ChildSpan;
+use crate::helpers::serde::is_default;
 use crate::helpers::tracing::format_elapsed;
 use crate::prelude::*;
 use crate::wargaming;
@@ -40,12 +45,19 @@ pub struct TankSnapshot {
 
     #[serde(flatten)]
     pub stats: RandomStatsSnapshot,
+
+    /// Number of the Ace Tanker badges earned on the tank.
+    #[serde_as(as = "TryFromInto<i32>")]
+    #[serde(default, rename = "nmst", skip_serializing_if = "is_default")]
+    pub n_masteries: u32,
 }
 
 impl TypedDocument for TankSnapshot {
     const NAME: &'static str = "tank_snapshots";
 }
 
+impl InsertManyNew for TankSnapshot {}
+
 impl Indexes for TankSnapshot {
     type I = [IndexModel; 1];
 
@@ -83,7 +95,7 @@ impl TankSnapshot {
         realm: wargaming::Realm,
         account_id: wargaming::AccountId,
         stats: wargaming::TankStats,
-        _achievements: &wargaming::TankAchievements,
+        achievements: &wargaming::TankAchievements,
     ) -> Self {
         Self {
             realm,
@@ -92,6 +104,7 @@ impl TankSnapshot {
             tank_id: stats.tank_id,
             battle_life_time: stats.battle_life_time,
             stats: stats.all.into(),
+            n_masteries: achievements.n_masteries(),
         }
     }
 
@@ -152,6 +165,7 @@ impl Sub<TankSnapshot> for TankSnapshot {
             tank_id: self.tank_id,
             battle_life_time: self.battle_life_time - rhs.battle_life_time,
             stats: self.stats - rhs.stats,
+            n_masteries: self.n_masteries.saturating_sub(rhs.n_masteries),
         }
     }
 }
@@ -170,6 +184,10 @@ impl TankSnapshot {
         Ok(())
     }
 
+    /// Retrieves the latest snapshot of each tank before the specified moment.
+    ///
+    /// For the long periods, consults the rollups for everything before the bucket
+    /// which contains the moment, so that only the bucket itself is scanned in the raw snapshots.
     #[instrument(
         skip_all,
         level = "debug",
@@ -185,16 +203,101 @@ impl TankSnapshot {
         if tank_ids.is_empty() {
             return Ok(Vec::new());
         }
+        if let Some(granularity) = Granularity::for_period(now() - before) {
+            let cutoff = granularity.bucket_start(before);
+            match TankSnapshotRollupState::retrieve(from, realm, granularity).await? {
+                Some(state) if cutoff <= state.until => {
+                    return Self::retrieve_latest_using_rollups(
+                        from,
+                        realm,
+                        account_id,
+                        before,
+                        tank_ids,
+                        granularity,
+                        state.since,
+                        cutoff,
+                    )
+                    .await;
+                }
+                _ => debug!(?granularity, "the rollups are behind"),
+            }
+        }
+        let filter = doc! {
+            "rlm": realm.to_str(),
+            "aid": account_id,
+            "tid": {"$in": tank_ids},
+            "lbts": {"$lt": before},
+        };
+        Self::aggregate_latest(Self::NAME, from, filter).await
+    }
+
+    /// Looks up the tanks in the raw snapshots within the cutoff bucket first,
+    /// then in the rollups, and finally in the raw snapshots before the rollups.
+    #[allow(clippy::too_many_arguments)]
+    #[instrument(level = "debug", skip_all, fields(granularity = ?granularity, cutoff = ?cutoff))]
+    async fn retrieve_latest_using_rollups(
+        from: &Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+        before: DateTime,
+        tank_ids: &[wargaming::TankId],
+        granularity: Granularity,
+        since: DateTime,
+        cutoff: DateTime,
+    ) -> Result<Vec<Self>> {
+        let filter = doc! {
+            "rlm": realm.to_str(),
+            "aid": account_id,
+            "tid": {"$in": tank_ids},
+            "lbts": {"$gte": cutoff, "$lt": before},
+        };
+        let mut snapshots = Self::aggregate_latest(Self::NAME, from, filter).await?;
+
+        let tank_ids = Self::exclude_found(tank_ids, &snapshots);
+        if !tank_ids.is_empty() {
+            let filter = doc! {
+                "rlm": realm.to_str(),
+                "aid": account_id,
+                "tid": {"$in": &tank_ids},
+                "gr": granularity.to_str(),
+                "bkt": {"$gte": since, "$lt": cutoff},
+            };
+            snapshots
+                .extend(Self::aggregate_latest(TankSnapshotRollup::NAME, from, filter).await?);
+        }
 
+        let tank_ids = Self::exclude_found(&tank_ids, &snapshots);
+        if !tank_ids.is_empty() {
+            debug!(n_tanks = tank_ids.len(), "falling back to the raw snapshots");
+            let filter = doc! {
+                "rlm": realm.to_str(),
+                "aid": account_id,
+                "tid": {"$in": &tank_ids},
+                "lbts": {"$lt": since},
+            };
+            snapshots.extend(Self::aggregate_latest(Self::NAME, from, filter).await?);
+        }
+
+        Ok(snapshots)
+    }
+
+    fn exclude_found(tank_ids: &[wargaming::TankId], found: &[Self]) -> Vec<wargaming::TankId> {
+        tank_ids
+            .iter()
+            .copied()
+            .filter(|tank_id| !found.iter().any(|snapshot| snapshot.tank_id == *tank_id))
+            .collect()
+    }
+
+    /// Runs the pipeline which picks the latest matched snapshot of each tank.
+    async fn aggregate_latest(
+        collection_name: &str,
+        from: &Database,
+        filter: Document,
+    ) -> Result<Vec<Self>> {
+        let account_id = filter.get("aid").cloned();
         let pipeline = [
-            doc! {
-                "$match": {
-                    "rlm": realm.to_str(),
-                    "aid": account_id,
-                    "tid": {"$in": tank_ids},
-                    "lbts": {"$lt": before},
-                },
-            },
+            doc! { "$match": filter },
             doc! { "$sort": { "lbts": -1_i32 } },
             doc! {
                 "$group": {
@@ -204,15 +307,19 @@ impl TankSnapshot {
             },
         ];
 
+        let span = ChildSpan::start("db.aggregate", || {
+            let stages = pipeline.iter().cloned().map(Bson::Document).collect();
+            format!("{collection_name}: {}", Bson::Array(stages))
+        });
         let start_instant = Instant::now();
-        debug!("running the pipeline…");
-        let collection = Self::collection(from);
+        debug!(collection_name, "running the pipeline…");
+        let collection = from.collection::<Document>(collection_name);
         let future = spawn(async move { collection.aggregate(pipeline, None).await });
         let cursor = timeout(time::Duration::from_secs(30), future)
             .await
             .context("timed out to retrieve the latest tanks snapshots")??
             .with_context(|| {
-                format!("failed to retrieve the latest tank snapshots for #{account_id}")
+                format!("failed to retrieve the latest tank snapshots for {account_id:?}")
             })?;
         let stream = cursor
             .try_filter_map(|document| async move {
@@ -223,6 +330,8 @@ impl TankSnapshot {
             .await?;
 
         debug!(elapsed = format_elapsed(start_instant).as_str(), "done");
+        span.set_data("n_rows", stream.len());
+        span.finish();
         Ok(stream)
     }
 
@@ -261,4 +370,121 @@ impl TankSnapshot {
         );
         Ok(snapshots)
     }
+
+    /// Streams a page of the account's snapshots, ordered by the last battle time and tank ID.
+    ///
+    /// The page starts right after the `(since, after_tank_id)` cursor, which is the last
+    /// snapshot's last battle time and tank ID. Without the tank ID, the page starts
+    /// after all the snapshots with the last battle time.
+    #[instrument(
+        skip_all,
+        level = "info",
+        fields(account_id = account_id, since = ?since, after_tank_id = ?after_tank_id),
+    )]
+    pub async fn retrieve_page(
+        from: &Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+        since: DateTime,
+        after_tank_id: Option<wargaming::TankId>,
+        limit: i64,
+    ) -> Result<Cursor<Self>> {
+        let filter = Self::page_filter(realm, account_id, since, after_tank_id);
+        let options = FindOptions::builder()
+            .sort(doc! { "lbts": 1, "tid": 1 })
+            .limit(limit)
+            .build();
+        Ok(Self::collection(from).find(filter, options).await?)
+    }
+
+    /// The snapshots share the last battle time, when the account played multiple tanks
+    /// between the crawls, so the time alone can't be the cursor.
+    fn page_filter(
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+        since: DateTime,
+        after_tank_id: Option<wargaming::TankId>,
+    ) -> Document {
+        match after_tank_id {
+            Some(tank_id) => doc! {
+                "rlm": realm.to_str(),
+                "aid": account_id,
+                "$or": [
+                    { "lbts": { "$gt": since } },
+                    { "lbts": since, "tid": { "$gt": tank_id } },
+                ],
+            },
+            None => doc! { "rlm": realm.to_str(), "aid": account_id, "lbts": { "$gt": since } },
+        }
+    }
+
+    /// Streams the realm's snapshots with the last battle time within the specified range.
+    #[instrument(skip_all, level = "info", fields(realm = ?realm, since = ?since, until = ?until))]
+    pub async fn retrieve_range(
+        from: &Database,
+        realm: wargaming::Realm,
+        since: DateTime,
+        until: DateTime,
+    ) -> Result<Cursor<Self>> {
+        let filter = doc! {
+            "rlm": realm.to_str(),
+            "lbts": { "$gte": since, "$lt": until },
+        };
+        let options = FindOptions::builder().batch_size(10000).build();
+        Ok(Self::collection(from).find(filter, options).await?)
+    }
+
+    /// Retrieves all the snapshots before the specified moment,
+    /// grouped by account and tank, the latest snapshot first.
+    #[instrument(skip_all, level = "info", fields(realm = ?realm, until = ?until))]
+    pub async fn retrieve_grouped_until(
+        from: &Database,
+        realm: wargaming::Realm,
+        until: DateTime,
+    ) -> Result<Cursor<Self>> {
+        let filter = doc! { "rlm": realm.to_str(), "lbts": { "$lt": until } };
+        let options = FindOptions::builder()
+            .sort(doc! { "rlm": 1, "aid": 1, "tid": 1, "lbts": -1 })
+            .batch_size(10000)
+            .build();
+        Ok(Self::collection(from).find(filter, options).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_filter_with_tank_id_ok() {
+        let since = Utc.timestamp_opt(1660050000, 0).unwrap();
+        let filter = TankSnapshot::page_filter(wargaming::Realm::Europe, 1, since, Some(42));
+        assert_eq!(
+            filter,
+            doc! {
+                "rlm": "eu",
+                "aid": 1,
+                "$or": [{ "lbts": { "$gt": since } }, { "lbts": since, "tid": { "$gt": 42 } }],
+            },
+        );
+    }
+
+    /// The export documents the cursor in this exact shape.
+    #[test]
+    fn serialize_cursor_ok() -> Result {
+        let snapshot = TankSnapshot {
+            realm: wargaming::Realm::Europe,
+            last_battle_time: Utc.timestamp_opt(1660050000, 0).unwrap(),
+            account_id: 1,
+            tank_id: 42,
+            battle_life_time: Duration::zero(),
+            stats: RandomStatsSnapshot::default(),
+            n_masteries: 0,
+        };
+        let json = serde_json::to_value(snapshot)?;
+        let lbts = serde_json::json!({ "$date": { "$numberLong": "1660050000000" } });
+        assert_eq!(json["lbts"], lbts);
+        assert_eq!(json["tid"], 42);
+        Ok(())
+    }
 }
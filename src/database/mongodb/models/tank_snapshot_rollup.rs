@@ -0,0 +1,249 @@
+//! Pre-aggregated tank snapshots for the long periods.
+//!
+//! A rollup contains the latest snapshot of each tank within a week or a month,
+//! so that looking up the tank statistics a year ago does not have to scan the entire history.
+
+use chrono::NaiveDate;
+use mongodb::bson::{doc, Document};
+use mongodb::options::{AggregateOptions, FindOneOptions, IndexOptions};
+use mongodb::{bson, Database, IndexModel};
+use serde::{Deserialize, Serialize};
+
+use crate::database::mongodb::traits::{Indexes, TypedDocument, Upsert};
+use crate::database::TankSnapshot;
+use crate::prelude::*;
+use crate::wargaming;
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Granularity {
+    #[serde(rename = "w")]
+    Weekly,
+
+    #[serde(rename = "m")]
+    Monthly,
+}
+
+impl Granularity {
+    pub const ALL: [Self; 2] = [Self::Weekly, Self::Monthly];
+
+    /// Selects the granularity which is sufficiently precise for the period.
+    pub fn for_period(period: Duration) -> Option<Self> {
+        if period >= Duration::days(180) {
+            Some(Self::Monthly)
+        } else if period >= Duration::days(28) {
+            Some(Self::Weekly)
+        } else {
+            None
+        }
+    }
+
+    pub const fn to_str(self) -> &'static str {
+        match self {
+            Self::Weekly => "w",
+            Self::Monthly => "m",
+        }
+    }
+
+    /// Start of the bucket which contains the specified moment:
+    /// Monday for the weekly granularity and the 1st day for the monthly one.
+    pub fn bucket_start(self, moment: DateTime) -> DateTime {
+        let date = moment.date_naive();
+        let start = match self {
+            Self::Weekly => date - Duration::days(date.weekday().num_days_from_monday() as i64),
+            Self::Monthly => date.with_day(1).unwrap(),
+        };
+        Self::midnight(start)
+    }
+
+    /// Start of the bucket which follows the specified one.
+    pub fn next_bucket_start(self, bucket_start: DateTime) -> DateTime {
+        match self {
+            Self::Weekly => bucket_start + Duration::days(7),
+            Self::Monthly => {
+                let date = bucket_start.date_naive();
+                let next = if date.month() == 12 {
+                    NaiveDate::from_ymd_opt(date.year() + 1, 1, 1)
+                } else {
+                    NaiveDate::from_ymd_opt(date.year(), date.month() + 1, 1)
+                };
+                Self::midnight(next.unwrap())
+            }
+        }
+    }
+
+    fn midnight(date: NaiveDate) -> DateTime {
+        Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap())
+    }
+}
+
+#[derive(Deserialize)]
+pub struct TankSnapshotRollup {
+    #[serde(flatten)]
+    pub snapshot: TankSnapshot,
+}
+
+impl TypedDocument for TankSnapshotRollup {
+    const NAME: &'static str = "tank_snapshot_rollups";
+}
+
+impl Indexes for TankSnapshotRollup {
+    type I = [IndexModel; 1];
+
+    fn indexes() -> Self::I {
+        [
+            // Ensures the only entry per tank per bucket, and is required by `$merge`.
+            IndexModel::builder()
+                .keys(doc! { "rlm": 1, "aid": 1, "tid": 1, "gr": 1, "bkt": -1 })
+                .options(IndexOptions::builder().unique(true).build())
+                .build(),
+        ]
+    }
+}
+
+impl TankSnapshotRollup {
+    /// Rolls up the realm's tank snapshots within the bucket.
+    #[instrument(skip_all, level = "info", fields(realm = ?realm, granularity = ?granularity, bucket_start = ?bucket_start))]
+    pub async fn rollup_bucket(
+        in_: &Database,
+        realm: wargaming::Realm,
+        granularity: Granularity,
+        bucket_start: DateTime,
+    ) -> Result {
+        let bucket_end = granularity.next_bucket_start(bucket_start);
+        let pipeline = [
+            doc! {
+                "$match": {
+                    "rlm": realm.to_str(),
+                    "lbts": { "$gte": bucket_start, "$lt": bucket_end },
+                },
+            },
+            doc! { "$sort": { "lbts": -1_i32 } },
+            doc! {
+                "$group": {
+                    "_id": { "aid": "$aid", "tid": "$tid" },
+                    "root": { "$first": "$$ROOT" },
+                },
+            },
+            doc! { "$replaceRoot": { "newRoot": "$root" } },
+            doc! { "$unset": "_id" },
+            doc! { "$set": { "gr": granularity.to_str(), "bkt": bucket_start } },
+            doc! {
+                "$merge": {
+                    "into": Self::NAME,
+                    "on": ["rlm", "aid", "tid", "gr", "bkt"],
+                    "whenMatched": "replace",
+                    "whenNotMatched": "insert",
+                },
+            },
+        ];
+        let options = AggregateOptions::builder().allow_disk_use(true).build();
+        let start_instant = Instant::now();
+        TankSnapshot::collection(in_)
+            .aggregate(pipeline, options)
+            .await
+            .with_context(|| format!("failed to roll up the bucket {bucket_start}"))?;
+        info!(elapsed = ?start_instant.elapsed(), "rolled up");
+        Ok(())
+    }
+}
+
+/// Tracks the rollup progress.
+#[serde_with::serde_as]
+#[derive(Serialize, Deserialize)]
+pub struct TankSnapshotRollupState {
+    #[serde(rename = "rlm")]
+    pub realm: wargaming::Realm,
+
+    #[serde(rename = "gr")]
+    pub granularity: Granularity,
+
+    /// Start of the first rolled up bucket.
+    #[serde(rename = "since")]
+    #[serde_as(as = "bson::DateTime")]
+    pub since: DateTime,
+
+    /// End of the last rolled up bucket.
+    #[serde(rename = "until")]
+    #[serde_as(as = "bson::DateTime")]
+    pub until: DateTime,
+}
+
+impl TypedDocument for TankSnapshotRollupState {
+    const NAME: &'static str = "tank_snapshot_rollup_states";
+}
+
+impl Indexes for TankSnapshotRollupState {
+    type I = [IndexModel; 1];
+
+    fn indexes() -> Self::I {
+        [IndexModel::builder()
+            .keys(doc! { "rlm": 1, "gr": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build()]
+    }
+}
+
+#[async_trait]
+impl Upsert for TankSnapshotRollupState {
+    type Update = Document;
+
+    #[inline]
+    fn query(&self) -> Document {
+        doc! { "rlm": self.realm.to_str(), "gr": self.granularity.to_str() }
+    }
+
+    #[inline]
+    fn update(&self) -> Result<Self::Update> {
+        Ok(doc! { "$set": bson::to_bson(self)? })
+    }
+}
+
+impl TankSnapshotRollupState {
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm, granularity = ?granularity))]
+    pub async fn retrieve(
+        from: &Database,
+        realm: wargaming::Realm,
+        granularity: Granularity,
+    ) -> Result<Option<Self>> {
+        let filter = doc! { "rlm": realm.to_str(), "gr": granularity.to_str() };
+        Self::collection(from)
+            .find_one(filter, FindOneOptions::default())
+            .await
+            .context("failed to retrieve the rollup state")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weekly_bucket_ok() {
+        // Wednesday.
+        let moment = Utc.with_ymd_and_hms(2022, 8, 10, 15, 30, 0).unwrap();
+        let start = Granularity::Weekly.bucket_start(moment);
+        assert_eq!(start, Utc.with_ymd_and_hms(2022, 8, 8, 0, 0, 0).unwrap());
+        assert_eq!(
+            Granularity::Weekly.next_bucket_start(start),
+            Utc.with_ymd_and_hms(2022, 8, 15, 0, 0, 0).unwrap(),
+        );
+    }
+
+    #[test]
+    fn monthly_bucket_ok() {
+        let moment = Utc.with_ymd_and_hms(2022, 12, 10, 15, 30, 0).unwrap();
+        let start = Granularity::Monthly.bucket_start(moment);
+        assert_eq!(start, Utc.with_ymd_and_hms(2022, 12, 1, 0, 0, 0).unwrap());
+        assert_eq!(
+            Granularity::Monthly.next_bucket_start(start),
+            Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap(),
+        );
+    }
+
+    #[test]
+    fn for_period_ok() {
+        assert_eq!(Granularity::for_period(Duration::days(7)), None);
+        assert_eq!(Granularity::for_period(Duration::days(30)), Some(Granularity::Weekly));
+        assert_eq!(Granularity::for_period(Duration::days(365)), Some(Granularity::Monthly));
+    }
+}
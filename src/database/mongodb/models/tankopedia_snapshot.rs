@@ -0,0 +1,102 @@
+//! Imported tankopedias, so that the old periods display the vehicles as they were back then,
+//! even though the vehicles have been renamed or re-tiered since.
+
+use mongodb::bson::{doc, Document};
+use mongodb::options::{FindOneOptions, UpdateOptions};
+use mongodb::{bson, Database, IndexModel};
+use serde::{Deserialize, Serialize};
+
+use crate::database::mongodb::traits::{Indexes, TypedDocument};
+use crate::database::VehicleAttributes;
+use crate::prelude::*;
+use crate::wargaming::Vehicle;
+
+#[serde_with::serde_as]
+#[derive(Serialize, Deserialize)]
+pub struct TankopediaSnapshot {
+    /// Import time, which also versions the snapshot.
+    #[serde(rename = "ts")]
+    #[serde_as(as = "bson::DateTime")]
+    pub timestamp: DateTime,
+
+    #[serde(rename = "v")]
+    pub vehicles: Vec<Vehicle>,
+}
+
+impl TypedDocument for TankopediaSnapshot {
+    const NAME: &'static str = "tankopedia_snapshots";
+}
+
+#[async_trait]
+impl Indexes for TankopediaSnapshot {
+    type I = [IndexModel; 1];
+
+    fn indexes() -> Self::I {
+        [IndexModel::builder().keys(doc! { "ts": -1 }).build()]
+    }
+}
+
+impl TankopediaSnapshot {
+    pub fn new(vehicles: Vec<Vehicle>) -> Self {
+        Self {
+            timestamp: now(),
+            vehicles,
+        }
+    }
+
+    pub async fn insert(&self, into: &Database) -> Result {
+        Self::collection(into)
+            .insert_one(self, None)
+            .await
+            .context("failed to insert the tankopedia snapshot")?;
+        Ok(())
+    }
+
+    /// Applies the attributes to the vehicle in all the snapshots,
+    /// because the attributes are not historical.
+    #[instrument(skip_all, level = "debug", fields(tank_id = attributes.tank_id))]
+    pub async fn apply_attributes(to: &Database, attributes: &VehicleAttributes) -> Result<u64> {
+        let update = doc! { "$set": { "v.$[vehicle].is_collectible": attributes.is_collectible } };
+        let options = UpdateOptions::builder()
+            .array_filters(vec![doc! { "vehicle.tank_id": attributes.tank_id }])
+            .build();
+        let result = Self::collection(to)
+            .update_many(doc! { "v.tank_id": attributes.tank_id }, update, options)
+            .await
+            .context("failed to apply the vehicle attributes to the tankopedia snapshots")?;
+        Ok(result.modified_count)
+    }
+
+    /// Retrieves the latest snapshot imported before the specified moment, if any.
+    #[instrument(skip_all, level = "debug", fields(as_of = ?as_of))]
+    pub async fn retrieve_as_of(from: &Database, as_of: DateTime) -> Result<Option<Self>> {
+        let filter = doc! { "ts": { "$lte": as_of } };
+        let options = FindOneOptions::builder().sort(doc! { "ts": -1 }).build();
+        Self::collection(from)
+            .find_one(filter, options)
+            .await
+            .with_context(|| format!("failed to retrieve the tankopedia as of {as_of}"))
+    }
+
+    /// Retrieves only the timestamp of the snapshot, which [`Self::retrieve_as_of`] would return.
+    #[instrument(skip_all, level = "debug", fields(as_of = ?as_of))]
+    pub async fn retrieve_timestamp_as_of(
+        from: &Database,
+        as_of: DateTime,
+    ) -> Result<Option<DateTime>> {
+        let filter = doc! { "ts": { "$lte": as_of } };
+        let options = FindOneOptions::builder()
+            .sort(doc! { "ts": -1 })
+            .projection(doc! { "_id": 0, "ts": 1 })
+            .build();
+        let document = Self::collection(from)
+            .clone_with_type::<Document>()
+            .find_one(filter, options)
+            .await
+            .with_context(|| format!("failed to retrieve the tankopedia timestamp as of {as_of}"))?;
+        match document {
+            Some(document) => Ok(Some(document.get_datetime("ts")?.to_chrono())),
+            None => Ok(None),
+        }
+    }
+}
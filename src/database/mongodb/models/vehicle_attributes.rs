@@ -0,0 +1,80 @@
+//! Vehicle metadata, which the Wargaming.net API does not expose.
+//!
+//! The bundled `tankopedia/attributes.json` provides the defaults, and the documents
+//! override them per vehicle, so that new collectibles do not require a release.
+
+use mongodb::bson::{doc, Document};
+use mongodb::options::IndexOptions;
+use mongodb::{Database, IndexModel};
+use serde::{Deserialize, Serialize};
+
+use crate::database::mongodb::traits::{Indexes, TypedDocument, Upsert};
+use crate::prelude::*;
+use crate::wargaming;
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VehicleAttributes {
+    #[serde(rename = "tid")]
+    pub tank_id: wargaming::TankId,
+
+    #[serde(rename = "coll", default)]
+    pub is_collectible: bool,
+}
+
+impl TypedDocument for VehicleAttributes {
+    const NAME: &'static str = "vehicle_attributes";
+}
+
+#[async_trait]
+impl Indexes for VehicleAttributes {
+    type I = [IndexModel; 1];
+
+    fn indexes() -> Self::I {
+        [IndexModel::builder()
+            .keys(doc! { "tid": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build()]
+    }
+}
+
+#[async_trait]
+impl Upsert for VehicleAttributes {
+    type Update = Document;
+
+    #[inline]
+    fn query(&self) -> Document {
+        doc! { "tid": self.tank_id }
+    }
+
+    #[inline]
+    fn update(&self) -> Result<Self::Update> {
+        Ok(doc! { "$set": { "coll": self.is_collectible } })
+    }
+}
+
+impl VehicleAttributes {
+    pub const fn apply(&self, vehicle: &mut wargaming::Vehicle) {
+        vehicle.is_collectible = self.is_collectible;
+    }
+
+    #[instrument(skip_all, level = "debug")]
+    pub async fn retrieve_all(from: &Database) -> Result<Vec<Self>> {
+        Self::find_vec(from, doc! {}, None).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_ok() {
+        let mut vehicle = wargaming::Vehicle::new_hardcoded(113);
+        let attributes = VehicleAttributes {
+            tank_id: 113,
+            is_collectible: true,
+        };
+        attributes.apply(&mut vehicle);
+        assert!(vehicle.is_collectible);
+    }
+}
@@ -0,0 +1,133 @@
+//! Request-scoped accounting of the MongoDB commands, which helps to hunt down
+//! the pathological pages.
+//!
+//! The driver emits the command events from within the calling task,
+//! so a task-local budget sees all the commands issued by the scoped future.
+//! The tasks, spawned from within the scope, must be spawned with [`spawn`]
+//! for their commands to get accounted as well.
+
+use std::future::Future;
+use std::sync::Mutex;
+
+use tokio::task::JoinHandle;
+
+use mongodb::event::command::{
+    CommandEventHandler, CommandFailedEvent, CommandStartedEvent, CommandSucceededEvent,
+};
+
+use crate::prelude::*;
+
+/// Commands beyond this number are counted, but not kept for the report.
+const MAX_REPORTED_COMMANDS: usize = 50;
+
+tokio::task_local! {
+    static BUDGET: Arc<Mutex<QueryReport>>;
+}
+
+#[derive(Default, Debug)]
+pub struct QueryReport {
+    pub n_commands: usize,
+
+    /// Cumulative time of the completed commands.
+    pub total_time: time::Duration,
+
+    /// Command names along with their pipelines or filters, if any.
+    pub commands: Vec<String>,
+}
+
+impl QueryReport {
+    fn on_started(&mut self, event: &CommandStartedEvent) {
+        self.n_commands += 1;
+        if self.commands.len() < MAX_REPORTED_COMMANDS {
+            self.commands.push(Self::describe(event));
+        }
+    }
+
+    fn describe(event: &CommandStartedEvent) -> String {
+        let collection = event.command.get_str(&event.command_name).unwrap_or_default();
+        match ["pipeline", "filter", "q"]
+            .into_iter()
+            .find_map(|key| event.command.get(key))
+        {
+            Some(argument) => format!("{} {}: {}", event.command_name, collection, argument),
+            None => format!("{} {}", event.command_name, collection),
+        }
+    }
+}
+
+/// Runs the future while accounting its MongoDB commands.
+pub async fn scope<F: Future>(future: F) -> (F::Output, QueryReport) {
+    BUDGET
+        .scope(Arc::default(), async move {
+            let output = future.await;
+            let report = BUDGET.with(|budget| std::mem::take(&mut *budget.lock().unwrap()));
+            (output, report)
+        })
+        .await
+}
+
+/// Spawns the future, propagating the current budget into the new task, if any.
+pub fn spawn<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    match BUDGET.try_with(Arc::clone) {
+        Ok(budget) => tokio::spawn(BUDGET.scope(budget, future)),
+        Err(_) => tokio::spawn(future),
+    }
+}
+
+/// Feeds the command events into the current budget, if any.
+pub struct QueryBudgetHandler;
+
+impl QueryBudgetHandler {
+    fn with_report(f: impl FnOnce(&mut QueryReport)) {
+        let _ = BUDGET.try_with(|budget| f(&mut budget.lock().unwrap()));
+    }
+}
+
+impl CommandEventHandler for QueryBudgetHandler {
+    fn handle_command_started_event(&self, event: CommandStartedEvent) {
+        Self::with_report(|report| report.on_started(&event));
+    }
+
+    fn handle_command_succeeded_event(&self, event: CommandSucceededEvent) {
+        Self::with_report(|report| report.total_time += event.duration);
+    }
+
+    fn handle_command_failed_event(&self, event: CommandFailedEvent) {
+        Self::with_report(|report| report.total_time += event.duration);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn scope_ok() {
+        let add_time = || {
+            QueryBudgetHandler::with_report(|report| {
+                report.total_time += time::Duration::from_secs(1);
+            })
+        };
+        add_time();
+        let ((), report) = scope(async { add_time() }).await;
+        assert_eq!(report.total_time, time::Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn spawn_ok() -> Result {
+        let (result, report) = scope(async {
+            spawn(async {
+                QueryBudgetHandler::with_report(|report| report.n_commands += 1);
+            })
+            .await
+        })
+        .await;
+        result?;
+        assert_eq!(report.n_commands, 1);
+        Ok(())
+    }
+}
@@ -2,12 +2,16 @@ use std::fmt::Debug;
 
 use futures::TryStreamExt;
 use mongodb::bson::Document;
-use mongodb::options::{FindOptions, UpdateModifications, UpdateOptions, WriteConcern};
+use mongodb::error::{BulkWriteFailure, ErrorKind};
+use mongodb::options::{
+    FindOptions, InsertManyOptions, UpdateModifications, UpdateOptions, WriteConcern,
+};
 use mongodb::{Collection, Database, IndexModel};
 use serde::de::DeserializeOwned;
-use tokio::spawn;
+use serde::Serialize;
 use tokio::time::timeout;
 
+use crate::database::mongodb::query_budget::spawn;
 use crate::prelude::*;
 
 #[async_trait]
@@ -89,3 +93,39 @@ pub trait Upsert: TypedDocument {
             .build()
     }
 }
+
+#[async_trait]
+pub trait InsertManyNew: TypedDocument + Serialize {
+    /// See <https://www.mongodb.com/docs/manual/reference/error-codes/>.
+    const DUPLICATE_KEY_ERROR_CODE: i32 = 11000;
+
+    /// Inserts the documents, skipping those which are already in the database.
+    ///
+    /// # Returns
+    ///
+    /// Number of the actually inserted documents.
+    #[instrument(skip_all, level = "debug", fields(collection = Self::NAME, n_documents = documents.len()))]
+    async fn insert_many_new(into: &Database, documents: &[Self]) -> Result<usize> {
+        if documents.is_empty() {
+            return Ok(0);
+        }
+        let options = InsertManyOptions::builder().ordered(false).build();
+        match Self::collection(into).insert_many(documents, options).await {
+            Ok(result) => Ok(result.inserted_ids.len()),
+            Err(error) => match error.kind.as_ref() {
+                ErrorKind::BulkWrite(BulkWriteFailure {
+                    write_errors: Some(write_errors),
+                    write_concern_error: None,
+                    ..
+                }) if write_errors
+                    .iter()
+                    .all(|error| error.code == Self::DUPLICATE_KEY_ERROR_CODE) =>
+                {
+                    debug!(n_duplicates = write_errors.len());
+                    Ok(documents.len() - write_errors.len())
+                }
+                _ => Err(error).with_context(|| format!("failed to insert into `{}`", Self::NAME)),
+            },
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! Discovery of the unknown accounts, so that bootstrapping a realm does not require
+//! a brute-force scan of the account ID ranges.
+
+use ahash::AHashSet;
+use itertools::Itertools;
+use tokio::time::sleep;
+
+use crate::crawler::fast_lane::FastLane;
+use crate::database::mongodb::traits::InsertManyNew;
+use crate::helpers::redis;
+use crate::opts::{DiscoverClanMembersOpts, DiscoverRatingLeadersOpts};
+use crate::prelude::*;
+use crate::wargaming::WargamingApi;
+
+/// Number of the rating leagues, from Diamond down to Bronze.
+const N_RATING_LEAGUES: u8 = 5;
+
+/// Walks the realm's clan list page by page and inserts the clan members,
+/// which are not in the database yet.
+#[instrument(skip_all)]
+pub async fn discover_clan_members(opts: DiscoverClanMembersOpts) -> Result {
+    sentry::configure_scope(|scope| {
+        scope.set_tag("app", "discover-clan-members");
+        scope.set_tag("realm", opts.realm);
+    });
+
+    let connections = &opts.connections;
+    let api = WargamingApi::new(
+        connections.application_ids.clone(),
+        connections.api_timeouts(),
+        connections.max_api_rps,
+    )?;
+    let db = database::mongodb::open(&connections.internal.mongodb_uri).await?;
+
+    let (mut n_clans, mut n_members, mut n_inserted) = (0_usize, 0_usize, 0_usize);
+    for page_no in opts.start_page.. {
+        let clan_ids = api
+            .get_clans(opts.realm, page_no)
+            .await?
+            .into_iter()
+            .map(|clan| clan.id)
+            .collect_vec();
+        if clan_ids.is_empty() {
+            break;
+        }
+        let accounts = api
+            .get_clan_infos(opts.realm, &clan_ids)
+            .await?
+            .into_iter()
+            .flat_map(|info| info.members_ids)
+            .map(|account_id| database::Account::new(opts.realm, account_id))
+            .collect_vec();
+        n_clans += clan_ids.len();
+        n_members += accounts.len();
+        n_inserted += database::Account::insert_many_new(&db, &accounts).await?;
+        info!(page_no, n_clans, n_members, n_inserted, "discovering…");
+    }
+
+    info!(n_clans, n_members, n_inserted, "discovered");
+    Ok(())
+}
+
+/// Periodically fetches the rating league leaderboards, inserts the unknown leaders,
+/// and pushes them into the fast lane, so that they get crawled ahead of the sampled accounts.
+#[instrument(skip_all)]
+pub async fn discover_rating_leaders(opts: DiscoverRatingLeadersOpts) -> Result {
+    sentry::configure_scope(|scope| {
+        scope.set_tag("app", "discover-rating-leaders");
+        scope.set_tag("realm", opts.realm);
+    });
+
+    let connections = &opts.connections;
+    let api = WargamingApi::new(
+        connections.application_ids.clone(),
+        connections.api_timeouts(),
+        connections.max_api_rps,
+    )?;
+    let db = database::mongodb::open(&connections.internal.mongodb_uri).await?;
+    let redis =
+        redis::connect(&connections.internal.redis_uri, connections.internal.redis_pool_size)
+            .await?;
+    let fast_lane = FastLane::new(redis, opts.interval);
+
+    loop {
+        let mut account_ids = Vec::new();
+        for league in 0..N_RATING_LEAGUES {
+            let leaders = api.get_rating_leaderboard(opts.realm, league).await?;
+            account_ids.extend(leaders.into_iter().map(|leader| leader.account_id));
+        }
+        account_ids.sort_unstable();
+        account_ids.dedup();
+
+        let known_ids: AHashSet<wargaming::AccountId> =
+            database::Account::retrieve_many(&db, opts.realm, &account_ids)
+                .await?
+                .into_iter()
+                .map(|account| account.id)
+                .collect();
+        let unknown_accounts = account_ids
+            .into_iter()
+            .filter(|account_id| !known_ids.contains(account_id))
+            .map(|account_id| database::Account::new(opts.realm, account_id))
+            .collect_vec();
+        let n_inserted = database::Account::insert_many_new(&db, &unknown_accounts).await?;
+        for account in &unknown_accounts {
+            fast_lane.push(account).await?;
+        }
+        info!(n_known = known_ids.len(), n_inserted, "discovered");
+
+        sleep(opts.interval).await;
+    }
+}
@@ -0,0 +1,44 @@
+//! Shell completions and man pages, generated from the CLI options.
+
+use std::fs;
+use std::fs::File;
+
+use clap::CommandFactory;
+use clap_complete::Shell;
+use clap_mangen::Man;
+
+use crate::opts::{GenerateOpts, Opts};
+use crate::prelude::*;
+
+const BIN_NAME: &str = "blitz-dashboard";
+
+#[instrument(skip_all)]
+pub fn run(opts: &GenerateOpts) -> Result {
+    fs::create_dir_all(&opts.out_dir)
+        .with_context(|| format!("failed to create `{}`", opts.out_dir.display()))?;
+
+    let mut command = Opts::command();
+    for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+        let path = clap_complete::generate_to(shell, &mut command, BIN_NAME, &opts.out_dir)
+            .with_context(|| format!("failed to generate the {shell} completions"))?;
+        info!(?path, "generated");
+    }
+
+    command.build();
+    render_man(command.clone().name(BIN_NAME), opts)?;
+    for subcommand in command.get_subcommands().filter(|subcommand| !subcommand.is_hide_set()) {
+        let name = format!("{BIN_NAME}-{}", subcommand.get_name());
+        render_man(subcommand.clone().name(name), opts)?;
+    }
+
+    Ok(())
+}
+
+fn render_man(command: clap::Command, opts: &GenerateOpts) -> Result {
+    let path = opts.out_dir.join(format!("{}.1", command.get_name()));
+    let mut file =
+        File::create(&path).with_context(|| format!("failed to create `{}`", path.display()))?;
+    Man::new(command).render(&mut file)?;
+    info!(?path, "generated");
+    Ok(())
+}
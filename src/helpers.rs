@@ -1,9 +1,14 @@
 pub mod average;
+pub mod backoff;
 pub mod compression;
 pub mod hash;
+pub mod histogram;
+pub mod lock;
+pub mod period;
 pub mod redis;
 pub mod result;
 pub mod sentry;
 pub mod serde;
+pub mod single_flight;
 pub mod time;
 pub mod tracing;
@@ -0,0 +1,47 @@
+//! Decorrelated jitter backoff.
+//!
+//! See: <https://aws.amazon.com/blogs/architecture/exponential-backoff-and-jitter/>.
+
+use rand::{thread_rng, Rng};
+
+use crate::prelude::*;
+
+pub struct Backoff {
+    base: time::Duration,
+    cap: time::Duration,
+    last: time::Duration,
+}
+
+impl Backoff {
+    pub const fn new(base: time::Duration, cap: time::Duration) -> Self {
+        Self {
+            base,
+            cap,
+            last: base,
+        }
+    }
+
+    /// Picks the next delay randomly between the base and the tripled previous delay.
+    pub fn next(&mut self) -> time::Duration {
+        let upper = (self.last * 3).max(self.base);
+        self.last = thread_rng().gen_range(self.base..=upper).min(self.cap);
+        self.last
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_ok() {
+        let base = time::Duration::from_millis(100);
+        let cap = time::Duration::from_secs(1);
+        let mut backoff = Backoff::new(base, cap);
+        for _ in 0..100 {
+            let delay = backoff.next();
+            assert!(delay >= base);
+            assert!(delay <= cap);
+        }
+    }
+}
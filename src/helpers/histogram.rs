@@ -0,0 +1,105 @@
+//! Log-linear histogram in the spirit of HDR Histogram: constant memory,
+//! while the relative error is bounded by the number of sub-buckets per power of two.
+
+const SUB_BUCKET_BITS: u32 = 4;
+const N_SUB_BUCKETS: u64 = 1 << SUB_BUCKET_BITS;
+const N_BUCKETS: usize = ((64 - SUB_BUCKET_BITS as u64 + 1) * N_SUB_BUCKETS) as usize;
+
+#[derive(Clone)]
+pub struct Histogram {
+    counts: Vec<u64>,
+    n_values: u64,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self {
+            counts: vec![0; N_BUCKETS],
+            n_values: 0,
+        }
+    }
+}
+
+impl Histogram {
+    pub fn record(&mut self, value: u64) {
+        self.counts[Self::index_of(value)] += 1;
+        self.n_values += 1;
+    }
+
+    pub const fn len(&self) -> u64 {
+        self.n_values
+    }
+
+    /// Returns the highest value equivalent to the one at the quantile, if any recorded.
+    pub fn quantile(&self, quantile: f64) -> Option<u64> {
+        if self.n_values == 0 {
+            return None;
+        }
+        let rank = ((quantile * self.n_values as f64).ceil() as u64).clamp(1, self.n_values);
+        let mut n_values = 0;
+        self.counts.iter().enumerate().find_map(|(index, count)| {
+            n_values += count;
+            (n_values >= rank).then(|| Self::highest_equivalent_value(index))
+        })
+    }
+
+    /// Values below `2 * N_SUB_BUCKETS` get their own buckets, the larger ones
+    /// share a bucket with the other values of the same top bits.
+    const fn index_of(value: u64) -> usize {
+        if value < 2 * N_SUB_BUCKETS {
+            return value as usize;
+        }
+        let shift = 63 - value.leading_zeros() - SUB_BUCKET_BITS;
+        (shift as u64 * N_SUB_BUCKETS + (value >> shift)) as usize
+    }
+
+    const fn highest_equivalent_value(index: usize) -> u64 {
+        let index = index as u64;
+        if index < 2 * N_SUB_BUCKETS {
+            return index;
+        }
+        let shift = index / N_SUB_BUCKETS - 1;
+        let mantissa = index % N_SUB_BUCKETS + N_SUB_BUCKETS;
+        (mantissa << shift) + ((1 << shift) - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_ok() {
+        assert_eq!(Histogram::default().quantile(0.5), None);
+    }
+
+    #[test]
+    fn small_values_exact_ok() {
+        let mut histogram = Histogram::default();
+        for value in 1..=10 {
+            histogram.record(value);
+        }
+        assert_eq!(histogram.len(), 10);
+        assert_eq!(histogram.quantile(0.5), Some(5));
+        assert_eq!(histogram.quantile(1.0), Some(10));
+    }
+
+    #[test]
+    fn quantiles_ok() {
+        let mut histogram = Histogram::default();
+        for value in 1..=1000 {
+            histogram.record(value);
+        }
+        for (quantile, expected) in [(0.5, 500.0), (0.95, 950.0), (0.99, 990.0)] {
+            let actual = histogram.quantile(quantile).unwrap() as f64;
+            assert!((actual - expected).abs() / expected <= 1.0 / N_SUB_BUCKETS as f64);
+        }
+    }
+
+    #[test]
+    fn max_value_ok() {
+        let mut histogram = Histogram::default();
+        histogram.record(u64::MAX);
+        assert_eq!(histogram.quantile(0.5), Some(u64::MAX));
+    }
+}
@@ -0,0 +1,122 @@
+//! Redis-based lock, which lets only one replica run a singleton job.
+
+use std::future::Future;
+
+use fred::pool::RedisPool;
+use fred::prelude::*;
+use tokio::time::sleep;
+
+use crate::prelude::*;
+
+/// Acquires the lock, or prolongs it if it's already held by the same owner.
+const ACQUIRE_SCRIPT: &str = r#"
+    if redis.call('get', KEYS[1]) == ARGV[1] then
+        return redis.call('pexpire', KEYS[1], ARGV[2])
+    end
+    if redis.call('set', KEYS[1], ARGV[1], 'nx', 'px', ARGV[2]) then
+        return 1
+    end
+    return 0
+"#;
+
+/// Releases the lock, only if it's held by the same owner.
+const RELEASE_SCRIPT: &str = r#"
+    if redis.call('get', KEYS[1]) == ARGV[1] then
+        return redis.call('del', KEYS[1])
+    end
+    return 0
+"#;
+
+pub struct Lock {
+    redis: RedisPool,
+    key: String,
+    token: String,
+    ttl: time::Duration,
+}
+
+impl Lock {
+    /// Instantiates the lock with a unique owner token.
+    /// The lock expires, unless renewed within the TTL, so that another replica could take over.
+    pub fn new(redis: RedisPool, name: &str, ttl: time::Duration) -> Self {
+        Self {
+            redis,
+            key: format!("lock:{name}"),
+            token: format!("{:016x}", rand::random::<u64>()),
+            ttl,
+        }
+    }
+
+    /// Waits for the lock and runs the job, while renewing the lock in the background.
+    ///
+    /// Should the lock get lost (for example, because of a network partition),
+    /// the job gets cancelled, and an error gets returned. A failed release is only logged,
+    /// since the lock expires anyway, and the job's own result matters more.
+    #[instrument(skip_all, fields(key = self.key.as_str()))]
+    pub async fn run_exclusively(&self, job: impl Future<Output = Result>) -> Result {
+        self.acquire().await?;
+        let result = tokio::select! {
+            result = job => result,
+            result = self.keep_renewing() => {
+                warn!("lost the lock, cancelling the job");
+                result
+            },
+        };
+        if let Err(error) = self.release().await {
+            warn!("{:#}", error);
+        }
+        result
+    }
+
+    async fn acquire(&self) -> Result {
+        let mut is_waiting_logged = false;
+        while !self.try_acquire().await? {
+            if !is_waiting_logged {
+                info!("the lock is held by another replica, waiting…");
+                is_waiting_logged = true;
+            }
+            sleep(self.renewal_interval()).await;
+        }
+        info!("acquired the lock");
+        Ok(())
+    }
+
+    /// Renews the lock until it gets lost, which is always an error.
+    async fn keep_renewing(&self) -> Result {
+        loop {
+            sleep(self.renewal_interval()).await;
+            if !self.try_acquire().await? {
+                bail!("lost the lock");
+            }
+            debug!("renewed the lock");
+        }
+    }
+
+    async fn try_acquire(&self) -> Result<bool> {
+        let n_updated: i64 = self
+            .redis
+            .eval(
+                ACQUIRE_SCRIPT,
+                vec![self.key.as_str()],
+                vec![self.token.clone(), self.ttl.as_millis().to_string()],
+            )
+            .await
+            .context("failed to acquire the lock")?;
+        Ok(n_updated == 1)
+    }
+
+    async fn release(&self) -> Result {
+        let n_deleted: i64 = self
+            .redis
+            .eval(RELEASE_SCRIPT, vec![self.key.as_str()], vec![self.token.clone()])
+            .await
+            .context("failed to release the lock")?;
+        if n_deleted == 1 {
+            info!("released the lock");
+        }
+        Ok(())
+    }
+
+    fn renewal_interval(&self) -> time::Duration {
+        self.ttl / 3
+    }
+}
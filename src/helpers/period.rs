@@ -0,0 +1,127 @@
+//! Period-like values, which are accepted as plain seconds or humantime strings,
+//! for example: `3600`, `90d`, or `3 months`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+
+use crate::prelude::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Period(pub time::Duration);
+
+impl FromStr for Period {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let value = value.trim();
+        if let Ok(secs) = value.parse::<u64>() {
+            return Ok(Self(time::Duration::from_secs(secs)));
+        }
+        // humantime does not allow the whitespace between a number and its unit.
+        let value = value
+            .split_whitespace()
+            .fold(String::new(), |mut value, part| {
+                if !value.is_empty() && !part.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                    value.push(' ');
+                }
+                value.push_str(part);
+                value
+            });
+        let duration = humantime::parse_duration(&value)
+            .with_context(|| format!("`{value}` is not a valid period"))?;
+        Ok(Self(duration))
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", humantime::format_duration(self.0))
+    }
+}
+
+impl From<Period> for time::Duration {
+    fn from(period: Period) -> Self {
+        period.0
+    }
+}
+
+/// Serialized as the number of seconds.
+impl Serialize for Period {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.0.as_secs())
+    }
+}
+
+/// Deserialized from the number of seconds, or from any string accepted by [`Period::from_str`].
+impl<'de> Deserialize<'de> for Period {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Secs(u64),
+            String(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Secs(secs) => Ok(Self(time::Duration::from_secs(secs))),
+            Raw::String(value) => Self::from_str(&value).map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+/// Allows `#[serde_as(as = "Period")]` on the plain [`time::Duration`] fields.
+impl SerializeAs<time::Duration> for Period {
+    fn serialize_as<S: Serializer>(
+        source: &time::Duration,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Self(*source).serialize(serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, time::Duration> for Period {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<time::Duration, D::Error> {
+        Ok(Self::deserialize(deserializer)?.0)
+    }
+}
+
+/// CLI value parser.
+pub fn parse_period(value: &str) -> Result<time::Duration> {
+    Ok(Period::from_str(value)?.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::time::{from_days, from_months};
+
+    #[test]
+    fn from_str_ok() -> Result {
+        assert_eq!(Period::from_str("3600")?.0, time::Duration::from_secs(3600));
+        assert_eq!(Period::from_str("90d")?.0, from_days(90));
+        assert_eq!(Period::from_str("3 months")?.0, from_months(3));
+        assert_eq!(Period::from_str("1 day 2h")?.0, from_days(1) + time::Duration::from_secs(7200));
+        assert!(Period::from_str("soon").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn display_round_trip_ok() -> Result {
+        let period = Period(from_months(3) + from_days(2));
+        assert_eq!(Period::from_str(&period.to_string())?, period);
+        Ok(())
+    }
+
+    #[test]
+    fn serde_round_trip_ok() -> Result {
+        let period = Period(from_days(90));
+        let json = serde_json::to_string(&period)?;
+        assert_eq!(json, "7776000");
+        assert_eq!(serde_json::from_str::<Period>(&json)?, period);
+        assert_eq!(serde_json::from_str::<Period>(r#""90d""#)?, period);
+        Ok(())
+    }
+}
@@ -1,4 +1,35 @@
+use sentry::protocol::Value;
+use sentry::{Span, TransactionOrSpan};
+
 /// Clears current user in Sentry.
 pub fn clear_user() {
     sentry::configure_scope(|scope| scope.set_user(None));
 }
+
+/// Performance span, which is a child of the current transaction or span.
+///
+/// Does nothing, when there's no current transaction, for example, in the background tasks.
+pub struct ChildSpan(Option<Span>);
+
+impl ChildSpan {
+    /// Starts the span. The description is only built, when it is going to be used.
+    pub fn start(op: &str, describe: impl FnOnce() -> String) -> Self {
+        let parent = sentry::configure_scope(|scope| scope.get_span());
+        Self(parent.map(|parent| match parent {
+            TransactionOrSpan::Transaction(transaction) => transaction.start_child(op, &describe()),
+            TransactionOrSpan::Span(span) => span.start_child(op, &describe()),
+        }))
+    }
+
+    pub fn set_data(&self, key: &str, value: impl Into<Value>) {
+        if let Some(span) = &self.0 {
+            span.set_data(key, value.into());
+        }
+    }
+
+    pub fn finish(self) {
+        if let Some(span) = self.0 {
+            span.finish();
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! Request coalescing: concurrent callers for the same key take turns,
+//! so that the first one fetches the value and the rest find it in the cache.
+
+use std::hash::Hash;
+use std::sync::Mutex;
+
+use tokio::sync::OwnedMutexGuard;
+
+use crate::prelude::*;
+
+pub struct SingleFlight<K> {
+    locks: Mutex<AHashMap<K, Arc<tokio::sync::Mutex<()>>>>,
+}
+
+impl<K> Default for SingleFlight<K> {
+    fn default() -> Self {
+        Self {
+            locks: Mutex::new(AHashMap::default()),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone> SingleFlight<K> {
+    /// Waits for the other callers with the same key, if any.
+    pub async fn lock(&self, key: K) -> Guard<'_, K> {
+        let mutex = Arc::clone(self.locks.lock().unwrap().entry(key.clone()).or_default());
+        let guard = mutex.lock_owned().await;
+        Guard {
+            parent: self,
+            key,
+            guard: Some(guard),
+        }
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.locks.lock().unwrap().len()
+    }
+}
+
+pub struct Guard<'a, K: Hash + Eq + Clone> {
+    parent: &'a SingleFlight<K>,
+    key: K,
+    guard: Option<OwnedMutexGuard<()>>,
+}
+
+impl<K: Hash + Eq + Clone> Drop for Guard<'_, K> {
+    /// Releases the lock and removes it, unless someone else is waiting for it.
+    fn drop(&mut self) {
+        self.guard = None;
+        let mut locks = self.parent.locks.lock().unwrap();
+        if locks.get(&self.key).is_some_and(|mutex| Arc::strong_count(mutex) == 1) {
+            locks.remove(&self.key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use futures::future::join_all;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn coalesce_ok() {
+        let single_flight = SingleFlight::default();
+        let n_fetches = AtomicU32::new(0);
+        let cached = Mutex::new(None);
+
+        join_all((0..10).map(|_| async {
+            let _guard = single_flight.lock(42).await;
+            if cached.lock().unwrap().is_none() {
+                tokio::task::yield_now().await;
+                n_fetches.fetch_add(1, Ordering::Relaxed);
+                *cached.lock().unwrap() = Some(42);
+            }
+        }))
+        .await;
+
+        assert_eq!(n_fetches.load(Ordering::Relaxed), 1);
+        assert_eq!(single_flight.len(), 0);
+    }
+}
@@ -1,7 +1,8 @@
 use std::borrow::Cow;
+use std::sync::Arc;
 
 use sentry::integrations::tracing::EventFilter;
-use sentry::{ClientInitGuard, ClientOptions};
+use sentry::{ClientInitGuard, ClientOptions, TransactionContext};
 use tracing::Level;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
@@ -9,13 +10,28 @@ use tracing_subscriber::{EnvFilter, Layer};
 
 use crate::prelude::*;
 
+/// Operation of the web request transactions.
+pub const HTTP_SERVER_OP: &str = "http.server";
+
 /// Initialises tracing.
-pub fn init(sentry_dsn: Option<String>, traces_sample_rate: f32) -> Result<ClientInitGuard> {
+///
+/// The web requests may be sampled at their own rate, so that the page rendering
+/// could be traced more often than the background services.
+pub fn init(
+    sentry_dsn: Option<String>,
+    traces_sample_rate: f32,
+    web_traces_sample_rate: Option<f32>,
+) -> Result<ClientInitGuard> {
+    let traces_sampler = move |context: &TransactionContext| match web_traces_sample_rate {
+        Some(sample_rate) if context.operation() == HTTP_SERVER_OP => sample_rate,
+        _ => traces_sample_rate,
+    };
     let guard = sentry::init((
         sentry_dsn,
         ClientOptions {
             release: Some(Cow::Borrowed(env!("CARGO_PKG_VERSION"))),
             traces_sample_rate,
+            traces_sampler: Some(Arc::new(traces_sampler)),
             enable_profiling: true,
             profiles_sample_rate: traces_sample_rate,
             attach_stacktrace: true,
@@ -41,6 +57,7 @@ pub fn init(sentry_dsn: Option<String>, traces_sample_rate: f32) -> Result<Clien
         .or_else(|_| EnvFilter::try_new("blitz_dashboard=info"))?;
     let format_layer = tracing_subscriber::fmt::layer()
         .without_time()
+        .with_writer(std::io::stderr) // keep the standard output clean for the dumps
         .with_filter(format_filter);
 
     tracing_subscriber::Registry::default()
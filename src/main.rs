@@ -8,28 +8,45 @@
     clippy::needless_pass_by_value
 )]
 
-use clap::Parser;
 use helpers::tracing;
 use sentry::integrations::anyhow::capture_anyhow;
 
 use crate::opts::{Opts, Subcommand};
 use crate::prelude::*;
 
+mod account_links;
+mod announcements;
+mod anomalies;
+mod api_tokens;
+mod archive;
+mod audit_log;
+mod blocklist;
 mod crawler;
 pub mod database;
+mod discovery;
+mod generate;
 mod helpers;
 mod math;
 mod opts;
 mod prelude;
+mod rating_backfill;
+mod rollup;
+mod snapshot_compression;
 mod tankopedia;
+mod train_set;
+mod vehicle_attributes;
 pub mod wargaming;
 mod web;
 
 const CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 fn main() -> Result {
-    let opts: Opts = Opts::parse();
-    let _sentry_guard = tracing::init(opts.sentry_dsn.clone(), opts.traces_sample_rate)?;
+    let opts = Opts::parse_with_config()?;
+    let _sentry_guard = tracing::init(
+        opts.sentry_dsn.clone(),
+        opts.traces_sample_rate,
+        opts.web_traces_sample_rate,
+    )?;
     info!(version = CRATE_VERSION);
 
     tokio::runtime::Builder::new_multi_thread()
@@ -42,9 +59,25 @@ fn main() -> Result {
 async fn run_subcommand(opts: Opts) -> Result {
     let start_instant = Instant::now();
     let result = match opts.subcommand {
+        Subcommand::Announcements(opts) => announcements::run(opts).await,
+        Subcommand::Anomalies(opts) => anomalies::run(opts).await,
+        Subcommand::ArchiveAccounts(opts) => archive::run(opts).await,
+        Subcommand::AuditLog(opts) => audit_log::run(opts).await,
+        Subcommand::BackfillRatingSnapshots(opts) => rating_backfill::run(opts).await,
+        Subcommand::Blocklist(opts) => blocklist::run(opts).await,
         Subcommand::Crawl(opts) => crawler::run_crawler(opts).await,
         Subcommand::CrawlAccounts(opts) => crawler::crawl_accounts(opts).await,
+        Subcommand::DiscoverClanMembers(opts) => discovery::discover_clan_members(opts).await,
+        Subcommand::DiscoverRatingLeaders(opts) => discovery::discover_rating_leaders(opts).await,
+        Subcommand::DumpTrainSet(opts) => train_set::dump(opts).await,
+        Subcommand::Generate(opts) => generate::run(&opts),
         Subcommand::ImportTankopedia(opts) => tankopedia::import(opts).await,
+        Subcommand::ImportTrainSet(opts) => train_set::import(opts).await,
+        Subcommand::IssueApiToken(opts) => api_tokens::issue(opts).await,
+        Subcommand::LinkAccounts(opts) => account_links::run(opts).await,
+        Subcommand::ReportSnapshotCompression(opts) => snapshot_compression::report(opts).await,
+        Subcommand::RollupTankSnapshots(opts) => rollup::run(opts).await,
+        Subcommand::VehicleAttributes(opts) => vehicle_attributes::run(opts).await,
         Subcommand::Web(opts) => web::run(opts).await,
     };
     info!(elapsed = ?start_instant.elapsed(), "the command has finished");
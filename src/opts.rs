@@ -1,18 +1,31 @@
 //! CLI options.
 
+use std::ffi::OsString;
+use std::net::{IpAddr, SocketAddr};
 use std::num::NonZeroU32;
+use std::path::PathBuf;
+use std::str::FromStr;
 
 use clap::builder::EnumValueParser;
-use clap::Parser;
+use clap::{CommandFactory, FromArgMatches, Parser};
 
+use crate::helpers::period::parse_period;
+use crate::opts::config::Config;
 use crate::prelude::*;
 use crate::wargaming;
+use crate::web::bind::BindAddress;
+use crate::web::blocklist;
 
+mod config;
 mod parsers;
 
 #[derive(Parser)]
 #[clap(author, version, about, rename_all = "kebab-case")]
 pub struct Opts {
+    /// TOML configuration file, which provides the defaults for the options below.
+    #[clap(long, env = "BLITZ_DASHBOARD_CONFIG")]
+    pub config: Option<PathBuf>,
+
     /// Sentry DSN.
     #[clap(long, env = "BLITZ_DASHBOARD_SENTRY_DSN")]
     pub sentry_dsn: Option<String>,
@@ -25,15 +38,59 @@ pub struct Opts {
     )]
     pub traces_sample_rate: f32,
 
+    /// Performance traces sample rate for the web requests, defaults to `--traces-sample-rate`.
+    #[clap(long, env = "BLITZ_DASHBOARD_WEB_TRACES_SAMPLE_RATE")]
+    pub web_traces_sample_rate: Option<f32>,
+
     #[clap(subcommand)]
     pub subcommand: Subcommand,
 }
 
+impl Opts {
+    /// Parses the command-line arguments, taking the defaults from the configuration file,
+    /// if one is specified.
+    pub fn parse_with_config() -> Result<Self> {
+        let args: Vec<OsString> = std::env::args_os().collect();
+
+        // Only need the configuration path for now, the rest is parsed afterwards.
+        let config_path = Self::command()
+            .ignore_errors(true)
+            .try_get_matches_from(&args)
+            .unwrap_or_else(|error| error.exit())
+            .get_one::<PathBuf>("config")
+            .cloned();
+
+        let mut command = Self::command();
+        if let Some(config_path) = config_path {
+            command = Config::read(&config_path)?.apply(command);
+        }
+        let matches = command.get_matches_from(args);
+        Ok(Self::from_arg_matches(&matches).unwrap_or_else(|error| error.exit()))
+    }
+}
+
 #[derive(Parser)]
 pub enum Subcommand {
+    Announcements(AnnouncementsOpts),
+    Anomalies(AnomaliesOpts),
+    ArchiveAccounts(ArchiveAccountsOpts),
+    AuditLog(AuditLogOpts),
+    BackfillRatingSnapshots(BackfillRatingSnapshotsOpts),
+    Blocklist(BlocklistOpts),
     Crawl(CrawlerOpts),
     CrawlAccounts(CrawlAccountsOpts),
+    DiscoverClanMembers(DiscoverClanMembersOpts),
+    DiscoverRatingLeaders(DiscoverRatingLeadersOpts),
+    DumpTrainSet(DumpTrainSetOpts),
+    #[clap(hide = true)]
+    Generate(GenerateOpts),
     ImportTankopedia(ImportTankopediaOpts),
+    ImportTrainSet(ImportTrainSetOpts),
+    IssueApiToken(IssueApiTokenOpts),
+    LinkAccounts(LinkAccountsOpts),
+    ReportSnapshotCompression(ReportSnapshotCompressionOpts),
+    RollupTankSnapshots(RollupTankSnapshotsOpts),
+    VehicleAttributes(VehicleAttributesOpts),
     Web(WebOpts),
 }
 
@@ -51,10 +108,88 @@ pub struct WebOpts {
     #[structopt(long, default_value = "8081", env = "BLITZ_DASHBOARD_WEB_BIND_PORT")]
     pub port: u16,
 
+    /// Bind addresses, `host:port` or `unix:/path/to/socket`, which replace `--host` and `--port`.
+    #[clap(long, value_delimiter = ',', env = "BLITZ_DASHBOARD_WEB_BIND")]
+    pub bind: Vec<BindAddress>,
+
     /// Google Analytics measurement ID.
     #[structopt(long, env = "BLITZ_DASHBOARD_WEB_GTAG")]
     pub gtag: Option<String>,
 
+    /// Base domain for the realm subdomains (`{realm}.{base_domain}/{account_id}`),
+    /// for example: `yastati.st`. Realm subdomains are disabled, if omitted.
+    #[clap(long, env = "BLITZ_DASHBOARD_WEB_BASE_DOMAIN")]
+    pub base_domain: Option<String>,
+
+    /// An account viewed on the website gets pushed into the crawler's fast lane
+    /// at most once within this interval, and only if it hasn't been crawled within it.
+    #[clap(
+        long,
+        default_value = "15min",
+        value_parser = parse_period,
+        env = "BLITZ_DASHBOARD_WEB_FAST_LANE_THROTTLE",
+    )]
+    pub fast_lane_throttle: time::Duration,
+
+    /// Maximum number of the heavy API requests per minute per API token,
+    /// for example, the tank snapshot exports.
+    #[clap(long, default_value = "60", env = "BLITZ_DASHBOARD_WEB_API_RATE_LIMIT")]
+    pub api_rate_limit: u32,
+
+    /// Number of the most viewed accounts, whose API caches are refreshed every 20 seconds.
+    /// Zero disables the warm-up.
+    #[clap(long, default_value = "50", env = "BLITZ_DASHBOARD_WEB_WARM_UP_ACCOUNTS")]
+    pub warm_up_accounts: usize,
+
+    /// Cloudflare Turnstile site key. The search and group pages challenge the visitors,
+    /// if specified together with the secret key.
+    #[clap(
+        long,
+        env = "BLITZ_DASHBOARD_WEB_TURNSTILE_SITE_KEY",
+        requires = "turnstile_secret_key"
+    )]
+    pub turnstile_site_key: Option<String>,
+
+    /// Cloudflare Turnstile secret key.
+    #[clap(
+        long,
+        env = "BLITZ_DASHBOARD_WEB_TURNSTILE_SECRET_KEY",
+        requires = "turnstile_site_key"
+    )]
+    pub turnstile_secret_key: Option<String>,
+
+    /// Secret for signing the cookies, at least 32 bytes long. When not specified,
+    /// a random one is generated, which invalidates the signed cookies on restart.
+    #[clap(long, env = "BLITZ_DASHBOARD_WEB_COOKIE_SECRET")]
+    pub cookie_secret: Option<String>,
+
+    /// A request, which issues more MongoDB commands, gets logged along with the pipelines.
+    #[clap(long, default_value = "50", env = "BLITZ_DASHBOARD_WEB_QUERY_BUDGET_COMMANDS")]
+    pub query_budget_commands: usize,
+
+    /// A request, which spends longer in MongoDB, gets logged along with the pipelines.
+    #[clap(
+        long,
+        default_value = "500ms",
+        value_parser = parse_period,
+        env = "BLITZ_DASHBOARD_WEB_QUERY_BUDGET_TIME",
+    )]
+    pub query_budget_time: time::Duration,
+
+    /// Realm-wide counts below the threshold, for example, the activity heatmap cells,
+    /// get rounded to either zero or the threshold. Zero disables the coarsening.
+    #[clap(long, default_value = "10", env = "BLITZ_DASHBOARD_WEB_SMALL_COUNT_THRESHOLD")]
+    pub small_count_threshold: u32,
+
+    /// Adds the random noise within the threshold to the small counts instead of rounding.
+    #[clap(long, env = "BLITZ_DASHBOARD_WEB_SMALL_COUNT_NOISE")]
+    pub small_count_noise: bool,
+
+    /// Path to the `ip2asn-combined.tsv` from <https://iptoasn.com/>.
+    /// The blocked autonomous systems only match, if specified.
+    #[clap(long, env = "BLITZ_DASHBOARD_WEB_ASN_DATABASE")]
+    pub asn_database: Option<PathBuf>,
+
     #[structopt(
         long,
         env = "BLITZ_DASHBOARD_WEB_TRAINER_BASE_URL",
@@ -63,6 +198,18 @@ pub struct WebOpts {
     pub trainer_base_url: String,
 }
 
+impl WebOpts {
+    /// Explicit bind addresses, or else `--host` and `--port`.
+    pub fn bind_addresses(&self) -> Result<Vec<BindAddress>> {
+        if self.bind.is_empty() {
+            let address = SocketAddr::new(IpAddr::from_str(&self.host)?, self.port);
+            Ok(vec![BindAddress::Tcp(address)])
+        } else {
+            Ok(self.bind.clone())
+        }
+    }
+}
+
 /// Runs the account crawler.
 #[derive(Parser)]
 pub struct CrawlerOpts {
@@ -73,7 +220,7 @@ pub struct CrawlerOpts {
     #[clap(
         long,
         default_value = "0s",
-        value_parser = humantime::parse_duration,
+        value_parser = parse_period,
         env = "BLITZ_DASHBOARD_CRAWLER_MIN_OFFSET",
     )]
     pub min_offset: time::Duration,
@@ -81,7 +228,7 @@ pub struct CrawlerOpts {
     #[clap(
         long,
         default_value = "24h",
-        value_parser = humantime::parse_duration,
+        value_parser = parse_period,
         env = "BLITZ_DASHBOARD_CRAWLER_OFFSET_SCALE",
     )]
     pub offset_scale: time::Duration,
@@ -97,14 +244,80 @@ pub struct CrawlerOpts {
 
     #[clap(long, env = "BLITZ_DASHBOARD_CRAWLER_HEARTBEAT_URL")]
     pub heartbeat_url: Option<String>,
+
+    /// Posts a JSON event with the realm, account ID and new battles
+    /// for every account with the new battles.
+    #[clap(long, env = "BLITZ_DASHBOARD_CRAWLER_WEBHOOK_URL")]
+    pub webhook_url: Option<String>,
+
+    /// Crawls the accounts viewed on the website ahead of the sampled ones.
+    /// Requires Redis.
+    #[clap(long, env = "BLITZ_DASHBOARD_CRAWLER_FAST_LANE")]
+    pub fast_lane: bool,
+
+    /// Zero-based index of this crawler's shard of the account space.
+    #[clap(long, default_value = "0", env = "BLITZ_DASHBOARD_CRAWLER_SHARD_INDEX")]
+    pub shard_index: u32,
+
+    /// Total number of the crawler shards within the realm.
+    ///
+    /// The accounts are split by the remainder of their IDs rather than by a hash:
+    /// Wargaming.net issues the IDs sequentially, so the remainder alone spreads
+    /// the accounts, including the recently registered ones, evenly over the shards.
+    #[clap(
+        long,
+        default_value = "1",
+        value_parser = parsers::non_zero_u32,
+        env = "BLITZ_DASHBOARD_CRAWLER_SHARD_COUNT",
+    )]
+    pub shard_count: u32,
 }
 
 /// Updates the bundled Tankopedia module.
 #[derive(Parser)]
 pub struct ImportTankopediaOpts {
-    /// Wargaming.net API application ID.
-    #[structopt(short, long, env = "BLITZ_DASHBOARD_APPLICATION_ID")]
-    pub application_id: String,
+    /// Wargaming.net API application ID, or comma-separated `realm=application_id` pairs.
+    #[structopt(short, long = "application-id", env = "BLITZ_DASHBOARD_APPLICATION_ID")]
+    pub application_ids: wargaming::ApplicationIds,
+
+    /// MongoDB connection URI to also store the imported tankopedia snapshot at,
+    /// which the old periods get displayed with.
+    #[clap(long = "mongodb-uri", env = "BLITZ_DASHBOARD_MONGODB_URI")]
+    pub mongodb_uri: Option<String>,
+
+    /// Local JSON patch, which gets merged over the API response:
+    /// `{"tank_id": {"name": …, "tier": …, "is_premium": …, "nation": …, "type": …}}`,
+    /// all the fields are optional for the known vehicles.
+    #[clap(long, env = "BLITZ_DASHBOARD_TANKOPEDIA_PATCH")]
+    pub patch: Option<PathBuf>,
+}
+
+/// Manages the blocklist of the abusive viewers.
+#[derive(Parser)]
+pub struct BlocklistOpts {
+    #[clap(flatten)]
+    pub internal: InternalConnectionOpts,
+
+    #[clap(subcommand)]
+    pub action: BlocklistAction,
+}
+
+#[derive(Parser)]
+pub enum BlocklistAction {
+    /// Blocks the IP address or the autonomous system, for example: `AS13335`.
+    Add {
+        target: blocklist::Target,
+
+        /// Blocks for the specified time, permanently – otherwise.
+        #[clap(long, value_parser = parse_period)]
+        expire: Option<time::Duration>,
+    },
+
+    /// Unblocks the IP address or the autonomous system.
+    Remove { target: blocklist::Target },
+
+    /// Lists the blocked IP addresses and autonomous systems.
+    List,
 }
 
 /// Crawls the specified account IDs.
@@ -122,6 +335,433 @@ pub struct CrawlAccountsOpts {
     pub end_id: wargaming::AccountId,
 }
 
+/// Inserts the unknown members of the realm's clans into the database.
+#[derive(Parser)]
+pub struct DiscoverClanMembersOpts {
+    #[clap(flatten)]
+    pub connections: ConnectionOpts,
+
+    /// Realm to discover.
+    #[clap(
+        long,
+        ignore_case = true,
+        value_parser = EnumValueParser::<wargaming::Realm>::new(),
+    )]
+    pub realm: wargaming::Realm,
+
+    /// Clan list page to start from, for resuming an interrupted discovery.
+    #[clap(long, default_value = "1")]
+    pub start_page: u32,
+}
+
+/// Periodically discovers the rating league leaders and queues them for crawling.
+#[derive(Parser)]
+pub struct DiscoverRatingLeadersOpts {
+    #[clap(flatten)]
+    pub connections: ConnectionOpts,
+
+    /// Realm to discover.
+    #[clap(
+        long,
+        ignore_case = true,
+        value_parser = EnumValueParser::<wargaming::Realm>::new(),
+    )]
+    pub realm: wargaming::Realm,
+
+    /// Interval between the leaderboard checks.
+    #[clap(
+        long,
+        default_value = "1h",
+        value_parser = parse_period,
+        env = "BLITZ_DASHBOARD_DISCOVERY_INTERVAL",
+    )]
+    pub interval: time::Duration,
+}
+
+/// Dumps the train set tank snapshots as NDJSON.
+#[derive(Parser)]
+pub struct DumpTrainSetOpts {
+    #[clap(flatten)]
+    pub internal: InternalConnectionOpts,
+
+    /// Realm to dump.
+    #[clap(
+        long,
+        ignore_case = true,
+        value_parser = EnumValueParser::<wargaming::Realm>::new(),
+    )]
+    pub realm: wargaming::Realm,
+
+    /// Dump the snapshots with the last battle time since the specified moment (RFC 3339).
+    #[clap(long, required_unless_present = "last_battles")]
+    pub since: Option<DateTime>,
+
+    /// Instead of the time window, dump the snapshots covering the most recent battles
+    /// of each account's tank. Unlike `--since`, keeps the rarely playing accounts.
+    #[clap(long, conflicts_with = "since")]
+    pub last_battles: Option<NonZeroU32>,
+
+    /// Dump the snapshots with the last battle time before the specified moment (RFC 3339).
+    /// Defaults to the current time.
+    #[clap(long)]
+    pub until: Option<DateTime>,
+
+    /// Output file path. Writes to the standard output, if omitted.
+    #[clap(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Compress the output with gzip.
+    #[clap(long)]
+    pub gzip: bool,
+
+    /// Mark the specified fraction of the account's tanks with `is_test`.
+    /// The split is deterministic, so that the evaluation is reproducible across the dumps.
+    #[clap(long, value_parser = parsers::fraction)]
+    pub test_fraction: Option<f64>,
+
+    /// Seed of the test split hash, change it to get another split.
+    #[clap(long, default_value = "0", requires = "test_fraction")]
+    pub split_seed: u64,
+}
+
+/// Imports the train set tank snapshots from NDJSON.
+#[derive(Parser)]
+pub struct ImportTrainSetOpts {
+    #[clap(flatten)]
+    pub internal: InternalConnectionOpts,
+
+    /// Input file path. Reads from the standard input, if omitted.
+    #[clap(short, long)]
+    pub input: Option<PathBuf>,
+
+    /// Decompress the input with gzip.
+    #[clap(long)]
+    pub gzip: bool,
+
+    /// Number of snapshots to insert in one query.
+    #[clap(
+        long,
+        default_value = "1000",
+        value_parser = parsers::non_zero_usize,
+    )]
+    pub batch_size: usize,
+}
+
+/// Issues a token for the protected API endpoints and prints it.
+#[derive(Parser)]
+pub struct IssueApiTokenOpts {
+    #[clap(flatten)]
+    pub internal: InternalConnectionOpts,
+
+    /// Who the token is issued to, for example, the site domain.
+    #[clap(long)]
+    pub name: String,
+}
+
+/// Manages the links between the accounts of the players, who have migrated across the realms.
+#[derive(Parser)]
+pub struct LinkAccountsOpts {
+    #[clap(flatten)]
+    pub internal: InternalConnectionOpts,
+
+    #[clap(subcommand)]
+    pub action: LinkAccountsAction,
+}
+
+#[derive(Parser)]
+pub enum LinkAccountsAction {
+    /// Links the accounts together, for example: `eu/123 ru/456`.
+    Add {
+        #[clap(num_args = 2.., value_parser = parsers::realm_account_id)]
+        accounts: Vec<(wargaming::Realm, wargaming::AccountId)>,
+    },
+
+    /// Unlinks the account from the others.
+    Remove {
+        #[clap(value_parser = parsers::realm_account_id)]
+        account: (wargaming::Realm, wargaming::AccountId),
+    },
+
+    /// Lists the linked accounts.
+    List,
+}
+
+/// Manages the vehicle attributes, which the Wargaming.net API does not expose.
+#[derive(Parser)]
+pub struct VehicleAttributesOpts {
+    #[clap(flatten)]
+    pub internal: InternalConnectionOpts,
+
+    #[clap(subcommand)]
+    pub action: VehicleAttributesAction,
+}
+
+#[derive(Parser)]
+pub enum VehicleAttributesAction {
+    /// Sets the vehicle's attributes and applies them to the stored tankopedia snapshots.
+    Set {
+        tank_id: wargaming::TankId,
+
+        /// Whether the vehicle is only available in the collector's section.
+        #[clap(long, action = clap::ArgAction::Set)]
+        collectible: bool,
+    },
+
+    /// Lists the stored attributes, which override the bundled ones.
+    List,
+}
+
+/// Manages the site-wide announcements.
+#[derive(Parser)]
+pub struct AnnouncementsOpts {
+    #[clap(flatten)]
+    pub internal: InternalConnectionOpts,
+
+    #[clap(subcommand)]
+    pub action: AnnouncementsAction,
+}
+
+#[derive(Parser)]
+pub enum AnnouncementsAction {
+    /// Publishes the announcement, or replaces the one with the same ID.
+    Add {
+        /// Short slug, for example: `maintenance-2023-02-01`.
+        id: String,
+
+        /// Markdown text: paragraphs, `**bold**`, `_italic_`, `` `code` `` and `[links](https://…)`.
+        markdown: String,
+
+        #[clap(
+            long,
+            default_value = "info",
+            value_parser = EnumValueParser::<database::Severity>::new(),
+        )]
+        severity: database::Severity,
+
+        /// Hides the announcement after the specified time.
+        #[clap(long, default_value = "7days", value_parser = parse_period)]
+        expire: time::Duration,
+    },
+
+    /// Removes the announcement.
+    Remove { id: String },
+
+    /// Lists all the announcements including the expired ones.
+    List,
+}
+
+/// Generates the shell completions and the man pages for packaging.
+#[derive(Parser)]
+pub struct GenerateOpts {
+    /// Target directory, which gets created if missing.
+    #[clap(long, default_value = "target/assets")]
+    pub out_dir: PathBuf,
+}
+
+/// Lists the audit log of the administrative and the notable viewer actions.
+#[derive(Parser)]
+pub struct AuditLogOpts {
+    #[clap(flatten)]
+    pub internal: InternalConnectionOpts,
+
+    /// Page number, the most recent entries come first.
+    #[clap(long, default_value = "1", value_parser = parsers::non_zero_usize)]
+    pub page: usize,
+
+    /// Number of entries per page.
+    #[clap(long, default_value = "50", value_parser = parsers::non_zero_usize)]
+    pub per_page: usize,
+}
+
+/// Flags the accounts with the statistically implausible victory ratio jumps for a review.
+#[derive(Parser)]
+pub struct AnomaliesOpts {
+    #[clap(flatten)]
+    pub internal: InternalConnectionOpts,
+
+    #[clap(subcommand)]
+    pub action: AnomaliesAction,
+}
+
+#[derive(Parser)]
+pub enum AnomaliesAction {
+    /// Periodically tests the recently active accounts.
+    ///
+    /// Intended to be run as a system service.
+    Detect(DetectAnomaliesOpts),
+
+    /// Lists the flagged accounts, the recently detected first.
+    List {
+        #[clap(
+            long,
+            ignore_case = true,
+            value_parser = EnumValueParser::<wargaming::Realm>::new(),
+        )]
+        realm: Option<wargaming::Realm>,
+    },
+
+    /// Dismisses the reviewed flag, for example: `eu/123`.
+    Dismiss {
+        #[clap(value_parser = parsers::realm_account_id)]
+        account: (wargaming::Realm, wargaming::AccountId),
+    },
+}
+
+#[derive(Parser)]
+pub struct DetectAnomaliesOpts {
+    /// Realm to test.
+    #[clap(
+        long,
+        ignore_case = true,
+        value_parser = EnumValueParser::<wargaming::Realm>::new(),
+        env = "BLITZ_DASHBOARD_ANOMALIES_REALM",
+    )]
+    pub realm: wargaming::Realm,
+
+    /// Rolling window, which is tested against the account's victory ratio before it.
+    #[clap(
+        long,
+        default_value = "1day",
+        value_parser = parse_period,
+        env = "BLITZ_DASHBOARD_ANOMALIES_WINDOW",
+    )]
+    pub window: time::Duration,
+
+    /// Minimal number of the window's battles to test, the fewer ones are too noisy.
+    #[clap(long, default_value = "50", env = "BLITZ_DASHBOARD_ANOMALIES_MIN_BATTLES")]
+    pub min_battles: u32,
+
+    /// Minimal z-score of the window's victory ratio to flag the account.
+    #[clap(long, default_value = "4", env = "BLITZ_DASHBOARD_ANOMALIES_MIN_Z_SCORE")]
+    pub min_z_score: f64,
+
+    /// Interval between the runs.
+    #[clap(
+        long,
+        default_value = "1h",
+        value_parser = parse_period,
+        env = "BLITZ_DASHBOARD_ANOMALIES_INTERVAL",
+    )]
+    pub interval: time::Duration,
+}
+
+/// Moves the long inactive accounts out of the crawler's sight.
+#[derive(Parser)]
+pub struct ArchiveAccountsOpts {
+    #[clap(flatten)]
+    pub internal: InternalConnectionOpts,
+
+    /// Realm to archive.
+    #[clap(
+        long,
+        ignore_case = true,
+        value_parser = EnumValueParser::<wargaming::Realm>::new(),
+    )]
+    pub realm: wargaming::Realm,
+
+    /// Archive the accounts, which haven't played for this long.
+    #[clap(long, default_value = "365days", value_parser = parse_period)]
+    pub inactive_for: time::Duration,
+
+    /// Zstandard compression level of the archived accounts' snapshots.
+    #[clap(long, default_value = "3")]
+    pub zstd_level: i32,
+
+    /// Number of the accounts to archive in one query.
+    #[clap(
+        long,
+        default_value = "1000",
+        value_parser = parsers::non_zero_usize,
+    )]
+    pub batch_size: usize,
+}
+
+/// Derives the missing rating snapshots from the historical account snapshots.
+#[derive(Parser)]
+pub struct BackfillRatingSnapshotsOpts {
+    #[clap(flatten)]
+    pub internal: InternalConnectionOpts,
+
+    /// Realm to backfill.
+    #[clap(
+        long,
+        ignore_case = true,
+        value_parser = EnumValueParser::<wargaming::Realm>::new(),
+    )]
+    pub realm: wargaming::Realm,
+}
+
+/// Estimates the account snapshot size reduction by the tank last battle time compression.
+#[derive(Parser)]
+pub struct ReportSnapshotCompressionOpts {
+    #[clap(flatten)]
+    pub internal: InternalConnectionOpts,
+
+    /// Realm to sample the account snapshots from.
+    #[clap(
+        long,
+        ignore_case = true,
+        value_parser = EnumValueParser::<wargaming::Realm>::new(),
+    )]
+    pub realm: wargaming::Realm,
+
+    /// Number of the account snapshots to sample.
+    #[clap(
+        long,
+        default_value = "1000",
+        value_parser = parsers::non_zero_usize,
+    )]
+    pub sample_size: usize,
+
+    /// Zstandard compression level.
+    #[clap(long, default_value = "3")]
+    pub zstd_level: i32,
+}
+
+/// Maintains the weekly and monthly tank snapshot rollups for the long periods.
+#[derive(Parser)]
+pub struct RollupTankSnapshotsOpts {
+    #[clap(flatten)]
+    pub internal: InternalConnectionOpts,
+
+    /// Realm to roll up.
+    #[clap(
+        long,
+        ignore_case = true,
+        value_parser = EnumValueParser::<wargaming::Realm>::new(),
+        env = "BLITZ_DASHBOARD_ROLLUP_REALM",
+    )]
+    pub realm: wargaming::Realm,
+
+    /// How far back the rollups should start on the first run.
+    #[clap(
+        long,
+        default_value = "400days",
+        value_parser = parse_period,
+        env = "BLITZ_DASHBOARD_ROLLUP_BACKFILL",
+    )]
+    pub backfill: time::Duration,
+
+    /// Delay before rolling up a finished bucket,
+    /// so that the late-crawled snapshots get into the rollup.
+    #[clap(
+        long,
+        default_value = "2days",
+        value_parser = parse_period,
+        env = "BLITZ_DASHBOARD_ROLLUP_DELAY",
+    )]
+    pub delay: time::Duration,
+
+    /// Interval between the checks for the new finished buckets.
+    #[clap(
+        long,
+        default_value = "1h",
+        value_parser = parse_period,
+        env = "BLITZ_DASHBOARD_ROLLUP_INTERVAL",
+    )]
+    pub interval: time::Duration,
+}
+
 #[derive(Parser)]
 pub struct BufferingOpts {
     /// Number of account batches which should get concurrently crawled.
@@ -159,10 +799,32 @@ pub struct SharedCrawlerOpts {
     #[structopt(
         long,
         default_value = "1min",
-        value_parser = humantime::parse_duration,
+        value_parser = parse_period,
         env = "BLITZ_DASHBOARD_CRAWLER_LOG_INTERVAL",
     )]
     pub log_interval: time::Duration,
+
+    /// Compresses the tank last battle times of the account snapshots
+    /// with the specified Zstandard level. Stores them uncompressed, if omitted.
+    #[clap(long, env = "BLITZ_DASHBOARD_CRAWLER_ZSTD_LEVEL")]
+    pub zstd_level: Option<i32>,
+
+    /// Reports to Sentry, when the 95th percentile of the account lag exceeds the threshold
+    /// for `--lag-alert-intervals` metrics intervals in a row. Disabled, if omitted.
+    #[clap(
+        long,
+        value_parser = parse_period,
+        env = "BLITZ_DASHBOARD_CRAWLER_LAG_ALERT_THRESHOLD",
+    )]
+    pub lag_alert_threshold: Option<time::Duration>,
+
+    #[clap(
+        long,
+        default_value = "5",
+        value_parser = parsers::non_zero_usize,
+        env = "BLITZ_DASHBOARD_CRAWLER_LAG_ALERT_INTERVALS",
+    )]
+    pub lag_alert_intervals: usize,
 }
 
 #[derive(Parser)]
@@ -170,22 +832,53 @@ pub struct ConnectionOpts {
     #[clap(flatten)]
     pub internal: InternalConnectionOpts,
 
-    /// Wargaming.net API application ID.
-    #[clap(short, long, env = "BLITZ_DASHBOARD_APPLICATION_ID")]
-    pub application_id: String,
+    /// Wargaming.net API application ID, or comma-separated `realm=application_id` pairs,
+    /// optionally with a plain application ID for the rest of the realms.
+    #[clap(short, long = "application-id", env = "BLITZ_DASHBOARD_APPLICATION_ID")]
+    pub application_ids: wargaming::ApplicationIds,
 
     /// Wargaming.net API timeout.
     #[structopt(
         long,
         default_value = "10sec",
-        value_parser = humantime::parse_duration,
+        value_parser = parse_period,
         env = "BLITZ_DASHBOARD_API_TIMEOUT",
     )]
     pub api_timeout: time::Duration,
 
+    /// Wargaming.net API timeout of the account search, defaults to `--api-timeout`.
+    #[clap(
+        long,
+        value_parser = parse_period,
+        env = "BLITZ_DASHBOARD_API_SEARCH_TIMEOUT",
+    )]
+    pub api_search_timeout: Option<time::Duration>,
+
+    /// Wargaming.net API timeout of the tank statistics, defaults to `--api-timeout`.
+    #[clap(
+        long,
+        value_parser = parse_period,
+        env = "BLITZ_DASHBOARD_API_TANKS_STATS_TIMEOUT",
+    )]
+    pub api_tanks_stats_timeout: Option<time::Duration>,
+
     /// Maximum number of requests per second for the API.
     #[clap(long, env = "BLITZ_DASHBOARD_MAX_API_RPS", default_value = "19")]
     pub max_api_rps: NonZeroU32,
+
+    /// Soft daily request budget per application ID, the requests get slowed down near it.
+    #[clap(long, env = "BLITZ_DASHBOARD_API_DAILY_BUDGET")]
+    pub api_daily_budget: Option<NonZeroU32>,
+}
+
+impl ConnectionOpts {
+    pub const fn api_timeouts(&self) -> wargaming::ApiTimeouts {
+        wargaming::ApiTimeouts {
+            default: self.api_timeout,
+            search_accounts: self.api_search_timeout,
+            tanks_stats: self.api_tanks_stats_timeout,
+        }
+    }
 }
 
 #[derive(Parser)]
@@ -0,0 +1,266 @@
+//! Configuration file, which provides the defaults for the command-line options.
+//!
+//! The command-line arguments take precedence over the environment variables,
+//! which in turn take precedence over the configuration file.
+
+use std::num::NonZeroU32;
+use std::path::Path;
+
+use clap::Command;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use toml_edit::{Document, Item};
+
+use crate::helpers::period::Period;
+use crate::prelude::*;
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Config {
+    sentry_dsn: Option<String>,
+    traces_sample_rate: Option<f32>,
+    web_traces_sample_rate: Option<f32>,
+
+    /// Applies to every subcommand which connects to the databases or the API.
+    #[serde(default)]
+    connections: ConnectionConfig,
+
+    /// Applies to the `web` subcommand.
+    #[serde(default)]
+    web: WebConfig,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct ConnectionConfig {
+    mongodb_uri: Option<String>,
+    redis_uri: Option<String>,
+    redis_pool_size: Option<usize>,
+    application_id: Option<String>,
+
+    #[serde(default)]
+    api_timeout: Option<Period>,
+
+    max_api_rps: Option<NonZeroU32>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct WebConfig {
+    host: Option<String>,
+    port: Option<u16>,
+    bind: Option<Vec<String>>,
+    gtag: Option<String>,
+    base_domain: Option<String>,
+    turnstile_site_key: Option<String>,
+    turnstile_secret_key: Option<String>,
+    cookie_secret: Option<String>,
+}
+
+/// Argument ID and its default value.
+type Defaults = Vec<(&'static str, String)>;
+
+impl Config {
+    pub fn read(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read `{}`", path.display()))?;
+        Self::from_str(&text).with_context(|| format!("invalid configuration `{}`", path.display()))
+    }
+
+    fn from_str(text: &str) -> Result<Self> {
+        let document: Document = text.parse()?;
+        Ok(serde_json::from_value(to_json(document.as_item()))?)
+    }
+
+    /// Sets the configured values as the default values of the corresponding arguments.
+    pub fn apply(&self, command: Command) -> Command {
+        let mut command = set_defaults(command, &self.defaults());
+        let connection_defaults = self.connections.defaults();
+        let web_defaults = self.web.defaults();
+        let names: Vec<String> = command
+            .get_subcommands()
+            .map(|subcommand| subcommand.get_name().to_string())
+            .collect();
+        for name in names {
+            command = command.mut_subcommand(&name, |subcommand| {
+                let subcommand = set_defaults(subcommand, &connection_defaults);
+                if name == "web" {
+                    set_defaults(subcommand, &web_defaults)
+                } else {
+                    subcommand
+                }
+            });
+        }
+        command
+    }
+
+    fn defaults(&self) -> Defaults {
+        collect_defaults([
+            ("sentry_dsn", self.sentry_dsn.clone()),
+            ("traces_sample_rate", self.traces_sample_rate.map(|rate| rate.to_string())),
+            ("web_traces_sample_rate", self.web_traces_sample_rate.map(|rate| rate.to_string())),
+        ])
+    }
+}
+
+impl ConnectionConfig {
+    fn defaults(&self) -> Defaults {
+        collect_defaults([
+            ("mongodb_uri", self.mongodb_uri.clone()),
+            ("redis_uri", self.redis_uri.clone()),
+            ("redis_pool_size", self.redis_pool_size.map(|size| size.to_string())),
+            ("application_ids", self.application_id.clone()),
+            ("api_timeout", self.api_timeout.map(|timeout| timeout.to_string())),
+            ("max_api_rps", self.max_api_rps.map(|rps| rps.to_string())),
+        ])
+    }
+}
+
+impl WebConfig {
+    fn defaults(&self) -> Defaults {
+        collect_defaults([
+            ("host", self.host.clone()),
+            ("port", self.port.map(|port| port.to_string())),
+            ("bind", self.bind.as_ref().map(|addresses| addresses.join(","))),
+            ("gtag", self.gtag.clone()),
+            ("base_domain", self.base_domain.clone()),
+            ("turnstile_site_key", self.turnstile_site_key.clone()),
+            ("turnstile_secret_key", self.turnstile_secret_key.clone()),
+            ("cookie_secret", self.cookie_secret.clone()),
+        ])
+    }
+}
+
+fn collect_defaults<const N: usize>(values: [(&'static str, Option<String>); N]) -> Defaults {
+    values
+        .into_iter()
+        .filter_map(|(id, value)| value.map(|value| (id, value)))
+        .collect()
+}
+
+/// Sets the defaults of the arguments, which the command actually has.
+fn set_defaults(mut command: Command, defaults: &Defaults) -> Command {
+    for (id, value) in defaults {
+        if command.get_arguments().any(|arg| arg.get_id() == id) {
+            command = command.mut_arg(id, |arg| arg.default_value(value.clone()).required(false));
+        }
+    }
+    command
+}
+
+/// Converts the TOML document into JSON, so that it could be deserialized with `serde_json`.
+fn to_json(item: &Item) -> Value {
+    match item {
+        Item::None => Value::Null,
+        Item::Value(value) => value_to_json(value),
+        Item::Table(table) => Value::Object(
+            table
+                .iter()
+                .map(|(key, item)| (key.to_string(), to_json(item)))
+                .collect(),
+        ),
+        Item::ArrayOfTables(tables) => Value::Array(
+            tables
+                .iter()
+                .map(|table| to_json(&Item::Table(table.clone())))
+                .collect(),
+        ),
+    }
+}
+
+fn value_to_json(value: &toml_edit::Value) -> Value {
+    match value {
+        toml_edit::Value::String(value) => Value::from(value.value().as_str()),
+        toml_edit::Value::Integer(value) => Value::from(*value.value()),
+        toml_edit::Value::Float(value) => Value::from(*value.value()),
+        toml_edit::Value::Boolean(value) => Value::from(*value.value()),
+        toml_edit::Value::Datetime(value) => Value::from(value.value().to_string()),
+        toml_edit::Value::Array(array) => Value::Array(array.iter().map(value_to_json).collect()),
+        toml_edit::Value::InlineTable(table) => Value::Object(
+            table
+                .iter()
+                .map(|(key, value)| (key.to_string(), value_to_json(value)))
+                .collect::<Map<_, _>>(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::{CommandFactory, FromArgMatches};
+
+    use super::*;
+    use crate::opts::{Opts, Subcommand};
+    use crate::wargaming;
+
+    // language=TOML
+    const CONFIG: &str = r#"
+        traces-sample-rate = 0.5
+
+        [connections]
+        mongodb-uri = "mongodb://example.com/yastatist"
+        application-id = "demo"
+        api-timeout = "30s"
+        max-api-rps = 10
+
+        [web]
+        port = 8080
+        bind = ["[::]:8080", "unix:/run/blitz-dashboard.sock"]
+    "#;
+
+    fn parse(config: &Config, args: &[&str]) -> Result<Opts> {
+        let matches = config.apply(Opts::command()).try_get_matches_from(args)?;
+        Ok(Opts::from_arg_matches(&matches)?)
+    }
+
+    #[test]
+    fn apply_ok() -> Result {
+        let config = Config::from_str(CONFIG)?;
+        let opts = parse(&config, &["blitz-dashboard", "web"])?;
+        assert_eq!(opts.traces_sample_rate, 0.5);
+        match opts.subcommand {
+            Subcommand::Web(opts) => {
+                assert_eq!(opts.port, 8080);
+                assert_eq!(opts.bind.len(), 2);
+                assert_eq!(
+                    opts.connections
+                        .application_ids
+                        .get(wargaming::Realm::Europe)?,
+                    "demo"
+                );
+                assert_eq!(opts.connections.api_timeout, time::Duration::from_secs(30));
+                assert_eq!(opts.connections.max_api_rps.get(), 10);
+                assert_eq!(
+                    opts.connections.internal.mongodb_uri,
+                    "mongodb://example.com/yastatist"
+                );
+                assert_eq!(opts.connections.internal.redis_uri, "redis://127.0.0.1/0");
+            }
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn arguments_override_config_ok() -> Result {
+        let config = Config::from_str(CONFIG)?;
+        let opts = parse(&config, &["blitz-dashboard", "web", "--port", "8000"])?;
+        match opts.subcommand {
+            Subcommand::Web(opts) => assert_eq!(opts.port, 8000),
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn empty_ok() -> Result {
+        Config::from_str("")?;
+        Config::from_str("[connections]\nmongodb-uri = \"mongodb://localhost\"")?;
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_key_error() {
+        assert!(Config::from_str("[web]\nprot = 8080").is_err());
+    }
+}
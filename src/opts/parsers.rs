@@ -4,7 +4,7 @@ use anyhow::anyhow;
 use clap::builder::PossibleValue;
 
 use crate::prelude::*;
-use crate::wargaming;
+use crate::{database, wargaming};
 
 pub fn account_id(value: &str) -> Result<wargaming::AccountId> {
     match wargaming::AccountId::from_str(value)? {
@@ -13,6 +13,14 @@ pub fn account_id(value: &str) -> Result<wargaming::AccountId> {
     }
 }
 
+/// Parses the realm-qualified account ID, for example: `eu/123`.
+pub fn realm_account_id(value: &str) -> Result<(wargaming::Realm, wargaming::AccountId)> {
+    let (realm, account_id) = value
+        .split_once('/')
+        .ok_or_else(|| anyhow!("expected `<realm>/<account ID>`, got `{}`", value))?;
+    Ok((wargaming::Realm::from_str(realm)?, self::account_id(account_id)?))
+}
+
 pub fn non_zero_usize(value: &str) -> Result<usize> {
     match FromStr::from_str(value)? {
         value if value >= 1 => Ok(value),
@@ -20,7 +28,6 @@ pub fn non_zero_usize(value: &str) -> Result<usize> {
     }
 }
 
-#[allow(dead_code)]
 pub fn non_zero_u32(value: &str) -> Result<u32> {
     match FromStr::from_str(value)? {
         value if value >= 1 => Ok(value),
@@ -28,9 +35,17 @@ pub fn non_zero_u32(value: &str) -> Result<u32> {
     }
 }
 
+/// Parses the fraction within `(0, 1)`.
+pub fn fraction(value: &str) -> Result<f64> {
+    match f64::from_str(value)? {
+        value if value > 0.0 && value < 1.0 => Ok(value),
+        _ => Err(anyhow!("expected a fraction between 0 and 1")),
+    }
+}
+
 impl clap::ValueEnum for wargaming::Realm {
     fn value_variants<'a>() -> &'a [Self] {
-        &[Self::Russia, Self::Europe, Self::NorthAmerica, Self::Asia]
+        &[Self::Russia, Self::Europe, Self::NorthAmerica, Self::Asia, Self::China]
     }
 
     fn to_possible_value(&self) -> Option<PossibleValue> {
@@ -38,7 +53,18 @@ impl clap::ValueEnum for wargaming::Realm {
             Self::Russia => Some(PossibleValue::new(Self::Russia.to_str())),
             Self::Europe => Some(PossibleValue::new(Self::Europe.to_str())),
             Self::Asia => Some(PossibleValue::new(Self::Asia.to_str())),
+            Self::China => Some(PossibleValue::new(Self::China.to_str())),
             Self::NorthAmerica => Some(PossibleValue::new(Self::NorthAmerica.to_str())),
         }
     }
 }
+
+impl clap::ValueEnum for database::Severity {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Info, Self::Warning, Self::Danger]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(PossibleValue::new(self.as_str()))
+    }
+}
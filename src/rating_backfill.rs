@@ -0,0 +1,86 @@
+//! Backfill of the rating snapshots, which started later than the account snapshots.
+
+use futures::TryStreamExt;
+
+use crate::database::{AccountSnapshot, RatingSnapshot};
+use crate::opts::BackfillRatingSnapshotsOpts;
+use crate::prelude::*;
+
+/// Derives the daily rating snapshots from the historical account snapshots,
+/// only inserting the missing days.
+///
+/// Intended to be run one time per realm.
+#[instrument(skip_all)]
+pub async fn run(opts: BackfillRatingSnapshotsOpts) -> Result {
+    sentry::configure_scope(|scope| {
+        scope.set_tag("app", "backfill-rating-snapshots");
+        scope.set_tag("realm", opts.realm);
+    });
+
+    let db = database::mongodb::open(&opts.internal.mongodb_uri).await?;
+    let mut snapshots = AccountSnapshot::retrieve_all_without_tanks(&db, opts.realm).await?;
+    let mut pending = None;
+    let mut n_account_snapshots = 0_usize;
+    let mut n_rating_snapshots = 0_usize;
+    while let Some(snapshot) = snapshots.try_next().await? {
+        n_account_snapshots += 1;
+        if let Some(snapshot) = RatingSnapshot::from_account_snapshot(&snapshot) {
+            if let Some(finished) = push(&mut pending, snapshot) {
+                finished.insert_missing(&db).await?;
+                n_rating_snapshots += 1;
+            }
+        }
+    }
+    if let Some(finished) = pending {
+        finished.insert_missing(&db).await?;
+        n_rating_snapshots += 1;
+    }
+    info!(n_account_snapshots, n_rating_snapshots, "finished");
+    Ok(())
+}
+
+/// Merges the next snapshot of the same day into the pending one,
+/// otherwise returns the finished pending snapshot.
+///
+/// The account snapshots must come ordered by account and then by time.
+fn push(pending: &mut Option<RatingSnapshot>, next: RatingSnapshot) -> Option<RatingSnapshot> {
+    match pending {
+        Some(snapshot) if snapshot.is_same_day(&next) => {
+            snapshot.close_rating = next.close_rating;
+            None
+        }
+        _ => pending.replace(next),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(account_id: wargaming::AccountId, day: u32, rating: f64) -> RatingSnapshot {
+        RatingSnapshot {
+            realm: wargaming::Realm::Europe,
+            account_id,
+            season: 1,
+            date: Utc.with_ymd_and_hms(2022, 10, day, 0, 0, 0).unwrap(),
+            open_rating: wargaming::MmRating(rating),
+            close_rating: wargaming::MmRating(rating),
+        }
+    }
+
+    #[test]
+    fn push_ok() {
+        let mut pending = None;
+        assert!(push(&mut pending, snapshot(1, 1, 10.0)).is_none());
+        assert!(push(&mut pending, snapshot(1, 1, 11.0)).is_none());
+
+        let finished = push(&mut pending, snapshot(1, 2, 12.0)).unwrap();
+        assert_eq!(finished.open_rating, wargaming::MmRating(10.0));
+        assert_eq!(finished.close_rating, wargaming::MmRating(11.0));
+
+        let finished = push(&mut pending, snapshot(2, 2, 20.0)).unwrap();
+        assert_eq!(finished.account_id, 1);
+        assert_eq!(finished.open_rating, wargaming::MmRating(12.0));
+        assert_eq!(pending.unwrap().account_id, 2);
+    }
+}
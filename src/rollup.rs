@@ -0,0 +1,80 @@
+//! Background job which maintains the tank snapshot rollups.
+
+use tokio::time::sleep;
+
+use crate::database::mongodb::traits::Upsert;
+use crate::database::{Granularity, TankSnapshotRollup, TankSnapshotRollupState};
+use crate::helpers::lock::Lock;
+use crate::helpers::redis;
+use crate::opts::RollupTankSnapshotsOpts;
+use crate::prelude::*;
+
+const LOCK_TTL: time::Duration = time::Duration::from_secs(60);
+
+/// Periodically rolls up the finished buckets.
+/// Only one replica per realm is active at a time.
+///
+/// Intended to be run as a system service.
+#[instrument(skip_all)]
+pub async fn run(opts: RollupTankSnapshotsOpts) -> Result {
+    sentry::configure_scope(|scope| {
+        scope.set_tag("app", "rollup-tank-snapshots");
+        scope.set_tag("realm", opts.realm);
+    });
+
+    let db = database::mongodb::open(&opts.internal.mongodb_uri).await?;
+    let redis = redis::connect(&opts.internal.redis_uri, opts.internal.redis_pool_size).await?;
+    let lock = Lock::new(redis, &format!("rollup:{}", opts.realm.to_str()), LOCK_TTL);
+    let backfill = Duration::from_std(opts.backfill)?;
+    let delay = Duration::from_std(opts.delay)?;
+    loop {
+        let result = lock
+            .run_exclusively(async {
+                for granularity in Granularity::ALL {
+                    catch_up(&db, opts.realm, granularity, backfill, delay).await?;
+                }
+                Ok(())
+            })
+            .await;
+        if let Err(error) = result {
+            // The state is saved after every bucket, so the next round resumes from there,
+            // once this or another replica acquires the lock.
+            warn!("failed to roll up the snapshots: {:#}", error);
+        }
+        sleep(opts.interval).await;
+    }
+}
+
+/// Rolls up the buckets which have been finished for at least the delay.
+#[instrument(skip_all, fields(granularity = ?granularity))]
+async fn catch_up(
+    db: &mongodb::Database,
+    realm: wargaming::Realm,
+    granularity: Granularity,
+    backfill: Duration,
+    delay: Duration,
+) -> Result {
+    let mut state = match TankSnapshotRollupState::retrieve(db, realm, granularity).await? {
+        Some(state) => state,
+        None => {
+            let since = granularity.bucket_start(now() - backfill);
+            info!(%since, "starting the rollups");
+            TankSnapshotRollupState {
+                realm,
+                granularity,
+                since,
+                until: since,
+            }
+        }
+    };
+    loop {
+        let bucket_end = granularity.next_bucket_start(state.until);
+        if bucket_end + delay > now() {
+            debug!(until = %state.until, "caught up");
+            break Ok(());
+        }
+        TankSnapshotRollup::rollup_bucket(db, realm, granularity, state.until).await?;
+        state.until = bucket_end;
+        state.upsert(db).await?;
+    }
+}
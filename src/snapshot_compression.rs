@@ -0,0 +1,72 @@
+//! Report on the account snapshot compression, to decide whether it's worth enabling.
+
+use futures::TryStreamExt;
+use mongodb::bson;
+use mongodb::bson::doc;
+
+use crate::database::mongodb::traits::TypedDocument;
+use crate::database::AccountSnapshot;
+use crate::opts::ReportSnapshotCompressionOpts;
+use crate::prelude::*;
+
+/// Samples the account snapshots and compares their BSON sizes before and after the compression.
+#[instrument(skip_all)]
+pub async fn report(opts: ReportSnapshotCompressionOpts) -> Result {
+    sentry::configure_scope(|scope| scope.set_tag("app", "report-snapshot-compression"));
+    info!(
+        realm = ?opts.realm,
+        sample_size = opts.sample_size,
+        zstd_level = opts.zstd_level,
+        "sampling…",
+    );
+
+    let db = database::mongodb::open(&opts.internal.mongodb_uri).await?;
+    let pipeline = [
+        doc! { "$match": { "rlm": opts.realm.to_str() } },
+        doc! { "$sample": { "size": opts.sample_size as i64 } },
+    ];
+    let mut documents = AccountSnapshot::collection(&db)
+        .clone_with_type::<bson::Document>()
+        .aggregate(pipeline, None)
+        .await
+        .context("failed to sample the account snapshots")?;
+
+    let mut report = Report::default();
+    while let Some(document) = documents.try_next().await? {
+        let mut snapshot: AccountSnapshot = bson::from_document(document)?;
+        snapshot.decompress()?;
+        let plain_size = bson::to_vec(&bson::to_document(&snapshot)?)?.len();
+        snapshot.compress(opts.zstd_level)?;
+        let compressed_size = bson::to_vec(&bson::to_document(&snapshot)?)?.len();
+        report.add(plain_size, compressed_size);
+    }
+
+    println!("Snapshots:        {}", report.n_snapshots);
+    println!("Plain size:       {} bytes", report.plain_size);
+    println!("Compressed size:  {} bytes", report.compressed_size);
+    println!("Size reduction:   {:.1}%", report.reduction() * 100.0);
+    Ok(())
+}
+
+#[derive(Default)]
+struct Report {
+    n_snapshots: usize,
+    plain_size: usize,
+    compressed_size: usize,
+}
+
+impl Report {
+    const fn add(&mut self, plain_size: usize, compressed_size: usize) {
+        self.n_snapshots += 1;
+        self.plain_size += plain_size;
+        self.compressed_size += compressed_size;
+    }
+
+    fn reduction(&self) -> f64 {
+        if self.plain_size == 0 {
+            0.0
+        } else {
+            1.0 - self.compressed_size as f64 / self.plain_size as f64
+        }
+    }
+}
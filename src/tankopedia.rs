@@ -9,16 +9,86 @@ use tracing::instrument;
 
 use crate::opts::ImportTankopediaOpts;
 use crate::prelude::*;
+use crate::wargaming::cache::{CacheCounters, CacheStats};
 use crate::wargaming::models::{Nation, TankId, TankType, Vehicle};
 use crate::wargaming::{Tankopedia, WargamingApi};
+use crate::web::i18n::LANGUAGES;
 
+pub mod cache;
 mod generated;
+mod patch;
+
+/// Bundled vehicle lookups, a miss means an unknown vehicle.
+static COUNTERS: CacheCounters = CacheCounters::new();
 
 /// Retrieves a vehicle from the Tankopedia.
 pub fn get_vehicle(tank_id: TankId) -> Cow<'static, Vehicle> {
-    generated::GENERATED
-        .get(&tank_id)
-        .map_or_else(|| Cow::Owned(Vehicle::new_hardcoded(tank_id)), Cow::Borrowed)
+    match generated::GENERATED.get(&tank_id) {
+        Some(vehicle) => {
+            COUNTERS.hit();
+            Cow::Borrowed(vehicle)
+        }
+        None => {
+            COUNTERS.miss();
+            Cow::Owned(Vehicle::new_hardcoded(tank_id))
+        }
+    }
+}
+
+/// Bundled vehicle lookups since the start.
+pub fn stats() -> CacheStats {
+    COUNTERS.stats()
+}
+
+/// Number of the bundled vehicles.
+pub fn len() -> usize {
+    generated::GENERATED.len()
+}
+
+/// Tankopedia as of a moment in the past, which falls back to the bundled one
+/// for the vehicles missing back then.
+#[derive(Default)]
+pub struct HistoricalTankopedia(AHashMap<TankId, Vehicle>);
+
+impl From<database::TankopediaSnapshot> for HistoricalTankopedia {
+    fn from(snapshot: database::TankopediaSnapshot) -> Self {
+        Self(
+            snapshot
+                .vehicles
+                .into_iter()
+                .map(|vehicle| (vehicle.tank_id, vehicle))
+                .collect(),
+        )
+    }
+}
+
+impl HistoricalTankopedia {
+    pub fn get_vehicle(&self, tank_id: TankId) -> Cow<'_, Vehicle> {
+        self.0
+            .get(&tank_id)
+            .map_or_else(|| get_vehicle(tank_id), Cow::Borrowed)
+    }
+}
+
+/// Retrieves the vehicle's bundled name in the language, other than English.
+pub fn get_localized_name(tank_id: TankId, language: &str) -> Option<&'static str> {
+    generated::LOCALIZED_NAMES
+        .get(language)
+        .and_then(|names| names.get(&tank_id))
+        .copied()
+}
+
+/// Vehicle names by language and tank ID, only those which differ from the English ones.
+type LocalizedNames = BTreeMap<String, BTreeMap<TankId, String>>;
+
+/// Iterates over the bundled vehicles.
+pub fn iter_vehicles() -> impl Iterator<Item = &'static Vehicle> {
+    generated::GENERATED.values()
+}
+
+/// Tells whether the bundled tankopedia contains any vehicles.
+pub fn is_loaded() -> bool {
+    !generated::GENERATED.is_empty()
 }
 
 /// Updates the bundled `tankopedia.json` and generates the bundled [`phf::Map`] with the tankopedia.
@@ -27,7 +97,7 @@ pub async fn import(opts: ImportTankopediaOpts) -> Result {
     sentry::configure_scope(|scope| scope.set_tag("app", "import-tankopedia"));
 
     let api = WargamingApi::new(
-        &opts.application_id,
+        opts.application_ids,
         time::Duration::from_secs(30),
         NonZeroU32::new(10).unwrap(),
     )?;
@@ -39,12 +109,55 @@ pub async fn import(opts: ImportTankopediaOpts) -> Result {
     let mut tankopedia: Tankopedia =
         serde_json::from_str::<Tankopedia>(&fs::read_to_string(&json_path)?)?
             .into_iter()
-            .chain(api.get_tankopedia().await?)
+            .chain(api.get_tankopedia("en").await?)
             .collect();
     fs::write(&json_path, serde_json::to_string_pretty(&tankopedia)?)?;
 
+    let names_path = json_path.with_file_name("names.json");
+    let mut localized_names: LocalizedNames =
+        serde_json::from_str(&fs::read_to_string(&names_path)?)?;
+    for language in LANGUAGES.into_iter().filter(|language| *language != "en") {
+        let names = localized_names.entry(language.to_string()).or_default();
+        for (tank_id, vehicle) in api.get_tankopedia(language).await? {
+            let english_name = tankopedia.get(&tank_id).map(|vehicle| &vehicle.name);
+            if english_name != Some(&vehicle.name) {
+                names.insert(vehicle.tank_id, vehicle.name.into_owned());
+            }
+        }
+    }
+    fs::write(&names_path, serde_json::to_string_pretty(&localized_names)?)?;
+
     insert_missing_vehicles(&mut tankopedia)?;
-    info!(n_vehicles = tankopedia.len(), "finished");
+    if let Some(patch_path) = &opts.patch {
+        let conflicts = patch::apply(&mut tankopedia, patch::read(patch_path)?)?;
+        for conflict in &conflicts {
+            warn!(
+                conflict.tank_id,
+                conflict.field,
+                conflict.original,
+                conflict.patched,
+                "the patch overrides the tankopedia",
+            );
+        }
+        info!(n_conflicts = conflicts.len(), "applied the patch");
+    }
+    let db = match &opts.mongodb_uri {
+        Some(mongodb_uri) => Some(database::mongodb::open(mongodb_uri).await?),
+        None => None,
+    };
+    let mut attributes = bundled_attributes()?;
+    if let Some(db) = &db {
+        attributes.extend(database::VehicleAttributes::retrieve_all(db).await?);
+    }
+    apply_attributes(&mut tankopedia, &attributes);
+    info!(n_vehicles = tankopedia.len(), n_attributes = attributes.len(), "finished");
+
+    if let Some(db) = &db {
+        database::TankopediaSnapshot::new(tankopedia.values().cloned().collect())
+            .insert(db)
+            .await?;
+        info!("inserted the tankopedia snapshot");
+    }
 
     let mut file = fs::File::create(
         Path::new(file!())
@@ -68,6 +181,21 @@ pub async fn import(opts: ImportTankopediaOpts) -> Result {
         writeln!(&mut file, "        is_premium: {:?},", vehicle.is_premium)?;
         writeln!(&mut file, "        nation: Nation::{:?},", vehicle.nation)?;
         writeln!(&mut file, "        type_: TankType::{:?},", vehicle.type_)?;
+        writeln!(&mut file, "        is_collectible: {:?},", vehicle.is_collectible)?;
+        writeln!(&mut file, "    }},")?;
+    }
+    writeln!(&mut file, "}};")?;
+    writeln!(&mut file)?;
+    writeln!(
+        &mut file,
+        "pub static LOCALIZED_NAMES: phf::Map<&'static str, phf::Map<u32, &'static str>> = \
+         phf::phf_map! {{",
+    )?;
+    for (language, names) in localized_names {
+        writeln!(&mut file, "    {language:?} => phf::phf_map! {{")?;
+        for (tank_id, name) in names {
+            writeln!(&mut file, "        {tank_id}_u32 => {name:?},")?;
+        }
         writeln!(&mut file, "    }},")?;
     }
     writeln!(&mut file, "}};")?;
@@ -75,8 +203,24 @@ pub async fn import(opts: ImportTankopediaOpts) -> Result {
     Ok(())
 }
 
+/// Vehicle attributes, which are maintained by hand in `tankopedia/attributes.json`.
+fn bundled_attributes() -> Result<Vec<database::VehicleAttributes>> {
+    serde_json::from_str(include_str!("tankopedia/attributes.json"))
+        .context("failed to parse the bundled vehicle attributes")
+}
+
+/// Applies the attributes in order, so that the latter ones override the former.
+fn apply_attributes(tankopedia: &mut Tankopedia, attributes: &[database::VehicleAttributes]) {
+    for attributes in attributes {
+        match tankopedia.get_mut(&attributes.tank_id.to_string()) {
+            Some(vehicle) => attributes.apply(vehicle),
+            None => warn!(attributes.tank_id, "the vehicle is missing from the tankopedia"),
+        }
+    }
+}
+
 /// Inserts the hand-coded tanks that are somehow missing from the Tankopedia.
-fn insert_missing_vehicles(vehicles: &mut BTreeMap<String, Vehicle>) -> Result {
+fn insert_missing_vehicles(vehicles: &mut Tankopedia) -> Result {
     for vehicle in [
         Vehicle {
             tank_id: 20817,
@@ -85,6 +229,7 @@ fn insert_missing_vehicles(vehicles: &mut BTreeMap<String, Vehicle>) -> Result {
             is_premium: true,
             nation: Nation::Uk,
             type_: TankType::Medium,
+            is_collectible: false,
         },
         Vehicle {
             tank_id: 3089,
@@ -93,6 +238,7 @@ fn insert_missing_vehicles(vehicles: &mut BTreeMap<String, Vehicle>) -> Result {
             is_premium: false,
             nation: Nation::Germany,
             type_: TankType::Light,
+            is_collectible: false,
         },
         Vehicle {
             tank_id: 64081,
@@ -101,6 +247,7 @@ fn insert_missing_vehicles(vehicles: &mut BTreeMap<String, Vehicle>) -> Result {
             is_premium: true,
             nation: Nation::Uk,
             type_: TankType::Heavy,
+            is_collectible: false,
         },
         Vehicle {
             tank_id: 1329,
@@ -109,6 +256,7 @@ fn insert_missing_vehicles(vehicles: &mut BTreeMap<String, Vehicle>) -> Result {
             is_premium: false,
             nation: Nation::China,
             type_: TankType::Light,
+            is_collectible: false,
         },
         Vehicle {
             tank_id: 609,
@@ -117,6 +265,7 @@ fn insert_missing_vehicles(vehicles: &mut BTreeMap<String, Vehicle>) -> Result {
             is_premium: false,
             nation: Nation::Japan,
             type_: TankType::Light,
+            is_collectible: false,
         },
         Vehicle {
             tank_id: 23297,
@@ -125,6 +274,7 @@ fn insert_missing_vehicles(vehicles: &mut BTreeMap<String, Vehicle>) -> Result {
             is_premium: true,
             nation: Nation::Ussr,
             type_: TankType::Heavy,
+            is_collectible: false,
         },
         Vehicle {
             tank_id: 18241,
@@ -133,6 +283,7 @@ fn insert_missing_vehicles(vehicles: &mut BTreeMap<String, Vehicle>) -> Result {
             is_premium: true,
             nation: Nation::France,
             type_: TankType::Medium,
+            is_collectible: false,
         },
         Vehicle {
             tank_id: 577,
@@ -141,6 +292,7 @@ fn insert_missing_vehicles(vehicles: &mut BTreeMap<String, Vehicle>) -> Result {
             is_premium: true,
             nation: Nation::France,
             type_: TankType::AT,
+            is_collectible: false,
         },
         Vehicle {
             tank_id: 81,
@@ -149,6 +301,7 @@ fn insert_missing_vehicles(vehicles: &mut BTreeMap<String, Vehicle>) -> Result {
             is_premium: true,
             nation: Nation::Uk,
             type_: TankType::Medium,
+            is_collectible: false,
         },
         Vehicle {
             tank_id: 545,
@@ -157,6 +310,7 @@ fn insert_missing_vehicles(vehicles: &mut BTreeMap<String, Vehicle>) -> Result {
             is_premium: true,
             nation: Nation::Usa,
             type_: TankType::Light,
+            is_collectible: false,
         },
         Vehicle {
             tank_id: 24849,
@@ -165,6 +319,7 @@ fn insert_missing_vehicles(vehicles: &mut BTreeMap<String, Vehicle>) -> Result {
             is_premium: true,
             nation: Nation::Germany,
             type_: TankType::AT,
+            is_collectible: false,
         },
         Vehicle {
             tank_id: 9777,
@@ -173,6 +328,7 @@ fn insert_missing_vehicles(vehicles: &mut BTreeMap<String, Vehicle>) -> Result {
             is_premium: true,
             nation: Nation::China,
             type_: TankType::Heavy,
+            is_collectible: false,
         },
         Vehicle {
             tank_id: 12417,
@@ -181,6 +337,7 @@ fn insert_missing_vehicles(vehicles: &mut BTreeMap<String, Vehicle>) -> Result {
             is_premium: true,
             nation: Nation::Europe,
             type_: TankType::Heavy,
+            is_collectible: false,
         },
         Vehicle {
             tank_id: 10545,
@@ -189,6 +346,7 @@ fn insert_missing_vehicles(vehicles: &mut BTreeMap<String, Vehicle>) -> Result {
             is_premium: true,
             nation: Nation::China,
             type_: TankType::Light,
+            is_collectible: false,
         },
     ] {
         match vehicles.get(&vehicle.tank_id.to_string()) {
@@ -199,3 +357,32 @@ fn insert_missing_vehicles(vehicles: &mut BTreeMap<String, Vehicle>) -> Result {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn historical_tankopedia_ok() {
+        let tankopedia = HistoricalTankopedia::from(database::TankopediaSnapshot::new(vec![
+            Vehicle {
+                tank_id: 1,
+                name: Cow::Borrowed("T-34 (old)"),
+                ..Vehicle::new_hardcoded(1)
+            },
+        ]));
+        assert_eq!(tankopedia.get_vehicle(1).name, "T-34 (old)");
+        assert_eq!(tankopedia.get_vehicle(1).tier, 0);
+        assert_eq!(tankopedia.get_vehicle(1025).name, get_vehicle(1025).name);
+    }
+
+    #[test]
+    fn bundled_attributes_ok() -> Result {
+        let attributes = bundled_attributes()?;
+        for attributes in attributes {
+            let vehicle = get_vehicle(attributes.tank_id);
+            assert_eq!(vehicle.is_collectible, attributes.is_collectible, "#{}", vehicle.tank_id);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+//! In-process cache of the historical tankopedias, so that a player view only reads
+//! the whole snapshot when a newer one has been imported.
+
+use std::sync::Mutex;
+
+use crate::helpers::single_flight::SingleFlight;
+use crate::prelude::*;
+use crate::tankopedia::HistoricalTankopedia;
+
+/// The display periods are short, so only the few recent snapshots are ever requested.
+const MAX_ENTRIES: usize = 8;
+
+/// Historical tankopedias keyed by the snapshot timestamps.
+#[derive(Clone, Default)]
+pub struct HistoricalTankopediaCache {
+    tankopedias: Arc<Mutex<AHashMap<DateTime, Arc<HistoricalTankopedia>>>>,
+    in_flight: Arc<SingleFlight<DateTime>>,
+}
+
+impl HistoricalTankopediaCache {
+    /// Retrieves the tankopedia as of the moment, which is empty, if there were no snapshots.
+    #[instrument(skip_all, level = "debug", fields(as_of = ?as_of))]
+    pub async fn get(
+        &self,
+        db: &mongodb::Database,
+        as_of: DateTime,
+    ) -> Result<Arc<HistoricalTankopedia>> {
+        let Some(timestamp) =
+            database::TankopediaSnapshot::retrieve_timestamp_as_of(db, as_of).await? else {
+            return Ok(Arc::default());
+        };
+        if let Some(tankopedia) = self.get_cached(timestamp) {
+            return Ok(tankopedia);
+        }
+
+        let _guard = self.in_flight.lock(timestamp).await;
+        if let Some(tankopedia) = self.get_cached(timestamp) {
+            return Ok(tankopedia);
+        }
+        debug!(%timestamp, "reading the snapshot…");
+        let tankopedia: Arc<HistoricalTankopedia> =
+            database::TankopediaSnapshot::retrieve_as_of(db, timestamp)
+                .await?
+                .map(HistoricalTankopedia::from)
+                .unwrap_or_default()
+                .into();
+        self.insert(timestamp, tankopedia.clone());
+        Ok(tankopedia)
+    }
+
+    fn get_cached(&self, timestamp: DateTime) -> Option<Arc<HistoricalTankopedia>> {
+        self.tankopedias.lock().unwrap().get(&timestamp).cloned()
+    }
+
+    /// Inserts the tankopedia, evicting the oldest one when the cache is full.
+    fn insert(&self, timestamp: DateTime, tankopedia: Arc<HistoricalTankopedia>) {
+        let mut tankopedias = self.tankopedias.lock().unwrap();
+        if tankopedias.len() >= MAX_ENTRIES {
+            if let Some(oldest) = tankopedias.keys().min().copied() {
+                tankopedias.remove(&oldest);
+            }
+        }
+        tankopedias.insert(timestamp, tankopedia);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_evicts_oldest_ok() {
+        let cache = HistoricalTankopediaCache::default();
+        let timestamp = |secs| Utc.timestamp_opt(secs, 0).unwrap();
+        for secs in 0..=MAX_ENTRIES as i64 {
+            cache.insert(timestamp(secs), Arc::default());
+        }
+        assert!(cache.get_cached(timestamp(0)).is_none());
+        assert!(cache.get_cached(timestamp(1)).is_some());
+        assert!(cache.get_cached(timestamp(MAX_ENTRIES as i64)).is_some());
+    }
+}
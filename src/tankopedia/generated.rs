@@ -12,6 +12,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     10001_u32 => Vehicle {
         tank_id: 10001,
@@ -20,6 +21,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: false,
     },
     10017_u32 => Vehicle {
         tank_id: 10017,
@@ -28,6 +30,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     10049_u32 => Vehicle {
         tank_id: 10049,
@@ -36,6 +39,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::AT,
+        is_collectible: false,
     },
     10065_u32 => Vehicle {
         tank_id: 10065,
@@ -44,6 +48,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     10097_u32 => Vehicle {
         tank_id: 10097,
@@ -52,6 +57,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     10113_u32 => Vehicle {
         tank_id: 10113,
@@ -60,6 +66,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     10241_u32 => Vehicle {
         tank_id: 10241,
@@ -68,6 +75,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     1025_u32 => Vehicle {
         tank_id: 1025,
@@ -76,6 +84,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     10257_u32 => Vehicle {
         tank_id: 10257,
@@ -84,6 +93,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     10273_u32 => Vehicle {
         tank_id: 10273,
@@ -92,6 +102,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     10353_u32 => Vehicle {
         tank_id: 10353,
@@ -100,6 +111,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     10369_u32 => Vehicle {
         tank_id: 10369,
@@ -108,6 +120,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::AT,
+        is_collectible: false,
     },
     1041_u32 => Vehicle {
         tank_id: 1041,
@@ -116,6 +129,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     10497_u32 => Vehicle {
         tank_id: 10497,
@@ -124,6 +138,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     10513_u32 => Vehicle {
         tank_id: 10513,
@@ -132,6 +147,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     10529_u32 => Vehicle {
         tank_id: 10529,
@@ -140,6 +156,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: true,
     },
     10545_u32 => Vehicle {
         tank_id: 10545,
@@ -148,6 +165,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::China,
         type_: TankType::Light,
+        is_collectible: false,
     },
     1057_u32 => Vehicle {
         tank_id: 1057,
@@ -156,6 +174,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     10625_u32 => Vehicle {
         tank_id: 10625,
@@ -164,6 +183,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::AT,
+        is_collectible: false,
     },
     1073_u32 => Vehicle {
         tank_id: 1073,
@@ -172,6 +192,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     10753_u32 => Vehicle {
         tank_id: 10753,
@@ -180,6 +201,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     10769_u32 => Vehicle {
         tank_id: 10769,
@@ -188,6 +210,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     10785_u32 => Vehicle {
         tank_id: 10785,
@@ -196,6 +219,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     10817_u32 => Vehicle {
         tank_id: 10817,
@@ -204,6 +228,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::AT,
+        is_collectible: false,
     },
     10881_u32 => Vehicle {
         tank_id: 10881,
@@ -212,6 +237,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::AT,
+        is_collectible: false,
     },
     1089_u32 => Vehicle {
         tank_id: 1089,
@@ -220,6 +246,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     11009_u32 => Vehicle {
         tank_id: 11009,
@@ -228,6 +255,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     11025_u32 => Vehicle {
         tank_id: 11025,
@@ -236,6 +264,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     11041_u32 => Vehicle {
         tank_id: 11041,
@@ -244,6 +273,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     1105_u32 => Vehicle {
         tank_id: 1105,
@@ -252,6 +282,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     11073_u32 => Vehicle {
         tank_id: 11073,
@@ -260,6 +291,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::AT,
+        is_collectible: false,
     },
     11137_u32 => Vehicle {
         tank_id: 11137,
@@ -268,6 +300,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::AT,
+        is_collectible: false,
     },
     1121_u32 => Vehicle {
         tank_id: 1121,
@@ -276,6 +309,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     11265_u32 => Vehicle {
         tank_id: 11265,
@@ -284,6 +318,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: true,
     },
     11281_u32 => Vehicle {
         tank_id: 11281,
@@ -292,6 +327,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     11297_u32 => Vehicle {
         tank_id: 11297,
@@ -300,6 +336,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     113_u32 => Vehicle {
         tank_id: 113,
@@ -308,6 +345,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::AT,
+        is_collectible: true,
     },
     1137_u32 => Vehicle {
         tank_id: 1137,
@@ -316,6 +354,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     11393_u32 => Vehicle {
         tank_id: 11393,
@@ -324,6 +363,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::AT,
+        is_collectible: false,
     },
     11521_u32 => Vehicle {
         tank_id: 11521,
@@ -332,6 +372,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     1153_u32 => Vehicle {
         tank_id: 1153,
@@ -340,6 +381,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     11537_u32 => Vehicle {
         tank_id: 11537,
@@ -348,6 +390,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     11553_u32 => Vehicle {
         tank_id: 11553,
@@ -356,6 +399,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     11585_u32 => Vehicle {
         tank_id: 11585,
@@ -364,6 +408,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::AT,
+        is_collectible: false,
     },
     11649_u32 => Vehicle {
         tank_id: 11649,
@@ -372,6 +417,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::AT,
+        is_collectible: false,
     },
     11777_u32 => Vehicle {
         tank_id: 11777,
@@ -380,6 +426,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     11793_u32 => Vehicle {
         tank_id: 11793,
@@ -388,6 +435,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     11809_u32 => Vehicle {
         tank_id: 11809,
@@ -396,6 +444,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     11905_u32 => Vehicle {
         tank_id: 11905,
@@ -404,6 +453,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     12033_u32 => Vehicle {
         tank_id: 12033,
@@ -412,6 +462,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     12049_u32 => Vehicle {
         tank_id: 12049,
@@ -420,6 +471,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     12065_u32 => Vehicle {
         tank_id: 12065,
@@ -428,6 +480,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     12097_u32 => Vehicle {
         tank_id: 12097,
@@ -436,6 +489,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::AT,
+        is_collectible: false,
     },
     12161_u32 => Vehicle {
         tank_id: 12161,
@@ -444,6 +498,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Europe,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     12305_u32 => Vehicle {
         tank_id: 12305,
@@ -452,6 +507,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     12321_u32 => Vehicle {
         tank_id: 12321,
@@ -460,6 +516,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     12417_u32 => Vehicle {
         tank_id: 12417,
@@ -468,6 +525,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Europe,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     12545_u32 => Vehicle {
         tank_id: 12545,
@@ -476,6 +534,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     1297_u32 => Vehicle {
         tank_id: 1297,
@@ -484,6 +543,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     13073_u32 => Vehicle {
         tank_id: 13073,
@@ -492,6 +552,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: false,
     },
     13089_u32 => Vehicle {
         tank_id: 13089,
@@ -500,6 +561,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     1313_u32 => Vehicle {
         tank_id: 1313,
@@ -508,6 +570,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     1329_u32 => Vehicle {
         tank_id: 1329,
@@ -516,6 +579,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::Light,
+        is_collectible: false,
     },
     13329_u32 => Vehicle {
         tank_id: 13329,
@@ -524,6 +588,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: true,
     },
     13345_u32 => Vehicle {
         tank_id: 13345,
@@ -532,6 +597,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     13569_u32 => Vehicle {
         tank_id: 13569,
@@ -540,6 +606,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     1361_u32 => Vehicle {
         tank_id: 1361,
@@ -548,6 +615,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     1377_u32 => Vehicle {
         tank_id: 1377,
@@ -556,6 +624,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     13825_u32 => Vehicle {
         tank_id: 13825,
@@ -564,6 +633,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     13841_u32 => Vehicle {
         tank_id: 13841,
@@ -572,6 +642,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     13857_u32 => Vehicle {
         tank_id: 13857,
@@ -580,6 +651,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     13889_u32 => Vehicle {
         tank_id: 13889,
@@ -588,6 +660,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::AT,
+        is_collectible: false,
     },
     1393_u32 => Vehicle {
         tank_id: 1393,
@@ -596,6 +669,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     1409_u32 => Vehicle {
         tank_id: 1409,
@@ -604,6 +678,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     14097_u32 => Vehicle {
         tank_id: 14097,
@@ -612,6 +687,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     14113_u32 => Vehicle {
         tank_id: 14113,
@@ -620,6 +696,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     14145_u32 => Vehicle {
         tank_id: 14145,
@@ -628,6 +705,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::Light,
+        is_collectible: false,
     },
     14337_u32 => Vehicle {
         tank_id: 14337,
@@ -636,6 +714,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     14609_u32 => Vehicle {
         tank_id: 14609,
@@ -644,6 +723,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     14625_u32 => Vehicle {
         tank_id: 14625,
@@ -652,6 +732,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     14865_u32 => Vehicle {
         tank_id: 14865,
@@ -660,6 +741,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     14881_u32 => Vehicle {
         tank_id: 14881,
@@ -668,6 +750,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     15137_u32 => Vehicle {
         tank_id: 15137,
@@ -676,6 +759,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: true,
     },
     1537_u32 => Vehicle {
         tank_id: 1537,
@@ -684,6 +768,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     15393_u32 => Vehicle {
         tank_id: 15393,
@@ -692,6 +777,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     15441_u32 => Vehicle {
         tank_id: 15441,
@@ -700,6 +786,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     1553_u32 => Vehicle {
         tank_id: 1553,
@@ -708,6 +795,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     15617_u32 => Vehicle {
         tank_id: 15617,
@@ -716,6 +804,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     15649_u32 => Vehicle {
         tank_id: 15649,
@@ -724,6 +813,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     1569_u32 => Vehicle {
         tank_id: 1569,
@@ -732,6 +822,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     15697_u32 => Vehicle {
         tank_id: 15697,
@@ -740,6 +831,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     1585_u32 => Vehicle {
         tank_id: 1585,
@@ -748,6 +840,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     15889_u32 => Vehicle {
         tank_id: 15889,
@@ -756,6 +849,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     15905_u32 => Vehicle {
         tank_id: 15905,
@@ -764,6 +858,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     15937_u32 => Vehicle {
         tank_id: 15937,
@@ -772,6 +867,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     15953_u32 => Vehicle {
         tank_id: 15953,
@@ -780,6 +876,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     1601_u32 => Vehicle {
         tank_id: 1601,
@@ -788,6 +885,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Light,
+        is_collectible: false,
     },
     16145_u32 => Vehicle {
         tank_id: 16145,
@@ -796,6 +894,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: true,
     },
     1617_u32 => Vehicle {
         tank_id: 1617,
@@ -804,6 +903,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     16193_u32 => Vehicle {
         tank_id: 16193,
@@ -812,6 +912,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     1633_u32 => Vehicle {
         tank_id: 1633,
@@ -820,6 +921,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     16401_u32 => Vehicle {
         tank_id: 16401,
@@ -828,6 +930,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     16449_u32 => Vehicle {
         tank_id: 16449,
@@ -836,6 +939,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Light,
+        is_collectible: false,
     },
     1649_u32 => Vehicle {
         tank_id: 1649,
@@ -844,6 +948,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::AT,
+        is_collectible: false,
     },
     16641_u32 => Vehicle {
         tank_id: 16641,
@@ -852,6 +957,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     1665_u32 => Vehicle {
         tank_id: 1665,
@@ -860,6 +966,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     16657_u32 => Vehicle {
         tank_id: 16657,
@@ -868,6 +975,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     16673_u32 => Vehicle {
         tank_id: 16673,
@@ -876,6 +984,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     16705_u32 => Vehicle {
         tank_id: 16705,
@@ -884,6 +993,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     16897_u32 => Vehicle {
         tank_id: 16897,
@@ -892,6 +1002,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     17_u32 => Vehicle {
         tank_id: 17,
@@ -900,6 +1011,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     17169_u32 => Vehicle {
         tank_id: 17169,
@@ -908,6 +1020,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     17217_u32 => Vehicle {
         tank_id: 17217,
@@ -916,6 +1029,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Heavy,
+        is_collectible: true,
     },
     17233_u32 => Vehicle {
         tank_id: 17233,
@@ -924,6 +1038,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     17425_u32 => Vehicle {
         tank_id: 17425,
@@ -932,6 +1047,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     17473_u32 => Vehicle {
         tank_id: 17473,
@@ -940,6 +1056,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Light,
+        is_collectible: false,
     },
     17489_u32 => Vehicle {
         tank_id: 17489,
@@ -948,6 +1065,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     17729_u32 => Vehicle {
         tank_id: 17729,
@@ -956,6 +1074,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     17745_u32 => Vehicle {
         tank_id: 17745,
@@ -964,6 +1083,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: true,
     },
     17953_u32 => Vehicle {
         tank_id: 17953,
@@ -972,6 +1092,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     17985_u32 => Vehicle {
         tank_id: 17985,
@@ -980,6 +1101,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     18001_u32 => Vehicle {
         tank_id: 18001,
@@ -988,6 +1110,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     1809_u32 => Vehicle {
         tank_id: 1809,
@@ -996,6 +1119,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     18177_u32 => Vehicle {
         tank_id: 18177,
@@ -1004,6 +1128,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     18209_u32 => Vehicle {
         tank_id: 18209,
@@ -1012,6 +1137,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     18241_u32 => Vehicle {
         tank_id: 18241,
@@ -1020,6 +1146,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     1825_u32 => Vehicle {
         tank_id: 1825,
@@ -1028,6 +1155,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     18257_u32 => Vehicle {
         tank_id: 18257,
@@ -1036,6 +1164,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     1841_u32 => Vehicle {
         tank_id: 1841,
@@ -1044,6 +1173,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     18433_u32 => Vehicle {
         tank_id: 18433,
@@ -1052,6 +1182,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     18449_u32 => Vehicle {
         tank_id: 18449,
@@ -1060,6 +1191,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: false,
     },
     18497_u32 => Vehicle {
         tank_id: 18497,
@@ -1068,6 +1200,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     18513_u32 => Vehicle {
         tank_id: 18513,
@@ -1076,6 +1209,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     1857_u32 => Vehicle {
         tank_id: 1857,
@@ -1084,6 +1218,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::Light,
+        is_collectible: false,
     },
     18689_u32 => Vehicle {
         tank_id: 18689,
@@ -1092,6 +1227,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     18753_u32 => Vehicle {
         tank_id: 18753,
@@ -1100,6 +1236,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::AT,
+        is_collectible: false,
     },
     18769_u32 => Vehicle {
         tank_id: 18769,
@@ -1108,6 +1245,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     1889_u32 => Vehicle {
         tank_id: 1889,
@@ -1116,6 +1254,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     18945_u32 => Vehicle {
         tank_id: 18945,
@@ -1124,6 +1263,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     18961_u32 => Vehicle {
         tank_id: 18961,
@@ -1132,6 +1272,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: false,
     },
     18977_u32 => Vehicle {
         tank_id: 18977,
@@ -1140,6 +1281,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     19009_u32 => Vehicle {
         tank_id: 19009,
@@ -1148,6 +1290,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Light,
+        is_collectible: true,
     },
     19025_u32 => Vehicle {
         tank_id: 19025,
@@ -1156,6 +1299,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     1905_u32 => Vehicle {
         tank_id: 1905,
@@ -1164,6 +1308,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     1921_u32 => Vehicle {
         tank_id: 1921,
@@ -1172,6 +1317,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     19217_u32 => Vehicle {
         tank_id: 19217,
@@ -1180,6 +1326,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     19233_u32 => Vehicle {
         tank_id: 19233,
@@ -1188,6 +1335,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     19265_u32 => Vehicle {
         tank_id: 19265,
@@ -1196,6 +1344,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::AT,
+        is_collectible: false,
     },
     19281_u32 => Vehicle {
         tank_id: 19281,
@@ -1204,6 +1353,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     19473_u32 => Vehicle {
         tank_id: 19473,
@@ -1212,6 +1362,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     19489_u32 => Vehicle {
         tank_id: 19489,
@@ -1220,6 +1371,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     19537_u32 => Vehicle {
         tank_id: 19537,
@@ -1228,6 +1380,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Light,
+        is_collectible: false,
     },
     19713_u32 => Vehicle {
         tank_id: 19713,
@@ -1236,6 +1389,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     19729_u32 => Vehicle {
         tank_id: 19729,
@@ -1244,6 +1398,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     19745_u32 => Vehicle {
         tank_id: 19745,
@@ -1252,6 +1407,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     19793_u32 => Vehicle {
         tank_id: 19793,
@@ -1260,6 +1416,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Light,
+        is_collectible: false,
     },
     19969_u32 => Vehicle {
         tank_id: 19969,
@@ -1268,6 +1425,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     19985_u32 => Vehicle {
         tank_id: 19985,
@@ -1276,6 +1434,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     20001_u32 => Vehicle {
         tank_id: 20001,
@@ -1284,6 +1443,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     20049_u32 => Vehicle {
         tank_id: 20049,
@@ -1292,6 +1452,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Light,
+        is_collectible: false,
     },
     20257_u32 => Vehicle {
         tank_id: 20257,
@@ -1300,6 +1461,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     20305_u32 => Vehicle {
         tank_id: 20305,
@@ -1308,6 +1470,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     20481_u32 => Vehicle {
         tank_id: 20481,
@@ -1316,6 +1479,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     2049_u32 => Vehicle {
         tank_id: 2049,
@@ -1324,6 +1488,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     20497_u32 => Vehicle {
         tank_id: 20497,
@@ -1332,6 +1497,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     20513_u32 => Vehicle {
         tank_id: 20513,
@@ -1340,6 +1506,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     20561_u32 => Vehicle {
         tank_id: 20561,
@@ -1348,6 +1515,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     2065_u32 => Vehicle {
         tank_id: 2065,
@@ -1356,6 +1524,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: false,
     },
     20737_u32 => Vehicle {
         tank_id: 20737,
@@ -1364,6 +1533,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     20753_u32 => Vehicle {
         tank_id: 20753,
@@ -1372,6 +1542,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     20769_u32 => Vehicle {
         tank_id: 20769,
@@ -1380,6 +1551,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     20817_u32 => Vehicle {
         tank_id: 20817,
@@ -1388,6 +1560,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     2097_u32 => Vehicle {
         tank_id: 2097,
@@ -1396,6 +1569,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     20993_u32 => Vehicle {
         tank_id: 20993,
@@ -1404,6 +1578,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     21009_u32 => Vehicle {
         tank_id: 21009,
@@ -1412,6 +1587,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     21025_u32 => Vehicle {
         tank_id: 21025,
@@ -1420,6 +1596,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     21073_u32 => Vehicle {
         tank_id: 21073,
@@ -1428,6 +1605,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: true,
     },
     21249_u32 => Vehicle {
         tank_id: 21249,
@@ -1436,6 +1614,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: true,
     },
     21265_u32 => Vehicle {
         tank_id: 21265,
@@ -1444,6 +1623,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     21281_u32 => Vehicle {
         tank_id: 21281,
@@ -1452,6 +1632,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     2129_u32 => Vehicle {
         tank_id: 2129,
@@ -1460,6 +1641,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Light,
+        is_collectible: false,
     },
     21329_u32 => Vehicle {
         tank_id: 21329,
@@ -1468,6 +1650,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     2145_u32 => Vehicle {
         tank_id: 2145,
@@ -1476,6 +1659,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     21505_u32 => Vehicle {
         tank_id: 21505,
@@ -1484,6 +1668,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     21521_u32 => Vehicle {
         tank_id: 21521,
@@ -1492,6 +1677,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     21585_u32 => Vehicle {
         tank_id: 21585,
@@ -1500,6 +1686,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     2161_u32 => Vehicle {
         tank_id: 2161,
@@ -1508,6 +1695,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::AT,
+        is_collectible: false,
     },
     21761_u32 => Vehicle {
         tank_id: 21761,
@@ -1516,6 +1704,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     2177_u32 => Vehicle {
         tank_id: 2177,
@@ -1524,6 +1713,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Light,
+        is_collectible: false,
     },
     21777_u32 => Vehicle {
         tank_id: 21777,
@@ -1532,6 +1722,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     21793_u32 => Vehicle {
         tank_id: 21793,
@@ -1540,6 +1731,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     21841_u32 => Vehicle {
         tank_id: 21841,
@@ -1548,6 +1740,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     22033_u32 => Vehicle {
         tank_id: 22033,
@@ -1556,6 +1749,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: true,
     },
     22049_u32 => Vehicle {
         tank_id: 22049,
@@ -1564,6 +1758,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: true,
     },
     22273_u32 => Vehicle {
         tank_id: 22273,
@@ -1572,6 +1767,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: true,
     },
     22305_u32 => Vehicle {
         tank_id: 22305,
@@ -1580,6 +1776,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     22529_u32 => Vehicle {
         tank_id: 22529,
@@ -1588,6 +1785,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     22545_u32 => Vehicle {
         tank_id: 22545,
@@ -1596,6 +1794,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     22561_u32 => Vehicle {
         tank_id: 22561,
@@ -1604,6 +1803,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     22785_u32 => Vehicle {
         tank_id: 22785,
@@ -1612,6 +1812,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     22801_u32 => Vehicle {
         tank_id: 22801,
@@ -1620,6 +1821,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: true,
     },
     22817_u32 => Vehicle {
         tank_id: 22817,
@@ -1628,6 +1830,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     23041_u32 => Vehicle {
         tank_id: 23041,
@@ -1636,6 +1839,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     2305_u32 => Vehicle {
         tank_id: 2305,
@@ -1644,6 +1848,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     23057_u32 => Vehicle {
         tank_id: 23057,
@@ -1652,6 +1857,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: false,
     },
     23073_u32 => Vehicle {
         tank_id: 23073,
@@ -1660,6 +1866,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     2321_u32 => Vehicle {
         tank_id: 2321,
@@ -1668,6 +1875,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     23297_u32 => Vehicle {
         tank_id: 23297,
@@ -1676,6 +1884,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     23313_u32 => Vehicle {
         tank_id: 23313,
@@ -1684,6 +1893,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     23329_u32 => Vehicle {
         tank_id: 23329,
@@ -1692,6 +1902,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     2353_u32 => Vehicle {
         tank_id: 2353,
@@ -1700,6 +1911,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::Light,
+        is_collectible: false,
     },
     23553_u32 => Vehicle {
         tank_id: 23553,
@@ -1708,6 +1920,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: true,
     },
     23569_u32 => Vehicle {
         tank_id: 23569,
@@ -1716,6 +1929,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     23585_u32 => Vehicle {
         tank_id: 23585,
@@ -1724,6 +1938,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     2369_u32 => Vehicle {
         tank_id: 2369,
@@ -1732,6 +1947,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::AT,
+        is_collectible: false,
     },
     23809_u32 => Vehicle {
         tank_id: 23809,
@@ -1740,6 +1956,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     23825_u32 => Vehicle {
         tank_id: 23825,
@@ -1748,6 +1965,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     23841_u32 => Vehicle {
         tank_id: 23841,
@@ -1756,6 +1974,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     2385_u32 => Vehicle {
         tank_id: 2385,
@@ -1764,6 +1983,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     2401_u32 => Vehicle {
         tank_id: 2401,
@@ -1772,6 +1992,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Japan,
         type_: TankType::Light,
+        is_collectible: false,
     },
     24065_u32 => Vehicle {
         tank_id: 24065,
@@ -1780,6 +2001,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     24081_u32 => Vehicle {
         tank_id: 24081,
@@ -1788,6 +2010,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     24097_u32 => Vehicle {
         tank_id: 24097,
@@ -1796,6 +2019,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     24321_u32 => Vehicle {
         tank_id: 24321,
@@ -1804,6 +2028,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     2433_u32 => Vehicle {
         tank_id: 2433,
@@ -1812,6 +2037,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Light,
+        is_collectible: false,
     },
     24337_u32 => Vehicle {
         tank_id: 24337,
@@ -1820,6 +2046,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     24577_u32 => Vehicle {
         tank_id: 24577,
@@ -1828,6 +2055,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     24593_u32 => Vehicle {
         tank_id: 24593,
@@ -1836,6 +2064,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     24609_u32 => Vehicle {
         tank_id: 24609,
@@ -1844,6 +2073,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: true,
     },
     24849_u32 => Vehicle {
         tank_id: 24849,
@@ -1852,6 +2082,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: true,
     },
     24865_u32 => Vehicle {
         tank_id: 24865,
@@ -1860,6 +2091,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     25089_u32 => Vehicle {
         tank_id: 25089,
@@ -1868,6 +2100,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     25105_u32 => Vehicle {
         tank_id: 25105,
@@ -1876,6 +2109,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     25345_u32 => Vehicle {
         tank_id: 25345,
@@ -1884,6 +2118,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     25361_u32 => Vehicle {
         tank_id: 25361,
@@ -1892,6 +2127,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     25377_u32 => Vehicle {
         tank_id: 25377,
@@ -1900,6 +2136,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     2561_u32 => Vehicle {
         tank_id: 2561,
@@ -1908,6 +2145,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     25633_u32 => Vehicle {
         tank_id: 25633,
@@ -1916,6 +2154,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     257_u32 => Vehicle {
         tank_id: 257,
@@ -1924,6 +2163,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     2577_u32 => Vehicle {
         tank_id: 2577,
@@ -1932,6 +2172,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: true,
     },
     25889_u32 => Vehicle {
         tank_id: 25889,
@@ -1940,6 +2181,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     2593_u32 => Vehicle {
         tank_id: 2593,
@@ -1948,6 +2190,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     2609_u32 => Vehicle {
         tank_id: 2609,
@@ -1956,6 +2199,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::China,
         type_: TankType::Light,
+        is_collectible: false,
     },
     26145_u32 => Vehicle {
         tank_id: 26145,
@@ -1964,6 +2208,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     2625_u32 => Vehicle {
         tank_id: 2625,
@@ -1972,6 +2217,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     26401_u32 => Vehicle {
         tank_id: 26401,
@@ -1980,6 +2226,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     2657_u32 => Vehicle {
         tank_id: 2657,
@@ -1988,6 +2235,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     26657_u32 => Vehicle {
         tank_id: 26657,
@@ -1996,6 +2244,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     2689_u32 => Vehicle {
         tank_id: 2689,
@@ -2004,6 +2253,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Light,
+        is_collectible: false,
     },
     26913_u32 => Vehicle {
         tank_id: 26913,
@@ -2012,6 +2262,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     27169_u32 => Vehicle {
         tank_id: 27169,
@@ -2020,6 +2271,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     2817_u32 => Vehicle {
         tank_id: 2817,
@@ -2028,6 +2280,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     2849_u32 => Vehicle {
         tank_id: 2849,
@@ -2036,6 +2289,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     2865_u32 => Vehicle {
         tank_id: 2865,
@@ -2044,6 +2298,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     2881_u32 => Vehicle {
         tank_id: 2881,
@@ -2052,6 +2307,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     289_u32 => Vehicle {
         tank_id: 289,
@@ -2060,6 +2316,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     2897_u32 => Vehicle {
         tank_id: 2897,
@@ -2068,6 +2325,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     2913_u32 => Vehicle {
         tank_id: 2913,
@@ -2076,6 +2334,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Japan,
         type_: TankType::Light,
+        is_collectible: true,
     },
     2945_u32 => Vehicle {
         tank_id: 2945,
@@ -2084,6 +2343,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     3073_u32 => Vehicle {
         tank_id: 3073,
@@ -2092,6 +2352,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     3089_u32 => Vehicle {
         tank_id: 3089,
@@ -2100,6 +2361,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: false,
     },
     3105_u32 => Vehicle {
         tank_id: 3105,
@@ -2108,6 +2370,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     3121_u32 => Vehicle {
         tank_id: 3121,
@@ -2116,6 +2379,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::Light,
+        is_collectible: false,
     },
     3137_u32 => Vehicle {
         tank_id: 3137,
@@ -2124,6 +2388,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     3153_u32 => Vehicle {
         tank_id: 3153,
@@ -2132,6 +2397,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     3201_u32 => Vehicle {
         tank_id: 3201,
@@ -2140,6 +2406,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Europe,
         type_: TankType::Heavy,
+        is_collectible: true,
     },
     321_u32 => Vehicle {
         tank_id: 321,
@@ -2148,6 +2415,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     33_u32 => Vehicle {
         tank_id: 33,
@@ -2156,6 +2424,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     3329_u32 => Vehicle {
         tank_id: 3329,
@@ -2164,6 +2433,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     3345_u32 => Vehicle {
         tank_id: 3345,
@@ -2172,6 +2442,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: false,
     },
     3361_u32 => Vehicle {
         tank_id: 3361,
@@ -2180,6 +2451,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     337_u32 => Vehicle {
         tank_id: 337,
@@ -2188,6 +2460,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     3425_u32 => Vehicle {
         tank_id: 3425,
@@ -2196,6 +2469,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     3457_u32 => Vehicle {
         tank_id: 3457,
@@ -2204,6 +2478,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     353_u32 => Vehicle {
         tank_id: 353,
@@ -2212,6 +2487,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     3585_u32 => Vehicle {
         tank_id: 3585,
@@ -2220,6 +2496,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     3601_u32 => Vehicle {
         tank_id: 3601,
@@ -2228,6 +2505,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     3633_u32 => Vehicle {
         tank_id: 3633,
@@ -2236,6 +2514,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     3649_u32 => Vehicle {
         tank_id: 3649,
@@ -2244,6 +2523,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::Light,
+        is_collectible: false,
     },
     3681_u32 => Vehicle {
         tank_id: 3681,
@@ -2252,6 +2532,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     3697_u32 => Vehicle {
         tank_id: 3697,
@@ -2260,6 +2541,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     3713_u32 => Vehicle {
         tank_id: 3713,
@@ -2268,6 +2550,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     385_u32 => Vehicle {
         tank_id: 385,
@@ -2276,6 +2559,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     3857_u32 => Vehicle {
         tank_id: 3857,
@@ -2284,6 +2568,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     3873_u32 => Vehicle {
         tank_id: 3873,
@@ -2292,6 +2577,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     3905_u32 => Vehicle {
         tank_id: 3905,
@@ -2300,6 +2586,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     3921_u32 => Vehicle {
         tank_id: 3921,
@@ -2308,6 +2595,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     3937_u32 => Vehicle {
         tank_id: 3937,
@@ -2316,6 +2604,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::AT,
+        is_collectible: false,
     },
     3953_u32 => Vehicle {
         tank_id: 3953,
@@ -2324,6 +2613,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     3969_u32 => Vehicle {
         tank_id: 3969,
@@ -2332,6 +2622,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     4113_u32 => Vehicle {
         tank_id: 4113,
@@ -2340,6 +2631,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     4145_u32 => Vehicle {
         tank_id: 4145,
@@ -2348,6 +2640,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     4193_u32 => Vehicle {
         tank_id: 4193,
@@ -2356,6 +2649,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::AT,
+        is_collectible: false,
     },
     4225_u32 => Vehicle {
         tank_id: 4225,
@@ -2364,6 +2658,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     4353_u32 => Vehicle {
         tank_id: 4353,
@@ -2372,6 +2667,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     4369_u32 => Vehicle {
         tank_id: 4369,
@@ -2380,6 +2676,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     4385_u32 => Vehicle {
         tank_id: 4385,
@@ -2388,6 +2685,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     4401_u32 => Vehicle {
         tank_id: 4401,
@@ -2396,6 +2694,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     4417_u32 => Vehicle {
         tank_id: 4417,
@@ -2404,6 +2703,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     4433_u32 => Vehicle {
         tank_id: 4433,
@@ -2412,6 +2712,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     4449_u32 => Vehicle {
         tank_id: 4449,
@@ -2420,6 +2721,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Japan,
         type_: TankType::Heavy,
+        is_collectible: true,
     },
     4465_u32 => Vehicle {
         tank_id: 4465,
@@ -2428,6 +2730,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     4481_u32 => Vehicle {
         tank_id: 4481,
@@ -2436,6 +2739,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     4609_u32 => Vehicle {
         tank_id: 4609,
@@ -2444,6 +2748,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     4657_u32 => Vehicle {
         tank_id: 4657,
@@ -2452,6 +2757,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     4689_u32 => Vehicle {
         tank_id: 4689,
@@ -2460,6 +2766,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     4705_u32 => Vehicle {
         tank_id: 4705,
@@ -2468,6 +2775,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Japan,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     4721_u32 => Vehicle {
         tank_id: 4721,
@@ -2476,6 +2784,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Heavy,
+        is_collectible: true,
     },
     4737_u32 => Vehicle {
         tank_id: 4737,
@@ -2484,6 +2793,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Europe,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     4881_u32 => Vehicle {
         tank_id: 4881,
@@ -2492,6 +2802,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: false,
     },
     4897_u32 => Vehicle {
         tank_id: 4897,
@@ -2500,6 +2811,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     49_u32 => Vehicle {
         tank_id: 49,
@@ -2508,6 +2820,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::China,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     4929_u32 => Vehicle {
         tank_id: 4929,
@@ -2516,6 +2829,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::Light,
+        is_collectible: false,
     },
     4945_u32 => Vehicle {
         tank_id: 4945,
@@ -2524,6 +2838,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Light,
+        is_collectible: true,
     },
     4961_u32 => Vehicle {
         tank_id: 4961,
@@ -2532,6 +2847,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::AT,
+        is_collectible: false,
     },
     4977_u32 => Vehicle {
         tank_id: 4977,
@@ -2540,6 +2856,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     4993_u32 => Vehicle {
         tank_id: 4993,
@@ -2548,6 +2865,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     51201_u32 => Vehicle {
         tank_id: 51201,
@@ -2556,6 +2874,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: true,
     },
     5121_u32 => Vehicle {
         tank_id: 5121,
@@ -2564,6 +2883,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     513_u32 => Vehicle {
         tank_id: 513,
@@ -2572,6 +2892,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     5137_u32 => Vehicle {
         tank_id: 5137,
@@ -2580,6 +2901,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     51457_u32 => Vehicle {
         tank_id: 51457,
@@ -2588,6 +2910,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     51473_u32 => Vehicle {
         tank_id: 51473,
@@ -2596,6 +2919,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     51489_u32 => Vehicle {
         tank_id: 51489,
@@ -2604,6 +2928,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     5153_u32 => Vehicle {
         tank_id: 5153,
@@ -2612,6 +2937,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     5169_u32 => Vehicle {
         tank_id: 5169,
@@ -2620,6 +2946,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     51713_u32 => Vehicle {
         tank_id: 51713,
@@ -2628,6 +2955,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     51729_u32 => Vehicle {
         tank_id: 51729,
@@ -2636,6 +2964,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: false,
     },
     51745_u32 => Vehicle {
         tank_id: 51745,
@@ -2644,6 +2973,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     51809_u32 => Vehicle {
         tank_id: 51809,
@@ -2652,6 +2982,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Japan,
         type_: TankType::Light,
+        is_collectible: false,
     },
     5185_u32 => Vehicle {
         tank_id: 5185,
@@ -2660,6 +2991,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::Light,
+        is_collectible: false,
     },
     51985_u32 => Vehicle {
         tank_id: 51985,
@@ -2668,6 +3000,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     5201_u32 => Vehicle {
         tank_id: 5201,
@@ -2676,6 +3009,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Light,
+        is_collectible: false,
     },
     52065_u32 => Vehicle {
         tank_id: 52065,
@@ -2684,6 +3018,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Japan,
         type_: TankType::AT,
+        is_collectible: true,
     },
     5217_u32 => Vehicle {
         tank_id: 5217,
@@ -2692,6 +3027,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::AT,
+        is_collectible: false,
     },
     52225_u32 => Vehicle {
         tank_id: 52225,
@@ -2700,6 +3036,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     52241_u32 => Vehicle {
         tank_id: 52241,
@@ -2708,6 +3045,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: true,
     },
     52257_u32 => Vehicle {
         tank_id: 52257,
@@ -2716,6 +3054,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     5233_u32 => Vehicle {
         tank_id: 5233,
@@ -2724,6 +3063,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Heavy,
+        is_collectible: true,
     },
     52481_u32 => Vehicle {
         tank_id: 52481,
@@ -2732,6 +3072,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: true,
     },
     5249_u32 => Vehicle {
         tank_id: 5249,
@@ -2740,6 +3081,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     52497_u32 => Vehicle {
         tank_id: 52497,
@@ -2748,6 +3090,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: false,
     },
     52513_u32 => Vehicle {
         tank_id: 52513,
@@ -2756,6 +3099,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     52561_u32 => Vehicle {
         tank_id: 52561,
@@ -2764,6 +3108,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     52737_u32 => Vehicle {
         tank_id: 52737,
@@ -2772,6 +3117,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     52769_u32 => Vehicle {
         tank_id: 52769,
@@ -2780,6 +3126,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     529_u32 => Vehicle {
         tank_id: 529,
@@ -2788,6 +3135,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     52993_u32 => Vehicle {
         tank_id: 52993,
@@ -2796,6 +3144,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     53025_u32 => Vehicle {
         tank_id: 53025,
@@ -2804,6 +3153,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     53249_u32 => Vehicle {
         tank_id: 53249,
@@ -2812,6 +3162,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     53505_u32 => Vehicle {
         tank_id: 53505,
@@ -2820,6 +3171,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     53537_u32 => Vehicle {
         tank_id: 53537,
@@ -2828,6 +3180,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     53585_u32 => Vehicle {
         tank_id: 53585,
@@ -2836,6 +3189,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     53761_u32 => Vehicle {
         tank_id: 53761,
@@ -2844,6 +3198,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     5377_u32 => Vehicle {
         tank_id: 5377,
@@ -2852,6 +3207,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     53841_u32 => Vehicle {
         tank_id: 53841,
@@ -2860,6 +3216,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     5393_u32 => Vehicle {
         tank_id: 5393,
@@ -2868,6 +3225,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: false,
     },
     5409_u32 => Vehicle {
         tank_id: 5409,
@@ -2876,6 +3234,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     54097_u32 => Vehicle {
         tank_id: 54097,
@@ -2884,6 +3243,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     5425_u32 => Vehicle {
         tank_id: 5425,
@@ -2892,6 +3252,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     54273_u32 => Vehicle {
         tank_id: 54273,
@@ -2900,6 +3261,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     54289_u32 => Vehicle {
         tank_id: 54289,
@@ -2908,6 +3270,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     54353_u32 => Vehicle {
         tank_id: 54353,
@@ -2916,6 +3279,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     5441_u32 => Vehicle {
         tank_id: 5441,
@@ -2924,6 +3288,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     545_u32 => Vehicle {
         tank_id: 545,
@@ -2932,6 +3297,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     54529_u32 => Vehicle {
         tank_id: 54529,
@@ -2940,6 +3306,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     54545_u32 => Vehicle {
         tank_id: 54545,
@@ -2948,6 +3315,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     5457_u32 => Vehicle {
         tank_id: 5457,
@@ -2956,6 +3324,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     5473_u32 => Vehicle {
         tank_id: 5473,
@@ -2964,6 +3333,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     54785_u32 => Vehicle {
         tank_id: 54785,
@@ -2972,6 +3342,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     54801_u32 => Vehicle {
         tank_id: 54801,
@@ -2980,6 +3351,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: false,
     },
     54865_u32 => Vehicle {
         tank_id: 54865,
@@ -2988,6 +3360,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Light,
+        is_collectible: false,
     },
     5489_u32 => Vehicle {
         tank_id: 5489,
@@ -2996,6 +3369,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     5505_u32 => Vehicle {
         tank_id: 5505,
@@ -3004,6 +3378,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     55057_u32 => Vehicle {
         tank_id: 55057,
@@ -3012,6 +3387,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     55073_u32 => Vehicle {
         tank_id: 55073,
@@ -3020,6 +3396,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     55297_u32 => Vehicle {
         tank_id: 55297,
@@ -3028,6 +3405,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     55313_u32 => Vehicle {
         tank_id: 55313,
@@ -3036,6 +3414,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     55889_u32 => Vehicle {
         tank_id: 55889,
@@ -3044,6 +3423,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     56097_u32 => Vehicle {
         tank_id: 56097,
@@ -3052,6 +3432,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     56577_u32 => Vehicle {
         tank_id: 56577,
@@ -3060,6 +3441,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     56609_u32 => Vehicle {
         tank_id: 56609,
@@ -3068,6 +3450,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     5665_u32 => Vehicle {
         tank_id: 5665,
@@ -3076,6 +3459,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     5681_u32 => Vehicle {
         tank_id: 5681,
@@ -3084,6 +3468,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::China,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     57105_u32 => Vehicle {
         tank_id: 57105,
@@ -3092,6 +3477,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     5713_u32 => Vehicle {
         tank_id: 5713,
@@ -3100,6 +3486,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     5729_u32 => Vehicle {
         tank_id: 5729,
@@ -3108,6 +3495,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     57361_u32 => Vehicle {
         tank_id: 57361,
@@ -3116,6 +3504,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     5745_u32 => Vehicle {
         tank_id: 5745,
@@ -3124,6 +3513,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     5761_u32 => Vehicle {
         tank_id: 5761,
@@ -3132,6 +3522,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     57617_u32 => Vehicle {
         tank_id: 57617,
@@ -3140,6 +3531,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     577_u32 => Vehicle {
         tank_id: 577,
@@ -3148,6 +3540,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::AT,
+        is_collectible: false,
     },
     58641_u32 => Vehicle {
         tank_id: 58641,
@@ -3156,6 +3549,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     58881_u32 => Vehicle {
         tank_id: 58881,
@@ -3164,6 +3558,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     5889_u32 => Vehicle {
         tank_id: 5889,
@@ -3172,6 +3567,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     59137_u32 => Vehicle {
         tank_id: 59137,
@@ -3180,6 +3576,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     5921_u32 => Vehicle {
         tank_id: 5921,
@@ -3188,6 +3585,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     593_u32 => Vehicle {
         tank_id: 593,
@@ -3196,6 +3594,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     5937_u32 => Vehicle {
         tank_id: 5937,
@@ -3204,6 +3603,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::China,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     5953_u32 => Vehicle {
         tank_id: 5953,
@@ -3212,6 +3612,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     59649_u32 => Vehicle {
         tank_id: 59649,
@@ -3220,6 +3621,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     59665_u32 => Vehicle {
         tank_id: 59665,
@@ -3228,6 +3630,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     5969_u32 => Vehicle {
         tank_id: 5969,
@@ -3236,6 +3639,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     5985_u32 => Vehicle {
         tank_id: 5985,
@@ -3244,6 +3648,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     59905_u32 => Vehicle {
         tank_id: 59905,
@@ -3252,6 +3657,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     6001_u32 => Vehicle {
         tank_id: 6001,
@@ -3260,6 +3666,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     60161_u32 => Vehicle {
         tank_id: 60161,
@@ -3268,6 +3675,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     6017_u32 => Vehicle {
         tank_id: 6017,
@@ -3276,6 +3684,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     60177_u32 => Vehicle {
         tank_id: 60177,
@@ -3284,6 +3693,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     60417_u32 => Vehicle {
         tank_id: 60417,
@@ -3292,6 +3702,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     609_u32 => Vehicle {
         tank_id: 609,
@@ -3300,6 +3711,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::Light,
+        is_collectible: false,
     },
     60929_u32 => Vehicle {
         tank_id: 60929,
@@ -3308,6 +3720,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: true,
     },
     6145_u32 => Vehicle {
         tank_id: 6145,
@@ -3316,6 +3729,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     6161_u32 => Vehicle {
         tank_id: 6161,
@@ -3324,6 +3738,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: true,
     },
     6177_u32 => Vehicle {
         tank_id: 6177,
@@ -3332,6 +3747,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     6193_u32 => Vehicle {
         tank_id: 6193,
@@ -3340,6 +3756,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::China,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     6209_u32 => Vehicle {
         tank_id: 6209,
@@ -3348,6 +3765,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     6225_u32 => Vehicle {
         tank_id: 6225,
@@ -3356,6 +3774,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     6241_u32 => Vehicle {
         tank_id: 6241,
@@ -3364,6 +3783,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     625_u32 => Vehicle {
         tank_id: 625,
@@ -3372,6 +3792,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     6257_u32 => Vehicle {
         tank_id: 6257,
@@ -3380,6 +3801,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     6273_u32 => Vehicle {
         tank_id: 6273,
@@ -3388,6 +3810,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     62737_u32 => Vehicle {
         tank_id: 62737,
@@ -3396,6 +3819,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: false,
     },
     62977_u32 => Vehicle {
         tank_id: 62977,
@@ -3404,6 +3828,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     62993_u32 => Vehicle {
         tank_id: 62993,
@@ -3412,6 +3837,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     63553_u32 => Vehicle {
         tank_id: 63553,
@@ -3420,6 +3846,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     63585_u32 => Vehicle {
         tank_id: 63585,
@@ -3428,6 +3855,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Japan,
         type_: TankType::Heavy,
+        is_collectible: true,
     },
     63601_u32 => Vehicle {
         tank_id: 63601,
@@ -3436,6 +3864,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     63841_u32 => Vehicle {
         tank_id: 63841,
@@ -3444,6 +3873,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Japan,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     64001_u32 => Vehicle {
         tank_id: 64001,
@@ -3452,6 +3882,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     6401_u32 => Vehicle {
         tank_id: 6401,
@@ -3460,6 +3891,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     64017_u32 => Vehicle {
         tank_id: 64017,
@@ -3468,6 +3900,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     64065_u32 => Vehicle {
         tank_id: 64065,
@@ -3476,6 +3909,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     64081_u32 => Vehicle {
         tank_id: 64081,
@@ -3484,6 +3918,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     641_u32 => Vehicle {
         tank_id: 641,
@@ -3492,6 +3927,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     6417_u32 => Vehicle {
         tank_id: 6417,
@@ -3500,6 +3936,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     64257_u32 => Vehicle {
         tank_id: 64257,
@@ -3508,6 +3945,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     64273_u32 => Vehicle {
         tank_id: 64273,
@@ -3516,6 +3954,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     6433_u32 => Vehicle {
         tank_id: 6433,
@@ -3524,6 +3963,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     64337_u32 => Vehicle {
         tank_id: 64337,
@@ -3532,6 +3972,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     6449_u32 => Vehicle {
         tank_id: 6449,
@@ -3540,6 +3981,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::AT,
+        is_collectible: false,
     },
     64529_u32 => Vehicle {
         tank_id: 64529,
@@ -3548,6 +3990,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     64561_u32 => Vehicle {
         tank_id: 64561,
@@ -3556,6 +3999,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::China,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     64593_u32 => Vehicle {
         tank_id: 64593,
@@ -3564,6 +4008,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     6465_u32 => Vehicle {
         tank_id: 6465,
@@ -3572,6 +4017,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::Light,
+        is_collectible: false,
     },
     64769_u32 => Vehicle {
         tank_id: 64769,
@@ -3580,6 +4026,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     64801_u32 => Vehicle {
         tank_id: 64801,
@@ -3588,6 +4035,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     6481_u32 => Vehicle {
         tank_id: 6481,
@@ -3596,6 +4044,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Light,
+        is_collectible: true,
     },
     64849_u32 => Vehicle {
         tank_id: 64849,
@@ -3604,6 +4053,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     6497_u32 => Vehicle {
         tank_id: 6497,
@@ -3612,6 +4062,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     6529_u32 => Vehicle {
         tank_id: 6529,
@@ -3620,6 +4071,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     65329_u32 => Vehicle {
         tank_id: 65329,
@@ -3628,6 +4080,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::China,
         type_: TankType::Light,
+        is_collectible: false,
     },
     65377_u32 => Vehicle {
         tank_id: 65377,
@@ -3636,6 +4089,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Japan,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     6657_u32 => Vehicle {
         tank_id: 6657,
@@ -3644,6 +4098,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     6673_u32 => Vehicle {
         tank_id: 6673,
@@ -3652,6 +4107,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     6689_u32 => Vehicle {
         tank_id: 6689,
@@ -3660,6 +4116,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     6705_u32 => Vehicle {
         tank_id: 6705,
@@ -3668,6 +4125,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::Light,
+        is_collectible: false,
     },
     6721_u32 => Vehicle {
         tank_id: 6721,
@@ -3676,6 +4134,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     6753_u32 => Vehicle {
         tank_id: 6753,
@@ -3684,6 +4143,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     6785_u32 => Vehicle {
         tank_id: 6785,
@@ -3692,6 +4152,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     6913_u32 => Vehicle {
         tank_id: 6913,
@@ -3700,6 +4161,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     6929_u32 => Vehicle {
         tank_id: 6929,
@@ -3708,6 +4170,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     6945_u32 => Vehicle {
         tank_id: 6945,
@@ -3716,6 +4179,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     6961_u32 => Vehicle {
         tank_id: 6961,
@@ -3724,6 +4188,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::China,
         type_: TankType::AT,
+        is_collectible: false,
     },
     6977_u32 => Vehicle {
         tank_id: 6977,
@@ -3732,6 +4197,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     6993_u32 => Vehicle {
         tank_id: 6993,
@@ -3740,6 +4206,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Light,
+        is_collectible: false,
     },
     7009_u32 => Vehicle {
         tank_id: 7009,
@@ -3748,6 +4215,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Japan,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     7025_u32 => Vehicle {
         tank_id: 7025,
@@ -3756,6 +4224,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     7041_u32 => Vehicle {
         tank_id: 7041,
@@ -3764,6 +4233,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     7169_u32 => Vehicle {
         tank_id: 7169,
@@ -3772,6 +4242,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     7185_u32 => Vehicle {
         tank_id: 7185,
@@ -3780,6 +4251,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     7201_u32 => Vehicle {
         tank_id: 7201,
@@ -3788,6 +4260,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     7217_u32 => Vehicle {
         tank_id: 7217,
@@ -3796,6 +4269,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::China,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     7249_u32 => Vehicle {
         tank_id: 7249,
@@ -3804,6 +4278,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     7281_u32 => Vehicle {
         tank_id: 7281,
@@ -3812,6 +4287,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     7297_u32 => Vehicle {
         tank_id: 7297,
@@ -3820,6 +4296,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     7425_u32 => Vehicle {
         tank_id: 7425,
@@ -3828,6 +4305,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     7441_u32 => Vehicle {
         tank_id: 7441,
@@ -3836,6 +4314,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     7473_u32 => Vehicle {
         tank_id: 7473,
@@ -3844,6 +4323,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::AT,
+        is_collectible: false,
     },
     7505_u32 => Vehicle {
         tank_id: 7505,
@@ -3852,6 +4332,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     7537_u32 => Vehicle {
         tank_id: 7537,
@@ -3860,6 +4341,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::AT,
+        is_collectible: true,
     },
     7553_u32 => Vehicle {
         tank_id: 7553,
@@ -3868,6 +4350,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     769_u32 => Vehicle {
         tank_id: 769,
@@ -3876,6 +4359,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Light,
+        is_collectible: false,
     },
     7697_u32 => Vehicle {
         tank_id: 7697,
@@ -3884,6 +4368,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     7713_u32 => Vehicle {
         tank_id: 7713,
@@ -3892,6 +4377,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: true,
     },
     7729_u32 => Vehicle {
         tank_id: 7729,
@@ -3900,6 +4386,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::AT,
+        is_collectible: false,
     },
     7745_u32 => Vehicle {
         tank_id: 7745,
@@ -3908,6 +4395,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::AT,
+        is_collectible: false,
     },
     7761_u32 => Vehicle {
         tank_id: 7761,
@@ -3916,6 +4404,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     7793_u32 => Vehicle {
         tank_id: 7793,
@@ -3924,6 +4413,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     7809_u32 => Vehicle {
         tank_id: 7809,
@@ -3932,6 +4422,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     785_u32 => Vehicle {
         tank_id: 785,
@@ -3940,6 +4431,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: false,
     },
     7937_u32 => Vehicle {
         tank_id: 7937,
@@ -3948,6 +4440,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     7953_u32 => Vehicle {
         tank_id: 7953,
@@ -3956,6 +4449,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::AT,
+        is_collectible: false,
     },
     7985_u32 => Vehicle {
         tank_id: 7985,
@@ -3964,6 +4458,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::AT,
+        is_collectible: false,
     },
     8001_u32 => Vehicle {
         tank_id: 8001,
@@ -3972,6 +4467,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     801_u32 => Vehicle {
         tank_id: 801,
@@ -3980,6 +4476,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     8017_u32 => Vehicle {
         tank_id: 8017,
@@ -3988,6 +4485,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     8049_u32 => Vehicle {
         tank_id: 8049,
@@ -3996,6 +4494,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     8065_u32 => Vehicle {
         tank_id: 8065,
@@ -4004,6 +4503,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     81_u32 => Vehicle {
         tank_id: 81,
@@ -4012,6 +4512,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     817_u32 => Vehicle {
         tank_id: 817,
@@ -4020,6 +4521,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::China,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     8193_u32 => Vehicle {
         tank_id: 8193,
@@ -4028,6 +4530,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
     },
     8209_u32 => Vehicle {
         tank_id: 8209,
@@ -4036,6 +4539,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Germany,
         type_: TankType::Light,
+        is_collectible: true,
     },
     8225_u32 => Vehicle {
         tank_id: 8225,
@@ -4044,6 +4548,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     8241_u32 => Vehicle {
         tank_id: 8241,
@@ -4052,6 +4557,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::China,
         type_: TankType::AT,
+        is_collectible: false,
     },
     8257_u32 => Vehicle {
         tank_id: 8257,
@@ -4060,6 +4566,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::AT,
+        is_collectible: true,
     },
     8273_u32 => Vehicle {
         tank_id: 8273,
@@ -4068,6 +4575,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     8305_u32 => Vehicle {
         tank_id: 8305,
@@ -4076,6 +4584,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Heavy,
+        is_collectible: true,
     },
     8321_u32 => Vehicle {
         tank_id: 8321,
@@ -4084,6 +4593,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     8465_u32 => Vehicle {
         tank_id: 8465,
@@ -4092,6 +4602,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     849_u32 => Vehicle {
         tank_id: 849,
@@ -4100,6 +4611,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     8497_u32 => Vehicle {
         tank_id: 8497,
@@ -4108,6 +4620,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::China,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     8513_u32 => Vehicle {
         tank_id: 8513,
@@ -4116,6 +4629,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::France,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     8529_u32 => Vehicle {
         tank_id: 8529,
@@ -4124,6 +4638,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     8561_u32 => Vehicle {
         tank_id: 8561,
@@ -4132,6 +4647,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Light,
+        is_collectible: true,
     },
     8577_u32 => Vehicle {
         tank_id: 8577,
@@ -4140,6 +4656,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     865_u32 => Vehicle {
         tank_id: 865,
@@ -4148,6 +4665,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Japan,
         type_: TankType::Light,
+        is_collectible: false,
     },
     8737_u32 => Vehicle {
         tank_id: 8737,
@@ -4156,6 +4674,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     8753_u32 => Vehicle {
         tank_id: 8753,
@@ -4164,6 +4683,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::China,
         type_: TankType::Light,
+        is_collectible: false,
     },
     8785_u32 => Vehicle {
         tank_id: 8785,
@@ -4172,6 +4692,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     881_u32 => Vehicle {
         tank_id: 881,
@@ -4180,6 +4701,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     8817_u32 => Vehicle {
         tank_id: 8817,
@@ -4188,6 +4710,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Light,
+        is_collectible: true,
     },
     8833_u32 => Vehicle {
         tank_id: 8833,
@@ -4196,6 +4719,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     8961_u32 => Vehicle {
         tank_id: 8961,
@@ -4204,6 +4728,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     897_u32 => Vehicle {
         tank_id: 897,
@@ -4212,6 +4737,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     8993_u32 => Vehicle {
         tank_id: 8993,
@@ -4220,6 +4746,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     9009_u32 => Vehicle {
         tank_id: 9009,
@@ -4228,6 +4755,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::China,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     9041_u32 => Vehicle {
         tank_id: 9041,
@@ -4236,6 +4764,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     9073_u32 => Vehicle {
         tank_id: 9073,
@@ -4244,6 +4773,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     9089_u32 => Vehicle {
         tank_id: 9089,
@@ -4252,6 +4782,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Europe,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     9217_u32 => Vehicle {
         tank_id: 9217,
@@ -4260,6 +4791,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Ussr,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     9249_u32 => Vehicle {
         tank_id: 9249,
@@ -4268,6 +4800,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::AT,
+        is_collectible: false,
     },
     9297_u32 => Vehicle {
         tank_id: 9297,
@@ -4276,6 +4809,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     9329_u32 => Vehicle {
         tank_id: 9329,
@@ -4284,6 +4818,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     9345_u32 => Vehicle {
         tank_id: 9345,
@@ -4292,6 +4827,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: true,
     },
     9489_u32 => Vehicle {
         tank_id: 9489,
@@ -4300,6 +4836,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     9505_u32 => Vehicle {
         tank_id: 9505,
@@ -4308,6 +4845,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     9521_u32 => Vehicle {
         tank_id: 9521,
@@ -4316,6 +4854,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::China,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     9553_u32 => Vehicle {
         tank_id: 9553,
@@ -4324,6 +4863,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     9601_u32 => Vehicle {
         tank_id: 9601,
@@ -4332,6 +4872,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Europe,
         type_: TankType::Medium,
+        is_collectible: false,
     },
     9745_u32 => Vehicle {
         tank_id: 9745,
@@ -4340,6 +4881,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Germany,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     9761_u32 => Vehicle {
         tank_id: 9761,
@@ -4348,6 +4890,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Usa,
         type_: TankType::Light,
+        is_collectible: false,
     },
     9777_u32 => Vehicle {
         tank_id: 9777,
@@ -4356,6 +4899,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::China,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     9793_u32 => Vehicle {
         tank_id: 9793,
@@ -4364,6 +4908,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::France,
         type_: TankType::AT,
+        is_collectible: false,
     },
     9809_u32 => Vehicle {
         tank_id: 9809,
@@ -4372,6 +4917,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Uk,
         type_: TankType::AT,
+        is_collectible: false,
     },
     9841_u32 => Vehicle {
         tank_id: 9841,
@@ -4380,6 +4926,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Other,
         type_: TankType::Light,
+        is_collectible: false,
     },
     9857_u32 => Vehicle {
         tank_id: 9857,
@@ -4388,6 +4935,7 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: true,
         nation: Nation::Europe,
         type_: TankType::Heavy,
+        is_collectible: false,
     },
     9985_u32 => Vehicle {
         tank_id: 9985,
@@ -4396,5 +4944,11 @@ pub static GENERATED: phf::Map<u32, Vehicle> = phf::phf_map! {
         is_premium: false,
         nation: Nation::Ussr,
         type_: TankType::AT,
+        is_collectible: false,
+    },
+};
+
+pub static LOCALIZED_NAMES: phf::Map<&'static str, phf::Map<u32, &'static str>> = phf::phf_map! {
+    "ru" => phf::phf_map! {
     },
 };
@@ -0,0 +1,154 @@
+//! Local tankopedia patch: manual fixes over the API response,
+//! and the vehicles which the API doesn't know about yet.
+//!
+//! The patch is a JSON object keyed by the tank ID, where every field is optional:
+//!
+//! ```json
+//! {"24609": {"tier": 8, "is_premium": true}}
+//! ```
+//!
+//! A vehicle missing from the tankopedia must be patched with all the fields.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::prelude::*;
+use crate::wargaming::models::{Nation, TankId, TankType, Tier, Vehicle};
+use crate::wargaming::Tankopedia;
+
+pub type Patch = BTreeMap<TankId, VehiclePatch>;
+
+#[derive(Deserialize, Default, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct VehiclePatch {
+    pub name: Option<String>,
+    pub tier: Option<Tier>,
+    pub is_premium: Option<bool>,
+    pub nation: Option<Nation>,
+
+    #[serde(rename = "type")]
+    pub type_: Option<TankType>,
+}
+
+/// Patched field, which the tankopedia has had a different value of.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Conflict {
+    pub tank_id: TankId,
+    pub field: &'static str,
+    pub original: String,
+    pub patched: String,
+}
+
+pub fn read(path: &Path) -> Result<Patch> {
+    let patch = fs::read_to_string(path)
+        .with_context(|| format!("failed to read the patch from `{}`", path.display()))?;
+    serde_json::from_str(&patch)
+        .with_context(|| format!("failed to parse the patch from `{}`", path.display()))
+}
+
+/// Applies the patch and reports the fields, which the patch has changed.
+///
+/// The fields, which already have the patched values, are the stale patch entries,
+/// they are only logged.
+pub fn apply(tankopedia: &mut Tankopedia, patch: Patch) -> Result<Vec<Conflict>> {
+    let mut conflicts = Vec::new();
+    for (tank_id, vehicle_patch) in patch {
+        let Some(vehicle) = tankopedia.get_mut(&tank_id.to_string()) else {
+            let vehicle = vehicle_patch.into_vehicle(tank_id)?;
+            info!(tank_id, name = ?vehicle.name, "inserted the patched vehicle");
+            tankopedia.insert(tank_id.to_string(), vehicle);
+            continue;
+        };
+        if let Some(patched) = vehicle_patch.name {
+            let mut name = vehicle.name.to_string();
+            patch_field(&mut conflicts, tank_id, "name", &mut name, Some(patched));
+            vehicle.name = Cow::Owned(name);
+        }
+        patch_field(&mut conflicts, tank_id, "tier", &mut vehicle.tier, vehicle_patch.tier);
+        let (is_premium, nation) = (vehicle_patch.is_premium, vehicle_patch.nation);
+        patch_field(&mut conflicts, tank_id, "is_premium", &mut vehicle.is_premium, is_premium);
+        patch_field(&mut conflicts, tank_id, "nation", &mut vehicle.nation, nation);
+        patch_field(&mut conflicts, tank_id, "type", &mut vehicle.type_, vehicle_patch.type_);
+    }
+    Ok(conflicts)
+}
+
+fn patch_field<T: PartialEq + Debug>(
+    conflicts: &mut Vec<Conflict>,
+    tank_id: TankId,
+    field: &'static str,
+    original: &mut T,
+    patched: Option<T>,
+) {
+    let Some(patched) = patched else { return };
+    if *original == patched {
+        info!(tank_id, field, "the patched value is already there, the entry may be removed");
+        return;
+    }
+    conflicts.push(Conflict {
+        tank_id,
+        field,
+        original: format!("{original:?}"),
+        patched: format!("{patched:?}"),
+    });
+    *original = patched;
+}
+
+impl VehiclePatch {
+    fn into_vehicle(self, tank_id: TankId) -> Result<Vehicle> {
+        let missing = || anyhow!("#{tank_id} is missing from the tankopedia, patch all the fields");
+        Ok(Vehicle {
+            tank_id,
+            name: Cow::Owned(self.name.ok_or_else(missing)?),
+            tier: self.tier.ok_or_else(missing)?,
+            is_premium: self.is_premium.ok_or_else(missing)?,
+            nation: self.nation.ok_or_else(missing)?,
+            type_: self.type_.ok_or_else(missing)?,
+            is_collectible: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_ok() -> Result {
+        let mut tankopedia = Tankopedia::new();
+        tankopedia.insert("1".to_string(), Vehicle { tier: 5, ..Vehicle::new_hardcoded(1) });
+        let patch: Patch = serde_json::from_str(
+            // language=JSON
+            r#"{
+                "1": {"tier": 6, "is_premium": false},
+                "2": {"name": "Secret", "tier": 8, "is_premium": true, "nation": "ussr", "type": "heavyTank"}
+            }"#,
+        )?;
+
+        let conflicts = apply(&mut tankopedia, patch)?;
+        assert_eq!(
+            conflicts,
+            [Conflict {
+                tank_id: 1,
+                field: "tier",
+                original: "5".to_string(),
+                patched: "6".to_string(),
+            }],
+        );
+        assert_eq!(tankopedia["1"].tier, 6);
+        assert_eq!(tankopedia["2"].name, "Secret");
+        assert_eq!(tankopedia["2"].type_, TankType::Heavy);
+        Ok(())
+    }
+
+    #[test]
+    fn apply_incomplete_new_vehicle_fails() {
+        let patch = Patch::from([(2, VehiclePatch { tier: Some(8), ..Default::default() })]);
+        assert!(apply(&mut Tankopedia::new(), patch).is_err());
+    }
+}
@@ -0,0 +1,316 @@
+//! Train set export and import for experimenting with the models outside the crate
+//! and replaying historical datasets into a fresh database.
+
+use std::num::NonZeroU32;
+
+use async_compression::tokio::bufread::GzipDecoder;
+use async_compression::tokio::write::GzipEncoder;
+use futures::TryStreamExt;
+use serde::Serialize;
+use tokio::fs::File;
+use tokio::io::{
+    stdin, stdout, AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader,
+    BufWriter,
+};
+
+use crate::database::mongodb::traits::InsertManyNew;
+use crate::opts::{DumpTrainSetOpts, ImportTrainSetOpts};
+use crate::prelude::*;
+
+/// Streams the tank snapshots within the specified time range as NDJSON.
+#[instrument(skip_all)]
+pub async fn dump(opts: DumpTrainSetOpts) -> Result {
+    sentry::configure_scope(|scope| scope.set_tag("app", "dump-train-set"));
+
+    let until = opts.until.unwrap_or_else(now);
+    info!(
+        realm = ?opts.realm,
+        since = ?opts.since,
+        last_battles = opts.last_battles,
+        test_fraction = opts.test_fraction,
+        %until,
+        gzip = opts.gzip,
+        "dumping…",
+    );
+
+    let db = database::mongodb::open(&opts.internal.mongodb_uri).await?;
+    let mut writer = open_writer(&opts).await?;
+    let mut snapshots = match opts.since {
+        Some(since) => {
+            database::TankSnapshot::retrieve_range(&db, opts.realm, since, until).await?
+        }
+        None => database::TankSnapshot::retrieve_grouped_until(&db, opts.realm, until).await?,
+    };
+    let mut window = opts.last_battles.map(BattleWindow::new);
+    let split = opts
+        .test_fraction
+        .map(|fraction| TestSplit::new(fraction, opts.split_seed));
+
+    let mut n_items = 0_usize;
+    while let Some(snapshot) = snapshots.try_next().await? {
+        if window.as_mut().is_some_and(|window| !window.contains(&snapshot)) {
+            continue;
+        }
+        let mut line = match &split {
+            Some(split) => serde_json::to_vec(&SplitItem {
+                is_test: split.is_test(snapshot.account_id, snapshot.tank_id),
+                snapshot: &snapshot,
+            })?,
+            None => serde_json::to_vec(&snapshot)?,
+        };
+        line.push(b'\n');
+        writer.write_all(&line).await?;
+        n_items += 1;
+    }
+    writer.shutdown().await?;
+
+    info!(n_items, "dumped");
+    Ok(())
+}
+
+/// Keeps the snapshots covering the most recent battles of each account's tank.
+///
+/// Expects the snapshots grouped by account and tank, the latest snapshot first.
+/// The last kept snapshot of a tank is the first one which is at least the specified
+/// number of battles behind the latest one, so that the window's delta could be calculated.
+struct BattleWindow {
+    n_battles: NonZeroU32,
+
+    /// Account and tank of the current group along with the latest number of battles.
+    latest: Option<(wargaming::AccountId, wargaming::TankId, u32)>,
+
+    /// The window is over for the current group.
+    is_exhausted: bool,
+}
+
+impl BattleWindow {
+    const fn new(n_battles: NonZeroU32) -> Self {
+        Self {
+            n_battles,
+            latest: None,
+            is_exhausted: false,
+        }
+    }
+
+    const fn contains(&mut self, snapshot: &database::TankSnapshot) -> bool {
+        let n_battles = match self.latest {
+            Some((account_id, tank_id, n_battles))
+                if account_id == snapshot.account_id && tank_id == snapshot.tank_id =>
+            {
+                n_battles
+            }
+            _ => {
+                self.latest =
+                    Some((snapshot.account_id, snapshot.tank_id, snapshot.stats.n_battles));
+                self.is_exhausted = false;
+                snapshot.stats.n_battles
+            }
+        };
+        if self.is_exhausted {
+            return false;
+        }
+        self.is_exhausted =
+            n_battles.saturating_sub(snapshot.stats.n_battles) >= self.n_battles.get();
+        true
+    }
+}
+
+/// Deterministic test split by account and tank, so that a tank's history never leaks
+/// from the test set into the train set.
+struct TestSplit {
+    /// The tanks with the hash below the threshold go to the test set.
+    threshold: u64,
+
+    seed: u64,
+}
+
+impl TestSplit {
+    fn new(fraction: f64, seed: u64) -> Self {
+        Self {
+            threshold: (fraction * u64::MAX as f64) as u64,
+            seed,
+        }
+    }
+
+    fn is_test(&self, account_id: wargaming::AccountId, tank_id: wargaming::TankId) -> bool {
+        let key = (u64::from(account_id) << 32) | u64::from(tank_id);
+        Self::mix(key ^ Self::mix(self.seed)) < self.threshold
+    }
+
+    /// SplitMix64 finalizer, unlike [`std::hash::Hasher`]s it's stable across the builds.
+    const fn mix(mut value: u64) -> u64 {
+        value = value.wrapping_add(0x9E3779B97F4A7C15);
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D049BB133111EB);
+        value ^ (value >> 31)
+    }
+}
+
+/// Dumped snapshot along with its split, the import ignores the extra field.
+#[derive(Serialize)]
+struct SplitItem<'a> {
+    #[serde(flatten)]
+    snapshot: &'a database::TankSnapshot,
+
+    is_test: bool,
+}
+
+/// Validates and inserts the NDJSON tank snapshots.
+/// The invalid lines are logged and skipped, the blank lines are ignored,
+/// and so are the snapshots, which are already in the database.
+#[instrument(skip_all)]
+pub async fn import(opts: ImportTrainSetOpts) -> Result {
+    sentry::configure_scope(|scope| scope.set_tag("app", "import-train-set"));
+    info!(gzip = opts.gzip, batch_size = opts.batch_size, "importing…");
+
+    let db = database::mongodb::open(&opts.internal.mongodb_uri).await?;
+    let mut lines = open_reader(&opts).await?.lines();
+
+    let mut batch = Vec::with_capacity(opts.batch_size);
+    let (mut line_number, mut n_items, mut n_skipped, mut n_inserted) = (0, 0, 0, 0);
+    while let Some(line) = lines.next_line().await? {
+        line_number += 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        n_items += 1;
+        let snapshot = match parse_line(&line) {
+            Ok(snapshot) => snapshot,
+            Err(error) => {
+                warn!(line_number, "skipping the invalid line: {:#}", error);
+                n_skipped += 1;
+                continue;
+            }
+        };
+        batch.push(snapshot);
+        if batch.len() == opts.batch_size {
+            n_inserted += database::TankSnapshot::insert_many_new(&db, &batch).await?;
+            batch.clear();
+        }
+    }
+    n_inserted += database::TankSnapshot::insert_many_new(&db, &batch).await?;
+
+    let n_existing = n_items - n_skipped - n_inserted;
+    info!(n_items, n_skipped, n_inserted, n_existing, "imported");
+    Ok(())
+}
+
+async fn open_writer(opts: &DumpTrainSetOpts) -> Result<Box<dyn AsyncWrite + Send + Unpin>> {
+    let writer: Box<dyn AsyncWrite + Send + Unpin> = match &opts.output {
+        Some(path) => Box::new(
+            File::create(path)
+                .await
+                .with_context(|| format!("failed to create `{}`", path.display()))?,
+        ),
+        None => Box::new(stdout()),
+    };
+    let writer = BufWriter::new(writer);
+    if opts.gzip {
+        Ok(Box::new(GzipEncoder::new(writer)))
+    } else {
+        Ok(Box::new(writer))
+    }
+}
+
+async fn open_reader(opts: &ImportTrainSetOpts) -> Result<Box<dyn AsyncBufRead + Send + Unpin>> {
+    let reader: Box<dyn AsyncRead + Send + Unpin> = match &opts.input {
+        Some(path) => Box::new(
+            File::open(path)
+                .await
+                .with_context(|| format!("failed to open `{}`", path.display()))?,
+        ),
+        None => Box::new(stdin()),
+    };
+    let reader = BufReader::new(reader);
+    if opts.gzip {
+        Ok(Box::new(BufReader::new(GzipDecoder::new(reader))))
+    } else {
+        Ok(Box::new(reader))
+    }
+}
+
+/// Parses and validates the tank snapshot.
+fn parse_line(line: &str) -> Result<database::TankSnapshot> {
+    let snapshot: database::TankSnapshot = serde_json::from_str(line)?;
+    let stats = &snapshot.stats;
+    if stats.n_wins > stats.n_battles {
+        bail!("more wins than battles");
+    }
+    if stats.n_survived_battles > stats.n_battles {
+        bail!("more survived battles than battles");
+    }
+    if stats.n_win_and_survived > stats.n_wins.min(stats.n_survived_battles) {
+        bail!("more wins and survivals than wins or survivals");
+    }
+    if stats.n_hits > stats.n_shots {
+        bail!("more hits than shots");
+    }
+    if snapshot.battle_life_time < Duration::zero() {
+        bail!("negative battle life time");
+    }
+    Ok(snapshot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_line_ok() -> Result {
+        let snapshot = parse_line(
+            // language=JSON
+            r#"{"rlm":"eu","lbts":{"$date":{"$numberLong":"1660000000000"}},"aid":5589968,"tid":1,"life":3600,"nb":10,"nw":6,"nsb":4,"nws":3,"shts":100,"hits":80}"#,
+        )?;
+        assert_eq!(snapshot.account_id, 5589968);
+        assert_eq!(snapshot.stats.n_battles, 10);
+        Ok(())
+    }
+
+    fn snapshot(account_id: wargaming::AccountId, n_battles: u32) -> database::TankSnapshot {
+        database::TankSnapshot {
+            realm: wargaming::Realm::Europe,
+            last_battle_time: Utc.timestamp_opt(1660000000, 0).unwrap(),
+            account_id,
+            tank_id: 1,
+            battle_life_time: Duration::zero(),
+            stats: database::RandomStatsSnapshot {
+                n_battles,
+                ..Default::default()
+            },
+            n_masteries: 0,
+        }
+    }
+
+    #[test]
+    fn battle_window_ok() {
+        let mut window = BattleWindow::new(NonZeroU32::new(10).unwrap());
+        assert!(window.contains(&snapshot(1, 100)));
+        assert!(window.contains(&snapshot(1, 95)));
+        assert!(window.contains(&snapshot(1, 88)));
+        assert!(!window.contains(&snapshot(1, 80)));
+        assert!(window.contains(&snapshot(2, 5)));
+        assert!(window.contains(&snapshot(2, 1)));
+    }
+
+    #[test]
+    fn test_split_ok() {
+        let split = TestSplit::new(0.2, 42);
+        let n_test = (1..=10000).filter(|account_id| split.is_test(*account_id, 1)).count();
+        assert!((1800..=2200).contains(&n_test), "n_test: {n_test}");
+
+        let reseeded = TestSplit::new(0.2, 43);
+        assert!((1..=100).any(|account_id| {
+            split.is_test(account_id, 1) != reseeded.is_test(account_id, 1)
+        }));
+        assert_eq!(split.is_test(123, 1), TestSplit::new(0.2, 42).is_test(123, 1));
+    }
+
+    #[test]
+    fn parse_line_more_wins_than_battles_error() {
+        assert!(parse_line(
+            // language=JSON
+            r#"{"rlm":"eu","lbts":{"$date":{"$numberLong":"1660000000000"}},"aid":5589968,"tid":1,"life":3600,"nb":1,"nw":2}"#,
+        )
+        .is_err());
+    }
+}
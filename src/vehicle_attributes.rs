@@ -0,0 +1,35 @@
+//! Management of the vehicle attributes, which the Wargaming.net API does not expose.
+
+use crate::database::mongodb::traits::Upsert;
+use crate::database::{AuditLogEntry, TankopediaSnapshot, VehicleAttributes};
+use crate::opts::{VehicleAttributesAction, VehicleAttributesOpts};
+use crate::prelude::*;
+
+#[instrument(skip_all)]
+pub async fn run(opts: VehicleAttributesOpts) -> Result {
+    sentry::configure_scope(|scope| scope.set_tag("app", "vehicle-attributes"));
+    let db = database::mongodb::open(&opts.internal.mongodb_uri).await?;
+    match opts.action {
+        VehicleAttributesAction::Set {
+            tank_id,
+            collectible,
+        } => {
+            let attributes = VehicleAttributes {
+                tank_id,
+                is_collectible: collectible,
+            };
+            attributes.upsert(&db).await?;
+            AuditLogEntry::cli("vehicle-attributes.set", format!("{attributes:?}"))
+                .insert(&db)
+                .await?;
+            let n_snapshots = TankopediaSnapshot::apply_attributes(&db, &attributes).await?;
+            info!(?attributes, n_snapshots, "set");
+        }
+        VehicleAttributesAction::List => {
+            for attributes in VehicleAttributes::retrieve_all(&db).await? {
+                println!("{}\tcollectible={}", attributes.tank_id, attributes.is_collectible);
+            }
+        }
+    }
+    Ok(())
+}
@@ -3,36 +3,57 @@
 use std::collections::{BTreeMap, HashMap};
 use std::num::NonZeroU32;
 use std::sync::atomic::{AtomicU32, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
-use anyhow::{bail, Context};
+use anyhow::Context;
+pub use error::{ApiError, ApiErrorCode};
+pub use lenient::Lenient;
 use governor::clock::DefaultClock;
 use governor::state::{InMemoryState, NotKeyed};
 use governor::{Jitter, Quota, RateLimiter};
 use itertools::Itertools;
 pub use models::*;
+pub use timeouts::ApiTimeouts;
 use reqwest::header::HeaderValue;
 use reqwest::{header, Url};
 use serde::de::DeserializeOwned;
 use tokio::time::sleep;
-use tracing::{debug, instrument, warn};
+use tracing::{instrument, warn};
 
+use crate::helpers::backoff::Backoff;
+use crate::helpers::histogram::Histogram;
 use crate::helpers::tracing::format_elapsed;
 use crate::prelude::*;
 use crate::wargaming::response::Response;
+use crate::wargaming::usage::ApiUsage;
 
 pub mod cache;
+pub mod error;
+pub mod lenient;
 pub mod models;
 pub mod response;
+pub mod timeouts;
+pub mod usage;
 
 #[derive(Clone)]
 pub struct WargamingApi {
     pub request_counter: Arc<AtomicU32>,
 
-    application_id: Arc<String>,
+    /// Response times in milliseconds by the endpoint path.
+    pub latencies: Arc<Mutex<AHashMap<String, Histogram>>>,
+
+    /// Failed attempts by the error kind, including the retried ones.
+    pub errors: Arc<Mutex<AHashMap<&'static str, u32>>>,
+
+    application_ids: Arc<ApplicationIds>,
     client: reqwest::Client,
+    timeouts: ApiTimeouts,
+    usage: Option<ApiUsage>,
     rate_limiter: Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock>>,
+
+    /// Replaces the realms' base URLs, so that the tests could mock the API.
+    override_base_url: Option<String>,
 }
 
 /// Represents the bundled `tankopedia.json` file.
@@ -42,60 +63,124 @@ pub type Tankopedia = BTreeMap<String, Vehicle>;
 impl WargamingApi {
     const USER_AGENT: &'static str =
         concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+    const MAX_ATTEMPTS: u32 = 10;
+    const BACKOFF_BASE: time::Duration = time::Duration::from_millis(250);
+    const BACKOFF_CAP: time::Duration = time::Duration::from_secs(10);
+
+    const CHINA_ERROR: &'static str = "the Chinese realm is not served by the Wargaming.net API";
 
     pub fn new(
-        application_id: &str,
-        timeout: time::Duration,
+        application_ids: ApplicationIds,
+        timeouts: impl Into<ApiTimeouts>,
         max_rps: NonZeroU32,
     ) -> Result<WargamingApi> {
-        info!(max_rps);
+        let timeouts = timeouts.into();
+        info!(max_rps, ?timeouts);
+
+        let rate_limiter = RateLimiter::direct(Quota::per_second(max_rps));
+
+        let this = Self {
+            application_ids: Arc::new(application_ids),
+            client: Self::build_client(&timeouts, true)?,
+            timeouts,
+            request_counter: Arc::new(AtomicU32::new(0)),
+            latencies: Arc::default(),
+            errors: Arc::default(),
+            rate_limiter: Arc::new(rate_limiter),
+            usage: None,
+            override_base_url: None,
+        };
+        Ok(this)
+    }
 
+    /// Sends all the requests to the mocked API, which is served over the plain HTTP.
+    #[cfg(test)]
+    pub fn with_base_url(mut self, base_url: String) -> Result<Self> {
+        self.client = Self::build_client(&self.timeouts, false)?;
+        self.override_base_url = Some(base_url);
+        Ok(self)
+    }
+
+    fn build_client(timeouts: &ApiTimeouts, https_only: bool) -> Result<reqwest::Client> {
         let mut headers = header::HeaderMap::new();
         headers.insert(header::USER_AGENT, HeaderValue::from_static(Self::USER_AGENT));
         headers.insert(header::ACCEPT, HeaderValue::from_static("application/json"));
         headers.insert(header::ACCEPT_ENCODING, HeaderValue::from_static("br, deflate, gzip"));
         headers.insert(header::CONNECTION, HeaderValue::from_static("keep-alive"));
 
-        let rate_limiter = RateLimiter::direct(Quota::per_second(max_rps));
+        let client = reqwest::ClientBuilder::new()
+            .default_headers(headers)
+            .https_only(https_only)
+            .connect_timeout(timeouts.default)
+            .brotli(true)
+            .gzip(true)
+            .deflate(true)
+            .tcp_nodelay(true)
+            .pool_max_idle_per_host(0) // https://github.com/hyperium/hyper/issues/2312
+            .build()?;
+        Ok(client)
+    }
 
-        let this = Self {
-            application_id: Arc::new(application_id.to_string()),
-            client: reqwest::ClientBuilder::new()
-                .default_headers(headers)
-                .https_only(true)
-                .timeout(timeout)
-                .connect_timeout(timeout)
-                .brotli(true)
-                .gzip(true)
-                .deflate(true)
-                .tcp_nodelay(true)
-                .pool_max_idle_per_host(0) // https://github.com/hyperium/hyper/issues/2312
-                .build()?,
-            request_counter: Arc::new(AtomicU32::new(0)),
-            rate_limiter: Arc::new(rate_limiter),
+    /// Enables the daily request accounting.
+    pub fn with_usage(mut self, usage: ApiUsage) -> Self {
+        self.usage = Some(usage);
+        self
+    }
+
+    /// Retrieves today's usage of the configured application IDs, if the accounting is enabled.
+    pub async fn retrieve_usage(&self) -> Result<Option<Vec<usage::DailyUsage>>> {
+        match &self.usage {
+            Some(usage) => Ok(Some(usage.retrieve_today(&self.application_ids).await?)),
+            None => Ok(None),
+        }
+    }
+
+    fn base_url(realm: Realm) -> Result<&'static str> {
+        match realm {
+            Realm::Asia => Ok("https://api.wotblitz.asia"),
+            Realm::Europe => Ok("https://api.wotblitz.eu"),
+            Realm::Russia => Ok("https://api.wotblitz.ru"),
+            Realm::NorthAmerica => Ok("https://api.wotblitz.com"),
+            Realm::China => bail!(Self::CHINA_ERROR),
+        }
+    }
+
+    /// Builds the realm's endpoint URL.
+    fn endpoint_url(&self, realm: Realm, path: &str) -> Result<String> {
+        let base_url = match &self.override_base_url {
+            Some(base_url) => base_url.as_str(),
+            None => Self::base_url(realm)?,
         };
-        Ok(this)
+        Ok(format!("{base_url}{path}"))
+    }
+
+    /// Game website host, which serves the rating leaderboards.
+    fn leaderboard_host(realm: Realm) -> Result<&'static str> {
+        match realm {
+            Realm::Asia => Ok("asia.wotblitz.com"),
+            Realm::Europe => Ok("eu.wotblitz.com"),
+            Realm::Russia => Ok("ru.wotblitz.com"),
+            Realm::NorthAmerica => Ok("na.wotblitz.com"),
+            Realm::China => bail!(Self::CHINA_ERROR),
+        }
     }
 
     /// See: <https://developers.wargaming.net/reference/all/wotb/account/list/>.
     #[instrument(skip_all, fields(realm = ?realm, query = query))]
     pub async fn search_accounts(&self, realm: Realm, query: &str) -> Result<Vec<FoundAccount>> {
-        let url = match realm {
-            Realm::Asia => "https://api.wotblitz.asia/wotb/account/list/",
-            Realm::Europe => "https://api.wotblitz.eu/wotb/account/list/",
-            Realm::Russia => "https://api.wotblitz.ru/wotb/account/list/",
-            Realm::NorthAmerica => "https://api.wotblitz.com/wotb/account/list/",
-        };
-        self.call(Url::parse_with_params(
-            url,
-            &[
-                ("application_id", self.application_id.as_str()),
-                ("limit", "20"),
-                ("search", query),
-            ],
-        )?)
-        .await
-        .with_context(|| format!("failed to search for accounts: `{query}`"))
+        let url = self.endpoint_url(realm, "/wotb/account/list/")?;
+        let accounts: Vec<Lenient<FoundAccount>> = self
+            .call(Url::parse_with_params(
+                &url,
+                &[
+                    ("application_id", self.application_ids.get(realm)?),
+                    ("limit", "20"),
+                    ("search", query),
+                ],
+            )?)
+            .await
+            .with_context(|| format!("failed to search for accounts: `{query}`"))?;
+        Ok(Lenient::flatten(accounts).collect())
     }
 
     /// See <https://developers.wargaming.net/reference/all/wotb/account/info/>.
@@ -109,22 +194,22 @@ impl WargamingApi {
             return Ok(HashMap::new());
         }
         let account_id = account_ids.iter().map(ToString::to_string).join(",");
-        let url = match realm {
-            Realm::Asia => "https://api.wotblitz.asia/wotb/account/info/",
-            Realm::Europe => "https://api.wotblitz.eu/wotb/account/info/",
-            Realm::Russia => "https://api.wotblitz.ru/wotb/account/info/",
-            Realm::NorthAmerica => "https://api.wotblitz.com/wotb/account/info/",
-        };
-        self.call(Url::parse_with_params(
-            url,
-            &[
-                ("application_id", self.application_id.as_str()),
-                ("account_id", account_id.as_str()),
-                ("extra", "statistics.rating"),
-            ],
-        )?)
-        .await
-        .with_context(|| format!("failed to get account infos: `{account_id}`"))
+        let url = self.endpoint_url(realm, "/wotb/account/info/")?;
+        let infos: HashMap<String, Option<Lenient<AccountInfo>>> = self
+            .call(Url::parse_with_params(
+                &url,
+                &[
+                    ("application_id", self.application_ids.get(realm)?),
+                    ("account_id", account_id.as_str()),
+                    ("extra", "statistics.rating"),
+                ],
+            )?)
+            .await
+            .with_context(|| format!("failed to get account infos: `{account_id}`"))?;
+        Ok(infos
+            .into_iter()
+            .map(|(account_id, info)| (account_id, info.and_then(|info| info.0)))
+            .collect())
     }
 
     /// See <https://developers.wargaming.net/reference/all/wotb/tanks/stats/>.
@@ -134,17 +219,13 @@ impl WargamingApi {
         realm: Realm,
         account_id: AccountId,
     ) -> Result<Vec<TankStats>> {
-        let url = match realm {
-            Realm::Asia => "https://api.wotblitz.asia/wotb/tanks/stats/",
-            Realm::Europe => "https://api.wotblitz.eu/wotb/tanks/stats/",
-            Realm::Russia => "https://api.wotblitz.ru/wotb/tanks/stats/",
-            Realm::NorthAmerica => "https://api.wotblitz.com/wotb/tanks/stats/",
-        };
-        Ok(self
-            .call_by_account(url, account_id)
+        let url = self.endpoint_url(realm, "/wotb/tanks/stats/")?;
+        let tanks_stats: Vec<Lenient<TankStats>> = self
+            .call_by_account(&url, realm, account_id)
             .await
             .with_context(|| format!("failed to get tanks stats for #{account_id}"))?
-            .unwrap_or_default())
+            .unwrap_or_default();
+        Ok(Lenient::flatten(tanks_stats).collect())
     }
 
     /// See <https://developers.wargaming.net/reference/all/wotb/tanks/achievements/>.
@@ -154,32 +235,111 @@ impl WargamingApi {
         realm: Realm,
         account_id: AccountId,
     ) -> Result<Vec<TankAchievements>> {
-        let url = match realm {
-            Realm::Asia => "https://api.wotblitz.asia/wotb/tanks/achievements/",
-            Realm::Europe => "https://api.wotblitz.eu/wotb/tanks/achievements/",
-            Realm::Russia => "https://api.wotblitz.ru/wotb/tanks/achievements/",
-            Realm::NorthAmerica => "https://api.wotblitz.com/wotb/tanks/achievements/",
-        };
-        Ok(self
-            .call_by_account(url, account_id)
+        let url = self.endpoint_url(realm, "/wotb/tanks/achievements/")?;
+        let achievements: Vec<Lenient<TankAchievements>> = self
+            .call_by_account(&url, realm, account_id)
             .await
             .with_context(|| format!("failed to get tanks achievements for #{account_id}"))?
-            .unwrap_or_default())
+            .unwrap_or_default();
+        Ok(Lenient::flatten(achievements).collect())
+    }
+
+    /// See <https://developers.wargaming.net/reference/all/wotb/clans/list/>.
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm, page_no = page_no))]
+    pub async fn get_clans(&self, realm: Realm, page_no: u32) -> Result<Vec<FoundClan>> {
+        let url = self.endpoint_url(realm, "/wotb/clans/list/")?;
+        let page_no = page_no.to_string();
+        let clans: Vec<Lenient<FoundClan>> = self
+            .call(Url::parse_with_params(
+                &url,
+                &[
+                    ("application_id", self.application_ids.get(realm)?),
+                    ("fields", "clan_id"),
+                    ("limit", "100"),
+                    ("page_no", page_no.as_str()),
+                ],
+            )?)
+            .await
+            .with_context(|| format!("failed to get the clans page #{page_no}"))?;
+        Ok(Lenient::flatten(clans).collect())
+    }
+
+    /// See <https://developers.wargaming.net/reference/all/wotb/clans/info/>.
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm, n_clans = clan_ids.len()))]
+    pub async fn get_clan_infos(&self, realm: Realm, clan_ids: &[ClanId]) -> Result<Vec<ClanInfo>> {
+        if clan_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let clan_id = clan_ids.iter().map(ToString::to_string).join(",");
+        let url = self.endpoint_url(realm, "/wotb/clans/info/")?;
+        let infos: HashMap<String, Option<Lenient<ClanInfo>>> = self
+            .call(Url::parse_with_params(
+                &url,
+                &[
+                    ("application_id", self.application_ids.get(realm)?),
+                    ("clan_id", clan_id.as_str()),
+                    ("fields", "clan_id,members_ids"),
+                ],
+            )?)
+            .await
+            .with_context(|| format!("failed to get the clan infos: `{clan_id}`"))?;
+        Ok(Lenient::flatten(infos.into_values().flatten()).collect())
+    }
+
+    /// Retrieves the top players of the rating league from the game website,
+    /// where the league `0` is the highest one.
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm, league = league))]
+    pub async fn get_rating_leaderboard(
+        &self,
+        realm: Realm,
+        league: u8,
+    ) -> Result<Vec<LeaderboardEntry>> {
+        let host = Self::leaderboard_host(realm)?;
+        let url = format!("https://{host}/en/api/rating-leaderboards/league/{league}/top/");
+        let leaderboard: Leaderboard = self
+            .call_bare(Url::parse(&url)?)
+            .await
+            .with_context(|| format!("failed to get the rating league #{league} leaderboard"))?;
+        Ok(Lenient::flatten(leaderboard.results).collect())
     }
 
     /// See <https://developers.wargaming.net/reference/all/wotb/encyclopedia/vehicles/>.
-    #[tracing::instrument(skip_all)]
-    pub async fn get_tankopedia(&self) -> Result<Tankopedia> {
+    #[tracing::instrument(skip_all, fields(language = language))]
+    pub async fn get_tankopedia(&self, language: &str) -> Result<Tankopedia> {
         info!("retrieving the tankopedia…");
-        self.call::<Tankopedia>(Url::parse_with_params(
-            "https://api.wotblitz.eu/wotb/encyclopedia/vehicles/",
+        let vehicles: BTreeMap<String, Lenient<Vehicle>> = self
+            .call(Url::parse_with_params(
+                "https://api.wotblitz.eu/wotb/encyclopedia/vehicles/",
+                &[
+                    ("application_id", self.application_ids.get(Realm::Europe)?),
+                    ("language", language),
+                ],
+            )?)
+            .await
+            .context("failed to get the tankopedia")?;
+        Ok(vehicles
+            .into_iter()
+            .filter_map(|(tank_id, vehicle)| Some((tank_id, vehicle.0?)))
+            .collect())
+    }
+
+    /// Sends a single cheap request without retrying, so that the realm availability
+    /// could be told from the failure.
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm))]
+    pub async fn ping(&self, realm: Realm) -> Result {
+        let url = self.endpoint_url(realm, "/wotb/account/info/")?;
+        let url = Url::parse_with_params(
+            &url,
             &[
-                ("application_id", self.application_id.as_str()),
-                ("language", "en"),
+                ("application_id", self.application_ids.get(realm)?),
+                ("account_id", "1"),
+                ("fields", "account_id"),
             ],
-        )?)
-        .await
-        .context("failed to get the tankopedia")
+        )?;
+        self.call_once::<Response<serde_json::Value>>(url, 1)
+            .await?
+            .into_result()?;
+        Ok(())
     }
 
     /// Convenience method for endpoints that return data in the form of a map by account ID.
@@ -187,6 +347,7 @@ impl WargamingApi {
     async fn call_by_account<T: DeserializeOwned>(
         &self,
         url: &str,
+        realm: Realm,
         account_id: AccountId,
     ) -> Result<Option<T>> {
         let account_id = account_id.to_string();
@@ -194,7 +355,7 @@ impl WargamingApi {
             .call(Url::parse_with_params(
                 url,
                 &[
-                    ("application_id", self.application_id.as_str()),
+                    ("application_id", self.application_ids.get(realm)?),
                     ("account_id", account_id.as_str()),
                 ],
             )?)
@@ -202,66 +363,129 @@ impl WargamingApi {
         Ok(map.remove(&account_id).flatten())
     }
 
+    /// Calls the official API endpoint, which wraps the data into the `{"data": …}` envelope.
+    async fn call<T: DeserializeOwned>(&self, url: Url) -> Result<T, ApiError> {
+        self.call_with(url, Response::into_result).await
+    }
+
+    /// Calls the endpoint, which returns the bare JSON, for example, the game website API.
+    async fn call_bare<T: DeserializeOwned>(&self, url: Url) -> Result<T, ApiError> {
+        self.call_with(url, Ok).await
+    }
+
+    /// Retries the transient errors, including those, which are reported in the unwrapped body.
     #[instrument(skip_all, fields(path = url.path()), err)]
-    async fn call<T: DeserializeOwned>(&self, url: Url) -> Result<T> {
-        for nr_attempt in 1..=10 {
-            match self.call_once(url.clone()).await {
-                Ok(response) => match response {
-                    Response::Data { data } => {
-                        trace!(nr_attempt, "ok");
-                        return Ok(data);
-                    }
-                    Response::Error { error } => {
-                        let message = error.message.as_str();
-                        match message {
-                            "REQUEST_LIMIT_EXCEEDED" => {
-                                warn!(error.code, nr_attempt, "request limit exceeded");
-                            }
-                            "SOURCE_NOT_AVAILABLE" => {
-                                warn!(error.code, nr_attempt, "source not available");
-                                sleep(time::Duration::from_secs(1)).await;
-                            }
-                            _ => {
-                                bail!("#{} {}/{}", nr_attempt, error.code, message);
-                            }
-                        }
-                    }
-                },
-                Err(error) => {
-                    warn!(path = url.path(), nr_attempt, "{:#}", error);
+    async fn call_with<R: DeserializeOwned, T>(
+        &self,
+        url: Url,
+        unwrap: fn(R) -> Result<T, ApiError>,
+    ) -> Result<T, ApiError> {
+        let mut backoff = Backoff::new(Self::BACKOFF_BASE, Self::BACKOFF_CAP);
+        let mut nr_attempt = 1;
+        loop {
+            let result = self.call_once(url.clone(), nr_attempt).await.and_then(unwrap);
+            if let Err(error) = &result {
+                *self.errors.lock().unwrap().entry(error.kind()).or_default() += 1;
+            }
+            match result {
+                Ok(data) => {
+                    trace!(nr_attempt, "ok");
+                    return Ok(data);
+                }
+                Err(error) if error.is_transient() && nr_attempt < Self::MAX_ATTEMPTS => {
+                    let delay = error.retry_after().unwrap_or_else(|| backoff.next());
+                    warn!(nr_attempt, ?delay, "{:#}, retrying…", Error::from(error));
+                    sleep(delay).await;
+                    nr_attempt += 1;
                 }
-            };
-            debug!(nr_attempt, "retrying…");
+                Err(error) => return Err(error),
+            }
         }
-        bail!("all attempts have failed")
     }
 
-    #[tracing::instrument(skip_all, fields(path = url.path()))]
-    async fn call_once<T: DeserializeOwned>(&self, url: Url) -> Result<Response<T>> {
+    #[instrument(skip_all, fields(path = url.path(), nr_attempt = nr_attempt))]
+    async fn call_once<T: DeserializeOwned>(
+        &self,
+        url: Url,
+        nr_attempt: u32,
+    ) -> Result<T, ApiError> {
         self.rate_limiter
             .until_ready_with_jitter(Jitter::up_to(time::Duration::from_millis(100)))
             .await;
 
+        self.record_usage(&url).await;
         let nr_request = self.request_counter.fetch_add(1, Ordering::Relaxed);
         trace!(nr_request, path = url.path(), "sending the request…");
 
         let start_instant = Instant::now();
+        let path = url.path().to_string();
+        let timeout = self.timeouts.for_path(&path);
+        let with_timeout = |error| ApiError::from_request(error, timeout);
         let response = self
             .client
             .get(url)
+            .timeout(timeout)
             .send()
             .await
-            .context("failed to send the request")?;
-
-        trace!(nr_request, status = ?response.status());
-        let result = response
-            .error_for_status()
-            .context("HTTP error")?
-            .json::<Response<T>>()
-            .await
-            .context("failed to deserialize the response");
+            .map_err(with_timeout)?;
+        let status = response.status();
+        trace!(nr_request, ?status);
+        if !status.is_success() {
+            return Err(ApiError::from_status(status, response.headers()));
+        }
+        let body = response.json::<T>().await.map_err(with_timeout)?;
 
         trace!(nr_request, elapsed = format_elapsed(start_instant).as_str(), "done");
-        result
+        self.record_latency(path, start_instant);
+        Ok(body)
+    }
+
+    /// Counts the request against its application ID, and slows down near the daily budget.
+    /// The accounting errors are not fatal, the requests go on regardless.
+    async fn record_usage(&self, url: &Url) {
+        let Some(usage) = &self.usage else { return };
+        let Some((_, application_id)) = url.query_pairs().find(|(name, _)| name == "application_id")
+        else {
+            return;
+        };
+        match usage.record(&application_id).await {
+            Ok(Some(delay)) => {
+                debug!(?delay, "slowing down near the daily budget");
+                sleep(delay).await;
+            }
+            Ok(None) => {}
+            Err(error) => warn!("failed to record the API usage: {:#}", error),
+        }
+    }
+
+    fn record_latency(&self, path: String, start_instant: Instant) {
+        let elapsed_millis = start_instant.elapsed().as_millis() as u64;
+        self.latencies
+            .lock()
+            .unwrap()
+            .entry(path)
+            .or_default()
+            .record(elapsed_millis);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_url_ok() {
+        for realm in Realm::ALL {
+            assert!(WargamingApi::base_url(realm).is_ok());
+        }
+        assert!(WargamingApi::base_url(Realm::China).is_err());
+    }
+
+    #[test]
+    fn leaderboard_host_ok() {
+        for realm in Realm::ALL {
+            assert!(WargamingApi::leaderboard_host(realm).is_ok());
+        }
+        assert!(WargamingApi::leaderboard_host(Realm::China).is_err());
     }
 }
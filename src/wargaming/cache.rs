@@ -1 +1,79 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::Serialize;
+
 pub mod account;
+
+/// Cache effectiveness counters.
+#[derive(Default)]
+pub struct CacheCounters {
+    n_hits: AtomicU64,
+    n_misses: AtomicU64,
+    n_coalesced: AtomicU64,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+
+    /// Requests which have waited for a concurrent one, instead of calling the API.
+    pub coalesced: u64,
+
+    /// Hits, including the coalesced requests, to all the requests, [`None`] until the first one.
+    pub hit_ratio: Option<f64>,
+}
+
+impl CacheCounters {
+    /// Instantiates the zero counters, also in a `static`.
+    pub const fn new() -> Self {
+        Self {
+            n_hits: AtomicU64::new(0),
+            n_misses: AtomicU64::new(0),
+            n_coalesced: AtomicU64::new(0),
+        }
+    }
+
+    pub fn hit(&self) {
+        self.n_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn miss(&self) {
+        self.n_misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn coalesced(&self) {
+        self.n_coalesced.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let hits = self.n_hits.load(Ordering::Relaxed);
+        let misses = self.n_misses.load(Ordering::Relaxed);
+        let coalesced = self.n_coalesced.load(Ordering::Relaxed);
+        let n_requests = hits + misses + coalesced;
+        CacheStats {
+            hits,
+            misses,
+            coalesced,
+            hit_ratio: (n_requests != 0).then(|| (hits + coalesced) as f64 / n_requests as f64),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_ok() {
+        let counters = CacheCounters::new();
+        assert_eq!(counters.stats().hit_ratio, None);
+        counters.hit();
+        counters.coalesced();
+        counters.miss();
+        counters.miss();
+        let stats = counters.stats();
+        assert_eq!((stats.hits, stats.misses, stats.coalesced), (1, 2, 1));
+        assert_eq!(stats.hit_ratio, Some(0.5));
+    }
+}
@@ -4,7 +4,9 @@ use fred::types::RedisKey;
 use mongodb::bson;
 use tracing::{debug, instrument};
 
+use crate::helpers::single_flight::SingleFlight;
 use crate::prelude::*;
+use crate::wargaming::cache::{CacheCounters, CacheStats};
 use crate::wargaming::models::AccountInfo;
 use crate::wargaming::{AccountId, Realm, WargamingApi};
 
@@ -12,26 +14,59 @@ use crate::wargaming::{AccountId, Realm, WargamingApi};
 pub struct AccountInfoCache {
     api: WargamingApi,
     redis: RedisPool,
+    in_flight: Arc<SingleFlight<(Realm, AccountId)>>,
+    counters: Arc<CacheCounters>,
 }
 
 impl AccountInfoCache {
     const EXPIRE: Option<Expiration> = Some(Expiration::EX(30));
 
-    pub const fn new(api: WargamingApi, redis: RedisPool) -> Self {
-        Self { api, redis }
+    pub fn new(api: WargamingApi, redis: RedisPool) -> Self {
+        Self {
+            api,
+            redis,
+            in_flight: Arc::default(),
+            counters: Arc::default(),
+        }
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.counters.stats()
     }
 
+    /// Retrieves the account information from the cache or the API.
+    /// Concurrent misses for the same account result in a single API call.
     #[instrument(skip_all, fields(realm = ?realm, account_id = account_id))]
     pub async fn get(&self, realm: Realm, account_id: AccountId) -> Result<Option<AccountInfo>> {
-        if let Some(blob) = self
-            .redis
-            .get::<Option<Vec<u8>>, _>(Self::cache_key(realm, account_id))
-            .await?
-        {
+        if let Some(account_info) = self.get_cached(realm, account_id).await? {
             debug!(account_id = account_id, "cache hit");
-            return Ok(bson::from_slice(&blob)?);
+            self.counters.hit();
+            return Ok(Some(account_info));
+        }
+
+        let _guard = self.in_flight.lock((realm, account_id)).await;
+        if let Some(account_info) = self.get_cached(realm, account_id).await? {
+            debug!(account_id = account_id, "coalesced");
+            self.counters.coalesced();
+            return Ok(Some(account_info));
         }
+        self.counters.miss();
+        self.fetch(realm, account_id).await
+    }
 
+    /// Retrieves the account information from the API and caches it, regardless of the cache.
+    /// Unlike [`Self::get`], it does not touch the hit and miss counters.
+    #[instrument(skip_all, fields(realm = ?realm, account_id = account_id))]
+    pub async fn refresh(
+        &self,
+        realm: Realm,
+        account_id: AccountId,
+    ) -> Result<Option<AccountInfo>> {
+        let _guard = self.in_flight.lock((realm, account_id)).await;
+        self.fetch(realm, account_id).await
+    }
+
+    async fn fetch(&self, realm: Realm, account_id: AccountId) -> Result<Option<AccountInfo>> {
         let account_info = self
             .api
             .get_account_info(realm, &[account_id])
@@ -49,7 +84,7 @@ impl AccountInfoCache {
         let blob = bson::to_vec(&account_info)?;
         debug!(account_id = account_info.id, n_bytes = blob.len(), "set cache");
         self.redis
-            .set(
+            .set::<(), _, _>(
                 Self::cache_key(realm, account_info.id),
                 blob.as_slice(),
                 Self::EXPIRE,
@@ -60,6 +95,14 @@ impl AccountInfoCache {
         Ok(())
     }
 
+    async fn get_cached(&self, realm: Realm, account_id: AccountId) -> Result<Option<AccountInfo>> {
+        let blob = self
+            .redis
+            .get::<Option<Vec<u8>>, _>(Self::cache_key(realm, account_id))
+            .await?;
+        Ok(blob.map(|blob| bson::from_slice(&blob)).transpose()?)
+    }
+
     #[inline]
     fn cache_key(realm: Realm, account_id: AccountId) -> RedisKey {
         RedisKey::from(format!("cache:3:a:i:{}:{}", realm.to_str(), account_id))
@@ -7,22 +7,37 @@ use tracing::{debug, instrument};
 
 use crate::database;
 use crate::helpers::compression::{compress, decompress};
+use crate::helpers::single_flight::SingleFlight;
 use crate::prelude::*;
+use crate::wargaming::cache::{CacheCounters, CacheStats};
 use crate::wargaming::{AccountId, Realm, TankId, WargamingApi};
 
 #[derive(Clone)]
 pub struct AccountTanksCache {
     api: WargamingApi,
     redis: RedisPool,
+    in_flight: Arc<SingleFlight<(Realm, AccountId)>>,
+    counters: Arc<CacheCounters>,
 }
 
 impl AccountTanksCache {
     const EXPIRE: Option<Expiration> = Some(Expiration::EX(30));
 
-    pub const fn new(api: WargamingApi, redis: RedisPool) -> Self {
-        Self { api, redis }
+    pub fn new(api: WargamingApi, redis: RedisPool) -> Self {
+        Self {
+            api,
+            redis,
+            in_flight: Arc::default(),
+            counters: Arc::default(),
+        }
     }
 
+    pub fn stats(&self) -> CacheStats {
+        self.counters.stats()
+    }
+
+    /// Retrieves the account's tank snapshots from the cache or the API.
+    /// Concurrent misses for the same account result in a single pair of API calls.
     #[instrument(skip_all, fields(realm = ?realm, account_id = account_id))]
     pub async fn get(
         &self,
@@ -30,29 +45,25 @@ impl AccountTanksCache {
         account_id: AccountId,
     ) -> Result<AHashMap<TankId, database::TankSnapshot>> {
         let cache_key = Self::cache_key(realm, account_id);
-        let snapshots = match self.redis.get::<Option<Vec<u8>>, _>(&cache_key).await? {
-            Some(blob) => {
+        let snapshots = match self.get_cached(&cache_key).await? {
+            Some(snapshots) => {
                 debug!(account_id, "cache hit");
-                let blob = decompress(&blob).await?;
-                let mut root = bson::from_slice::<bson::Document>(&blob)
-                    .context("failed to deserialize the tanks cache")?;
-                bson::from_bson(root.remove("root").ok_or_else(|| anyhow!("no root"))?)?
+                self.counters.hit();
+                snapshots
             }
             None => {
-                let (statistics, achievements) = {
-                    let get_statistics = self.api.get_tanks_stats(realm, account_id);
-                    let get_achievements = self.api.get_tanks_achievements(realm, account_id);
-                    try_join(get_statistics, get_achievements).await?
-                };
-                let snapshots =
-                    database::TankSnapshot::from_vec(realm, account_id, statistics, achievements);
-                let blob = bson::to_vec(&bson::doc! {"root": bson::to_bson(&snapshots)?})?;
-                let blob = compress(&blob).await?;
-                debug!(account_id, n_bytes = blob.len(), "set cache");
-                self.redis
-                    .set(&cache_key, blob.as_slice(), Self::EXPIRE, None, false)
-                    .await?;
-                snapshots
+                let _guard = self.in_flight.lock((realm, account_id)).await;
+                match self.get_cached(&cache_key).await? {
+                    Some(snapshots) => {
+                        debug!(account_id, "coalesced");
+                        self.counters.coalesced();
+                        snapshots
+                    }
+                    None => {
+                        self.counters.miss();
+                        self.fetch(realm, account_id, &cache_key).await?
+                    }
+                }
             }
         };
         Ok(snapshots
@@ -61,6 +72,52 @@ impl AccountTanksCache {
             .collect())
     }
 
+    /// Retrieves the account's tank snapshots from the API and caches them,
+    /// regardless of the cache.
+    /// Unlike [`Self::get`], it does not touch the hit and miss counters.
+    #[instrument(skip_all, fields(realm = ?realm, account_id = account_id))]
+    pub async fn refresh(&self, realm: Realm, account_id: AccountId) -> Result {
+        let _guard = self.in_flight.lock((realm, account_id)).await;
+        self.fetch(realm, account_id, &Self::cache_key(realm, account_id)).await?;
+        Ok(())
+    }
+
+    async fn get_cached(
+        &self,
+        cache_key: &RedisKey,
+    ) -> Result<Option<Vec<database::TankSnapshot>>> {
+        let blob = match self.redis.get::<Option<Vec<u8>>, _>(cache_key).await? {
+            Some(blob) => decompress(&blob).await?,
+            None => return Ok(None),
+        };
+        let mut root = bson::from_slice::<bson::Document>(&blob)
+            .context("failed to deserialize the tanks cache")?;
+        Ok(Some(bson::from_bson(root.remove("root").ok_or_else(|| anyhow!("no root"))?)?))
+    }
+
+    /// Retrieves the snapshots from the API and caches them.
+    async fn fetch(
+        &self,
+        realm: Realm,
+        account_id: AccountId,
+        cache_key: &RedisKey,
+    ) -> Result<Vec<database::TankSnapshot>> {
+        let (statistics, achievements) = {
+            let get_statistics = self.api.get_tanks_stats(realm, account_id);
+            let get_achievements = self.api.get_tanks_achievements(realm, account_id);
+            try_join(get_statistics, get_achievements).await?
+        };
+        let snapshots =
+            database::TankSnapshot::from_vec(realm, account_id, statistics, achievements);
+        let blob = bson::to_vec(&bson::doc! {"root": bson::to_bson(&snapshots)?})?;
+        let blob = compress(&blob).await?;
+        debug!(account_id, n_bytes = blob.len(), "set cache");
+        self.redis
+            .set::<(), _, _>(cache_key, blob.as_slice(), Self::EXPIRE, None, false)
+            .await?;
+        Ok(snapshots)
+    }
+
     #[inline]
     fn cache_key(realm: Realm, account_id: AccountId) -> RedisKey {
         RedisKey::from(format!("cache:6:a:t:{}:{}", realm.to_str(), account_id))
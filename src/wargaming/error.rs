@@ -0,0 +1,355 @@
+use std::error::Error as StdError;
+use std::fmt;
+
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
+
+use crate::prelude::*;
+use crate::wargaming::response;
+
+/// Longest `Retry-After` delay to honor.
+const MAX_RETRY_AFTER: time::Duration = time::Duration::from_secs(60);
+
+#[derive(Debug)]
+pub enum ApiError {
+    /// HTTP 429 or `REQUEST_LIMIT_EXCEEDED`.
+    RateLimited { retry_after: Option<time::Duration> },
+
+    /// HTTP 5xx or `SOURCE_NOT_AVAILABLE`.
+    Unavailable {
+        reason: String,
+        retry_after: Option<time::Duration>,
+    },
+
+    /// Failed to send the request or to receive the response.
+    Transport(reqwest::Error),
+
+    /// The request has exceeded the endpoint's timeout.
+    TimedOut {
+        timeout: time::Duration,
+        source: reqwest::Error,
+    },
+
+    /// The response is not a valid API response.
+    Decode(reqwest::Error),
+
+    /// Non-retryable HTTP status.
+    Http(StatusCode),
+
+    /// Non-retryable API error, for example, `INVALID_SEARCH`.
+    Api { code: ApiErrorCode, message: String },
+}
+
+/// Error reported by the API in the response body, see
+/// <https://developers.wargaming.net/documentation/guide/getting-started/#common-errors>.
+///
+/// The numeric code alone is ambiguous, `407` covers both the invalid parameters and the
+/// request limit, so the message is only looked at to tell these apart.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ApiErrorCode {
+    /// `402`: a required parameter is missing.
+    FieldNotSpecified { field: Option<String> },
+
+    /// `404`: the method or the requested entity doesn't exist.
+    NotFound { field: Option<String> },
+
+    /// `405`: the method is disabled.
+    MethodDisabled,
+
+    /// `407 REQUEST_LIMIT_EXCEEDED`.
+    RequestLimitExceeded,
+
+    /// `407`: the application ID is invalid or blocked, or the IP address is not allowed.
+    InvalidApplication,
+
+    /// `407`: the parameter value is rejected, for example, the search query is too short.
+    InvalidRequest { field: Option<String> },
+
+    /// `504 SOURCE_NOT_AVAILABLE`.
+    SourceNotAvailable,
+
+    /// Any other code.
+    Other(i32),
+}
+
+impl From<&response::Error> for ApiErrorCode {
+    fn from(error: &response::Error) -> Self {
+        let field = || error.field.clone();
+        match (error.code, error.message.as_str()) {
+            (402, _) => Self::FieldNotSpecified { field: field() },
+            (404, _) => Self::NotFound { field: field() },
+            (405, _) => Self::MethodDisabled,
+            (407, "REQUEST_LIMIT_EXCEEDED") => Self::RequestLimitExceeded,
+            (407, "INVALID_APPLICATION_ID" | "APPLICATION_IS_BLOCKED" | "INVALID_IP_ADDRESS") => {
+                Self::InvalidApplication
+            }
+            (407, _) => Self::InvalidRequest { field: field() },
+            (504, _) => Self::SourceNotAvailable,
+            (code, _) => Self::Other(code),
+        }
+    }
+}
+
+impl ApiErrorCode {
+    /// Numeric code as reported by the API.
+    pub const fn number(&self) -> i32 {
+        match self {
+            Self::FieldNotSpecified { .. } => 402,
+            Self::NotFound { .. } => 404,
+            Self::MethodDisabled => 405,
+            Self::RequestLimitExceeded | Self::InvalidApplication | Self::InvalidRequest { .. } => {
+                407
+            }
+            Self::SourceNotAvailable => 504,
+            Self::Other(code) => *code,
+        }
+    }
+
+    /// Tells whether the same request may succeed on retry.
+    pub const fn is_retryable(&self) -> bool {
+        matches!(self, Self::RequestLimitExceeded | Self::SourceNotAvailable)
+    }
+
+    /// Offending request parameter, if reported.
+    pub fn field(&self) -> Option<&str> {
+        match self {
+            Self::FieldNotSpecified { field }
+            | Self::NotFound { field }
+            | Self::InvalidRequest { field } => field.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::RateLimited { .. } => write!(f, "request limit exceeded"),
+            Self::Unavailable { reason, .. } => write!(f, "temporarily unavailable: {reason}"),
+            Self::Transport(_) => write!(f, "failed to send the request"),
+            Self::TimedOut { timeout, .. } => write!(f, "timed out after {timeout:?}"),
+            Self::Decode(_) => write!(f, "failed to deserialize the response"),
+            Self::Http(status) => write!(f, "HTTP {status}"),
+            Self::Api { code, message } => {
+                write!(f, "{}/{message}", code.number())?;
+                match code.field() {
+                    Some(field) => write!(f, " (field `{field}`)"),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+impl StdError for ApiError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Transport(error) | Self::Decode(error) | Self::TimedOut { source: error, .. } => {
+                Some(error)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for ApiError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_decode() {
+            Self::Decode(error)
+        } else {
+            Self::Transport(error)
+        }
+    }
+}
+
+impl From<response::Error> for ApiError {
+    fn from(error: response::Error) -> Self {
+        match ApiErrorCode::from(&error) {
+            ApiErrorCode::RequestLimitExceeded => Self::RateLimited { retry_after: None },
+            ApiErrorCode::SourceNotAvailable => Self::Unavailable {
+                reason: error.message,
+                retry_after: None,
+            },
+            code => Self::Api {
+                code,
+                message: error.message,
+            },
+        }
+    }
+}
+
+impl ApiError {
+    /// Categorizes the request error, keeping the effective timeout for the tuning.
+    pub fn from_request(error: reqwest::Error, timeout: time::Duration) -> Self {
+        if error.is_timeout() {
+            Self::TimedOut {
+                timeout,
+                source: error,
+            }
+        } else {
+            Self::from(error)
+        }
+    }
+
+    /// Categorizes the unsuccessful HTTP status.
+    pub fn from_status(status: StatusCode, headers: &HeaderMap) -> Self {
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            Self::RateLimited {
+                retry_after: parse_retry_after(headers),
+            }
+        } else if status.is_server_error() {
+            Self::Unavailable {
+                reason: status.to_string(),
+                retry_after: parse_retry_after(headers),
+            }
+        } else {
+            Self::Http(status)
+        }
+    }
+
+    /// Short name of the error kind for the metrics.
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::RateLimited { .. } => "rate_limited",
+            Self::Unavailable { .. } => "unavailable",
+            Self::Transport(_) => "transport",
+            Self::TimedOut { .. } => "timed_out",
+            Self::Decode(_) => "decode",
+            Self::Http(_) => "http",
+            Self::Api { .. } => "api",
+        }
+    }
+
+    /// Tells whether the same request may succeed on retry.
+    pub const fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Self::RateLimited { .. }
+                | Self::Unavailable { .. }
+                | Self::Transport(_)
+                | Self::TimedOut { .. }
+        ) || matches!(self, Self::Api { code, .. } if code.is_retryable())
+    }
+
+    /// Tells whether the request parameters have been rejected by the API,
+    /// for example, the search query contains forbidden characters.
+    pub const fn is_invalid_request(&self) -> bool {
+        matches!(self, Self::Api { code: ApiErrorCode::InvalidRequest { .. }, .. })
+    }
+
+    pub const fn retry_after(&self) -> Option<time::Duration> {
+        match self {
+            Self::RateLimited { retry_after } | Self::Unavailable { retry_after, .. } => {
+                *retry_after
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Parses the `Retry-After` header, which is either the number of seconds or an HTTP date.
+fn parse_retry_after(headers: &HeaderMap) -> Option<time::Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    let retry_after = match value.parse::<u64>() {
+        Ok(secs) => time::Duration::from_secs(secs),
+        Err(_) => {
+            let moment = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+            (moment.with_timezone(&Utc) - now()).to_std().unwrap_or_default()
+        }
+    };
+    Some(retry_after.min(MAX_RETRY_AFTER))
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    fn headers(retry_after: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static(retry_after));
+        headers
+    }
+
+    #[test]
+    fn from_status_ok() {
+        let error = ApiError::from_status(StatusCode::TOO_MANY_REQUESTS, &headers("5"));
+        assert!(error.is_transient());
+        assert_eq!(error.retry_after(), Some(time::Duration::from_secs(5)));
+
+        let error = ApiError::from_status(StatusCode::BAD_GATEWAY, &HeaderMap::new());
+        assert!(error.is_transient());
+        assert_eq!(error.retry_after(), None);
+
+        let error = ApiError::from_status(StatusCode::FORBIDDEN, &headers("5"));
+        assert!(!error.is_transient());
+    }
+
+    #[test]
+    fn parse_retry_after_ok() {
+        assert_eq!(parse_retry_after(&headers("3600")), Some(MAX_RETRY_AFTER));
+        assert_eq!(
+            parse_retry_after(&headers("Wed, 21 Oct 2015 07:28:00 GMT")),
+            Some(time::Duration::ZERO),
+        );
+        assert_eq!(parse_retry_after(&headers("soon")), None);
+        assert_eq!(parse_retry_after(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn from_response_error_ok() {
+        let error = ApiError::from(response_error(504, "SOURCE_NOT_AVAILABLE", None));
+        assert!(error.is_transient());
+
+        let error = ApiError::from(response_error(407, "REQUEST_LIMIT_EXCEEDED", None));
+        assert!(error.is_transient());
+
+        let error = ApiError::from(response_error(407, "INVALID_SEARCH", Some("search")));
+        assert!(!error.is_transient());
+        assert!(error.is_invalid_request());
+        assert_eq!(error.to_string(), "407/INVALID_SEARCH (field `search`)");
+
+        let error = ApiError::from(response_error(407, "INVALID_APPLICATION_ID", None));
+        assert!(!error.is_transient());
+        assert!(!error.is_invalid_request());
+        assert_eq!(error.to_string(), "407/INVALID_APPLICATION_ID");
+    }
+
+    fn response_error(code: i32, message: &str, field: Option<&str>) -> response::Error {
+        response::Error {
+            code,
+            message: message.to_string(),
+            field: field.map(ToString::to_string),
+        }
+    }
+
+    #[test]
+    fn parse_code_ok() {
+        let parse = |code, message, field| ApiErrorCode::from(&response_error(code, message, field));
+        assert_eq!(
+            parse(402, "ACCOUNT_ID_NOT_SPECIFIED", Some("account_id")),
+            ApiErrorCode::FieldNotSpecified { field: Some("account_id".to_string()) },
+        );
+        assert_eq!(
+            parse(404, "METHOD_NOT_FOUND", None),
+            ApiErrorCode::NotFound { field: None },
+        );
+        assert_eq!(parse(405, "METHOD_DISABLED", None), ApiErrorCode::MethodDisabled);
+        assert_eq!(parse(407, "REQUEST_LIMIT_EXCEEDED", None), ApiErrorCode::RequestLimitExceeded);
+        assert_eq!(parse(407, "APPLICATION_IS_BLOCKED", None), ApiErrorCode::InvalidApplication);
+        assert_eq!(parse(407, "INVALID_IP_ADDRESS", None), ApiErrorCode::InvalidApplication);
+        assert_eq!(
+            parse(407, "ACCOUNT_ID_LIST_LIMIT_EXCEEDED", Some("account_id")),
+            ApiErrorCode::InvalidRequest { field: Some("account_id".to_string()) },
+        );
+        assert_eq!(parse(504, "SOURCE_NOT_AVAILABLE", None), ApiErrorCode::SourceNotAvailable);
+        assert_eq!(parse(500, "WHATEVER", None), ApiErrorCode::Other(500));
+
+        assert!(ApiErrorCode::RequestLimitExceeded.is_retryable());
+        assert!(ApiErrorCode::SourceNotAvailable.is_retryable());
+        assert!(!ApiErrorCode::InvalidApplication.is_retryable());
+        assert_eq!(ApiErrorCode::Other(500).number(), 500);
+        assert_eq!(ApiErrorCode::InvalidRequest { field: None }.number(), 407);
+    }
+}
@@ -0,0 +1,144 @@
+//! Lenient deserialization of the API responses.
+//!
+//! The API occasionally returns items, which do not match the schema.
+//! Instead of failing the entire response, such items get skipped with a warning.
+
+use std::any::type_name;
+
+use sentry::{add_breadcrumb, Breadcrumb, Level};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+use crate::prelude::*;
+
+/// Deserialized item, or [`None`] if the item does not match the schema.
+pub struct Lenient<T>(pub Option<T>);
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Lenient<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        match T::deserialize(&value) {
+            Ok(item) => Ok(Self(Some(item))),
+            Err(error) => {
+                report_mismatch::<T>(&error, &value);
+                Ok(Self(None))
+            }
+        }
+    }
+}
+
+impl<T> Lenient<T> {
+    /// Drops the items, which do not match the schema.
+    pub fn flatten(items: impl IntoIterator<Item = Self>) -> impl Iterator<Item = T> {
+        items.into_iter().filter_map(|item| item.0)
+    }
+}
+
+/// Leaves the breadcrumb with the offending value and logs the warning.
+fn report_mismatch<T>(error: &serde_json::Error, value: &Value) {
+    let type_name = type_name::<T>();
+    add_breadcrumb(Breadcrumb {
+        category: Some("wargaming.schema".to_string()),
+        message: Some(format!("{type_name}: {error}")),
+        level: Level::Warning,
+        data: [("value".to_string(), value.clone())].into_iter().collect(),
+        ..Default::default()
+    });
+    warn!(type_name, "skipping the item which does not match the schema: {:#}", error);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rand::prelude::*;
+
+    use super::*;
+    use crate::wargaming::{AccountInfo, TankStats, Vehicle};
+
+    const TANKS_STATS: &str = include_str!("fixtures/tanks_stats.json");
+
+    const ACCOUNT_INFO: &str = include_str!("fixtures/account_info.json");
+
+    const TANKOPEDIA: &str = include_str!("fixtures/tankopedia.json");
+
+    #[test]
+    fn tanks_stats_golden_ok() -> Result {
+        let mut map: HashMap<String, Vec<Lenient<TankStats>>> = serde_json::from_str(TANKS_STATS)?;
+        let tanks_stats: Vec<TankStats> =
+            Lenient::flatten(map.remove("5589968").unwrap()).collect();
+
+        // The third one has no last battle time.
+        assert_eq!(tanks_stats.len(), 2);
+        assert_eq!(tanks_stats[0].tank_id, 2);
+        assert_eq!(tanks_stats[0].all.n_battles, 20);
+        assert_eq!(tanks_stats[1].tank_id, 1);
+
+        // Nulls and missing statistics are zeros.
+        assert_eq!(tanks_stats[1].all.n_battles, 3);
+        assert_eq!(tanks_stats[1].all.spotted, 0);
+        assert_eq!(tanks_stats[1].all.xp, 0);
+        assert_eq!(tanks_stats[1].all.damage_blocked, 0);
+        assert_eq!(tanks_stats[1].all.damage_assisted, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn account_info_golden_ok() -> Result {
+        let map: HashMap<String, Option<Lenient<AccountInfo>>> =
+            serde_json::from_str(ACCOUNT_INFO)?;
+        let info = map["5589968"].as_ref().unwrap().0.as_ref().unwrap();
+        assert_eq!(info.nickname, "eigenein");
+        assert_eq!(info.stats.rating.mm_rating.0, 0.0);
+        assert_eq!(info.stats.rating.current_season, 0);
+        assert!(map["1"].is_none());
+        assert!(map["2"].as_ref().unwrap().0.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn tankopedia_golden_ok() -> Result {
+        let map: HashMap<String, Lenient<Vehicle>> = serde_json::from_str(TANKOPEDIA)?;
+        let vehicle = map["1"].0.as_ref().unwrap();
+        assert_eq!(vehicle.nation, crate::wargaming::Nation::Other);
+        assert_eq!(vehicle.type_, crate::wargaming::TankType::Unknown);
+        assert!(map["2"].0.is_none());
+        Ok(())
+    }
+
+    /// Randomly corrupts the fixture and checks that the response as a whole still parses.
+    #[test]
+    fn tanks_stats_corrupted_ok() -> Result {
+        let original: Value = serde_json::from_str(TANKS_STATS)?;
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let mut value = original.clone();
+            for _ in 0..rng.gen_range(1..=3) {
+                corrupt(&mut value, &mut rng);
+            }
+            let map: HashMap<String, Vec<Lenient<TankStats>>> = serde_json::from_value(value)?;
+            let n_parsed = map["5589968"].iter().filter(|item| item.0.is_some()).count();
+            assert!(n_parsed <= 3);
+        }
+        Ok(())
+    }
+
+    /// Replaces, removes, or nullifies a random field of a random tank.
+    fn corrupt(value: &mut Value, rng: &mut StdRng) {
+        let items = value["5589968"].as_array_mut().unwrap();
+        let item = items.choose_mut(rng).unwrap().as_object_mut().unwrap();
+        let key = item.keys().choose(rng).unwrap().clone();
+        match rng.gen_range(0..3) {
+            0 => {
+                item.remove(&key);
+            }
+            1 => {
+                item.insert(key, Value::Null);
+            }
+            _ => {
+                item.insert(key, Value::from("surprise"));
+            }
+        }
+    }
+}
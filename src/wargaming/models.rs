@@ -2,6 +2,9 @@ use serde::Deserialize;
 
 pub use self::account_id::*;
 pub use self::account_info::*;
+pub use self::application_id::*;
+pub use self::clan::*;
+pub use self::leaderboard::*;
 pub use self::mm_rating::*;
 pub use self::nation::*;
 pub use self::realm::*;
@@ -13,6 +16,9 @@ pub use self::vehicle::*;
 
 pub mod account_id;
 pub mod account_info;
+pub mod application_id;
+pub mod clan;
+pub mod leaderboard;
 pub mod mm_rating;
 pub mod nation;
 pub mod realm;
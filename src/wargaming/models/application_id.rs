@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+use crate::prelude::*;
+use crate::wargaming::Realm;
+
+/// Wargaming.net application IDs, which may differ per realm because of the request quotas.
+///
+/// Parsed from a comma-separated list of `realm=application_id` pairs,
+/// optionally including a plain application ID for the rest of the realms.
+/// For example: `default_id,ru=russian_id,asia=asian_id`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ApplicationIds {
+    default: Option<String>,
+    per_realm: HashMap<Realm, String>,
+}
+
+impl FromStr for ApplicationIds {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let mut this = Self::default();
+        for part in value.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+            match part.split_once('=') {
+                Some((realm, application_id)) => {
+                    let realm = Realm::from_str(realm.trim())?;
+                    if this.per_realm.insert(realm, application_id.trim().to_string()).is_some() {
+                        bail!("duplicate application ID for `{}`", realm);
+                    }
+                }
+                None if this.default.is_none() => {
+                    this.default = Some(part.to_string());
+                }
+                None => bail!("duplicate default application ID"),
+            }
+        }
+        if this.default.is_none() && this.per_realm.is_empty() {
+            bail!("no application ID specified");
+        }
+        Ok(this)
+    }
+}
+
+impl ApplicationIds {
+    /// Iterates over the distinct application IDs.
+    pub fn all(&self) -> impl Iterator<Item = &str> {
+        self.default
+            .iter()
+            .chain(self.per_realm.values())
+            .map(String::as_str)
+            .sorted()
+            .dedup()
+    }
+
+    /// Lists the realms, which the application ID is used for.
+    pub fn realms_of(&self, application_id: &str) -> Vec<Realm> {
+        Realm::ALL
+            .into_iter()
+            .filter(|realm| self.get(*realm).is_ok_and(|id| id == application_id))
+            .collect()
+    }
+
+    /// Picks the realm's application ID, falling back to the default one.
+    pub fn get(&self, realm: Realm) -> Result<&str> {
+        self.per_realm
+            .get(&realm)
+            .or(self.default.as_ref())
+            .map(String::as_str)
+            .ok_or_else(|| anyhow!("no application ID for `{}`", realm))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_ok() -> Result {
+        let application_ids = ApplicationIds::from_str("demo")?;
+        assert_eq!(application_ids.get(Realm::Russia)?, "demo");
+        assert_eq!(application_ids.get(Realm::Asia)?, "demo");
+        Ok(())
+    }
+
+    #[test]
+    fn per_realm_ok() -> Result {
+        let application_ids = ApplicationIds::from_str("demo, ru=russia,asia=asia")?;
+        assert_eq!(application_ids.get(Realm::Russia)?, "russia");
+        assert_eq!(application_ids.get(Realm::Asia)?, "asia");
+        assert_eq!(application_ids.get(Realm::Europe)?, "demo");
+        assert_eq!(application_ids.all().collect_vec(), ["asia", "demo", "russia"]);
+        assert_eq!(application_ids.realms_of("demo"), [Realm::Europe, Realm::NorthAmerica]);
+        assert_eq!(application_ids.realms_of("russia"), [Realm::Russia]);
+        Ok(())
+    }
+
+    #[test]
+    fn missing_realm_error() -> Result {
+        let application_ids = ApplicationIds::from_str("ru=russia")?;
+        assert!(application_ids.get(Realm::Europe).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_error() {
+        assert!(ApplicationIds::from_str("").is_err());
+        assert!(ApplicationIds::from_str("a,b").is_err());
+        assert!(ApplicationIds::from_str("xx=a").is_err());
+        assert!(ApplicationIds::from_str("ru=a,ru=b").is_err());
+    }
+}
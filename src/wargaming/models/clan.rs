@@ -0,0 +1,36 @@
+use serde::Deserialize;
+
+use crate::wargaming::AccountId;
+
+pub type ClanId = u32;
+
+/// Clan list item.
+#[derive(Deserialize, Debug, Eq, PartialEq)]
+pub struct FoundClan {
+    #[serde(rename = "clan_id")]
+    pub id: ClanId,
+}
+
+/// Clan details with the member list.
+#[derive(Deserialize, Debug, Eq, PartialEq)]
+pub struct ClanInfo {
+    #[serde(rename = "clan_id")]
+    pub id: ClanId,
+
+    #[serde(default)]
+    pub members_ids: Vec<AccountId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn clan_info_ok() -> Result {
+        // language=JSON
+        let info: ClanInfo = serde_json::from_str(r#"{"clan_id":42,"members_ids":[1,2]}"#)?;
+        assert_eq!(info.members_ids, [1, 2]);
+        Ok(())
+    }
+}
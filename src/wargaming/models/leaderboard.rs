@@ -0,0 +1,35 @@
+use serde::Deserialize;
+
+use crate::wargaming::{AccountId, Lenient};
+
+/// Top of a rating league as the game website shows it.
+#[derive(Deserialize)]
+pub struct Leaderboard {
+    #[serde(default)]
+    pub results: Vec<Lenient<LeaderboardEntry>>,
+}
+
+#[derive(Deserialize, Debug, Eq, PartialEq)]
+pub struct LeaderboardEntry {
+    #[serde(rename = "spa_id")]
+    pub account_id: AccountId,
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn leaderboard_ok() -> Result {
+        let leaderboard: Leaderboard = serde_json::from_str(
+            // language=JSON
+            r#"{"results":[{"spa_id":42,"nickname":"Player","score":5400,"number":1},{"number":2}]}"#,
+        )?;
+        let entries = Lenient::flatten(leaderboard.results).collect_vec();
+        assert_eq!(entries, [LeaderboardEntry { account_id: 42 }]);
+        Ok(())
+    }
+}
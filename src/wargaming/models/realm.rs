@@ -1,20 +1,33 @@
 use std::fmt;
+use std::str::FromStr;
 
+use poem_openapi::Enum;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+use crate::prelude::*;
+
+#[derive(Serialize, Deserialize, Enum, Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Realm {
     #[serde(rename = "ru")]
+    #[oai(rename = "ru")]
     Russia,
 
     #[serde(rename = "eu")]
+    #[oai(rename = "eu")]
     Europe,
 
     #[serde(rename = "na")]
+    #[oai(rename = "na")]
     NorthAmerica,
 
     #[serde(rename = "asia")]
+    #[oai(rename = "asia")]
     Asia,
+
+    /// Not served by the Wargaming.net API, so the accounts can't be crawled yet.
+    #[serde(rename = "cn")]
+    #[oai(rename = "cn")]
+    China,
 }
 
 impl fmt::Display for Realm {
@@ -23,13 +36,32 @@ impl fmt::Display for Realm {
     }
 }
 
+impl FromStr for Realm {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "asia" => Ok(Self::Asia),
+            "cn" => Ok(Self::China),
+            "eu" => Ok(Self::Europe),
+            "na" => Ok(Self::NorthAmerica),
+            "ru" => Ok(Self::Russia),
+            _ => Err(anyhow!("unknown realm `{}`", value)),
+        }
+    }
+}
+
 impl Realm {
+    /// Realms served by the Wargaming.net API.
+    pub const ALL: [Self; 4] = [Self::Russia, Self::Europe, Self::NorthAmerica, Self::Asia];
+
     /// Converts the realm to string.
     /// I would've just called `bson::to_bson`, but this is faster and infallible.
     #[inline]
     pub const fn to_str(self) -> &'static str {
         match self {
             Self::Asia => "asia",
+            Self::China => "cn",
             Self::Europe => "eu",
             Self::NorthAmerica => "na",
             Self::Russia => "ru",
@@ -39,10 +71,72 @@ impl Realm {
     #[inline]
     pub const fn to_emoji(self) -> &'static str {
         match self {
-            Self::Asia => "🇨🇳",
+            Self::Asia => "🌏",
+            Self::China => "🇨🇳",
             Self::Europe => "🇪🇺",
             Self::NorthAmerica => "🇺🇸",
             Self::Russia => "🇷🇺",
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use poem_openapi::types::{ParseFromJSON, ParseFromParameter, ToJSON};
+
+    use super::*;
+
+    /// All the variants, including the ones not served by the API.
+    const VARIANTS: [Realm; 5] =
+        [Realm::Russia, Realm::Europe, Realm::NorthAmerica, Realm::Asia, Realm::China];
+
+    #[test]
+    fn from_str_ok() -> Result {
+        for realm in VARIANTS {
+            assert_eq!(Realm::from_str(realm.to_str())?, realm);
+            assert_eq!(realm.to_string(), realm.to_str());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn serde_round_trip_ok() -> Result {
+        for realm in VARIANTS {
+            let json = serde_json::to_string(&realm)?;
+            assert_eq!(json, format!(r#""{}""#, realm.to_str()));
+            assert_eq!(serde_json::from_str::<Realm>(&json)?, realm);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn oai_round_trip_ok() {
+        for realm in VARIANTS {
+            let json = realm.to_json();
+            assert_eq!(json, Some(serde_json::Value::from(realm.to_str())));
+            assert_eq!(Realm::parse_from_json(json).ok(), Some(realm));
+            assert_eq!(Realm::parse_from_parameter(realm.to_str()).ok(), Some(realm));
+        }
+    }
+
+    #[test]
+    fn to_emoji_ok() {
+        assert_eq!(Realm::China.to_emoji(), "🇨🇳");
+        let emojis: HashSet<&str> = VARIANTS.into_iter().map(Realm::to_emoji).collect();
+        assert_eq!(emojis.len(), VARIANTS.len());
+    }
+
+    #[test]
+    fn serialize_china_ok() -> Result {
+        assert_eq!(serde_json::to_string(&Realm::China)?, r#""cn""#);
+        assert_eq!(serde_json::from_str::<Realm>(r#""cn""#)?, Realm::China);
+        Ok(())
+    }
+
+    #[test]
+    fn from_str_unknown_error() {
+        assert!(Realm::from_str("xx").is_err());
+    }
+}
@@ -1,29 +1,60 @@
 use std::ops::Sub;
 
 use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DefaultOnNull};
 
 use crate::database;
 use crate::math::traits::{DamageDealt, DamageReceived, NBattles, NWins};
 use crate::wargaming::MmRating;
 
+/// Missing and `null` values are treated as zeros.
 #[must_use]
+#[serde_as]
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default, Copy)]
+#[serde(default)]
 pub struct BasicStats {
     #[serde(rename = "battles")]
+    #[serde_as(as = "DefaultOnNull")]
     pub n_battles: u32,
 
     #[serde(rename = "wins")]
+    #[serde_as(as = "DefaultOnNull")]
     pub n_wins: u32,
 
+    #[serde_as(as = "DefaultOnNull")]
     pub survived_battles: u32,
+
+    #[serde_as(as = "DefaultOnNull")]
     pub win_and_survived: u32,
+
+    #[serde_as(as = "DefaultOnNull")]
     pub damage_dealt: u64,
+
+    #[serde_as(as = "DefaultOnNull")]
     pub damage_received: u64,
+
+    #[serde_as(as = "DefaultOnNull")]
     pub shots: u32,
+
+    #[serde_as(as = "DefaultOnNull")]
     pub hits: u32,
+
+    #[serde_as(as = "DefaultOnNull")]
     pub frags: u32,
+
+    #[serde_as(as = "DefaultOnNull")]
     pub xp: u64,
+
+    #[serde_as(as = "DefaultOnNull")]
     pub spotted: u32,
+
+    /// Not provided by every API endpoint, hence zero unless present.
+    #[serde_as(as = "DefaultOnNull")]
+    pub damage_blocked: u64,
+
+    /// Not provided by every API endpoint, hence zero unless present.
+    #[serde_as(as = "DefaultOnNull")]
+    pub damage_assisted: u64,
 }
 
 impl From<&database::RandomStatsSnapshot> for BasicStats {
@@ -40,6 +71,25 @@ impl From<&database::RandomStatsSnapshot> for BasicStats {
             frags: snapshot.n_frags,
             xp: snapshot.xp,
             spotted: snapshot.n_spotted,
+            damage_blocked: snapshot.damage_blocked,
+            damage_assisted: snapshot.damage_assisted,
+        }
+    }
+}
+
+impl From<&database::RatingStatsSnapshot> for RatingStats {
+    /// The snapshot keeps only a few of the rating statistics, the rest are zeroed.
+    fn from(snapshot: &database::RatingStatsSnapshot) -> Self {
+        Self {
+            basic: BasicStats {
+                n_battles: snapshot.n_battles,
+                n_wins: snapshot.n_wins,
+                damage_dealt: snapshot.damage_dealt,
+                damage_received: snapshot.damage_received,
+                ..Default::default()
+            },
+            mm_rating: snapshot.mm_rating,
+            current_season: snapshot.current_season,
         }
     }
 }
@@ -60,6 +110,9 @@ impl Sub<database::RandomStatsSnapshot> for BasicStats {
             n_frags: self.frags - rhs.n_frags,
             xp: self.xp - rhs.xp,
             n_spotted: self.spotted - rhs.n_spotted,
+            // These may disappear from the API response, unlike the rest.
+            damage_blocked: self.damage_blocked.saturating_sub(rhs.damage_blocked),
+            damage_assisted: self.damage_assisted.saturating_sub(rhs.damage_assisted),
         }
     }
 }
@@ -88,13 +141,17 @@ impl DamageReceived for BasicStats {
     }
 }
 
+#[serde_as]
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default, Copy)]
+#[serde(default)]
 pub struct RatingStats {
     #[serde(flatten)]
     pub basic: BasicStats,
 
-    #[serde(default)]
+    #[serde_as(as = "DefaultOnNull")]
     pub mm_rating: MmRating,
 
+    /// `null` for the accounts, which have never played a rating battle.
+    #[serde_as(as = "DefaultOnNull")]
     pub current_season: u16,
 }
@@ -11,6 +11,15 @@ pub struct TankAchievements {
     pub max_series: HashMap<String, i32>,
 }
 
+impl TankAchievements {
+    /// Number of the Ace Tanker badges.
+    pub fn n_masteries(&self) -> u32 {
+        self.achievements
+            .get("markOfMastery")
+            .map_or(0, |n| (*n).max(0) as u32)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,12 +2,13 @@ use std::borrow::Cow;
 
 use serde::{Deserialize, Serialize};
 
+use crate::helpers::serde::is_default;
 use crate::wargaming::models::{Nation, TankId};
 
 pub type Tier = u8;
 
 /// Represents a generic vehicle from the tankopedia.
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 pub struct Vehicle {
     pub tank_id: TankId,
     pub name: Cow<'static, str>,
@@ -17,6 +18,11 @@ pub struct Vehicle {
 
     #[serde(rename = "type")]
     pub type_: TankType,
+
+    /// Premium vehicle, which is only available in the collector's section.
+    /// Comes from the vehicle attributes, the API does not expose it.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub is_collectible: bool,
 }
 
 impl Vehicle {
@@ -29,6 +35,7 @@ impl Vehicle {
             is_premium: false,
             type_: TankType::Unknown,
             nation: Nation::from_tank_id(tank_id).unwrap_or(Nation::Other),
+            is_collectible: false,
         }
     }
 }
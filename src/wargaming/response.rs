@@ -1,5 +1,7 @@
 use serde::Deserialize;
 
+use crate::wargaming::ApiError;
+
 #[derive(Deserialize, Debug)]
 #[serde(untagged)]
 pub enum Response<T> {
@@ -7,6 +9,15 @@ pub enum Response<T> {
     Error { error: Error },
 }
 
+impl<T> Response<T> {
+    pub fn into_result(self) -> Result<T, ApiError> {
+        match self {
+            Self::Data { data } => Ok(data),
+            Self::Error { error } => Err(ApiError::from(error)),
+        }
+    }
+}
+
 #[derive(Deserialize, Debug)]
 pub struct Error {
     #[serde(default)]
@@ -14,6 +25,10 @@ pub struct Error {
 
     #[serde(default)]
     pub code: i32,
+
+    /// Offending request parameter, if any.
+    #[serde(default)]
+    pub field: Option<String>,
 }
 
 #[cfg(test)]
@@ -42,7 +57,9 @@ mod tests {
         match response {
             Response::Data { .. } => unreachable!(),
             Response::Error { error } => {
-                assert_eq!(error.message, "NOT_ENOUGH_SEARCH_LENGTH")
+                assert_eq!(error.message, "NOT_ENOUGH_SEARCH_LENGTH");
+                assert_eq!(error.code, 407);
+                assert_eq!(error.field.as_deref(), Some("search"));
             }
         }
         Ok(())
@@ -0,0 +1,56 @@
+//! Per-endpoint request timeouts, since the endpoints differ a lot in their response times.
+
+use crate::prelude::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ApiTimeouts {
+    /// Also used to connect.
+    pub default: time::Duration,
+
+    /// `account/list` is slow, especially on the short queries.
+    pub search_accounts: Option<time::Duration>,
+
+    /// `tanks/stats` is heavy on the accounts with many tanks.
+    pub tanks_stats: Option<time::Duration>,
+}
+
+impl From<time::Duration> for ApiTimeouts {
+    fn from(default: time::Duration) -> Self {
+        Self {
+            default,
+            search_accounts: None,
+            tanks_stats: None,
+        }
+    }
+}
+
+impl ApiTimeouts {
+    /// Effective timeout for the endpoint path.
+    pub fn for_path(&self, path: &str) -> time::Duration {
+        let timeout = if path.ends_with("/account/list/") {
+            self.search_accounts
+        } else if path.ends_with("/tanks/stats/") {
+            self.tanks_stats
+        } else {
+            None
+        };
+        timeout.unwrap_or(self.default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn for_path_ok() {
+        let timeouts = ApiTimeouts {
+            default: time::Duration::from_secs(10),
+            search_accounts: Some(time::Duration::from_secs(20)),
+            tanks_stats: None,
+        };
+        assert_eq!(timeouts.for_path("/wotb/account/list/"), time::Duration::from_secs(20));
+        assert_eq!(timeouts.for_path("/wotb/tanks/stats/"), time::Duration::from_secs(10));
+        assert_eq!(timeouts.for_path("/wotb/account/info/"), time::Duration::from_secs(10));
+    }
+}
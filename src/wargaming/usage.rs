@@ -0,0 +1,106 @@
+//! Daily request accounting per application ID, which is shared by the crawlers
+//! and the web application via Redis.
+
+use std::num::NonZeroU32;
+
+use fred::pool::RedisPool;
+use fred::prelude::*;
+use serde::Serialize;
+
+use crate::prelude::*;
+use crate::wargaming::{ApplicationIds, Realm};
+
+/// The day buckets are kept for a week, so that the recent days could be looked up.
+const EXPIRE: i64 = 7 * 86400;
+
+/// The requests get slowed down after this share of the daily budget.
+const SLOWDOWN_THRESHOLD: f64 = 0.9;
+
+/// Delay before each request, which has exceeded the daily budget.
+const MAX_DELAY: time::Duration = time::Duration::from_secs(1);
+
+#[derive(Clone)]
+pub struct ApiUsage {
+    redis: RedisPool,
+
+    /// Soft daily request budget per application ID.
+    daily_budget: Option<NonZeroU32>,
+}
+
+/// Number of the requests made today with the application ID.
+#[derive(Serialize)]
+pub struct DailyUsage {
+    /// Realms, which use the application ID, so that the ID itself isn't exposed.
+    pub realms: Vec<Realm>,
+
+    pub n_requests: u64,
+
+    pub daily_budget: Option<NonZeroU32>,
+}
+
+impl ApiUsage {
+    pub const fn new(redis: RedisPool, daily_budget: Option<NonZeroU32>) -> Self {
+        Self {
+            redis,
+            daily_budget,
+        }
+    }
+
+    /// Counts the request against today's bucket.
+    ///
+    /// # Returns
+    ///
+    /// The delay to slow down with, when the daily budget is nearly exhausted.
+    #[instrument(skip_all, level = "trace")]
+    pub async fn record(&self, application_id: &str) -> Result<Option<time::Duration>> {
+        let key = Self::key(application_id, now());
+        let n_requests: u64 = self.redis.incr(&key).await?;
+        if n_requests == 1 {
+            self.redis.expire::<(), _>(&key, EXPIRE).await?;
+        }
+        Ok(self.daily_budget.and_then(|daily_budget| slowdown(n_requests, daily_budget)))
+    }
+
+    /// Retrieves today's usage of the application IDs.
+    #[instrument(skip_all, level = "debug")]
+    pub async fn retrieve_today(
+        &self,
+        application_ids: &ApplicationIds,
+    ) -> Result<Vec<DailyUsage>> {
+        let today = now();
+        let mut usages = Vec::new();
+        for application_id in application_ids.all() {
+            let n_requests: Option<u64> = self.redis.get(Self::key(application_id, today)).await?;
+            usages.push(DailyUsage {
+                realms: application_ids.realms_of(application_id),
+                n_requests: n_requests.unwrap_or_default(),
+                daily_budget: self.daily_budget,
+            });
+        }
+        Ok(usages)
+    }
+
+    fn key(application_id: &str, date: DateTime) -> String {
+        format!("wargaming:usage:{}:{}", application_id, date.format("%Y-%m-%d"))
+    }
+}
+
+/// Linearly increases the delay from the slowdown threshold up to the budget.
+fn slowdown(n_requests: u64, daily_budget: NonZeroU32) -> Option<time::Duration> {
+    let share = n_requests as f64 / daily_budget.get() as f64;
+    let factor = (share - SLOWDOWN_THRESHOLD) / (1.0 - SLOWDOWN_THRESHOLD);
+    (factor > 0.0).then(|| MAX_DELAY.mul_f64(factor.min(1.0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slowdown_ok() {
+        let daily_budget = NonZeroU32::new(1000).unwrap();
+        assert_eq!(slowdown(900, daily_budget), None);
+        assert_eq!(slowdown(950, daily_budget), Some(MAX_DELAY / 2));
+        assert_eq!(slowdown(2000, daily_budget), Some(MAX_DELAY));
+    }
+}
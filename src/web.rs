@@ -1,40 +1,78 @@
-use std::net::IpAddr;
-use std::str::FromStr;
 use std::time;
 
-use poem::listener::TcpListener;
 use poem::middleware::{CatchPanic, CookieJarManager, Tracing};
-use poem::{get, Endpoint, EndpointExt, Route, Server};
+use poem::web::cookie::CookieKey;
+use poem::{get, post, Endpoint, EndpointExt, Route, Server};
 use views::r#static;
 
+use crate::crawler::fast_lane::FastLane;
 use crate::helpers::redis;
 use crate::opts::WebOpts;
 use crate::prelude::*;
+use crate::tankopedia::cache::HistoricalTankopediaCache;
 use crate::wargaming::cache::account::{AccountInfoCache, AccountTanksCache};
+use crate::wargaming::usage::ApiUsage;
 use crate::wargaming::WargamingApi;
+use crate::web::asn::AsnDatabase;
 use crate::web::middleware::timeit::TimeItMiddleware;
-use crate::web::middleware::{ErrorMiddleware, SecurityHeadersMiddleware, SentryMiddleware};
+use crate::web::middleware::{
+    BlocklistMiddleware, ErrorMiddleware, QueryBudgetMiddleware, SecurityHeadersMiddleware,
+    SentryMiddleware, TurnstileMiddleware,
+};
+use crate::web::privacy::SmallCounts;
+use crate::web::rate_limit::RateLimiter;
+use crate::web::realm_host::BaseDomain;
 use crate::web::tracking_code::TrackingCode;
+use crate::web::turnstile::Turnstile;
 
+mod asn;
+pub mod bind;
+pub mod blocklist;
 mod cookies;
-mod i18n;
+mod coverage;
+mod error;
+pub mod i18n;
 pub mod middleware;
 mod partials;
+mod privacy;
+mod rate_limit;
+mod realm_host;
+mod search_bot;
+mod server_status;
 
 #[cfg(test)]
 mod test;
 
 mod tracking_code;
+mod turnstile;
+mod viewer_id;
 mod views;
+mod warm_up;
 
 /// Run the web app.
 pub async fn run(opts: WebOpts) -> Result {
     sentry::configure_scope(|scope| scope.set_tag("app", "web"));
-    info!(host = opts.host.as_str(), port = opts.port, "starting up…");
+    let bind_addresses = opts.bind_addresses()?;
+    info!(?bind_addresses, "starting up…");
 
     let app_data = AppData::initialize_from_opts(&opts).await?;
+    partials::pre_render_vehicle_titles();
+    tokio::spawn(server_status::run_monitor(app_data.api.clone(), app_data.redis.clone()));
+    tokio::spawn(coverage::run_updater(app_data.mongodb.clone(), app_data.redis.clone()));
+    tokio::spawn(views::player::records::run_updater(
+        app_data.mongodb.clone(),
+        app_data.redis.clone(),
+    ));
+    if opts.warm_up_accounts != 0 {
+        tokio::spawn(warm_up::run(
+            app_data.redis.clone(),
+            app_data.info_cache.clone(),
+            app_data.tanks_cache.clone(),
+            opts.warm_up_accounts,
+        ));
+    }
     let app = create_app(app_data).await?;
-    Server::new(TcpListener::bind((IpAddr::from_str(&opts.host)?, opts.port)))
+    Server::new(bind::listen(bind_addresses)?)
         .run_with_graceful_shutdown(
             app,
             async move {
@@ -51,48 +89,101 @@ struct AppData {
     api: WargamingApi,
     mongodb: mongodb::Database,
     redis: fred::pool::RedisPool,
+    info_cache: AccountInfoCache,
+    tanks_cache: AccountTanksCache,
+    tankopedia_cache: HistoricalTankopediaCache,
     tracking_code: TrackingCode,
+    base_domain: BaseDomain,
+    turnstile: Option<Turnstile>,
+    fast_lane: FastLane,
+    rate_limiter: RateLimiter,
+    cookie_key: CookieKey,
+    query_budget: QueryBudgetMiddleware,
+    small_counts: SmallCounts,
+    asn_database: Option<AsnDatabase>,
 }
 
 impl AppData {
     async fn initialize_from_opts(opts: &WebOpts) -> Result<Self> {
         let connections = &opts.connections;
 
-        let api = WargamingApi::new(
-            &connections.application_id,
-            connections.api_timeout,
-            connections.max_api_rps,
-        )?;
         let mongodb = crate::database::mongodb::open(&connections.internal.mongodb_uri).await?;
         let redis =
             redis::connect(&connections.internal.redis_uri, connections.internal.redis_pool_size)
                 .await?;
+        let api = WargamingApi::new(
+            connections.application_ids.clone(),
+            connections.api_timeouts(),
+            connections.max_api_rps,
+        )?
+        .with_usage(ApiUsage::new(redis.clone(), connections.api_daily_budget));
+        let info_cache = AccountInfoCache::new(api.clone(), redis.clone());
+        let tanks_cache = AccountTanksCache::new(api.clone(), redis.clone());
         let tracking_code = TrackingCode::new(opts)?;
+        let base_domain = BaseDomain(opts.base_domain.clone());
+        let turnstile = Turnstile::new(opts)?;
+        let fast_lane = FastLane::new(redis.clone(), opts.fast_lane_throttle);
+        let rate_limiter = RateLimiter::new(redis.clone(), opts.api_rate_limit);
+        let cookie_key = match &opts.cookie_secret {
+            Some(secret) if secret.len() < 32 => bail!("the cookie secret is too short"),
+            Some(secret) => CookieKey::derive_from(secret.as_bytes()),
+            None => {
+                warn!("no cookie secret specified, the signed cookies will reset on restart");
+                CookieKey::generate()
+            }
+        };
+
+        let asn_database = opts.asn_database.as_deref().map(AsnDatabase::read).transpose()?;
+
+        let query_budget = QueryBudgetMiddleware {
+            max_commands: opts.query_budget_commands,
+            max_time: opts.query_budget_time,
+        };
 
         Ok(Self {
             api,
             mongodb,
             redis,
+            info_cache,
+            tanks_cache,
+            tankopedia_cache: HistoricalTankopediaCache::default(),
             tracking_code,
+            base_domain,
+            turnstile,
+            fast_lane,
+            rate_limiter,
+            cookie_key,
+            query_budget,
+            small_counts: SmallCounts::from(opts),
+            asn_database,
         })
     }
 }
 
 #[instrument(skip_all)]
 async fn create_app(data: AppData) -> Result<impl Endpoint> {
-    let app = create_standalone_app()
+    let app = create_standalone_app(data.cookie_key)
         .await?
         .data(data.mongodb)
         .data(data.tracking_code)
-        .data(AccountInfoCache::new(data.api.clone(), data.redis.clone()))
-        .data(AccountTanksCache::new(data.api.clone(), data.redis.clone()))
+        .data(data.base_domain)
+        .data(data.turnstile)
+        .data(data.fast_lane)
+        .data(data.rate_limiter)
+        .data(data.info_cache)
+        .data(data.tanks_cache)
+        .data(data.tankopedia_cache)
         .data(data.redis)
-        .data(data.api);
+        .data(data.api)
+        .data(data.small_counts)
+        .data(data.asn_database)
+        .with(data.query_budget);
     Ok(app)
 }
 
 #[instrument(skip_all)]
-async fn create_standalone_app() -> Result<impl Endpoint> {
+async fn create_standalone_app(cookie_key: CookieKey) -> Result<impl Endpoint> {
+    let public_api = views::api::PublicApi::service();
     let app = Route::new()
         .at("/site.webmanifest", get(r#static::get_site_manifest))
         .at("/favicon.ico", get(r#static::get_favicon))
@@ -102,8 +193,13 @@ async fn create_standalone_app() -> Result<impl Endpoint> {
         .at("/android-chrome-512x512.png", get(r#static::get_android_chrome_512x512))
         .at("/apple-touch-icon.png", get(r#static::get_apple_touch_icon))
         .at("/static/table.js", get(r#static::get_table_js))
+        .at("/static/:fingerprint/table.js", get(r#static::get_table_js))
         .at("/static/navbar.js", get(r#static::get_navbar_js))
+        .at("/static/:fingerprint/navbar.js", get(r#static::get_navbar_js))
+        .at("/static/:fingerprint/timeline.js", get(r#static::get_timeline_js))
+        .at("/static/:fingerprint/announcements.js", get(r#static::get_announcements_js))
         .at("/static/theme.css", get(r#static::get_theme_css))
+        .at("/static/:fingerprint/theme.css", get(r#static::get_theme_css))
         .at("/robots.txt", get(r#static::get_robots_txt))
         .at("/static/flags/cn.svg", get(r#static::get_cn_svg))
         .at("/static/flags/de.svg", get(r#static::get_de_svg))
@@ -115,22 +211,49 @@ async fn create_standalone_app() -> Result<impl Endpoint> {
         .at("/static/flags/us.svg", get(r#static::get_us_svg))
         .at("/static/flags/xx.svg", get(r#static::get_xx_svg))
         .at("/", get(views::index::get))
-        .at("/search", get(views::search::get))
+        .at("/search", get(views::search::get).with(TurnstileMiddleware))
+        .at("/:realm/group", get(views::group::get).with(TurnstileMiddleware))
+        .at("/same-nickname", get(views::group::get_same_nickname).with(TurnstileMiddleware))
+        .at("/:realm", get(views::realm::get))
+        .at("/:realm/activity", get(views::activity::get))
+        .at("/:realm/:nickname<@[^/]+>", get(views::player::get_by_nickname))
         .at("/:realm/:account_id", get(views::player::get).post(views::player::post))
+        .at("/:realm/:account_id/at/:date", get(views::player::at::get))
+        .at("/:realm/:account_id/overlay", get(views::player::overlay::get))
+        .at("/:realm/:account_id/feed.xml", get(views::player::feed::get))
+        .at("/:realm/:account_id/records", get(views::player::records::get))
+        .at("/:realm/:account_id/linked", get(views::group::get_linked).with(TurnstileMiddleware))
+        .at("/:realm/:account_id/refresh", post(views::player::post_refresh))
+        .at("/:realm/:account_id/vehicle-target", post(views::player::post_vehicle_target))
+        .at("/:realm/:account_id/session-goal", post(views::player::post_session_goal))
+        .at("/:realm/:account_id/grind-target", post(views::player::post_grind_target))
+        .at("/:realm/:account_id/note", post(views::player::post_note))
+        .at("/:account_id<\\d+>", get(views::player::get_by_realm_host))
         .at("/error", get(views::error::get_error))
         .at("/random", get(views::random::get_random))
         .at("/sitemaps/:realm/sitemap.txt", get(views::sitemaps::get_sitemap))
         .at("/api/health", get(views::api::get_health))
+        .at("/api/cache-stats", get(views::api::get_cache_stats))
+        .at("/api/usage", get(views::api::get_usage))
+        .at("/api/status", get(views::status::get_current))
+        .at("/api/announcements", get(views::announcements::get))
+        .at("/status", get(views::status::get))
+        .at("/my/notes", get(views::notes::get))
+        .at("/healthz", get(views::health::get_healthz))
+        .at("/readyz", get(views::health::get_readyz))
+        .at("/api/openapi.json", public_api.spec_endpoint())
+        .nest("/api/v1", public_api)
         .at(
             "/api/:realm/accounts/:since/active-since",
             get(views::api::get_active_since).with(TimeItMiddleware),
         )
-        .data(i18n::build_resources()?)
+        .with(BlocklistMiddleware)
         .with(Tracing)
         .with(CatchPanic::new())
         .with(ErrorMiddleware)
         .with(SecurityHeadersMiddleware)
         .with(SentryMiddleware)
-        .with(CookieJarManager::new());
+        .with(CookieJarManager::with_key(cookie_key))
+        .data(i18n::build_resources()?); // outermost, so that the error pages get localized
     Ok(app)
 }
@@ -0,0 +1,101 @@
+//! IP-to-ASN lookup over a local copy of the [iptoasn.com](https://iptoasn.com/) database,
+//! so that the blocklist may match the whole autonomous systems.
+//!
+//! The database is the `ip2asn-combined.tsv` file: one range per line,
+//! `range_start`, `range_end`, `AS_number`, `country_code`, and `AS_description`, tab-separated.
+
+use std::fs;
+use std::net::IpAddr;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct AsnDatabase(Arc<Vec<Range>>);
+
+#[derive(Debug, PartialEq, Eq)]
+struct Range {
+    start: IpAddr,
+    end: IpAddr,
+    asn: u32,
+}
+
+impl AsnDatabase {
+    #[instrument(skip_all, fields(path = ?path))]
+    pub fn read(path: &Path) -> Result<Self> {
+        let database = fs::read_to_string(path).with_context(|| {
+            format!("failed to read the ASN database from `{}`", path.display())
+        })?;
+        let database = Self::parse(&database)?;
+        info!(n_ranges = database.0.len(), "loaded the ASN database");
+        Ok(database)
+    }
+
+    /// Looks up the autonomous system number, which announces the IP address.
+    pub fn lookup(&self, ip_addr: IpAddr) -> Option<u32> {
+        let index = self.0.partition_point(|range| range.start <= ip_addr);
+        let range = &self.0[index.checked_sub(1)?];
+        (ip_addr <= range.end).then_some(range.asn)
+    }
+
+    fn parse(database: &str) -> Result<Self> {
+        let mut ranges = Vec::new();
+        for (i, line) in database.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let range = Range::parse(line).with_context(|| format!("invalid line #{}", i + 1))?;
+            // Zero is the «not routed» range.
+            if range.asn != 0 {
+                ranges.push(range);
+            }
+        }
+        ranges.sort_unstable_by_key(|range| range.start);
+        Ok(Self(Arc::new(ranges)))
+    }
+}
+
+impl Range {
+    fn parse(line: &str) -> Result<Self> {
+        let mut fields = line.split('\t');
+        let mut next = || fields.next().ok_or_else(|| anyhow!("too few fields"));
+        let start: IpAddr = next()?.parse()?;
+        let end: IpAddr = next()?.parse()?;
+        let asn = next()?.parse()?;
+        if start.is_ipv4() != end.is_ipv4() || start > end {
+            bail!("invalid range {start}–{end}");
+        }
+        Ok(Self { start, end, asn })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_ok() -> Result {
+        let database = AsnDatabase::parse(
+            "1.0.0.0\t1.0.0.255\t13335\tUS\tCLOUDFLARENET\n\
+             1.0.1.0\t1.0.3.255\t0\tNone\tNot routed\n\
+             \n\
+             1.0.4.0\t1.0.7.255\t38803\tAU\tGTELECOM-AUSTRALIA\n\
+             2001:200::\t2001:200:e00:ffff:ffff:ffff:ffff:ffff\t2500\tJP\tWIDE-BB\n",
+        )?;
+        assert_eq!(database.lookup("1.0.0.42".parse()?), Some(13335));
+        assert_eq!(database.lookup("1.0.2.1".parse()?), None);
+        assert_eq!(database.lookup("1.0.7.255".parse()?), Some(38803));
+        assert_eq!(database.lookup("1.0.8.0".parse()?), None);
+        assert_eq!(database.lookup("0.255.255.255".parse()?), None);
+        assert_eq!(database.lookup("2001:200::1".parse()?), Some(2500));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_invalid_range_fails() {
+        assert!(AsnDatabase::parse("1.0.0.255\t1.0.0.0\t13335\tUS\tCLOUDFLARENET").is_err());
+        assert!(AsnDatabase::parse("1.0.0.0\t::1\t13335\tUS\tCLOUDFLARENET").is_err());
+        assert!(AsnDatabase::parse("1.0.0.0\t1.0.0.255").is_err());
+    }
+}
@@ -0,0 +1,82 @@
+//! Addresses, which the web application listens on.
+
+use std::fs;
+use std::io::ErrorKind;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use poem::listener::{BoxListener, Listener, TcpListener, UnixListener};
+
+use crate::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BindAddress {
+    Tcp(SocketAddr),
+
+    /// Unix domain socket path, specified with the `unix:` prefix.
+    Unix(PathBuf),
+}
+
+impl FromStr for BindAddress {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.strip_prefix("unix:") {
+            Some("") => bail!("the socket path is empty"),
+            Some(path) => Ok(Self::Unix(PathBuf::from(path))),
+            None => Ok(Self::Tcp(SocketAddr::from_str(value).with_context(|| {
+                format!("`{value}` is neither `host:port` nor `unix:/path`")
+            })?)),
+        }
+    }
+}
+
+impl BindAddress {
+    fn listener(self) -> Result<BoxListener> {
+        match self {
+            Self::Tcp(address) => Ok(TcpListener::bind(address).boxed()),
+            Self::Unix(path) => {
+                // The previous run leaves the socket behind, and binding to it would fail.
+                match fs::remove_file(&path) {
+                    Err(error) if error.kind() != ErrorKind::NotFound => {
+                        return Err(error).with_context(|| {
+                            format!("failed to remove the stale socket `{}`", path.display())
+                        });
+                    }
+                    _ => {}
+                }
+                Ok(UnixListener::bind(path).boxed())
+            }
+        }
+    }
+}
+
+/// Combines the addresses into the single listener.
+pub fn listen(addresses: Vec<BindAddress>) -> Result<BoxListener> {
+    addresses
+        .into_iter()
+        .map(BindAddress::listener)
+        .reduce(|lhs, rhs| Ok(lhs?.combine(rhs?).boxed()))
+        .ok_or_else(|| anyhow!("at least one bind address is required"))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ok() -> Result {
+        assert_eq!(
+            BindAddress::from_str("[::1]:8081")?,
+            BindAddress::Tcp(SocketAddr::from_str("[::1]:8081")?),
+        );
+        assert_eq!(
+            BindAddress::from_str("unix:/run/blitz-dashboard.sock")?,
+            BindAddress::Unix(PathBuf::from("/run/blitz-dashboard.sock")),
+        );
+        assert!(BindAddress::from_str("unix:").is_err());
+        assert!(BindAddress::from_str("localhost").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+//! Blocklist of the abusive viewers, which may be managed without redeploying.
+//!
+//! The blocked IP addresses and autonomous systems are kept in the Redis sorted set,
+//! scored by their expiry timestamps, so that the expired entries simply stop matching.
+
+use std::fmt::{Display, Formatter};
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use fred::pool::RedisPool;
+use fred::prelude::*;
+
+use crate::prelude::*;
+
+const KEY: &str = "web:blocklist";
+
+/// Score of the entries, which never expire.
+const PERMANENT: f64 = f64::INFINITY;
+
+/// Blocked IP address or autonomous system.
+///
+/// The autonomous systems are stored as `AS{number}`, which never clashes with an IP address.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Target {
+    IpAddr(IpAddr),
+    Asn(u32),
+}
+
+impl Display for Target {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IpAddr(ip_addr) => write!(f, "{ip_addr}"),
+            Self::Asn(asn) => write!(f, "AS{asn}"),
+        }
+    }
+}
+
+impl FromStr for Target {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.strip_prefix("AS").or_else(|| value.strip_prefix("as")) {
+            Some(asn) => Ok(Self::Asn(asn.parse().context("invalid AS number")?)),
+            None => Ok(Self::IpAddr(value.parse().context("invalid IP address")?)),
+        }
+    }
+}
+
+/// Blocked target along with its expiry time, if any.
+pub struct Entry {
+    pub target: String,
+    pub expires_at: Option<DateTime>,
+}
+
+/// Checks the IP address, and its autonomous system, if the latter is known.
+#[instrument(skip_all, level = "debug", fields(ip_addr = ?ip_addr, asn = ?asn))]
+pub async fn is_blocked(redis: &RedisPool, ip_addr: IpAddr, asn: Option<u32>) -> Result<bool> {
+    let now = now().timestamp() as f64;
+    let is_active = |expiry: Option<f64>| expiry.is_some_and(|expiry| expiry > now);
+    if is_active(redis.zscore(KEY, Target::IpAddr(ip_addr).to_string()).await?) {
+        return Ok(true);
+    }
+    match asn {
+        Some(asn) => Ok(is_active(redis.zscore(KEY, Target::Asn(asn).to_string()).await?)),
+        None => Ok(false),
+    }
+}
+
+/// Blocks the target, either for the specified time or permanently.
+#[instrument(skip_all, fields(target = %target, expire = ?expire))]
+pub async fn add(redis: &RedisPool, target: Target, expire: Option<time::Duration>) -> Result {
+    let expiry = match expire {
+        Some(expire) => (now() + Duration::from_std(expire)?).timestamp() as f64,
+        None => PERMANENT,
+    };
+    redis
+        .zadd::<(), _, _>(KEY, None, None, false, false, (expiry, target.to_string()))
+        .await?;
+    Ok(())
+}
+
+/// Unblocks the target.
+///
+/// # Returns
+///
+/// Whether the target has been blocked.
+#[instrument(skip_all, fields(target = %target))]
+pub async fn remove(redis: &RedisPool, target: Target) -> Result<bool> {
+    let n_removed: u32 = redis.zrem(KEY, target.to_string()).await?;
+    Ok(n_removed != 0)
+}
+
+/// Purges the expired entries and lists the active ones.
+#[instrument(skip_all)]
+pub async fn list(redis: &RedisPool) -> Result<Vec<Entry>> {
+    let now = now().timestamp() as f64;
+    redis.zremrangebyscore::<(), _, _, _>(KEY, f64::NEG_INFINITY, now).await?;
+    let entries: Vec<(String, f64)> = redis
+        .zrangebyscore(KEY, now, PERMANENT, true, None)
+        .await?;
+    Ok(entries
+        .into_iter()
+        .map(|(target, expiry)| Entry {
+            target,
+            expires_at: expiry
+                .is_finite()
+                .then(|| Utc.timestamp_opt(expiry as i64, 0).single())
+                .flatten(),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target_round_trip_ok() -> Result {
+        for target in ["192.0.2.1", "2001:db8::1", "AS13335"] {
+            assert_eq!(Target::from_str(target)?.to_string(), target);
+        }
+        assert_eq!(Target::from_str("as13335")?, Target::Asn(13335));
+        assert!(Target::from_str("AS-1").is_err());
+        assert!(Target::from_str("example.com").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+//! Site coverage on the landing pages: the tracked accounts and the battles per hour.
+//!
+//! One of the web replicas periodically updates the aggregates in Redis,
+//! so that the landing page only reads a single key.
+
+use fred::pool::RedisPool;
+use fred::prelude::*;
+use fred::types::Expiration;
+use serde::{Deserialize, Serialize};
+use tokio::time::sleep;
+
+use crate::database::Account;
+use crate::helpers::lock::Lock;
+use crate::prelude::*;
+
+const INTERVAL: time::Duration = time::Duration::from_secs(5 * 60);
+
+const LOCK_TTL: time::Duration = time::Duration::from_secs(60);
+
+const KEY: &str = "web:coverage";
+
+/// Outdated aggregates disappear, should the updates stop.
+const EXPIRE: Option<Expiration> = Some(Expiration::EX(3600));
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Coverage {
+    #[serde(rename = "t")]
+    pub timestamp: i64,
+
+    #[serde(rename = "na")]
+    pub n_accounts: u64,
+
+    /// Estimated from the accounts' activity scores, summed over the realms.
+    #[serde(rename = "nb")]
+    pub n_battles_per_hour: u64,
+
+    /// Exact number of the tracked accounts by realm, for the realm pages.
+    #[serde(rename = "nra", default)]
+    pub n_realm_accounts: AHashMap<wargaming::Realm, u64>,
+}
+
+impl Coverage {
+    const fn is_fresh(&self, now: DateTime) -> bool {
+        now.timestamp() - self.timestamp < INTERVAL.as_secs() as i64
+    }
+}
+
+/// Updates the aggregates forever.
+pub async fn run_updater(mongodb: mongodb::Database, redis: RedisPool) {
+    let lock = Lock::new(redis.clone(), "web:coverage", LOCK_TTL);
+    loop {
+        if let Err(error) = lock.run_exclusively(update(&mongodb, &redis)).await {
+            warn!("failed to update the coverage: {:#}", error);
+        }
+        sleep(INTERVAL).await;
+    }
+}
+
+/// Updates the aggregates, unless another replica has just done that.
+#[instrument(skip_all, level = "debug")]
+async fn update(mongodb: &mongodb::Database, redis: &RedisPool) -> Result {
+    let now = now();
+    if retrieve(redis).await?.is_some_and(|coverage| coverage.is_fresh(now)) {
+        debug!("the coverage is up-to-date");
+        return Ok(());
+    }
+    let mut n_battles_per_hour = 0.0;
+    let mut n_realm_accounts = AHashMap::default();
+    for realm in wargaming::Realm::ALL {
+        n_battles_per_hour += Account::estimate_battles_per_hour(mongodb, realm, now).await?;
+        n_realm_accounts.insert(realm, Account::count(mongodb, realm).await?);
+    }
+    let coverage = Coverage {
+        timestamp: now.timestamp(),
+        n_accounts: Account::estimated_count(mongodb).await?,
+        n_battles_per_hour: n_battles_per_hour.round() as u64,
+        n_realm_accounts,
+    };
+    debug!(?coverage, "updated");
+    redis
+        .set::<(), _, _>(KEY, serde_json::to_string(&coverage)?, EXPIRE, None, false)
+        .await?;
+    Ok(())
+}
+
+#[instrument(skip_all, level = "debug")]
+pub async fn retrieve(redis: &RedisPool) -> Result<Option<Coverage>> {
+    let coverage: Option<String> = redis.get(KEY).await?;
+    coverage
+        .map(|coverage| serde_json::from_str(&coverage).context("failed to parse the coverage"))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_fresh_ok() {
+        let now = Utc.timestamp_opt(1660000000, 0).unwrap();
+        let coverage = Coverage {
+            timestamp: now.timestamp() - 60,
+            n_accounts: 1,
+            n_battles_per_hour: 1,
+            n_realm_accounts: AHashMap::default(),
+        };
+        assert!(coverage.is_fresh(now));
+        assert!(!coverage.is_fresh(now + Duration::hours(1)));
+    }
+
+    #[test]
+    fn parse_without_realms_ok() -> Result {
+        let coverage: Coverage = serde_json::from_str(r#"{"t":1660000000,"na":10,"nb":5}"#)?;
+        assert!(coverage.n_realm_accounts.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+//! Web layer errors, which make the handlers' failure modes explicit.
+
+use std::error::Error as StdError;
+use std::fmt;
+
+use poem::error::{I18NError, ResponseError};
+use poem::http::StatusCode;
+use poem_openapi::registry::{MetaResponses, Registry};
+use poem_openapi::ApiResponse;
+
+use crate::prelude::*;
+use crate::wargaming::ApiError;
+
+#[derive(Debug)]
+pub enum WebError {
+    /// The requested entity does not exist.
+    NotFound,
+
+    /// The request is invalid, for example, it contains a malformed parameter.
+    BadRequest(Error),
+
+    /// The API token is missing or invalid.
+    Unauthorized,
+
+    /// The API token has exceeded its rate limit.
+    TooManyRequests,
+
+    /// Wargaming.net API has failed to respond.
+    UpstreamApi(Error),
+
+    /// MongoDB or Redis has failed to respond.
+    Database(Error),
+
+    /// Anything else, which goes wrong while building the page.
+    RenderError(Error),
+}
+
+impl fmt::Display for WebError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "not found"),
+            Self::BadRequest(error) => write!(f, "bad request: {error:#}"),
+            Self::Unauthorized => write!(f, "unauthorized"),
+            Self::TooManyRequests => write!(f, "too many requests"),
+            Self::UpstreamApi(error) => write!(f, "upstream API error: {error:#}"),
+            Self::Database(error) => write!(f, "database error: {error:#}"),
+            Self::RenderError(error) => write!(f, "render error: {error:#}"),
+        }
+    }
+}
+
+impl StdError for WebError {}
+
+impl ResponseError for WebError {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::NotFound => StatusCode::NOT_FOUND,
+            Self::BadRequest(_) => StatusCode::BAD_REQUEST,
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
+            Self::UpstreamApi(_) => StatusCode::BAD_GATEWAY,
+            Self::Database(_) => StatusCode::SERVICE_UNAVAILABLE,
+            Self::RenderError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+/// Categorizes the error by its root cause, so that the handlers could simply use `?`.
+impl From<Error> for WebError {
+    fn from(error: Error) -> Self {
+        let api_error = error.chain().find_map(|error| error.downcast_ref::<ApiError>());
+        let is_database_error = error.chain().any(|error| {
+            error.is::<mongodb::error::Error>() || error.is::<fred::error::RedisError>()
+        });
+        if let Some(api_error) = api_error {
+            if api_error.is_invalid_request() {
+                Self::BadRequest(error)
+            } else {
+                Self::UpstreamApi(error)
+            }
+        } else if is_database_error {
+            Self::Database(error)
+        } else {
+            Self::RenderError(error)
+        }
+    }
+}
+
+/// Allows the `PublicApi` operations to return `WebError` as well.
+/// The error responses carry no body, hence, nothing to describe.
+impl ApiResponse for WebError {
+    fn meta() -> MetaResponses {
+        MetaResponses {
+            responses: Vec::new(),
+        }
+    }
+
+    fn register(_registry: &mut Registry) {}
+}
+
+impl From<I18NError> for WebError {
+    fn from(error: I18NError) -> Self {
+        Self::RenderError(error.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wargaming::ApiErrorCode;
+
+    #[test]
+    fn from_api_error_ok() {
+        let error = Error::from(ApiError::Http(StatusCode::FORBIDDEN)).context("failed to search");
+        let error = WebError::from(error);
+        assert!(matches!(error, WebError::UpstreamApi(_)));
+        assert_eq!(error.status(), StatusCode::BAD_GATEWAY);
+    }
+
+    #[test]
+    fn from_invalid_request_ok() {
+        let error = WebError::from(Error::from(ApiError::Api {
+            code: ApiErrorCode::InvalidRequest {
+                field: Some("search".to_string()),
+            },
+            message: "INVALID_SEARCH".to_string(),
+        }));
+        assert!(matches!(error, WebError::BadRequest(_)));
+    }
+
+    #[test]
+    fn from_other_error_ok() {
+        let error = WebError::from(anyhow!("oops"));
+        assert!(matches!(error, WebError::RenderError(_)));
+        assert_eq!(error.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn not_found_ok() {
+        assert_eq!(WebError::NotFound.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn too_many_requests_ok() {
+        assert_eq!(WebError::TooManyRequests.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+}
@@ -5,6 +5,9 @@ use poem::i18n::I18NResources;
 
 use crate::prelude::*;
 
+/// Supported languages, the same as the `html-lang` texts.
+pub const LANGUAGES: [&str; 2] = ["ru", "en"];
+
 pub fn build_resources() -> Result<I18NResources> {
     I18NResources::builder()
         .add_ftl("ru", include_str!("i18n/ru.ftl"))
@@ -8,23 +8,66 @@ footer-title-created-with = Created with
 footer-title-discussions = Discussions
 footer-title-issues = Tasks & bugs
 footer-title-pull-requests = Pull requests
+footer-title-server-status = Server status
 footer-title-source-licensed = Source code licensed
 footer-title-support = Support
 hint-significantly-higher-than-target = Victory ratio is significantly higher than the target one
 hint-significantly-lower-than-target = Victory ratio is significantly lower than the target one
 html-lang = en
+message-bad-gateway = Wargaming.net API has failed to respond. Please, try again later.
+message-bad-request = The request could not be understood. Please, check the address and try again.
+message-challenge = Please, complete the check below – the page will continue automatically.
+message-forbidden = Access from your network has been restricted due to excessive requests.
+message-internal-server-error = An unexpected error has occurred. It has been reported, please, try again later.
+message-live-data-unavailable = Wargaming.net API is not responding, showing the data from
+message-no-data-as-of = There is no data on the account as of this date yet.
+message-no-notes = You havenʼt noted any accounts yet. Open a player page and add a note under the nickname.
 message-no-players-found = No accounts found with the specified nickname.
+message-not-found = The requested page does not exist.
 message-not-played-random = The user hasnʼt played in <strong>random</strong> battles within this period of time.
 message-not-played-rating = The user hasnʼt played in <strong>rating</strong> battles within this period of time.
+message-notes-private = The notes are only visible to you and are tied to this browser.
+message-personal-bests = Computed from the daily snapshots since the account has been tracked. The victory ratio only counts the days with at least 20 battles.
+message-realm-activity = Battles by weekday and hour of day (UTC) over the last 4 weeks.
+message-server-status = Availability of the Wargaming.net API over the last 24 hours, hour by hour.
+message-service-unavailable = The service is temporarily unavailable. Please, try again later.
+nation-china = China
+nation-europe = Europe
+nation-france = France
+nation-germany = Germany
+nation-japan = Japan
+nation-other = Other
+nation-uk = UK
+nation-usa = USA
+nation-ussr = USSR
+navbar-item-compact-layout = Compact layout
+navbar-item-compact-on-narrow-screens = Compact layout on narrow screens
 navbar-item-confidence-level = Confidence level
 navbar-item-confidence-level-help = Used to calculate the intervals
 navbar-item-current-masculine = Current
+navbar-item-extra-columns = Spotting, blocked, and assisted damage columns
+navbar-item-full-layout = Full layout
 navbar-item-target-victory-ratio = Target victory ratio
 navbar-item-target-victory-ratio-help = Used to track the current progress
 option-title-europe = Europe
 option-title-russia = Russia
+page-title-as-of = Profile as of date
+page-title-bad-gateway = Wargaming.net is not responding
+page-title-bad-request = Bad request
+page-title-challenge = Checking that you are a human
+page-title-forbidden = Access denied
+page-title-group = Group
+page-title-linked-accounts = Linked accounts
 page-title-index = World of Tanks Blitz playerʼs statistics
+page-title-internal-server-error = Something went wrong
+page-title-my-notes = My notes
+page-title-not-found = Page not found
+page-title-personal-bests = Personal bests
+page-title-realm-activity = Realm activity
+page-title-same-nickname = Same nickname in the other realms
 page-title-search = Search World of Tanks Blitz players
+page-title-server-status = Server status
+page-title-service-unavailable = Temporarily unavailable
 placeholder-nickname = Nickname
 preposition-and = and
 tank-type-at = AT-SPG
@@ -33,23 +76,71 @@ tank-type-light = Light
 tank-type-medium = Medium
 title-account-age-hint = Account age
 title-account-happy-birthday = Happy birthday!
+title-accounts-tracked = Accounts tracked
+title-activity-active = Active
+title-activity-active-hint = Plays about 5 or more battles a day
+title-activity-casual = Casual
+title-activity-casual-hint = Plays a few battles a week
+title-activity-dormant = Dormant
+title-activity-dormant-hint = Has barely played recently
+title-availability-24h = Availability over the last 24 hours
 title-average-damage = Average damage
 title-average-feminine = Average
 title-average-masculine = Average
+title-average-tier = Average tier
+title-battle-life-time = Time in battles
 title-battles = Battles
+title-battles-last-hour = Battles in the last hour
+title-battles-per-day = Battles per day
+title-battles-per-hour = Battles per hour
+title-best-day-damage = Best daily average damage
+title-best-day-victory-ratio = Best daily victory ratio
+title-best-vehicle = Best vehicle
+title-by-nation = By nation
+title-by-type = By type
 title-change = Change
+title-credible-interval = Credible interval
 title-damage = Damage
+title-damage-assisted-per-battle = Assisted per battle
+title-damage-blocked-per-battle = Blocked per battle
 title-damage-dealt = Damage dealt
 title-damage-dealt-per-battle = Damage per battle
+title-damage-per-minute = Damage per minute
 title-damage-ratio = Damage ratio
 title-damage-ratio-random-short = RDM ratio
 title-damage-ratio-rating-short = RTG ratio
+title-data-as-of = Data as of
 title-destroyed = Destroyed
+title-edit-note = Note
 title-exact-match = Exact match
 title-frags-per-battle = Frags per battle
+title-friday = Fri
+title-goal-battles = Battles
+title-goal-rating = Rating
+title-goal-reached = Goal reached!
+title-goal-wins = Wins
+title-grind = Grind
+title-grind-in-period = Within the period
+title-grind-masteries = Ace Tanker badges
+title-grind-remaining = Remaining
+title-grind-target = Grind target
+title-group-total = Together
+title-income-per-hour = Income per hour
+title-linked-total = All realms together
+title-linked-accounts = Accounts in the other realms
 title-hits = Accuracy
+title-hours = Hours
 title-interval = Interval
 title-last-played = Last played
+title-last-session = Last session
+title-longest-streak-days = Longest daily streak, days
+title-monday = Mon
+title-new-in-garage = New in garage
+title-next-date = Next date
+title-no-data-yet = No data crawled yet
+title-no-longer-played = No longer in garage
+title-note-tags-placeholder = Tags, comma-separated: platoon mate, seal clubber
+title-note-text-placeholder = Private note
 title-now = Now
 title-on-average = On average
 title-open-in-blitzhangar = Open in Blitz Hangar
@@ -74,20 +165,48 @@ title-period-8-hours = 8 hours
 title-posterior-gold = Gold booster yield
 title-posterior-gold-abbr = Gold booster yield per battle with correction on the number of battles
 title-posterior-masculine = Posterior
+title-previous-date = Previous date
+title-previous-session = Previous session
 title-random-battles = Random battles
 title-random-battles-short = Random
 title-rating = Rating
 title-rating-battles = Rating battles
 title-rating-battles-short = Rating
+title-refresh = Refresh
+title-reset-goal = Reset
+title-saturday = Sat
+title-save-note = Save
+title-search-notes = Nickname, tag, or text
+title-server-down = Down
+title-server-unknown = Unknown
+title-server-up = Up
+title-session-goal = Session goal
+title-set-goal = Set
+title-spotted-per-battle = Spotted per battle
+title-sunday = Sun
 title-survival-ratio = Survival rate
 title-survived = Survived
 title-target-victory-ratio-probability = Target VR probability
+title-thursday = Thu
+title-tier = Tier
+title-timeline = Timeline
 title-total = Total
 title-total-battles-hint = Battles
+title-tuesday = Tue
 title-type = Type
 title-vehicle = Vehicle
+title-vehicle-target = Vehicle target victory ratio, empty to reset
+title-vehicles-played = Vehicles played
 title-victory-probability = Posterior victory probability
 title-victory-ratio = Victory ratio
+title-victory-ratio-change = Overall victory ratio change
+title-victory-ratio-history = Victory ratio over the last 90 days
 title-victory-ratio-interval = Victory ratio (interval)
 title-victory-ratio-interval-abbr = Victory ratio with the correction on the number of battles, CI 90%
+title-wednesday = Wed
+title-weekly-summary = Weekly summary
 title-wins = Wins
+title-wins-per-hour = Wins per hour
+title-xp = XP
+title-xp-per-battle = XP per battle
+title-xp-per-hour = XP per hour
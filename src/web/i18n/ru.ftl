@@ -8,23 +8,66 @@ footer-title-created-with = Создан с помощью
 footer-title-discussions = Обсуждения
 footer-title-issues = Задачи и баги
 footer-title-pull-requests = Пул-реквесты
+footer-title-server-status = Состояние серверов
 footer-title-source-licensed = Исходный код лицензирован
 footer-title-support = Поддержка
 hint-significantly-higher-than-target = Процент побед значительно выше, чем целевой
 hint-significantly-lower-than-target = Процент побед значительно ниже, чем целевой
 html-lang = ru
+message-bad-gateway = API Wargaming.net не ответил. Пожалуйста, попробуйте позже.
+message-bad-request = Не удалось разобрать запрос. Пожалуйста, проверьте адрес и попробуйте еще раз.
+message-challenge = Пожалуйста, пройдите проверку ниже – страница продолжит загрузку автоматически.
+message-forbidden = Доступ из вашей сети ограничен из-за чрезмерного количества запросов.
+message-internal-server-error = Произошла непредвиденная ошибка. Мы уже о ней знаем, пожалуйста, попробуйте позже.
+message-live-data-unavailable = Wargaming.net API не отвечает, показаны данные от
+message-no-data-as-of = Данных об аккаунте на эту дату пока нет.
+message-no-notes = Вы ещё не оставили ни одной заметки. Откройте страницу игрока и добавьте заметку под никнеймом.
 message-no-players-found = Не найдено ни одного аккаунта с подобным именем.
+message-not-found = Запрошенная страница не существует.
 message-not-played-random = Пользователь не играл в <strong>случайных</strong> боях за этот период времени.
 message-not-played-rating = Пользователь не играл в <strong>рейтинговых</strong> боях за этот период времени.
+message-notes-private = Заметки видны только вам и привязаны к этому браузеру.
+message-personal-bests = Рассчитаны по ежедневным снимкам с начала отслеживания аккаунта. Процент побед учитывает только дни, в которые сыграно не менее 20 боёв.
+message-realm-activity = Бои по дням недели и часам (UTC) за последние 4 недели.
+message-server-status = Доступность API Wargaming.net за последние 24 часа, по часам.
+message-service-unavailable = Сервис временно недоступен. Пожалуйста, попробуйте позже.
+nation-china = Китай
+nation-europe = Европа
+nation-france = Франция
+nation-germany = Германия
+nation-japan = Япония
+nation-other = Сборная
+nation-uk = Великобритания
+nation-usa = США
+nation-ussr = СССР
+navbar-item-compact-layout = Компактный вид
+navbar-item-compact-on-narrow-screens = Компактный вид на узких экранах
 navbar-item-confidence-level = Уровень доверия
 navbar-item-confidence-level-help = Используется при расчете интервалов
 navbar-item-current-masculine = Текущий
+navbar-item-extra-columns = Колонки засвета, блока и помощи
+navbar-item-full-layout = Полный вид
 navbar-item-target-victory-ratio = Целевой процент побед
 navbar-item-target-victory-ratio-help = Помогает отслеживать текущий прогресс
 option-title-europe = Европа
 option-title-russia = Россия
+page-title-as-of = Профиль на дату
+page-title-bad-gateway = Wargaming.net не отвечает
+page-title-bad-request = Некорректный запрос
+page-title-challenge = Проверка, что вы человек
+page-title-forbidden = Доступ запрещен
+page-title-group = Группа
+page-title-linked-accounts = Связанные аккаунты
 page-title-index = Статистика игроков World of Tanks Blitz
+page-title-internal-server-error = Что-то пошло не так
+page-title-my-notes = Мои заметки
+page-title-not-found = Страница не найдена
+page-title-personal-bests = Личные рекорды
+page-title-realm-activity = Активность сервера
+page-title-same-nickname = Этот никнейм на других серверах
 page-title-search = Поиск игроков World of Tanks Blitz
+page-title-server-status = Состояние серверов
+page-title-service-unavailable = Временно недоступно
 placeholder-nickname = Никнейм
 preposition-and = и
 tank-type-at = ПТ
@@ -33,23 +76,71 @@ tank-type-light = ЛТ
 tank-type-medium = СТ
 title-account-age-hint = Возраст аккаунта
 title-account-happy-birthday = С днем рождения!
+title-accounts-tracked = Аккаунтов отслеживается
+title-activity-active = Активный
+title-activity-active-hint = Играет около 5 и более боёв в день
+title-activity-casual = Нерегулярный
+title-activity-casual-hint = Играет несколько боёв в неделю
+title-activity-dormant = Неактивный
+title-activity-dormant-hint = В последнее время почти не играет
+title-availability-24h = Доступность за последние 24 часа
 title-average-damage = Средний урон
 title-average-feminine = Средняя
 title-average-masculine = Средний
+title-average-tier = Средний уровень
+title-battle-life-time = Время в боях
 title-battles = Бои
+title-battles-last-hour = Боёв за последний час
+title-battles-per-day = Боёв в день
+title-battles-per-hour = Боев в час
+title-best-day-damage = Лучший средний урон за день
+title-best-day-victory-ratio = Лучший процент побед за день
+title-best-vehicle = Лучшая техника
+title-by-nation = По нациям
+title-by-type = По типам
 title-change = Изменение
+title-credible-interval = Доверительный интервал
 title-damage = Урон
+title-damage-assisted-per-battle = Помощь за бой
+title-damage-blocked-per-battle = Заблокировано за бой
 title-damage-dealt = Нанесенный урон
 title-damage-dealt-per-battle = Урон за бой
+title-damage-per-minute = Урон в минуту
 title-damage-ratio = Отношение урона
 title-damage-ratio-random-short = RDM отн.
 title-damage-ratio-rating-short = RTG отн.
+title-data-as-of = Данные на
 title-destroyed = Уничтожено
+title-edit-note = Заметка
 title-exact-match = Точное совпадение
 title-frags-per-battle = Фраги за бой
+title-friday = Пт
+title-goal-battles = Бои
+title-goal-rating = Рейтинг
+title-goal-reached = Цель достигнута!
+title-goal-wins = Победы
+title-grind = Прокачка
+title-grind-in-period = За период
+title-grind-masteries = Мастера
+title-grind-remaining = Осталось
+title-grind-target = Цель прокачки
+title-group-total = Вместе
+title-income-per-hour = Доход в час
+title-linked-total = Все серверы вместе
+title-linked-accounts = Аккаунты на других серверах
 title-hits = Точность
+title-hours = Часы
 title-interval = Интервал
 title-last-played = Играл
+title-last-session = Последняя сессия
+title-longest-streak-days = Самая длинная серия, дней
+title-monday = Пн
+title-new-in-garage = Новый в ангаре
+title-next-date = Следующая дата
+title-no-data-yet = Данные еще не собраны
+title-no-longer-played = Больше нет в ангаре
+title-note-tags-placeholder = Теги через запятую: взводный, тюлень
+title-note-text-placeholder = Личная заметка
 title-now = Сейчас
 title-on-average = В среднем
 title-open-in-blitzhangar = Открыть в Blitz Ангар
@@ -74,20 +165,48 @@ title-period-8-hours = 8 часов
 title-posterior-gold = Бустер золота
 title-posterior-gold-abbr = Доходность золотого бустера за бой, скорректированная на число проведенных боев
 title-posterior-masculine = Апостериорный
+title-previous-date = Предыдущая дата
+title-previous-session = Предыдущая сессия
 title-random-battles = Случайные бои
 title-random-battles-short = Случайные
 title-rating = Рейтинг
 title-rating-battles = Рейтинговые бои
 title-rating-battles-short = Рейтинговые
+title-refresh = Обновить
+title-reset-goal = Сбросить
+title-saturday = Сб
+title-save-note = Сохранить
+title-search-notes = Никнейм, тег или текст
+title-server-down = Недоступен
+title-server-unknown = Неизвестно
+title-server-up = Работает
+title-session-goal = Цель на сессию
+title-set-goal = Задать
+title-spotted-per-battle = Засвет за бой
+title-sunday = Вс
 title-survival-ratio = Выживаемость
 title-survived = Выжил
 title-target-victory-ratio-probability = Вероятность целевого WR
+title-thursday = Чт
+title-tier = Уровень
+title-timeline = Динамика
 title-total = Всего
 title-total-battles-hint = Боев
+title-tuesday = Вт
 title-type = Тип
 title-vehicle = Техника
+title-vehicle-target = Целевой процент побед для танка, пусто для сброса
+title-vehicles-played = Сыграно танков
 title-victory-probability = Вероятность победы
 title-victory-ratio = Процент побед
+title-victory-ratio-change = Изменение общего процента побед
+title-victory-ratio-history = Процент побед за последние 90 дней
 title-victory-ratio-interval = Процент побед (интервал)
 title-victory-ratio-interval-abbr = Процент побед, скорректированный на число боев, CI 90%
+title-wednesday = Ср
+title-weekly-summary = Недельная сводка
 title-wins = Победы
+title-wins-per-hour = Побед в час
+title-xp = Опыт
+title-xp-per-battle = Опыта за бой
+title-xp-per-hour = Опыта в час
@@ -1,8 +1,14 @@
+pub mod blocklist;
 pub mod error;
+pub mod query_budget;
 pub mod security_headers;
 pub mod sentry;
 pub mod timeit;
+pub mod turnstile;
 
+pub use self::blocklist::*;
 pub use self::error::*;
+pub use self::query_budget::*;
 pub use self::security_headers::*;
 pub use self::sentry::*;
+pub use self::turnstile::*;
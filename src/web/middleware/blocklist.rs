@@ -0,0 +1,61 @@
+use fred::pool::RedisPool;
+use poem::http::StatusCode;
+use poem::i18n::Locale;
+use poem::web::{Html, RealIp};
+use poem::{Endpoint, FromRequest, IntoResponse, Middleware, Request, Response, Result};
+
+use crate::prelude::*;
+use crate::web::asn::AsnDatabase;
+use crate::web::blocklist;
+use crate::web::views::error::render_error_page;
+
+/// Responds with the localized 403 page to the blocked IP addresses and autonomous systems.
+///
+/// Passes all the requests through, if Redis is not configured or the check fails,
+/// so that the blocklist never takes the website down.
+pub struct BlocklistMiddleware;
+
+impl<E: Endpoint<Output = Response>> Middleware<E> for BlocklistMiddleware {
+    type Output = BlocklistMiddlewareImpl<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        BlocklistMiddlewareImpl { ep }
+    }
+}
+
+pub struct BlocklistMiddlewareImpl<E> {
+    ep: E,
+}
+
+#[poem::async_trait]
+impl<E: Endpoint<Output = Response>> Endpoint for BlocklistMiddlewareImpl<E> {
+    type Output = Response;
+
+    async fn call(&self, request: Request) -> Result<Self::Output> {
+        let Some(redis) = request.data::<RedisPool>() else {
+            return self.ep.call(request).await;
+        };
+        let Some(ip_addr) = RealIp::from_request_without_body(&request).await?.0 else {
+            return self.ep.call(request).await;
+        };
+        let asn = request
+            .data::<Option<AsnDatabase>>()
+            .and_then(Option::as_ref)
+            .and_then(|database| database.lookup(ip_addr));
+        match blocklist::is_blocked(redis, ip_addr, asn).await {
+            Ok(true) => {}
+            Ok(false) => return self.ep.call(request).await,
+            Err(error) => {
+                warn!("failed to check the blocklist: {:#}", error);
+                return self.ep.call(request).await;
+            }
+        }
+
+        let locale = Locale::from_request_without_body(&request).await?;
+        let markup = render_error_page(StatusCode::FORBIDDEN, &locale)?;
+        Ok(Html(markup.into_string())
+            .with_status(StatusCode::FORBIDDEN)
+            .with_header("Cache-Control", "no-cache")
+            .into_response())
+    }
+}
@@ -2,11 +2,18 @@ use poem::error::{
     MethodNotAllowedError, NotFoundError, ParseCookieError, ParseJsonError, ParsePathError,
     ParseQueryError,
 };
-use poem::http::StatusCode;
-use poem::{Endpoint, IntoResponse, Middleware, Request, Response, Result};
+use poem::http::{header, StatusCode};
+use poem::i18n::{I18NResources, Locale};
+use poem::web::Html;
+use poem::{Endpoint, FromRequest, IntoResponse, Middleware, Request, Response, Result};
+use poem_openapi::error::{AuthorizationError, ParseParamError, ParseRequestPayloadError};
 
 use crate::prelude::*;
+use crate::web::error::WebError;
+use crate::web::views::error::render_error_page;
 
+/// Converts the errors into the status codes and, for the browsers, into the localized error pages.
+/// The error details are logged (and thus reported to Sentry), but never sent to the client.
 pub struct ErrorMiddleware;
 
 impl<E: Endpoint<Output = Response>> Middleware<E> for ErrorMiddleware {
@@ -28,29 +35,80 @@ impl<E: Endpoint<Output = Response>> Endpoint for ErrorMiddlewareImpl<E> {
     async fn call(&self, request: Request) -> Result<Self::Output> {
         let method = request.method().clone();
         let uri = request.uri().clone();
-        match self.ep.call(request).await {
+        let locale = Self::html_locale(&request).await;
+        let status = match self.ep.call(request).await {
             Err(error) if error.is::<NotFoundError>() => {
                 info!(?method, ?uri, "{:#}", error);
-                Ok(StatusCode::NOT_FOUND.into_response())
+                StatusCode::NOT_FOUND
+            }
+            Err(error) if error.is::<WebError>() => {
+                let status = error.status();
+                if status.is_server_error() {
+                    error!(?method, ?uri, "{:#}", error);
+                } else {
+                    info!(?method, ?uri, "{:#}", error);
+                }
+                status
             }
             Err(error) if error.is::<MethodNotAllowedError>() => {
                 info!(?method, ?uri, "{:#}", error);
-                Ok(StatusCode::METHOD_NOT_ALLOWED.into_response())
+                StatusCode::METHOD_NOT_ALLOWED
+            }
+            Err(error) if error.is::<AuthorizationError>() => {
+                info!(?method, ?uri, "{:#}", error);
+                StatusCode::UNAUTHORIZED
             }
             Err(error) => {
                 if error.is::<ParseQueryError>()
                     || error.is::<ParsePathError>()
                     || error.is::<ParseCookieError>()
                     || error.is::<ParseJsonError>()
+                    || error.is::<ParseParamError>()
+                    || error.is::<ParseRequestPayloadError>()
                 {
                     info!(?method, ?uri, "{:#}", error);
-                    Ok(StatusCode::BAD_REQUEST.into_response())
+                    StatusCode::BAD_REQUEST
                 } else {
                     error!(?method, ?uri, "{:#}", error);
-                    Ok(StatusCode::INTERNAL_SERVER_ERROR.into_response())
+                    StatusCode::INTERNAL_SERVER_ERROR
                 }
             }
-            result => result,
+            Ok(response) if response.status() == StatusCode::INTERNAL_SERVER_ERROR => {
+                // Caught panic or alike, the body must not get to the client.
+                // Other server errors, like `503` from the probes, are intentional.
+                error!(?method, ?uri, status = ?response.status(), "server error");
+                response.status()
+            }
+            Ok(response) => return Ok(response),
+        };
+        Ok(Self::error_response(status, locale.as_ref()))
+    }
+}
+
+impl<E> ErrorMiddlewareImpl<E> {
+    /// Extracts the locale, if the client accepts HTML.
+    async fn html_locale(request: &Request) -> Option<Locale> {
+        let accepts_html = request
+            .header(header::ACCEPT)
+            .is_some_and(|accept| accept.contains("text/html"));
+        if accepts_html && request.extensions().get::<I18NResources>().is_some() {
+            Locale::from_request_without_body(request).await.ok()
+        } else {
+            None
+        }
+    }
+
+    fn error_response(status: StatusCode, locale: Option<&Locale>) -> Response {
+        match locale.map(|locale| render_error_page(status, locale)) {
+            Some(Ok(markup)) => Html(markup.into_string())
+                .with_status(status)
+                .with_header("Cache-Control", "no-cache")
+                .into_response(),
+            Some(Err(error)) => {
+                error!("failed to render the error page: {:#}", error);
+                status.into_response()
+            }
+            None => status.into_response(),
         }
     }
 }
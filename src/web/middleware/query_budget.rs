@@ -0,0 +1,49 @@
+use poem::{Endpoint, Middleware, Request, Result};
+
+use crate::database::mongodb::query_budget;
+use crate::prelude::*;
+
+/// Logs the requests, which have issued too many MongoDB commands or spent too long in MongoDB.
+#[derive(Copy, Clone)]
+pub struct QueryBudgetMiddleware {
+    pub max_commands: usize,
+    pub max_time: time::Duration,
+}
+
+impl<E: Endpoint> Middleware<E> for QueryBudgetMiddleware {
+    type Output = QueryBudgetMiddlewareImpl<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        QueryBudgetMiddlewareImpl { ep, budget: *self }
+    }
+}
+
+pub struct QueryBudgetMiddlewareImpl<E> {
+    ep: E,
+    budget: QueryBudgetMiddleware,
+}
+
+#[poem::async_trait]
+impl<E: Endpoint> Endpoint for QueryBudgetMiddlewareImpl<E> {
+    type Output = E::Output;
+
+    async fn call(&self, request: Request) -> Result<Self::Output> {
+        let method = request.method().clone();
+        let uri = request.uri().clone();
+        let (response, report) = query_budget::scope(self.ep.call(request)).await;
+        if report.n_commands > self.budget.max_commands || report.total_time > self.budget.max_time
+        {
+            warn!(
+                ?method,
+                ?uri,
+                n_commands = report.n_commands,
+                total_time = ?report.total_time,
+                commands = ?report.commands,
+                "query budget exceeded",
+            );
+        } else {
+            debug!(?method, ?uri, n_commands = report.n_commands, total_time = ?report.total_time);
+        }
+        response
+    }
+}
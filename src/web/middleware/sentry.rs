@@ -4,6 +4,8 @@ use poem::web::RealIp;
 use poem::{Endpoint, FromRequest, Middleware, Request, Result};
 use sentry::{configure_scope, start_transaction, Transaction, TransactionContext};
 
+use crate::helpers::tracing::HTTP_SERVER_OP;
+
 pub struct SentryMiddleware;
 
 impl<E: Endpoint> Middleware<E> for SentryMiddleware {
@@ -32,9 +34,13 @@ impl<E: Endpoint> Endpoint for SentryMiddlewareImpl<E> {
 }
 
 impl<E> SentryMiddlewareImpl<E> {
+    /// Starts the transaction and binds it to the scope, so that the nested spans
+    /// become its children.
     fn start_transaction(request: &Request) -> Transaction {
-        let context = TransactionContext::new(request.uri().path(), request.method().as_str());
-        start_transaction(context)
+        let name = format!("{} {}", request.method(), request.uri().path());
+        let transaction = start_transaction(TransactionContext::new(&name, HTTP_SERVER_OP));
+        configure_scope(|scope| scope.set_span(Some(transaction.clone().into())));
+        transaction
     }
 
     async fn configure_scope(&self, request: &Request) -> Result<()> {
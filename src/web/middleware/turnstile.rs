@@ -0,0 +1,66 @@
+use fred::pool::RedisPool;
+use poem::http::StatusCode;
+use poem::i18n::Locale;
+use poem::web::{Html, Query, RealIp};
+use poem::{Endpoint, FromRequest, IntoResponse, Middleware, Request, Response, Result};
+
+use crate::web::turnstile::Turnstile;
+use crate::web::views::challenge::render_challenge_page;
+
+/// Name of the query parameter, which the Turnstile widget fills in.
+pub const TOKEN_PARAM: &str = "cf-turnstile-response";
+
+/// Challenges the client with Cloudflare Turnstile, unless its IP address has been verified recently.
+/// Passes all the requests through, if Turnstile is not configured.
+pub struct TurnstileMiddleware;
+
+impl<E: Endpoint<Output = Response>> Middleware<E> for TurnstileMiddleware {
+    type Output = TurnstileMiddlewareImpl<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        TurnstileMiddlewareImpl { ep }
+    }
+}
+
+pub struct TurnstileMiddlewareImpl<E> {
+    ep: E,
+}
+
+#[poem::async_trait]
+impl<E: Endpoint<Output = Response>> Endpoint for TurnstileMiddlewareImpl<E> {
+    type Output = Response;
+
+    async fn call(&self, request: Request) -> Result<Self::Output> {
+        let turnstile = request.data::<Option<Turnstile>>().and_then(Option::as_ref);
+        let (Some(turnstile), Some(redis)) = (turnstile, request.data::<RedisPool>()) else {
+            return self.ep.call(request).await;
+        };
+
+        let ip_addr = RealIp::from_request_without_body(&request).await?.0;
+        if turnstile.is_verified(redis, ip_addr).await? {
+            return self.ep.call(request).await;
+        }
+
+        let Query(params) =
+            Query::<Vec<(String, String)>>::from_request_without_body(&request).await?;
+        let token = params
+            .iter()
+            .find(|(name, _)| name == TOKEN_PARAM)
+            .map(|(_, value)| value.as_str());
+        if let Some(token) = token {
+            if turnstile.verify(redis, token, ip_addr).await? {
+                return self.ep.call(request).await;
+            }
+        }
+
+        let locale = Locale::from_request_without_body(&request).await?;
+        let params: Vec<(String, String)> =
+            params.into_iter().filter(|(name, _)| name != TOKEN_PARAM).collect();
+        let markup =
+            render_challenge_page(request.uri().path(), &params, &turnstile.site_key, &locale)?;
+        Ok(Html(markup.into_string())
+            .with_status(StatusCode::FORBIDDEN)
+            .with_header("Cache-Control", "no-cache")
+            .into_response())
+    }
+}
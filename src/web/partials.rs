@@ -1,20 +1,28 @@
 mod account_search;
 mod float;
 mod human_float;
+mod markdown;
 mod semaphore;
 
+use std::str::FromStr;
+
 use chrono::{DateTime, Utc};
 use chrono_humanize::{Accuracy, HumanTime, Tense};
-use maud::{html, Markup};
-use phf::phf_set;
+use maud::{html, Markup, PreEscaped};
+use once_cell::sync::Lazy;
+use poem::i18n::unic_langid::LanguageIdentifier;
 use poem::i18n::Locale;
 
 pub use self::account_search::*;
 pub use self::float::*;
 pub use self::human_float::*;
+pub use self::markdown::*;
 pub use self::semaphore::*;
 use crate::prelude::*;
+use crate::tankopedia;
 use crate::wargaming::models::tank_id::to_client_id;
+use crate::web::i18n;
+use crate::web::views::r#static::{ANNOUNCEMENTS_JS, THEME_CSS};
 
 #[must_use]
 pub fn headers() -> Markup {
@@ -27,7 +35,7 @@ pub fn headers() -> Markup {
         link rel="manifest" href="/site.webmanifest";
         link rel="stylesheet" href="https://cdn.jsdelivr.net/npm/bulma@0.9.4/css/bulma.min.css" crossorigin="anonymous" referrerpolicy="no-referrer";
         link rel="stylesheet" href="https://cdn.jsdelivr.net/npm/bulma-prefers-dark@0.1.0-beta.1/css/bulma-prefers-dark.min.css" crossorigin="anonymous" referrerpolicy="no-referrer";
-        link rel="stylesheet" href=(concat!("/static/theme.css?v", clap::crate_version!()));
+        link rel="stylesheet" href=(THEME_CSS.href());
         link rel="stylesheet" href="https://cdnjs.cloudflare.com/ajax/libs/font-awesome/6.1.2/css/all.min.css" integrity="sha512-1sCRPdkRXhBV2PBLUdRb4tMg1w2YPf37qatUFeS7zlBy7jJI8Lf4VHwWfZZfpXtYSLy85pkm9GaYVYMfw5BC1A==" crossorigin="anonymous" referrerpolicy="no-referrer";
         @if let Some(span) = sentry::configure_scope(|scope| scope.get_span()) {
             @for (key, value) in span.iter_headers() {
@@ -35,6 +43,7 @@ pub fn headers() -> Markup {
             }
         }
         script src="https://js.sentry-cdn.com/975bd87a20414620b4ab4d59e9698604.min.js" crossorigin="anonymous" {}
+        script type="module" src=(ANNOUNCEMENTS_JS.href()) {}
     }
 }
 
@@ -126,6 +135,36 @@ pub fn footer(locale: &Locale) -> Result<Markup> {
                                 } }
                             }
                         }
+
+                        p."mt-1" {
+                            span.icon-text.is-flex-wrap-nowrap {
+                                span.icon { i.fas.fa-server.has-text-info {} }
+                                span { a href="/status" {
+                                    (locale.text("footer-title-server-status")?)
+                                } }
+                            }
+                        }
+                        p."mt-1" id="server-status" {}
+                        script defer {
+                            (PreEscaped(r"
+                                'use strict';
+                                fetch('/api/status')
+                                    .then((response) => response.json())
+                                    .then((statuses) => {
+                                        const container = document.getElementById('server-status');
+                                        for (const status of statuses) {
+                                            const tag = document.createElement('span');
+                                            tag.className = 'tag is-light mr-1';
+                                            if (status.is_up !== null) {
+                                                tag.classList.add(status.is_up ? 'is-success' : 'is-danger');
+                                            }
+                                            tag.textContent = status.realm.toUpperCase();
+                                            container.appendChild(tag);
+                                        }
+                                    })
+                                    .catch(() => {});
+                            "))
+                        }
                     }
                 }
             }
@@ -143,6 +182,24 @@ pub fn home_button(locale: &Locale) -> Result<Markup> {
     Ok(markup)
 }
 
+pub fn activity_tag(level: database::ActivityLevel, locale: &Locale) -> Result<Markup> {
+    let (class, text, hint) = match level {
+        database::ActivityLevel::Active => {
+            ("is-success", locale.text("title-activity-active")?, "title-activity-active-hint")
+        }
+        database::ActivityLevel::Casual => {
+            ("is-info", locale.text("title-activity-casual")?, "title-activity-casual-hint")
+        }
+        database::ActivityLevel::Dormant => {
+            ("", locale.text("title-activity-dormant")?, "title-activity-dormant-hint")
+        }
+    };
+    let markup = html! {
+        span.tag.is-light.(class) title=(locale.text(hint)?) { (text) }
+    };
+    Ok(markup)
+}
+
 pub fn vehicle_th(vehicle: &wargaming::Vehicle, locale: &Locale) -> Result<Markup> {
     let markup = html! {
         th.is-white-space-nowrap {
@@ -152,8 +209,9 @@ pub fn vehicle_th(vehicle: &wargaming::Vehicle, locale: &Locale) -> Result<Marku
     Ok(markup)
 }
 
-pub fn vehicle_title(vehicle: &wargaming::Vehicle, locale: &Locale) -> Result<Markup> {
-    let flag = match vehicle.nation {
+#[must_use]
+pub const fn nation_flag_class(nation: wargaming::Nation) -> &'static str {
+    match nation {
         wargaming::Nation::China => "flag-icon-cn",
         wargaming::Nation::Europe => "flag-icon-eu",
         wargaming::Nation::France => "flag-icon-fr",
@@ -163,9 +221,76 @@ pub fn vehicle_title(vehicle: &wargaming::Vehicle, locale: &Locale) -> Result<Ma
         wargaming::Nation::Uk => "flag-icon-gb",
         wargaming::Nation::Usa => "flag-icon-us",
         wargaming::Nation::Ussr => "flag-icon-su",
-    };
+    }
+}
+
+/// Pre-rendered titles of the bundled vehicles, keyed by the language and the tank ID.
+///
+/// The vehicles tables render hundreds of them per request, and they only depend
+/// on the bundled tankopedia and the locale, which both are fixed at compile time.
+static VEHICLE_TITLES: Lazy<AHashMap<String, AHashMap<wargaming::TankId, VehicleTitle>>> =
+    Lazy::new(|| {
+        let resources = i18n::build_resources().expect("the i18n resources must be valid");
+        i18n::LANGUAGES
+            .iter()
+            .map(|language| {
+                let language_id =
+                    LanguageIdentifier::from_str(language).expect("the language must be valid");
+                let open_in_blitzhangar = resources
+                    .negotiate_languages(&[language_id])
+                    .text("title-open-in-blitzhangar")
+                    .expect("the text must exist");
+                let titles = tankopedia::iter_vehicles()
+                    .map(|vehicle| {
+                        let markup = render_vehicle_title(vehicle, language, &open_in_blitzhangar);
+                        (vehicle.tank_id, VehicleTitle { vehicle, markup })
+                    })
+                    .collect();
+                (language.to_string(), titles)
+            })
+            .collect()
+    });
+
+struct VehicleTitle {
+    vehicle: &'static wargaming::Vehicle,
+    markup: Markup,
+}
+
+/// Renders the titles of the bundled vehicles ahead of the first request.
+pub fn pre_render_vehicle_titles() {
+    let start_instant = Instant::now();
+    let n_titles: usize = VEHICLE_TITLES.values().map(AHashMap::len).sum();
+    info!(n_titles, elapsed = ?start_instant.elapsed(), "pre-rendered the vehicle titles");
+}
+
+/// Renders the vehicle title, taking it from [`VEHICLE_TITLES`], unless the vehicle
+/// differs from the bundled one – for example, when it comes from a historical tankopedia.
+pub fn vehicle_title(vehicle: &wargaming::Vehicle, locale: &Locale) -> Result<Markup> {
+    let language = locale.text("html-lang")?;
+    let title = VEHICLE_TITLES
+        .get(&language)
+        .and_then(|titles| titles.get(&vehicle.tank_id))
+        .filter(|title| title.vehicle == vehicle);
+    match title {
+        Some(title) => Ok(title.markup.clone()),
+        None => Ok(render_vehicle_title(
+            vehicle,
+            &language,
+            &locale.text("title-open-in-blitzhangar")?,
+        )),
+    }
+}
+
+/// Renders the title with the vehicle's name in the language, if the bundled tankopedia has it.
+fn render_vehicle_title(
+    vehicle: &wargaming::Vehicle,
+    language: &str,
+    open_in_blitzhangar: &str,
+) -> Markup {
+    let name = tankopedia::get_localized_name(vehicle.tank_id, language).unwrap_or(&vehicle.name);
+    let flag = nation_flag_class(vehicle.nation);
     let name_class = if vehicle.is_premium {
-        if COLLECTIBLE_VEHICLE_IDS.contains(&vehicle.tank_id) {
+        if vehicle.is_collectible {
             "has-text-info-dark"
         } else {
             "has-text-warning-dark"
@@ -183,13 +308,13 @@ pub fn vehicle_title(vehicle: &wargaming::Vehicle, locale: &Locale) -> Result<Ma
                 @if let Some(tier) = TIER_MARKUP.get(&vehicle.tier) {
                     strong."mx-1" { (tier) }
                 }
-                strong."mx-1".(name_class) { (vehicle.name) }
+                strong."mx-1".(name_class) { (name) }
             }
 
             @if let Ok(external_id) = to_client_id(vehicle.tank_id) {
                 span.icon {
                     a
-                        title=(locale.text("title-open-in-blitzhangar")?)
+                        title=(open_in_blitzhangar)
                         href=(format!("https://blitzhangar.com/tank/{external_id}"))
                         target="_blank"
                         rel="noopener noreferrer"
@@ -201,7 +326,7 @@ pub fn vehicle_title(vehicle: &wargaming::Vehicle, locale: &Locale) -> Result<Ma
         }
 
     };
-    Ok(markup)
+    markup
 }
 
 #[must_use]
@@ -226,71 +351,14 @@ pub static TIER_MARKUP: phf::Map<wargaming::Tier, &'static str> = phf::phf_map!
     10_u8 => "Ⅹ",
 };
 
-static COLLECTIBLE_VEHICLE_IDS: phf::Set<wargaming::TankId> = phf_set! {
-    113_u32,
-    1537_u32,
-    1617_u32,
-    2577_u32,
-    2881_u32,
-    2913_u32,
-    3105_u32,
-    3201_u32,
-    4449_u32,
-    4705_u32,
-    4721_u32,
-    4945_u32,
-    4977_u32,
-    4993_u32,
-    5233_u32,
-    6161_u32,
-    6417_u32,
-    6481_u32,
-    7041_u32,
-    7537_u32,
-    7713_u32,
-    8049_u32,
-    8209_u32,
-    8257_u32,
-    8305_u32,
-    8561_u32,
-    8817_u32,
-    8833_u32,
-    9009_u32,
-    9345_u32,
-    10017_u32,
-    10529_u32,
-    11265_u32,
-    13329_u32,
-    15137_u32,
-    15889_u32,
-    16145_u32,
-    17217_u32,
-    17745_u32,
-    19009_u32,
-    20817_u32,
-    21073_u32,
-    21249_u32,
-    21281_u32,
-    22033_u32,
-    22049_u32,
-    22273_u32,
-    22785_u32,
-    22801_u32,
-    23553_u32,
-    23569_u32,
-    24081_u32,
-    24097_u32,
-    24609_u32,
-    24849_u32,
-    51201_u32,
-    52065_u32,
-    52241_u32,
-    52257_u32,
-    52481_u32,
-    52993_u32,
-    55057_u32,
-    60929_u32,
-    63585_u32,
-    63841_u32,
-    64849_u32,
-};
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vehicle_titles_ok() {
+        for language in i18n::LANGUAGES {
+            assert_eq!(VEHICLE_TITLES[language].len(), tankopedia::iter_vehicles().count());
+        }
+    }
+}
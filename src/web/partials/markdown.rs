@@ -0,0 +1,91 @@
+//! Tiny subset of Markdown for the announcements: paragraphs, `**bold**`, `_italic_`,
+//! `` `code` `` and `[links](https://…)`. Anything else is rendered verbatim, and always escaped.
+
+use std::fmt::Write;
+
+use maud::{Escaper, Render};
+
+pub struct Markdown<'a>(pub &'a str);
+
+impl Render for Markdown<'_> {
+    fn render_to(&self, buffer: &mut String) {
+        for paragraph in self.0.split("\n\n").map(str::trim).filter(|text| !text.is_empty()) {
+            buffer.push_str("<p>");
+            render_inline(paragraph, buffer);
+            buffer.push_str("</p>");
+        }
+    }
+}
+
+fn render_inline(mut text: &str, buffer: &mut String) {
+    while !text.is_empty() {
+        if let Some((inner, rest)) = delimited(text, "**", "**") {
+            buffer.push_str("<strong>");
+            render_inline(inner, buffer);
+            buffer.push_str("</strong>");
+            text = rest;
+        } else if let Some((inner, rest)) = delimited(text, "_", "_") {
+            buffer.push_str("<em>");
+            render_inline(inner, buffer);
+            buffer.push_str("</em>");
+            text = rest;
+        } else if let Some((inner, rest)) = delimited(text, "`", "`") {
+            buffer.push_str("<code>");
+            escape(inner, buffer);
+            buffer.push_str("</code>");
+            text = rest;
+        } else if let Some((label, url, rest)) = link(text) {
+            buffer.push_str(r#"<a href=""#);
+            escape(url, buffer);
+            buffer.push_str(r#"" rel="noopener">"#);
+            render_inline(label, buffer);
+            buffer.push_str("</a>");
+            text = rest;
+        } else {
+            let length = text.chars().next().map_or(1, char::len_utf8);
+            escape(&text[..length], buffer);
+            text = &text[length..];
+        }
+    }
+}
+
+/// Splits off the non-empty span, which starts with the text.
+fn delimited<'a>(text: &'a str, start: &str, end: &str) -> Option<(&'a str, &'a str)> {
+    let text = text.strip_prefix(start)?;
+    let (inner, rest) = text.split_once(end)?;
+    (!inner.is_empty()).then_some((inner, rest))
+}
+
+/// Splits off the link, which starts with the text. Only the absolute HTTP(S) URLs are allowed.
+fn link(text: &str) -> Option<(&str, &str, &str)> {
+    let (label, rest) = delimited(text, "[", "](")?;
+    let (url, rest) = rest.split_once(')')?;
+    (url.starts_with("https://") || url.starts_with("http://")).then_some((label, url, rest))
+}
+
+fn escape(text: &str, buffer: &mut String) {
+    let _ = Escaper::new(buffer).write_str(text);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_ok() {
+        let markdown = "**Maintenance** on _Monday_.\n\nSee `#status` [here](https://yastati.st/status).";
+        let markup = Markdown(markdown).render().into_string();
+        assert_eq!(
+            markup,
+            r#"<p><strong>Maintenance</strong> on <em>Monday</em>.</p><p>See <code>#status</code> <a href="https://yastati.st/status" rel="noopener">here</a>.</p>"#,
+        );
+    }
+
+    #[test]
+    fn render_escaped_ok() {
+        let markup = Markdown("<script> [x](javascript:alert(1)) **unclosed")
+            .render()
+            .into_string();
+        assert_eq!(markup, "<p>&lt;script&gt; [x](javascript:alert(1)) **unclosed</p>");
+    }
+}
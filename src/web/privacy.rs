@@ -0,0 +1,84 @@
+//! Coarsening of the small realm-wide counts, so that the rare buckets,
+//! for example, a rarely played vehicle, don't expose a single player's results.
+
+use rand::prelude::*;
+
+use crate::opts::WebOpts;
+
+#[derive(Copy, Clone, Debug)]
+pub struct SmallCounts {
+    /// Counts below the threshold get coarsened, zero disables the coarsening.
+    threshold: i64,
+
+    /// Adds the random noise instead of rounding.
+    is_noisy: bool,
+}
+
+impl From<&WebOpts> for SmallCounts {
+    fn from(opts: &WebOpts) -> Self {
+        Self {
+            threshold: i64::from(opts.small_count_threshold),
+            is_noisy: opts.small_count_noise,
+        }
+    }
+}
+
+impl SmallCounts {
+    pub fn coarsen(self, count: i64) -> i64 {
+        self.coarsen_with(count, &mut thread_rng())
+    }
+
+    /// Rounds the small count to either zero or the threshold, or adds the uniform noise
+    /// within the threshold, which is clamped to be non-negative.
+    fn coarsen_with(self, count: i64, rng: &mut impl Rng) -> i64 {
+        if count >= self.threshold {
+            count
+        } else if self.is_noisy {
+            (count + rng.gen_range(-self.threshold..=self.threshold)).max(0)
+        } else if 2 * count >= self.threshold {
+            self.threshold
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_ok() {
+        let small_counts = SmallCounts {
+            threshold: 10,
+            is_noisy: false,
+        };
+        assert_eq!(small_counts.coarsen(0), 0);
+        assert_eq!(small_counts.coarsen(4), 0);
+        assert_eq!(small_counts.coarsen(5), 10);
+        assert_eq!(small_counts.coarsen(11), 11);
+    }
+
+    #[test]
+    fn noise_ok() {
+        let small_counts = SmallCounts {
+            threshold: 10,
+            is_noisy: true,
+        };
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let count = small_counts.coarsen_with(3, &mut rng);
+            assert!((0..=13).contains(&count), "{count}");
+        }
+        assert_eq!(small_counts.coarsen(10), 10);
+    }
+
+    #[test]
+    fn disabled_ok() {
+        let small_counts = SmallCounts {
+            threshold: 0,
+            is_noisy: true,
+        };
+        assert_eq!(small_counts.coarsen(1), 1);
+    }
+}
@@ -0,0 +1,33 @@
+//! Per-token rate limiting of the heavy API endpoints, shared by all the web replicas.
+
+use fred::pool::RedisPool;
+use fred::prelude::*;
+
+use crate::prelude::*;
+
+/// Fixed one-minute window counter in Redis.
+#[derive(Clone)]
+pub struct RateLimiter {
+    redis: RedisPool,
+    max_requests_per_minute: u32,
+}
+
+impl RateLimiter {
+    pub const fn new(redis: RedisPool, max_requests_per_minute: u32) -> Self {
+        Self {
+            redis,
+            max_requests_per_minute,
+        }
+    }
+
+    /// Counts the request, returns whether it's within the limit.
+    #[instrument(skip_all, level = "debug")]
+    pub async fn check(&self, key: &str) -> Result<bool> {
+        let key = format!("rate-limit:{}:{}", key, now().timestamp() / 60);
+        let n_requests: u32 = self.redis.incr(&key).await?;
+        if n_requests == 1 {
+            self.redis.expire::<(), _>(&key, 60).await?;
+        }
+        Ok(n_requests <= self.max_requests_per_minute)
+    }
+}
@@ -0,0 +1,75 @@
+//! Realm subdomains support, for example: `eu.yastati.st/{account_id}`.
+
+use poem::http::header;
+use poem::{FromRequest, Request, RequestBody};
+
+use crate::prelude::*;
+use crate::web::error::WebError;
+
+/// Base domain for the realm subdomains, for example: `yastati.st`.
+/// Realm subdomains are disabled, if not set.
+#[derive(Clone, Default)]
+pub struct BaseDomain(pub Option<String>);
+
+/// Extracts the realm from the request host subdomain.
+/// Rejects the request with «Not found», if the host is not a realm subdomain.
+pub struct RealmHost {
+    pub realm: wargaming::Realm,
+    pub base_domain: String,
+}
+
+#[poem::async_trait]
+impl<'a> FromRequest<'a> for RealmHost {
+    async fn from_request(request: &'a Request, _body: &mut RequestBody) -> poem::Result<Self> {
+        let base_domain = request
+            .data::<BaseDomain>()
+            .and_then(|base_domain| base_domain.0.as_deref())
+            .ok_or(WebError::NotFound)?;
+        let host = request
+            .uri()
+            .host()
+            .or_else(|| request.header(header::HOST))
+            .ok_or(WebError::NotFound)?;
+        let realm = parse_host(host, base_domain).ok_or(WebError::NotFound)?;
+        Ok(Self {
+            realm,
+            base_domain: base_domain.to_string(),
+        })
+    }
+}
+
+impl RealmHost {
+    /// Canonical URL of the account page on the base domain.
+    pub fn canonical_url(&self, account_id: wargaming::AccountId) -> String {
+        format!("https://{}/{}/{}", self.base_domain, self.realm, account_id)
+    }
+}
+
+/// Parses the realm from `{realm}.{base_domain}[:{port}]`.
+fn parse_host(host: &str, base_domain: &str) -> Option<wargaming::Realm> {
+    let host = host.split_once(':').map_or(host, |(host, _port)| host);
+    host.to_lowercase()
+        .strip_suffix(&base_domain.to_lowercase())?
+        .strip_suffix('.')?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_host_ok() {
+        assert_eq!(parse_host("eu.yastati.st", "yastati.st"), Some(wargaming::Realm::Europe));
+        assert_eq!(parse_host("RU.Yastati.St:8081", "yastati.st"), Some(wargaming::Realm::Russia));
+    }
+
+    #[test]
+    fn parse_host_not_realm_subdomain() {
+        assert_eq!(parse_host("yastati.st", "yastati.st"), None);
+        assert_eq!(parse_host("www.yastati.st", "yastati.st"), None);
+        assert_eq!(parse_host("eu.example.com", "yastati.st"), None);
+        assert_eq!(parse_host("euyastati.st", "yastati.st"), None);
+    }
+}
@@ -0,0 +1,55 @@
+//! Search engine crawlers, which get the lightweight player page.
+
+use poem::http::header;
+use poem::{FromRequest, Request, RequestBody};
+
+/// Lower-case `User-Agent` fragments of the common search engine crawlers.
+const USER_AGENT_FRAGMENTS: [&str; 10] = [
+    "googlebot",
+    "bingbot",
+    "yandexbot",
+    "duckduckbot",
+    "baiduspider",
+    "applebot",
+    "slurp",
+    "petalbot",
+    "seznambot",
+    "qwantify",
+];
+
+/// Tells whether the request comes from a search engine crawler.
+pub struct SearchBot(pub bool);
+
+#[poem::async_trait]
+impl<'a> FromRequest<'a> for SearchBot {
+    async fn from_request(request: &'a Request, _body: &mut RequestBody) -> poem::Result<Self> {
+        let user_agent = request.header(header::USER_AGENT).unwrap_or_default();
+        Ok(Self(is_search_bot(user_agent)))
+    }
+}
+
+fn is_search_bot(user_agent: &str) -> bool {
+    let user_agent = user_agent.to_lowercase();
+    USER_AGENT_FRAGMENTS
+        .iter()
+        .any(|fragment| user_agent.contains(fragment))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_search_bot_ok() {
+        assert!(is_search_bot(
+            "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)"
+        ));
+        assert!(is_search_bot(
+            "Mozilla/5.0 (compatible; YandexBot/3.0; +http://yandex.com/bots)"
+        ));
+        assert!(!is_search_bot(
+            "Mozilla/5.0 (X11; Linux x86_64; rv:109.0) Gecko/20100101 Firefox/110.0"
+        ));
+        assert!(!is_search_bot(""));
+    }
+}
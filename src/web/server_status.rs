@@ -0,0 +1,150 @@
+//! Availability of the Wargaming.net API realms, which helps the users
+//! to tell the dashboard issues from the upstream outages.
+//!
+//! The web application pings each realm periodically and keeps a day of the samples
+//! in the Redis lists, the newest first.
+
+use std::array;
+
+use fred::pool::RedisPool;
+use fred::prelude::*;
+use fred::types::RedisKey;
+use futures::future::join_all;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::wargaming::WargamingApi;
+
+const INTERVAL: time::Duration = time::Duration::from_secs(60);
+
+/// A day of the samples.
+const N_SAMPLES: i64 = 24 * 60;
+
+/// Samples older than this do not tell the current status.
+const MAX_LATEST_AGE_SECS: i64 = 5 * 60;
+
+pub const N_HOURS: usize = 24;
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Sample {
+    #[serde(rename = "t")]
+    pub timestamp: i64,
+
+    /// Response time in milliseconds, [`None`] means the request has failed.
+    #[serde(rename = "l")]
+    pub latency_millis: Option<u64>,
+}
+
+/// Pings the realms forever.
+pub async fn run_monitor(api: WargamingApi, redis: RedisPool) {
+    let mut interval = tokio::time::interval(INTERVAL);
+    loop {
+        interval.tick().await;
+        join_all(wargaming::Realm::ALL.map(|realm| ping(&api, &redis, realm))).await;
+    }
+}
+
+#[instrument(skip_all, level = "debug", fields(realm = ?realm))]
+async fn ping(api: &WargamingApi, redis: &RedisPool, realm: wargaming::Realm) {
+    let start_instant = Instant::now();
+    let latency_millis = match api.ping(realm).await {
+        Ok(()) => Some(start_instant.elapsed().as_millis() as u64),
+        Err(error) => {
+            warn!(?realm, "the realm is unavailable: {:#}", error);
+            None
+        }
+    };
+    let sample = Sample {
+        timestamp: now().timestamp(),
+        latency_millis,
+    };
+    if let Err(error) = record(redis, realm, sample).await {
+        warn!(?realm, "failed to record the server status: {:#}", error);
+    }
+}
+
+async fn record(redis: &RedisPool, realm: wargaming::Realm, sample: Sample) -> Result {
+    let key = key(realm);
+    redis.lpush::<(), _, _>(&key, serde_json::to_string(&sample)?).await?;
+    redis.ltrim::<(), _>(&key, 0, N_SAMPLES - 1).await?;
+    Ok(())
+}
+
+/// Retrieves the realm's samples, the newest first.
+#[instrument(skip_all, level = "debug", fields(realm = ?realm))]
+pub async fn retrieve(redis: &RedisPool, realm: wargaming::Realm) -> Result<Vec<Sample>> {
+    let samples: Vec<String> = redis.lrange(key(realm), 0, -1).await?;
+    samples
+        .iter()
+        .map(|sample| serde_json::from_str(sample).context("failed to parse the sample"))
+        .collect()
+}
+
+/// Current status of the realm, [`None`] means it's unknown.
+pub fn latest(samples: &[Sample], now: DateTime) -> Option<Sample> {
+    samples
+        .first()
+        .filter(|sample| now.timestamp() - sample.timestamp <= MAX_LATEST_AGE_SECS)
+        .copied()
+}
+
+/// Availability ratios within each of the last hours, the oldest first.
+/// [`None`] means there are no samples within the hour.
+pub fn hourly_availability(samples: &[Sample], now: DateTime) -> [Option<f64>; N_HOURS] {
+    let mut n_total = [0_u32; N_HOURS];
+    let mut n_available = [0_u32; N_HOURS];
+    for sample in samples {
+        let hours_ago = (now.timestamp() - sample.timestamp).div_euclid(3600);
+        if !(0..N_HOURS as i64).contains(&hours_ago) {
+            continue;
+        }
+        let index = N_HOURS - 1 - hours_ago as usize;
+        n_total[index] += 1;
+        if sample.latency_millis.is_some() {
+            n_available[index] += 1;
+        }
+    }
+    array::from_fn(|index| {
+        (n_total[index] != 0).then(|| n_available[index] as f64 / n_total[index] as f64)
+    })
+}
+
+fn key(realm: wargaming::Realm) -> RedisKey {
+    RedisKey::from(format!("web:server-status:{}", realm.to_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(timestamp: i64, latency_millis: Option<u64>) -> Sample {
+        Sample {
+            timestamp,
+            latency_millis,
+        }
+    }
+
+    #[test]
+    fn latest_ok() {
+        let now = Utc.timestamp_opt(1660000000, 0).unwrap();
+        let samples = [sample(1660000000 - 60, Some(100))];
+        assert_eq!(latest(&samples, now), Some(samples[0]));
+        assert_eq!(latest(&samples, now + Duration::hours(1)), None);
+        assert_eq!(latest(&[], now), None);
+    }
+
+    #[test]
+    fn hourly_availability_ok() {
+        let now = Utc.timestamp_opt(1660000000, 0).unwrap();
+        let samples = [
+            sample(1660000000 - 60, Some(100)),
+            sample(1660000000 - 120, None),
+            sample(1660000000 - 3600 - 60, Some(100)),
+            sample(1660000000 - 25 * 3600, None),
+        ];
+        let availability = hourly_availability(&samples, now);
+        assert_eq!(availability[N_HOURS - 1], Some(0.5));
+        assert_eq!(availability[N_HOURS - 2], Some(1.0));
+        assert_eq!(availability[0], None);
+    }
+}
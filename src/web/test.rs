@@ -1,14 +1,137 @@
-use poem::test::TestClient;
-use poem::{Endpoint, EndpointExt};
+use std::env;
+use std::net::TcpListener;
+
+use clap::Parser;
+use poem::endpoint::make_sync;
+use poem::http::header;
+use poem::i18n::Locale;
+use poem::listener::TcpAcceptor;
+use poem::test::{TestClient, TestResponse};
+use poem::web::cookie::CookieKey;
+use poem::web::Json;
+use poem::{Endpoint, EndpointExt, FromRequest, Request, Route, Server};
 use sentry::ClientInitGuard;
+use serde_json::json;
 
-use crate::prelude::Result;
-use crate::web::create_standalone_app;
+use crate::opts::WebOpts;
+use crate::prelude::*;
+use crate::wargaming::cache::account::{AccountInfoCache, AccountTanksCache};
+use crate::web::i18n;
 use crate::web::tracking_code::TrackingCode;
+use crate::web::{create_app, create_standalone_app, AppData};
+
+/// The database-backed tests write into these databases, and are skipped, unless both are set.
+const TEST_MONGODB_URI: &str = "BLITZ_DASHBOARD_TEST_MONGODB_URI";
+const TEST_REDIS_URI: &str = "BLITZ_DASHBOARD_TEST_REDIS_URI";
 
 pub async fn create_standalone_test_client() -> Result<(ClientInitGuard, TestClient<impl Endpoint>)>
 {
-    let sentry_guard = crate::tracing::init(None, 0.0)?;
-    let app = create_standalone_app().await?.data(TrackingCode::default());
+    // The global tracing subscriber may only be initialized once, hence, Sentry alone.
+    let sentry_guard = sentry::init(());
+    let app = create_standalone_app(CookieKey::generate()).await?.data(TrackingCode::default());
     Ok((sentry_guard, TestClient::new(app)))
 }
+
+/// Full application, which is backed by the test databases and the mocked Wargaming.net API.
+pub struct TestApp<E> {
+    pub client: TestClient<E>,
+    pub mongodb: mongodb::Database,
+    _sentry_guard: ClientInitGuard,
+}
+
+/// Creates the full application, or returns [`None`], when the test databases are not specified.
+pub async fn create_test_app(mock_api: MockApi) -> Result<Option<TestApp<impl Endpoint>>> {
+    let (Ok(mongodb_uri), Ok(redis_uri)) = (env::var(TEST_MONGODB_URI), env::var(TEST_REDIS_URI))
+    else {
+        return Ok(None);
+    };
+    let sentry_guard = sentry::init(());
+    let opts = WebOpts::try_parse_from([
+        "web",
+        "--mongodb-uri",
+        &mongodb_uri,
+        "--redis-uri",
+        &redis_uri,
+        "--application-id",
+        "test",
+    ])?;
+    let mut data = AppData::initialize_from_opts(&opts).await?;
+    data.api = data.api.with_base_url(mock_api.serve()?)?;
+    data.info_cache = AccountInfoCache::new(data.api.clone(), data.redis.clone());
+    data.tanks_cache = AccountTanksCache::new(data.api.clone(), data.redis.clone());
+    let mongodb = data.mongodb.clone();
+    Ok(Some(TestApp {
+        client: TestClient::new(create_app(data).await?),
+        mongodb,
+        _sentry_guard: sentry_guard,
+    }))
+}
+
+/// Wargaming.net API, which responds with the fixed data.
+#[derive(Default)]
+pub struct MockApi(Route);
+
+impl MockApi {
+    #[must_use]
+    pub fn with(self, path: &str, data: serde_json::Value) -> Self {
+        let mut response = json!({ "status": "ok" });
+        response["data"] = data;
+        Self(self.0.at(path, make_sync(move |_| Json(response.clone()))))
+    }
+
+    /// Mocks the account along with its empty garage.
+    #[must_use]
+    pub fn with_account(self, account_id: wargaming::AccountId, nickname: &str) -> Self {
+        let key = account_id.to_string();
+        let info = json!({
+            "account_id": account_id,
+            "nickname": nickname,
+            "created_at": 1415225091,
+            "last_battle_time": now().timestamp(),
+            "statistics": {
+                "all": { "battles": 100, "wins": 55, "damage_dealt": 100000 },
+                "rating": {},
+            },
+        });
+        self.with("/wotb/account/info/", json!({ &key: info }))
+            .with("/wotb/tanks/stats/", json!({ &key: [] }))
+            .with("/wotb/tanks/achievements/", json!({ &key: [] }))
+    }
+
+    /// Serves the API in the background, and returns its base URL.
+    fn serve(self) -> Result<String> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        listener.set_nonblocking(true)?;
+        let base_url = format!("http://{}", listener.local_addr()?);
+        tokio::spawn(Server::new_with_acceptor(TcpAcceptor::from_std(listener)?).run(self.0));
+        Ok(base_url)
+    }
+}
+
+/// Negotiates the locale the same way a request with the `Accept-Language` would,
+/// so that the markup could be rendered outside of a handler.
+pub async fn create_test_locale(language: &str) -> Result<Locale> {
+    let mut request = Request::builder()
+        .header(header::ACCEPT_LANGUAGE, language)
+        .finish();
+    request.extensions_mut().insert(i18n::build_resources()?);
+    let locale = Locale::from_request_without_body(&request)
+        .await
+        .expect("the locale extractor never fails");
+    Ok(locale)
+}
+
+/// Fetches the page's markup, asserting the success.
+pub async fn get_markup<E: Endpoint>(
+    client: &TestClient<E>,
+    uri: &str,
+    language: &str,
+) -> Result<String> {
+    let response: TestResponse = client
+        .get(uri)
+        .header(header::ACCEPT_LANGUAGE, language)
+        .send()
+        .await;
+    response.assert_status_is_ok();
+    Ok(response.0.into_body().into_string().await?)
+}
@@ -0,0 +1,99 @@
+//! Cloudflare Turnstile verification, which keeps the bots from burning the API quota.
+//!
+//! See: <https://developers.cloudflare.com/turnstile/get-started/server-side-validation/>.
+
+use std::net::IpAddr;
+
+use fred::pool::RedisPool;
+use fred::prelude::*;
+use serde::Deserialize;
+
+use crate::opts::WebOpts;
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct Turnstile {
+    pub site_key: String,
+    secret_key: String,
+    client: reqwest::Client,
+}
+
+#[derive(Deserialize)]
+struct VerifyResponse {
+    success: bool,
+
+    #[serde(default, rename = "error-codes")]
+    error_codes: Vec<String>,
+}
+
+impl Turnstile {
+    const VERIFY_URL: &'static str = "https://challenges.cloudflare.com/turnstile/v0/siteverify";
+
+    /// A verified IP address is not challenged again during this period.
+    const EXPIRE: Option<Expiration> = Some(Expiration::EX(3600));
+
+    /// Instantiates the verifier, if the keys are configured.
+    pub fn new(opts: &WebOpts) -> Result<Option<Self>> {
+        let (Some(site_key), Some(secret_key)) =
+            (&opts.turnstile_site_key, &opts.turnstile_secret_key) else {
+            return Ok(None);
+        };
+        let client = reqwest::ClientBuilder::new()
+            .https_only(true)
+            .timeout(time::Duration::from_secs(10))
+            .build()?;
+        Ok(Some(Self {
+            site_key: site_key.clone(),
+            secret_key: secret_key.clone(),
+            client,
+        }))
+    }
+
+    #[instrument(skip_all, level = "debug", fields(ip_addr = ?ip_addr))]
+    pub async fn is_verified(&self, redis: &RedisPool, ip_addr: Option<IpAddr>) -> Result<bool> {
+        match ip_addr {
+            Some(ip_addr) => Ok(redis.exists::<bool, _>(Self::cache_key(ip_addr)).await?),
+            None => Ok(false),
+        }
+    }
+
+    /// Verifies the token with Cloudflare and remembers the IP address on success.
+    #[instrument(skip_all, level = "info", fields(ip_addr = ?ip_addr))]
+    pub async fn verify(
+        &self,
+        redis: &RedisPool,
+        token: &str,
+        ip_addr: Option<IpAddr>,
+    ) -> Result<bool> {
+        let ip_addr_string = ip_addr.map(|ip_addr| ip_addr.to_string());
+        let mut form = vec![("secret", self.secret_key.as_str()), ("response", token)];
+        if let Some(ip_addr) = &ip_addr_string {
+            form.push(("remoteip", ip_addr.as_str()));
+        }
+        let response: VerifyResponse = self
+            .client
+            .post(Self::VERIFY_URL)
+            .form(&form)
+            .send()
+            .await
+            .context("failed to send the verification request")?
+            .error_for_status()?
+            .json()
+            .await
+            .context("failed to deserialize the verification response")?;
+        if !response.success {
+            info!(error_codes = ?response.error_codes, "verification failed");
+            return Ok(false);
+        }
+        if let Some(ip_addr) = ip_addr {
+            redis
+                .set::<(), _, _>(Self::cache_key(ip_addr), 1, Self::EXPIRE, None, false)
+                .await?;
+        }
+        Ok(true)
+    }
+
+    fn cache_key(ip_addr: IpAddr) -> String {
+        format!("turnstile:{ip_addr}")
+    }
+}
@@ -0,0 +1,35 @@
+use poem::web::cookie::CookieJar;
+use rand::prelude::*;
+
+use crate::prelude::*;
+use crate::web::cookies;
+
+/// Opaque random identifier of the browser, which scopes the viewer's private data.
+///
+/// Stored in the signed cookie, which is only set when the viewer saves something.
+pub struct ViewerId(pub String);
+
+impl ViewerId {
+    pub const COOKIE_NAME: &'static str = "viewer-id";
+
+    /// Retrieves the existing identifier, if any.
+    pub fn get(jar: &CookieJar) -> Option<Self> {
+        jar.signed()
+            .get(Self::COOKIE_NAME)
+            .and_then(|cookie| cookie.value::<String>().ok())
+            .map(Self)
+    }
+
+    /// Retrieves the existing identifier or issues a new one, prolonging the cookie.
+    pub fn get_or_issue(jar: &CookieJar) -> Self {
+        let this = Self::get(jar)
+            .unwrap_or_else(|| Self(hex::encode(thread_rng().gen::<[u8; 16]>())));
+        let cookie = cookies::Builder::new(Self::COOKIE_NAME)
+            .value(&this.0)
+            .expires_in(Duration::weeks(5 * 52))
+            .set_path("/")
+            .build();
+        jar.signed().add(cookie);
+        this
+    }
+}
@@ -1,9 +1,17 @@
+pub mod activity;
+pub mod announcements;
 pub mod api;
+pub mod challenge;
 pub mod error;
 pub mod gone;
+pub mod group;
+pub mod health;
 pub mod index;
+pub mod notes;
 pub mod player;
 pub mod random;
+pub mod realm;
 pub mod search;
 pub mod sitemaps;
+pub mod status;
 pub mod r#static;
@@ -0,0 +1,146 @@
+//! Realm's battles by weekday and hour of day over the last few weeks.
+
+use chrono::{Datelike, Timelike};
+use maud::{html, DOCTYPE};
+use poem::i18n::Locale;
+use poem::web::{Data, Html, Path};
+use poem::{handler, IntoResponse, Response};
+
+use crate::prelude::*;
+use crate::web::error::WebError;
+use crate::web::partials::*;
+use crate::web::privacy::SmallCounts;
+use crate::web::TrackingCode;
+
+const N_WEEKS: i64 = 4;
+
+const WEEKDAY_KEYS: [&str; 7] = [
+    "title-monday",
+    "title-tuesday",
+    "title-wednesday",
+    "title-thursday",
+    "title-friday",
+    "title-saturday",
+    "title-sunday",
+];
+
+/// Battles by weekday starting from Monday, and then by hour in UTC.
+struct Heatmap([[i64; 24]; 7]);
+
+impl Heatmap {
+    fn new(hourly_battles: &[database::HourlyBattles]) -> Self {
+        let mut n_battles = [[0; 24]; 7];
+        for item in hourly_battles {
+            let weekday = item.hour.weekday().num_days_from_monday() as usize;
+            n_battles[weekday][item.hour.hour() as usize] += item.n_battles;
+        }
+        Self(n_battles)
+    }
+
+    /// Coarsens the quiet hours, which could otherwise tell when a particular player plays.
+    fn coarsened(self, small_counts: SmallCounts) -> Self {
+        Self(self.0.map(|hours| hours.map(|n_battles| small_counts.coarsen(n_battles))))
+    }
+
+    fn max(&self) -> i64 {
+        self.0.iter().flatten().copied().max().unwrap_or_default()
+    }
+}
+
+#[instrument(skip_all, level = "info", fields(realm = ?realm))]
+#[handler]
+pub async fn get(
+    Path(realm): Path<wargaming::Realm>,
+    mongodb: Data<&mongodb::Database>,
+    small_counts: Data<&SmallCounts>,
+    tracking_code: Data<&TrackingCode>,
+    locale: Locale,
+) -> Result<Response, WebError> {
+    let since = now() - Duration::weeks(N_WEEKS);
+    let hourly_battles = database::HourlyBattles::retrieve_since(&mongodb, realm, since).await?;
+    let heatmap = Heatmap::new(&hourly_battles).coarsened(**small_counts);
+    let max = heatmap.max().max(1) as f64;
+
+    let markup = html! {
+        (DOCTYPE)
+        html.has-navbar-fixed-top lang=(locale.text("html-lang")?) {
+            head {
+                (headers())
+                title { (locale.text("page-title-realm-activity")?) " – " (locale.text("page-title-index")?) }
+            }
+            body {
+                (tracking_code.0)
+                nav.navbar.has-shadow.is-fixed-top role="navigation" aria-label="main navigation" {
+                    div.navbar-brand {
+                        (home_button(&locale)?)
+                    }
+                    div.navbar-menu {
+                        div.navbar-end {
+                            form.navbar-item action="/search" method="GET" {
+                                (AccountSearch::new(realm, &locale).try_into_markup()?)
+                            }
+                        }
+                    }
+                }
+
+                section.section {
+                    div.container {
+                        h1.title { (realm.to_emoji()) " " (locale.text("page-title-realm-activity")?) }
+                        p.subtitle."is-6".has-text-grey { (locale.text("message-realm-activity")?) }
+
+                        div.box {
+                            div.table-container {
+                                table.table.is-narrow.is-fullwidth {
+                                    thead {
+                                        tr {
+                                            th {}
+                                            @for hour in 0..24 {
+                                                th.has-text-centered."is-size-7" { (hour) }
+                                            }
+                                        }
+                                    }
+                                    tbody {
+                                        @for (key, n_battles) in WEEKDAY_KEYS.iter().zip(heatmap.0) {
+                                            tr {
+                                                th."is-size-7" { (locale.text(key)?) }
+                                                @for n_battles in n_battles {
+                                                    td
+                                                        style=(format!("background-color: hsla(171, 100%, 41%, {:.2})", n_battles as f64 / max))
+                                                        title=(n_battles) {}
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                (footer(&locale)?)
+            }
+        }
+    };
+
+    Ok(Html(markup.into_string())
+        .with_header("Cache-Control", "public, max-age=3600")
+        .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heatmap_ok() {
+        let item = |timestamp, n_battles| database::HourlyBattles {
+            realm: wargaming::Realm::Europe,
+            hour: Utc.timestamp_opt(timestamp, 0).unwrap(),
+            n_battles,
+        };
+        // Monday, 2022-08-08 13:00 UTC, and the same hour a week later.
+        let heatmap = Heatmap::new(&[item(1659963600, 10), item(1660568400, 5)]);
+        assert_eq!(heatmap.0[0][13], 15);
+        assert_eq!(heatmap.max(), 15);
+    }
+}
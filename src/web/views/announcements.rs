@@ -0,0 +1,47 @@
+//! Site-wide announcements.
+//!
+//! The pages are publicly cached, hence the banner is fetched and rendered on the client side.
+
+use maud::Render;
+use poem::web::cookie::CookieJar;
+use poem::web::{Data, Json};
+use poem::{handler, IntoResponse};
+use serde::Serialize;
+
+use crate::database::Announcement;
+use crate::prelude::*;
+use crate::web::error::WebError;
+use crate::web::partials::Markdown;
+
+/// Comma-separated IDs of the dismissed announcements, set by `announcements.js`.
+const DISMISSED_COOKIE_NAME: &str = "dismissed-announcements";
+
+#[derive(Serialize)]
+struct RenderedAnnouncement {
+    id: String,
+    severity: &'static str,
+    html: String,
+}
+
+#[handler]
+#[instrument(skip_all, level = "info")]
+pub async fn get(
+    mongodb: Data<&mongodb::Database>,
+    cookies: &CookieJar,
+) -> Result<impl IntoResponse, WebError> {
+    let dismissed = cookies
+        .get(DISMISSED_COOKIE_NAME)
+        .map(|cookie| cookie.value_str().to_string())
+        .unwrap_or_default();
+    let announcements: Vec<RenderedAnnouncement> = Announcement::retrieve_active(&mongodb, now())
+        .await?
+        .into_iter()
+        .filter(|announcement| !dismissed.split(',').any(|id| id == announcement.id))
+        .map(|announcement| RenderedAnnouncement {
+            html: Markdown(&announcement.markdown).render().into_string(),
+            severity: announcement.severity.as_str(),
+            id: announcement.id,
+        })
+        .collect();
+    Ok(Json(announcements).with_header("Cache-Control", "private, max-age=60"))
+}
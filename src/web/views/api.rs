@@ -1,25 +1,109 @@
-use futures::StreamExt;
+use std::io;
+
+use futures::future::try_join;
+use futures::{StreamExt, TryStreamExt};
 use poem::http::StatusCode;
-use poem::web::{Data, Path};
+use poem::web::headers::authorization::Bearer as BearerHeader;
+use poem::web::headers::Authorization;
+use poem::web::{Data, Json, Path, TypedHeader};
 use poem::{handler, Body, IntoResponse, Response};
+use poem_openapi::auth::Bearer;
+use poem_openapi::{
+    param, payload, ApiResponse, Enum, Object, OpenApi, OpenApiService, SecurityScheme,
+};
+use serde::Serialize;
 
-use crate::database::AccountIdProjection;
+use self::timeline::{Metric, Period, Timeline};
+use crate::crawler::fast_lane::FastLane;
+use crate::database::{
+    AccountIdProjection, AccountSnapshot, ApiToken, Granularity, RatingSnapshot, TankSnapshot,
+};
 use crate::prelude::*;
+use crate::tankopedia;
+use crate::wargaming::cache::account::{AccountInfoCache, AccountTanksCache};
+use crate::wargaming::cache::CacheStats;
+use crate::wargaming::WargamingApi;
+use crate::web::error::WebError;
+use crate::web::rate_limit::RateLimiter;
+
+mod timeline;
 
 const CACHE_CONTROL: &str = "no-cache";
 
+/// Maximum number of accounts in a single refresh request.
+const MAX_REFRESH_ACCOUNTS: usize = 100;
+
+/// Maximum number of tank snapshots in a single export page.
+const MAX_EXPORT_SNAPSHOTS: u32 = 10000;
+
 #[handler]
 #[instrument(skip_all, level = "info")]
 pub async fn get_health() -> Result<impl IntoResponse> {
     Ok(Response::from(StatusCode::NO_CONTENT).with_header("Cache-Control", CACHE_CONTROL))
 }
 
+#[derive(Serialize)]
+struct CacheStatsReport {
+    account_info: CacheStats,
+    account_tanks: CacheStats,
+    tankopedia: CacheStats,
+    n_tankopedia_vehicles: usize,
+}
+
+/// Hit, miss and coalesced request counters of the API caches and the bundled tankopedia
+/// since the start.
+#[handler]
+#[instrument(skip_all, level = "info")]
+pub async fn get_cache_stats(
+    authorization: Option<TypedHeader<Authorization<BearerHeader>>>,
+    db: Data<&mongodb::Database>,
+    info_cache: Data<&AccountInfoCache>,
+    tanks_cache: Data<&AccountTanksCache>,
+) -> Result<impl IntoResponse, WebError> {
+    authorize(&db, authorization).await?;
+    let report = CacheStatsReport {
+        account_info: info_cache.stats(),
+        account_tanks: tanks_cache.stats(),
+        tankopedia: tankopedia::stats(),
+        n_tankopedia_vehicles: tankopedia::len(),
+    };
+    Ok(Json(report).with_header("Cache-Control", CACHE_CONTROL))
+}
+
+/// Today's request counts per application ID against the daily budget.
+#[handler]
+#[instrument(skip_all, level = "info")]
+pub async fn get_usage(
+    authorization: Option<TypedHeader<Authorization<BearerHeader>>>,
+    db: Data<&mongodb::Database>,
+    api: Data<&WargamingApi>,
+) -> Result<impl IntoResponse, WebError> {
+    authorize(&db, authorization).await?;
+    let usage = api.retrieve_usage().await?.unwrap_or_default();
+    Ok(Json(usage).with_header("Cache-Control", CACHE_CONTROL))
+}
+
+/// Checks the same API tokens as the OpenAPI service does, but for the plain handlers.
+async fn authorize(
+    db: &mongodb::Database,
+    authorization: Option<TypedHeader<Authorization<BearerHeader>>>,
+) -> Result<ApiToken, WebError> {
+    let Some(TypedHeader(authorization)) = authorization else {
+        return Err(WebError::Unauthorized);
+    };
+    let api_token = ApiToken::retrieve(db, authorization.token())
+        .await?
+        .ok_or(WebError::Unauthorized)?;
+    debug!(name = api_token.name.as_str(), "authorized");
+    Ok(api_token)
+}
+
 #[handler]
 #[instrument(skip_all, level = "info")]
 pub async fn get_active_since(
     db: Data<&mongodb::Database>,
     Path((realm, since)): Path<(wargaming::Realm, DateTime)>,
-) -> Result<impl IntoResponse> {
+) -> Result<impl IntoResponse, WebError> {
     let stream = AccountIdProjection::retrieve_active_since(&db, realm, since)
         .await?
         .map(move |account| {
@@ -29,3 +113,277 @@ pub async fn get_active_since(
         .with_header("Cache-Control", CACHE_CONTROL)
         .with_content_type("application/json"))
 }
+
+/// The versioned public API, which is described by the OpenAPI specification.
+pub struct PublicApi;
+
+impl PublicApi {
+    /// Builds the service to be nested under `/api/v1`.
+    pub fn service() -> OpenApiService<Self, ()> {
+        OpenApiService::new(Self, "Blitz Dashboard", "1.0").server("/api/v1")
+    }
+}
+
+/// API token issued by `blitz-dashboard issue-api-token`.
+#[derive(SecurityScheme)]
+#[oai(type = "bearer")]
+struct ApiTokenAuth(Bearer);
+
+/// How fresh the crawled account data is.
+#[derive(Object)]
+#[oai(read_only_all)]
+struct Freshness {
+    /// Last battle timestamp of the latest snapshot in milliseconds, if any.
+    last_snapshot_time: Option<i64>,
+}
+
+/// Resolution of the rating history.
+#[derive(Enum, Copy, Clone, Debug, Default)]
+#[oai(rename_all = "snake_case")]
+enum Resolution {
+    #[default]
+    Day,
+    Week,
+    Month,
+}
+
+impl Resolution {
+    const fn granularity(self) -> Option<Granularity> {
+        match self {
+            Self::Day => None,
+            Self::Week => Some(Granularity::Weekly),
+            Self::Month => Some(Granularity::Monthly),
+        }
+    }
+}
+
+/// Rating within a day, week or month.
+#[derive(Object)]
+#[oai(read_only_all)]
+struct RatingPoint {
+    /// Start of the day, week or month in milliseconds.
+    date: i64,
+
+    /// The first seen display rating.
+    open: i32,
+
+    /// The last seen display rating.
+    close: i32,
+}
+
+impl From<RatingSnapshot> for RatingPoint {
+    fn from(snapshot: RatingSnapshot) -> Self {
+        Self {
+            date: snapshot.date.timestamp_millis(),
+            open: snapshot.open_rating.display_rating(),
+            close: snapshot.close_rating.display_rating(),
+        }
+    }
+}
+
+#[derive(Object)]
+struct RefreshRequest {
+    account_ids: Vec<wargaming::AccountId>,
+}
+
+/// Raw tank snapshots as NDJSON, the oldest first.
+#[derive(ApiResponse)]
+enum SnapshotsResponse {
+    #[oai(status = 200, content_type = "application/x-ndjson")]
+    Ok(payload::Binary<Body>, #[oai(header = "Cache-Control")] String),
+}
+
+#[OpenApi]
+impl PublicApi {
+    /// Account metric timeline, suitable for ApexCharts.
+    #[oai(path = "/:realm/:account_id/timeline", method = "get")]
+    #[instrument(skip_all, level = "info", fields(realm = ?realm.0, account_id = account_id.0))]
+    async fn get_timeline(
+        &self,
+        db: Data<&mongodb::Database>,
+        realm: param::Path<wargaming::Realm>,
+        account_id: param::Path<wargaming::AccountId>,
+        #[oai(default)] metric: param::Query<Metric>,
+        #[oai(default)] period: param::Query<Period>,
+    ) -> Result<payload::Response<payload::Json<Timeline>>, WebError> {
+        let (realm, account_id, period) = (realm.0, account_id.0, period.0);
+        let since = Timeline::since(period, now())?;
+        let (baseline, snapshots) = try_join(
+            AccountSnapshot::retrieve_latest(&db, realm, account_id, since),
+            AccountSnapshot::retrieve_since(&db, realm, account_id, since),
+        )
+        .await?;
+        let timeline = Timeline::new(
+            metric.0,
+            period,
+            since,
+            baseline.map(|snapshot| snapshot.random_stats),
+            &snapshots,
+        )?;
+        Ok(payload::Response::new(payload::Json(timeline))
+            .header("Cache-Control", "public, max-age=60"))
+    }
+
+    /// Account's rating history within the season, the most recent first.
+    ///
+    /// Defaults to the current season. Use the coarser `resolution` for the long seasons.
+    #[oai(path = "/:realm/:account_id/rating", method = "get")]
+    #[instrument(skip_all, level = "info", fields(realm = ?realm.0, account_id = account_id.0))]
+    async fn get_rating(
+        &self,
+        db: Data<&mongodb::Database>,
+        info_cache: Data<&AccountInfoCache>,
+        realm: param::Path<wargaming::Realm>,
+        account_id: param::Path<wargaming::AccountId>,
+        season: param::Query<Option<u16>>,
+        #[oai(default)] resolution: param::Query<Resolution>,
+    ) -> Result<payload::Response<payload::Json<Vec<RatingPoint>>>, WebError> {
+        let (realm, account_id) = (realm.0, account_id.0);
+        let season = match season.0 {
+            Some(season) => season,
+            None => {
+                let info = info_cache
+                    .get(realm, account_id)
+                    .await?
+                    .ok_or(WebError::NotFound)?;
+                info.stats.rating.current_season
+            }
+        };
+        let mut snapshots = RatingSnapshot::retrieve_season(&db, realm, account_id, season).await?;
+        if let Some(granularity) = resolution.0.granularity() {
+            snapshots = RatingSnapshot::downsample(snapshots, granularity);
+        }
+        let points = snapshots.into_iter().map(RatingPoint::from).collect();
+        Ok(payload::Response::new(payload::Json(points))
+            .header("Cache-Control", "public, max-age=300"))
+    }
+
+    /// Latest crawled snapshot time, which the player page polls after the manual refresh.
+    #[oai(path = "/:realm/:account_id/freshness", method = "get")]
+    #[instrument(skip_all, level = "info", fields(realm = ?realm.0, account_id = account_id.0))]
+    async fn get_freshness(
+        &self,
+        db: Data<&mongodb::Database>,
+        realm: param::Path<wargaming::Realm>,
+        account_id: param::Path<wargaming::AccountId>,
+    ) -> Result<payload::Response<payload::Json<Freshness>>, WebError> {
+        let last_snapshot_time =
+            AccountSnapshot::retrieve_latest_time(&db, realm.0, account_id.0).await?;
+        let freshness = Freshness {
+            last_snapshot_time: last_snapshot_time.map(|time| time.timestamp_millis()),
+        };
+        Ok(payload::Response::new(payload::Json(freshness)).header("Cache-Control", CACHE_CONTROL))
+    }
+
+    /// Streams the account's raw tank snapshots for the research.
+    ///
+    /// The lines are the raw documents in the extended JSON, so `lbts` is a BSON date:
+    /// `{"lbts": {"$date": {"$numberLong": "1660050000000"}}, "tid": 1, …}`.
+    ///
+    /// A page contains at most `limit` snapshots ordered by `lbts` and `tid`,
+    /// after the `since` (timestamp in milliseconds) and `after_tank_id` cursor.
+    /// To get the next page, pass the last line's `lbts.$date.$numberLong` as `since`
+    /// and its `tid` as `after_tank_id`. An empty page is the end.
+    ///
+    /// Rate limited per API token.
+    #[oai(path = "/:realm/:account_id/snapshots.ndjson", method = "get")]
+    #[instrument(skip_all, level = "info", fields(realm = ?realm.0, account_id = account_id.0))]
+    #[allow(clippy::too_many_arguments)]
+    async fn get_snapshots(
+        &self,
+        auth: ApiTokenAuth,
+        db: Data<&mongodb::Database>,
+        rate_limiter: Data<&RateLimiter>,
+        realm: param::Path<wargaming::Realm>,
+        account_id: param::Path<wargaming::AccountId>,
+        since: param::Query<Option<i64>>,
+        after_tank_id: param::Query<Option<wargaming::TankId>>,
+        limit: param::Query<Option<u32>>,
+    ) -> Result<SnapshotsResponse, WebError> {
+        let api_token = ApiToken::retrieve(&db, &auth.0.token)
+            .await?
+            .ok_or(WebError::Unauthorized)?;
+        if !rate_limiter.check(&api_token.hash).await? {
+            return Err(WebError::TooManyRequests);
+        }
+        let since = Utc
+            .timestamp_millis_opt(since.0.unwrap_or_default())
+            .single()
+            .ok_or_else(|| WebError::BadRequest(anyhow!("invalid `since`")))?;
+        let limit = limit.0.unwrap_or(MAX_EXPORT_SNAPSHOTS).clamp(1, MAX_EXPORT_SNAPSHOTS);
+        info!(
+            name = api_token.name.as_str(),
+            %since,
+            after_tank_id = ?after_tank_id.0,
+            limit,
+            "exporting…",
+        );
+        let (realm, account_id, limit) = (realm.0, account_id.0, i64::from(limit));
+        let stream =
+            TankSnapshot::retrieve_page(&db, realm, account_id, since, after_tank_id.0, limit)
+                .await?
+                .map(|snapshot| {
+                    let mut line = serde_json::to_vec(&snapshot?)?;
+                    line.push(b'\n');
+                    Ok::<_, Error>(line)
+                })
+                .map_err(io::Error::other);
+        Ok(SnapshotsResponse::Ok(
+            payload::Binary(Body::from_bytes_stream(stream)),
+            CACHE_CONTROL.to_string(),
+        ))
+    }
+
+    /// Pushes the accounts into the crawler's fast lane, so that the community sites
+    /// could request the fresh data.
+    #[oai(path = "/:realm/refresh", method = "post")]
+    #[instrument(skip_all, level = "info", fields(realm = ?realm.0))]
+    async fn post_refresh(
+        &self,
+        auth: ApiTokenAuth,
+        db: Data<&mongodb::Database>,
+        fast_lane: Data<&FastLane>,
+        realm: param::Path<wargaming::Realm>,
+        body: payload::Json<RefreshRequest>,
+    ) -> Result<payload::Response<()>, WebError> {
+        let api_token = ApiToken::retrieve(&db, &auth.0.token)
+            .await?
+            .ok_or(WebError::Unauthorized)?;
+        if body.account_ids.len() > MAX_REFRESH_ACCOUNTS {
+            return Err(WebError::BadRequest(anyhow!(
+                "at most {} accounts are allowed",
+                MAX_REFRESH_ACCOUNTS,
+            )));
+        }
+        info!(name = api_token.name.as_str(), n_accounts = body.account_ids.len(), "refreshing…");
+        for account in database::Account::retrieve_many(&db, realm.0, &body.account_ids).await? {
+            fast_lane.push(&account).await?;
+        }
+        Ok(payload::Response::new(())
+            .status(StatusCode::ACCEPTED)
+            .header("Cache-Control", CACHE_CONTROL))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use poem::http::StatusCode;
+
+    use crate::prelude::*;
+    use crate::web::test::create_standalone_test_client;
+
+    #[tokio::test]
+    async fn get_openapi_ok() -> Result {
+        let (_guard, client) = create_standalone_test_client().await?;
+        let response = client.get("/api/openapi.json").send().await;
+        response.assert_status(StatusCode::OK);
+        let spec: serde_json::Value =
+            serde_json::from_str(&response.0.into_body().into_string().await?)?;
+        assert!(spec["paths"]["/{realm}/{account_id}/timeline"]["get"].is_object());
+        assert!(spec["paths"]["/{realm}/{account_id}/freshness"]["get"].is_object());
+        assert!(spec["paths"]["/{realm}/{account_id}/rating"]["get"].is_object());
+        assert!(spec["paths"]["/{realm}/refresh"]["post"].is_object());
+        assert!(spec["paths"]["/{realm}/{account_id}/snapshots.ndjson"]["get"].is_object());
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+use std::borrow::Cow;
+
+use poem_openapi::registry::{MetaSchema, MetaSchemaRef};
+use poem_openapi::types::{ToJSON, Type};
+use poem_openapi::{Enum, Object};
+use serde::Serialize;
+
+use crate::database::{AccountSnapshot, RandomStatsSnapshot};
+use crate::helpers::time::{from_days, from_hours};
+use crate::math::traits::*;
+use crate::prelude::*;
+
+#[derive(Enum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[oai(rename_all = "snake_case")]
+pub enum Metric {
+    Battles,
+    #[default]
+    WinRate,
+    AverageDamage,
+    SurvivalRate,
+    Accuracy,
+}
+
+impl Metric {
+    /// Evaluates the metric over the battles played within a bucket.
+    fn evaluate(self, stats: &RandomStatsSnapshot) -> Option<f64> {
+        if self == Self::Battles {
+            return Some(stats.n_battles as f64);
+        }
+        if stats.n_battles == 0 {
+            return None;
+        }
+        match self {
+            Self::Battles => unreachable!(),
+            Self::WinRate => Some(stats.victory_ratio()),
+            Self::AverageDamage => Some(stats.average_damage_dealt()),
+            Self::SurvivalRate => Some(stats.survival_rate()),
+            Self::Accuracy => (stats.n_shots != 0).then(|| stats.accuracy()),
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Debug, Default)]
+pub enum Period {
+    #[oai(rename = "1d")]
+    Day,
+
+    #[oai(rename = "1w")]
+    Week,
+
+    #[default]
+    #[oai(rename = "1m")]
+    Month,
+
+    #[oai(rename = "3m")]
+    Quarter,
+
+    #[oai(rename = "6m")]
+    HalfYear,
+
+    #[oai(rename = "1y")]
+    Year,
+}
+
+impl Period {
+    /// Auto-selects the bucket size, so that there are around 30 buckets within the period.
+    ///
+    /// # Returns
+    ///
+    /// The bucket size and the number of buckets.
+    const fn buckets(self) -> (time::Duration, u32) {
+        match self {
+            Self::Day => (from_hours(1), 24),
+            Self::Week => (from_hours(6), 28),
+            Self::Month => (from_days(1), 30),
+            Self::Quarter => (from_days(3), 30),
+            Self::HalfYear => (from_days(7), 26),
+            Self::Year => (from_days(14), 26),
+        }
+    }
+}
+
+/// Evenly bucketed time series, suitable for ApexCharts.
+#[derive(Object)]
+#[oai(read_only_all)]
+pub struct Timeline {
+    pub metric: Metric,
+    pub bucket_secs: i64,
+    pub points: Vec<TimelinePoint>,
+}
+
+/// Pair of the bucket end timestamp in milliseconds and the metric value,
+/// which is `null` when there's nothing to evaluate the metric on.
+///
+/// OpenAPI has no notion of tuples, hence, described as a two-item array.
+#[derive(Serialize, Copy, Clone, Debug, PartialEq)]
+pub struct TimelinePoint(pub i64, pub Option<f64>);
+
+impl Type for TimelinePoint {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "TimelinePoint".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            items: Some(Box::new(Option::<f64>::schema_ref())),
+            min_items: Some(2),
+            max_items: Some(2),
+            ..MetaSchema::new("array")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ToJSON for TimelinePoint {
+    fn to_json(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(self).ok()
+    }
+}
+
+impl Timeline {
+    /// Calculates the start time of the first bucket.
+    pub fn since(period: Period, until: DateTime) -> Result<DateTime> {
+        let (bucket_size, n_buckets) = period.buckets();
+        Ok(until - Duration::from_std(bucket_size)? * n_buckets as i32)
+    }
+
+    /// Builds the timeline from the account snapshots.
+    ///
+    /// The snapshots must be sorted by the last battle time, oldest first.
+    /// The baseline is the latest snapshot before the first bucket, if any.
+    pub fn new(
+        metric: Metric,
+        period: Period,
+        since: DateTime,
+        baseline: Option<RandomStatsSnapshot>,
+        snapshots: &[AccountSnapshot],
+    ) -> Result<Self> {
+        let (bucket_size, n_buckets) = period.buckets();
+        let bucket_size = Duration::from_std(bucket_size)?;
+
+        let mut snapshots = snapshots.iter().peekable();
+        let mut previous = baseline;
+        let mut points = Vec::with_capacity(n_buckets as usize);
+        for i in 1..=n_buckets {
+            let bucket_end = since + bucket_size * i as i32;
+            let mut current = previous;
+            while let Some(snapshot) =
+                snapshots.next_if(|snapshot| snapshot.last_battle_time <= bucket_end)
+            {
+                current = Some(snapshot.random_stats);
+            }
+            let value = match (current, previous) {
+                (Some(current), Some(previous)) => metric.evaluate(&(current - previous)),
+                _ => None,
+            };
+            points.push(TimelinePoint(bucket_end.timestamp_millis(), value));
+            previous = current;
+        }
+
+        Ok(Self {
+            metric,
+            bucket_secs: bucket_size.num_seconds(),
+            points,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(last_battle_time: DateTime, n_battles: u32, n_wins: u32) -> AccountSnapshot {
+        let random_stats = RandomStatsSnapshot {
+            n_battles,
+            n_wins,
+            ..Default::default()
+        };
+        AccountSnapshot::for_test(last_battle_time, random_stats)
+    }
+
+    #[test]
+    fn timeline_ok() -> Result {
+        let since = Utc.timestamp_opt(1660000000, 0).unwrap();
+        let baseline = RandomStatsSnapshot {
+            n_battles: 10,
+            n_wins: 5,
+            ..Default::default()
+        };
+        let snapshots = [
+            snapshot(since + Duration::hours(1), 12, 6),
+            snapshot(since + Duration::hours(2), 14, 8),
+            snapshot(since + Duration::days(2), 16, 8),
+        ];
+        let timeline =
+            Timeline::new(Metric::WinRate, Period::Month, since, Some(baseline), &snapshots)?;
+        assert_eq!(timeline.bucket_secs, 86400);
+        assert_eq!(timeline.points.len(), 30);
+        assert_eq!(timeline.points[0].1, Some(0.75));
+        assert_eq!(timeline.points[1].1, Some(0.0));
+        assert_eq!(timeline.points[2].1, None);
+        Ok(())
+    }
+
+    #[test]
+    fn timeline_without_baseline_ok() -> Result {
+        let since = Utc.timestamp_opt(1660000000, 0).unwrap();
+        let snapshots = [
+            snapshot(since + Duration::hours(1), 12, 6),
+            snapshot(since + Duration::days(1) + Duration::hours(1), 14, 8),
+        ];
+        let timeline = Timeline::new(Metric::Battles, Period::Month, since, None, &snapshots)?;
+        assert_eq!(timeline.points[0].1, None);
+        assert_eq!(timeline.points[1].1, Some(2.0));
+        assert_eq!(timeline.points[2].1, Some(0.0));
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use maud::{html, Markup, PreEscaped, DOCTYPE};
+use poem::i18n::Locale;
+
+use crate::prelude::*;
+use crate::web::partials::{footer, headers, home_button};
+
+/// Renders the Turnstile challenge page, which re-submits the original request once solved.
+pub fn render_challenge_page(
+    path: &str,
+    params: &[(String, String)],
+    site_key: &str,
+    locale: &Locale,
+) -> Result<Markup> {
+    let markup = html! {
+        (DOCTYPE)
+        html lang=(locale.text("html-lang")?) {
+            head {
+                (headers())
+                title { (locale.text("page-title-challenge")?) " – " (locale.text("page-title-index")?) }
+                script src="https://challenges.cloudflare.com/turnstile/v0/api.js" async defer {}
+                script {
+                    (PreEscaped("function onTurnstileSuccess() { document.getElementById('challenge').submit(); }"))
+                }
+            }
+            body {
+                nav.navbar.has-shadow role="navigation" aria-label="main navigation" {
+                    div.navbar-brand {
+                        (home_button(locale)?)
+                    }
+                }
+
+                section.section {
+                    div.container {
+                        article.message.is-info {
+                            div.message-header {
+                                p { (locale.text("page-title-challenge")?) }
+                            }
+                            div.message-body {
+                                p."mb-4" { (locale.text("message-challenge")?) }
+                                form #challenge action=(path) method="GET" {
+                                    @for (name, value) in params {
+                                        input type="hidden" name=(name) value=(value);
+                                    }
+                                    div.cf-turnstile data-sitekey=(site_key) data-callback="onTurnstileSuccess" {}
+                                }
+                            }
+                        }
+                    }
+                }
+
+                (footer(locale)?)
+            }
+        }
+    };
+    Ok(markup)
+}
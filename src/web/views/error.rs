@@ -1,8 +1,121 @@
+use maud::{html, Markup, DOCTYPE};
 use poem::handler;
+use poem::http::StatusCode;
+use poem::i18n::Locale;
 
 use crate::prelude::*;
+use crate::web::partials::{footer, headers, home_button, AccountSearch};
 
 #[handler]
 pub async fn get_error() -> Result<()> {
     bail!("simulated error")
 }
+
+/// Renders the localized error page for the status code.
+/// Intentionally, it never includes the error itself.
+pub fn render_error_page(status: StatusCode, locale: &Locale) -> Result<Markup> {
+    let (title, message) = if status == StatusCode::NOT_FOUND {
+        (locale.text("page-title-not-found")?, locale.text("message-not-found")?)
+    } else if status == StatusCode::FORBIDDEN {
+        (locale.text("page-title-forbidden")?, locale.text("message-forbidden")?)
+    } else if status.is_client_error() {
+        (locale.text("page-title-bad-request")?, locale.text("message-bad-request")?)
+    } else if status == StatusCode::BAD_GATEWAY {
+        (locale.text("page-title-bad-gateway")?, locale.text("message-bad-gateway")?)
+    } else if status == StatusCode::SERVICE_UNAVAILABLE {
+        (
+            locale.text("page-title-service-unavailable")?,
+            locale.text("message-service-unavailable")?,
+        )
+    } else {
+        (
+            locale.text("page-title-internal-server-error")?,
+            locale.text("message-internal-server-error")?,
+        )
+    };
+
+    let markup = html! {
+        (DOCTYPE)
+        html lang=(locale.text("html-lang")?) {
+            head {
+                (headers())
+                title { (title) " – " (locale.text("page-title-index")?) }
+            }
+            body {
+                nav.navbar.has-shadow role="navigation" aria-label="main navigation" {
+                    div.navbar-brand {
+                        (home_button(locale)?)
+                    }
+                    div.navbar-menu.is-active {
+                        div.navbar-end {
+                            form.navbar-item action="/search" method="GET" {
+                                (AccountSearch::new(wargaming::Realm::Europe, locale).try_into_markup()?)
+                            }
+                        }
+                    }
+                }
+
+                section.section {
+                    div.container {
+                        article.message.(if status.is_server_error() { "is-danger" } else { "is-warning" }) {
+                            div.message-header {
+                                p { (status.as_u16()) " " (title) }
+                            }
+                            div.message-body {
+                                p { (message) }
+                            }
+                        }
+                    }
+                }
+
+                (footer(locale)?)
+            }
+        }
+    };
+    Ok(markup)
+}
+
+#[cfg(test)]
+mod tests {
+    use poem::http::{header, StatusCode};
+
+    use crate::prelude::*;
+    use crate::web::test::create_standalone_test_client;
+
+    #[tokio::test]
+    async fn error_page_ok() -> Result {
+        let (_guard, client) = create_standalone_test_client().await?;
+        let response = client
+            .get("/error")
+            .header(header::ACCEPT, "text/html")
+            .send()
+            .await;
+        response.assert_status(StatusCode::INTERNAL_SERVER_ERROR);
+        response.assert_content_type("text/html; charset=utf-8");
+        let body = response.0.into_body().into_string().await?;
+        assert!(!body.contains("simulated error"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn not_found_page_ok() -> Result {
+        let (_guard, client) = create_standalone_test_client().await?;
+        let response = client
+            .get("/does/not/exist")
+            .header(header::ACCEPT, "text/html")
+            .send()
+            .await;
+        response.assert_status(StatusCode::NOT_FOUND);
+        response.assert_content_type("text/html; charset=utf-8");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn error_without_html_ok() -> Result {
+        let (_guard, client) = create_standalone_test_client().await?;
+        let response = client.get("/error").send().await;
+        response.assert_status(StatusCode::INTERNAL_SERVER_ERROR);
+        response.assert_text("").await;
+        Ok(())
+    }
+}
@@ -0,0 +1,364 @@
+//! Combined statistics of a few accounts, for example, platoon mates
+//! or the same player's accounts in different realms.
+
+use std::str::FromStr;
+
+use futures::future::{join_all, try_join, try_join_all};
+use maud::{html, Markup, DOCTYPE};
+use poem::i18n::Locale;
+use poem::web::cookie::CookieJar;
+use poem::web::{Data, Html, Path, Query};
+use poem::{handler, IntoResponse, Response};
+use serde::{de, Deserialize, Deserializer};
+
+use crate::math::traits::*;
+use crate::prelude::*;
+use crate::wargaming::cache::account::{AccountInfoCache, AccountTanksCache};
+use crate::wargaming::WargamingApi;
+use crate::web::error::WebError;
+use crate::web::partials::*;
+use crate::web::views::player::display_preferences::DisplayPreferences;
+use crate::web::views::player::stats_delta::StatsDelta;
+use crate::web::views::search::models::Query as NicknameQuery;
+use crate::web::views::search::resolve_exact_match;
+use crate::web::TrackingCode;
+
+const MAX_MEMBERS: usize = 5;
+
+#[derive(Deserialize)]
+pub struct QueryParams {
+    #[serde(deserialize_with = "deserialize_account_ids")]
+    ids: Vec<wargaming::AccountId>,
+}
+
+#[derive(Deserialize)]
+pub struct SameNicknameParams {
+    nickname: NicknameQuery,
+
+    /// The realm, which the viewer has come from.
+    realm: wargaming::Realm,
+}
+
+#[derive(Copy, Clone)]
+enum Kind {
+    Group,
+    Linked,
+    SameNickname,
+}
+
+impl Kind {
+    /// The realm badges are only needed, when the members come from different realms.
+    const fn shows_realms(self) -> bool {
+        !matches!(self, Self::Group)
+    }
+
+    /// Page title and the total card title.
+    fn titles(self, locale: &Locale) -> Result<(String, String)> {
+        let (page_title_key, total_title_key) = match self {
+            Self::Group => ("page-title-group", "title-group-total"),
+            Self::Linked => ("page-title-linked-accounts", "title-linked-total"),
+            Self::SameNickname => ("page-title-same-nickname", "title-linked-total"),
+        };
+        Ok((locale.text(page_title_key)?, locale.text(total_title_key)?))
+    }
+}
+
+struct Member {
+    realm: wargaming::Realm,
+    info: wargaming::AccountInfo,
+    random: database::RandomStatsSnapshot,
+}
+
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all, level = "info", fields(realm = ?realm, ids = ?params.ids))]
+#[handler]
+pub async fn get(
+    Path(realm): Path<wargaming::Realm>,
+    Query(params): Query<QueryParams>,
+    cookies: &CookieJar,
+    mongodb: Data<&mongodb::Database>,
+    info_cache: Data<&AccountInfoCache>,
+    tanks_cache: Data<&AccountTanksCache>,
+    tracking_code: Data<&TrackingCode>,
+    locale: Locale,
+) -> Result<Response, WebError> {
+    let preferences = DisplayPreferences::from(cookies);
+    let accounts = params.ids.iter().map(|account_id| (realm, *account_id));
+    let members =
+        retrieve_members(&mongodb, &info_cache, &tanks_cache, accounts, &preferences).await?;
+    render(realm, &members, Kind::Group, tracking_code.0, &locale)
+}
+
+/// Combined statistics of the accounts, which are linked with `link-accounts`.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all, level = "info", fields(realm = ?realm, account_id = account_id))]
+#[handler]
+pub async fn get_linked(
+    Path((realm, account_id)): Path<(wargaming::Realm, wargaming::AccountId)>,
+    cookies: &CookieJar,
+    mongodb: Data<&mongodb::Database>,
+    info_cache: Data<&AccountInfoCache>,
+    tanks_cache: Data<&AccountTanksCache>,
+    tracking_code: Data<&TrackingCode>,
+    locale: Locale,
+) -> Result<Response, WebError> {
+    let links = database::AccountLink::retrieve_linked(&mongodb, realm, account_id).await?;
+    if links.len() < 2 {
+        return Err(WebError::NotFound);
+    }
+    let preferences = DisplayPreferences::from(cookies);
+    let accounts = links.iter().map(|link| (link.realm, link.account_id));
+    let members =
+        retrieve_members(&mongodb, &info_cache, &tanks_cache, accounts, &preferences).await?;
+    render(realm, &members, Kind::Linked, tracking_code.0, &locale)
+}
+
+/// Accounts with exactly the same nickname in the different realms, side by side.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all, level = "info", fields(nickname = params.nickname.0))]
+#[handler]
+pub async fn get_same_nickname(
+    Query(params): Query<SameNicknameParams>,
+    cookies: &CookieJar,
+    api: Data<&WargamingApi>,
+    mongodb: Data<&mongodb::Database>,
+    info_cache: Data<&AccountInfoCache>,
+    tanks_cache: Data<&AccountTanksCache>,
+    tracking_code: Data<&TrackingCode>,
+    locale: Locale,
+) -> Result<Response, WebError> {
+    let (api, nickname) = (*api, params.nickname.0.as_str());
+    let accounts: Vec<(wargaming::Realm, wargaming::AccountId)> =
+        join_all(wargaming::Realm::ALL.map(|realm| async move {
+            match api.search_accounts(realm, nickname).await {
+                Ok(found_accounts) => resolve_exact_match(&found_accounts, nickname)
+                    .map(|account_id| (realm, account_id)),
+                Err(error) => {
+                    warn!(?realm, "failed to search for the nickname: {:#}", error);
+                    None
+                }
+            }
+        }))
+        .await
+        .into_iter()
+        .flatten()
+        .collect();
+    let preferences = DisplayPreferences::from(cookies);
+    let members =
+        retrieve_members(&mongodb, &info_cache, &tanks_cache, accounts.into_iter(), &preferences)
+            .await?;
+    render(params.realm, &members, Kind::SameNickname, tracking_code.0, &locale)
+}
+
+async fn retrieve_members(
+    db: &mongodb::Database,
+    info_cache: &AccountInfoCache,
+    tanks_cache: &AccountTanksCache,
+    accounts: impl Iterator<Item = (wargaming::Realm, wargaming::AccountId)>,
+    preferences: &DisplayPreferences,
+) -> Result<Vec<Member>, WebError> {
+    let before = now() - Duration::from_std(preferences.period).map_err(Error::from)?;
+    let members: Vec<Member> = try_join_all(accounts.map(|(realm, account_id)| {
+        retrieve_member(db, info_cache, tanks_cache, realm, account_id, before)
+    }))
+    .await?
+    .into_iter()
+    .flatten()
+    .collect();
+    if members.is_empty() {
+        return Err(WebError::NotFound);
+    }
+    Ok(members)
+}
+
+/// Renders the members along with their total.
+fn render(
+    realm: wargaming::Realm,
+    members: &[Member],
+    kind: Kind,
+    tracking_code: &TrackingCode,
+    locale: &Locale,
+) -> Result<Response, WebError> {
+    let total: database::RandomStatsSnapshot = members.iter().map(|member| member.random).sum();
+    let (page_title, total_title) = kind.titles(locale)?;
+
+    let markup = html! {
+        (DOCTYPE)
+        html.has-navbar-fixed-top lang=(locale.text("html-lang")?) {
+            head {
+                (headers())
+                title {
+                    @for (i, member) in members.iter().enumerate() {
+                        @if i != 0 { ", " }
+                        (member.info.nickname)
+                    }
+                    " – " (page_title)
+                }
+            }
+            body {
+                (tracking_code)
+                nav.navbar.has-shadow.is-fixed-top role="navigation" aria-label="main navigation" {
+                    div.navbar-brand {
+                        (home_button(locale)?)
+                    }
+                    div.navbar-menu {
+                        div.navbar-end {
+                            form.navbar-item action="/search" method="GET" {
+                                (AccountSearch::new(realm, locale).try_into_markup()?)
+                            }
+                        }
+                    }
+                }
+
+                section.section {
+                    div.container {
+                        div.card."mb-5" {
+                            header.card-header {
+                                p.card-header-title {
+                                    span.icon-text.is-flex-wrap-nowrap {
+                                        span.icon.has-text-info { i.fa-solid.fa-people-group {} }
+                                        span { (total_title) }
+                                    }
+                                }
+                            }
+                            div.card-content {
+                                (render_stats(&total, locale)?)
+                            }
+                        }
+
+                        div.columns.is-multiline {
+                            @for member in members {
+                                div.column."is-6-tablet"."is-4-desktop" {
+                                    div.card {
+                                        header.card-header {
+                                            p.card-header-title {
+                                                @if kind.shows_realms() {
+                                                    span.tag.is-uppercase."mr-2" { (member.realm.to_emoji()) " " (member.realm.to_str()) }
+                                                }
+                                                a href=(format!("/{}/{}", member.realm, member.info.id)) {
+                                                    (member.info.nickname)
+                                                }
+                                            }
+                                        }
+                                        div.card-content {
+                                            (render_stats(&member.random, locale)?)
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                (footer(locale)?)
+            }
+        }
+    };
+
+    Ok(Html(markup.into_string())
+        .with_header("Cache-Control", "public, max-age=30, stale-while-revalidate=3600")
+        .into_response())
+}
+
+#[instrument(skip_all, level = "debug", fields(account_id = account_id))]
+async fn retrieve_member(
+    db: &mongodb::Database,
+    info_cache: &AccountInfoCache,
+    tanks_cache: &AccountTanksCache,
+    realm: wargaming::Realm,
+    account_id: wargaming::AccountId,
+    before: DateTime,
+) -> Result<Option<Member>> {
+    let (info, tanks) =
+        try_join(info_cache.get(realm, account_id), tanks_cache.get(realm, account_id)).await?;
+    let info = match info {
+        Some(info) => info,
+        None => return Ok(None),
+    };
+    database::Account::ensure_exists(db, realm, account_id)
+        .await
+        .context("failed to ensure the account existence")?;
+    let delta = StatsDelta::retrieve(db, realm, account_id, &info.stats, tanks, before).await?;
+    Ok(Some(Member {
+        realm,
+        info,
+        random: delta.random,
+    }))
+}
+
+fn render_stats(stats: &database::RandomStatsSnapshot, locale: &Locale) -> Result<Markup> {
+    let markup = html! {
+        @if stats.n_battles == 0 {
+            p.has-text-grey { (locale.text("message-not-played-random")?) }
+        } @else {
+            div.level.is-mobile {
+                div.level-item.has-text-centered {
+                    div {
+                        p.heading { (locale.text("title-battles")?) }
+                        p.title { (stats.n_battles) }
+                    }
+                }
+                div.level-item.has-text-centered {
+                    div {
+                        p.heading { (locale.text("title-victory-ratio")?) }
+                        p.title {
+                            (Float::from(stats.victory_ratio() * 100.0).precision(1))
+                            span.has-text-grey-light { "%" }
+                        }
+                    }
+                }
+                div.level-item.has-text-centered {
+                    div {
+                        p.heading { (locale.text("title-average-damage")?) }
+                        p.title { (Float::from(stats.average_damage_dealt()).precision(0)) }
+                    }
+                }
+            }
+        }
+    };
+    Ok(markup)
+}
+
+/// Parses the comma-separated account IDs, dropping the duplicates.
+fn deserialize_account_ids<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<wargaming::AccountId>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    parse_account_ids(&value).map_err(de::Error::custom)
+}
+
+fn parse_account_ids(value: &str) -> Result<Vec<wargaming::AccountId>> {
+    let mut account_ids = Vec::new();
+    for part in value.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+        let account_id = wargaming::AccountId::from_str(part)
+            .with_context(|| format!("`{part}` is not a valid account ID"))?;
+        if !account_ids.contains(&account_id) {
+            account_ids.push(account_id);
+        }
+    }
+    if account_ids.is_empty() {
+        bail!("no account IDs specified");
+    }
+    if account_ids.len() > MAX_MEMBERS {
+        bail!("at most {} accounts are allowed", MAX_MEMBERS);
+    }
+    Ok(account_ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_account_ids_ok() -> Result {
+        assert_eq!(parse_account_ids("1, 2,3,2,")?, vec![1, 2, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_account_ids_error() {
+        assert!(parse_account_ids("").is_err());
+        assert!(parse_account_ids("1,x").is_err());
+        assert!(parse_account_ids("1,2,3,4,5,6").is_err());
+    }
+}
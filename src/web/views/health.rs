@@ -0,0 +1,184 @@
+//! Health and readiness probes for the orchestrators.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+
+use fred::pool::RedisPool;
+use fred::prelude::*;
+use mongodb::bson::doc;
+use poem::http::StatusCode;
+use poem::web::{Data, Json};
+use poem::{handler, IntoResponse, Response};
+use serde::Serialize;
+use tokio::time::timeout;
+
+use crate::prelude::*;
+use crate::tankopedia;
+
+const CACHE_CONTROL: &str = "no-cache";
+const CHECK_TIMEOUT: time::Duration = time::Duration::from_secs(3);
+
+/// Liveness probe: responds with `204 No Content` when the dependencies are fine
+/// and with `503 Service Unavailable` otherwise.
+#[handler]
+#[instrument(skip_all, level = "info")]
+pub async fn get_healthz(
+    db: Data<&mongodb::Database>,
+    redis: Data<&RedisPool>,
+) -> Result<impl IntoResponse> {
+    let status = if Checks::run(&db, &redis).await.is_ok() {
+        StatusCode::NO_CONTENT
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    Ok(Response::from(status).with_header("Cache-Control", CACHE_CONTROL))
+}
+
+/// Readiness probe: responds with the JSON status breakdown.
+#[handler]
+#[instrument(skip_all, level = "info")]
+pub async fn get_readyz(
+    db: Data<&mongodb::Database>,
+    redis: Data<&RedisPool>,
+) -> Result<impl IntoResponse> {
+    let checks = Checks::run(&db, &redis).await;
+    let status = if checks.is_ok() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    let report = Report {
+        crawler_heartbeats: retrieve_heartbeat_ages(&db).await,
+        checks,
+    };
+    Ok(Json(report)
+        .with_status(status)
+        .with_header("Cache-Control", CACHE_CONTROL))
+}
+
+#[derive(Serialize)]
+struct Report {
+    #[serde(flatten)]
+    checks: Checks,
+
+    /// Seconds since the last heartbeat of each realm's crawler.
+    crawler_heartbeats: BTreeMap<&'static str, i64>,
+}
+
+#[derive(Serialize)]
+struct Checks {
+    mongodb: Check,
+    redis: Check,
+    tankopedia: Check,
+}
+
+impl Checks {
+    async fn run(db: &mongodb::Database, redis: &RedisPool) -> Self {
+        let (mongodb, redis) = futures::join!(
+            Check::run("mongodb", db.run_command(doc! { "ping": 1 }, None)),
+            Check::run("redis", redis.ping()),
+        );
+        let tankopedia = if tankopedia::is_loaded() {
+            Check::Ok
+        } else {
+            Check::Error {
+                message: "the tankopedia is empty".to_string(),
+            }
+        };
+        Self {
+            mongodb,
+            redis,
+            tankopedia,
+        }
+    }
+
+    const fn is_ok(&self) -> bool {
+        self.mongodb.is_ok() && self.redis.is_ok() && self.tankopedia.is_ok()
+    }
+}
+
+/// The response is public, so the messages are fixed, and the actual errors only get logged.
+#[derive(Serialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+enum Check {
+    Ok,
+    Error { message: String },
+}
+
+impl Check {
+    async fn run<T, E: Into<Error>>(
+        dependency: &'static str,
+        future: impl Future<Output = Result<T, E>>,
+    ) -> Self {
+        match timeout(CHECK_TIMEOUT, future).await {
+            Ok(Ok(_)) => Self::Ok,
+            Ok(Err(error)) => {
+                warn!(dependency, "health check failed: {:#}", error.into());
+                Self::Error {
+                    message: "unavailable".to_string(),
+                }
+            }
+            Err(_) => {
+                warn!(dependency, "health check timed out");
+                Self::Error {
+                    message: "timed out".to_string(),
+                }
+            }
+        }
+    }
+
+    const fn is_ok(&self) -> bool {
+        matches!(self, Self::Ok)
+    }
+}
+
+/// The heartbeats are only informational, for the crawlers are separate services.
+async fn retrieve_heartbeat_ages(db: &mongodb::Database) -> BTreeMap<&'static str, i64> {
+    match timeout(CHECK_TIMEOUT, database::CrawlerHeartbeat::retrieve_all(db)).await {
+        Ok(Ok(heartbeats)) => heartbeats
+            .into_iter()
+            .map(|heartbeat| {
+                (heartbeat.realm.to_str(), (now() - heartbeat.timestamp).num_seconds())
+            })
+            .collect(),
+        Ok(Err(error)) => {
+            warn!("failed to retrieve the crawler heartbeats: {:#}", error);
+            BTreeMap::new()
+        }
+        Err(_) => {
+            warn!("timed out to retrieve the crawler heartbeats");
+            BTreeMap::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_serialization_ok() -> Result {
+        let report = Report {
+            checks: Checks {
+                mongodb: Check::Ok,
+                redis: Check::Error {
+                    message: "timed out".to_string(),
+                },
+                tankopedia: Check::Ok,
+            },
+            crawler_heartbeats: BTreeMap::from([("eu", 42)]),
+        };
+        assert!(!report.checks.is_ok());
+        assert_eq!(
+            serde_json::to_string(&report)?,
+            // language=JSON
+            r#"{"mongodb":{"status":"ok"},"redis":{"status":"error","message":"timed out"},"tankopedia":{"status":"ok"},"crawler_heartbeats":{"eu":42}}"#,
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn tankopedia_is_loaded_ok() {
+        assert!(tankopedia::is_loaded());
+    }
+}
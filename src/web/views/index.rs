@@ -1,22 +1,35 @@
+use fred::pool::RedisPool;
 use maud::{html, DOCTYPE};
 use poem::i18n::Locale;
 use poem::web::{Data, Html};
 use poem::{handler, IntoResponse};
-use tracing::instrument;
+use tracing::{instrument, warn};
 
 use crate::helpers::sentry::clear_user;
 use crate::wargaming;
-use crate::web::partials::{headers, AccountSearch};
+use crate::web::coverage;
+use crate::web::error::WebError;
+use crate::web::partials::{headers, AccountSearch, HumanFloat};
 use crate::web::TrackingCode;
 
 #[instrument(skip_all)]
 #[handler]
 pub async fn get(
     tracking_code: Data<&TrackingCode>,
+    redis: Option<Data<&RedisPool>>, // the standalone app goes without Redis
     locale: Locale,
-) -> poem::Result<impl IntoResponse> {
+) -> Result<impl IntoResponse, WebError> {
     clear_user();
 
+    // The landing page must not break because of the decorative counters.
+    let coverage = match redis {
+        Some(redis) => coverage::retrieve(&redis).await.unwrap_or_else(|error| {
+            warn!("failed to retrieve the coverage: {:#}", error);
+            None
+        }),
+        None => None,
+    };
+
     let markup = html! {
         (DOCTYPE)
         html lang=(locale.text("html-lang")?) {
@@ -55,6 +68,22 @@ pub async fn get(
                                             }
                                         }
                                     }
+                                    @if let Some(coverage) = coverage {
+                                        div.level.is-mobile."mt-6" {
+                                            div.level-item.has-text-centered {
+                                                div {
+                                                    p.heading { (locale.text("title-accounts-tracked")?) }
+                                                    p.title { (HumanFloat(coverage.n_accounts as f64)) }
+                                                }
+                                            }
+                                            div.level-item.has-text-centered {
+                                                div {
+                                                    p.heading { (locale.text("title-battles-last-hour")?) }
+                                                    p.title { (HumanFloat(coverage.n_battles_per_hour as f64)) }
+                                                }
+                                            }
+                                        }
+                                    }
                                 }
                             }
                         }
@@ -67,13 +96,13 @@ pub async fn get(
     };
 
     Ok(Html(markup.into_string())
-        .with_header("Cache-Control", "public, max-age=604800, stale-while-revalidate=86400"))
+        .with_header("Cache-Control", "public, max-age=300, stale-while-revalidate=3600"))
 }
 
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
-    use crate::web::test::create_standalone_test_client;
+    use crate::web::test::{create_standalone_test_client, get_markup};
 
     #[tokio::test]
     async fn test_get_ok() -> Result {
@@ -83,4 +112,18 @@ mod tests {
         response.assert_header_exist("Cache-Control");
         Ok(())
     }
+
+    #[tokio::test]
+    async fn get_localized_ok() -> Result {
+        let (_guard, client) = create_standalone_test_client().await?;
+
+        let markup = get_markup(&client, "/", "ru").await?;
+        assert!(markup.contains(r#"lang="ru""#));
+        assert!(markup.contains("<title>Статистика игроков World of Tanks Blitz</title>"));
+
+        let markup = get_markup(&client, "/", "en-US").await?;
+        assert!(markup.contains(r#"lang="en""#));
+        assert!(markup.contains("<title>World of Tanks Blitz playerʼs statistics</title>"));
+        Ok(())
+    }
 }
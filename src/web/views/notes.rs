@@ -0,0 +1,125 @@
+//! Viewer's private notes on the accounts, searchable by the nickname, tag, or text.
+
+use chrono_humanize::Tense;
+use maud::{html, DOCTYPE};
+use poem::i18n::Locale;
+use poem::web::cookie::CookieJar;
+use poem::web::{Data, Html, Query};
+use poem::{handler, IntoResponse, Response};
+use serde::Deserialize;
+
+use crate::prelude::*;
+use crate::web::error::WebError;
+use crate::web::partials::*;
+use crate::web::viewer_id::ViewerId;
+use crate::web::TrackingCode;
+
+#[derive(Deserialize)]
+pub struct QueryParams {
+    #[serde(default)]
+    q: String,
+}
+
+#[instrument(skip_all, level = "info")]
+#[handler]
+pub async fn get(
+    Query(params): Query<QueryParams>,
+    cookies: &CookieJar,
+    mongodb: Data<&mongodb::Database>,
+    tracking_code: Data<&TrackingCode>,
+    locale: Locale,
+) -> Result<Response, WebError> {
+    let query = params.q.trim();
+    let notes: Vec<database::AccountNote> = match ViewerId::get(cookies) {
+        Some(ViewerId(viewer_id)) => database::AccountNote::retrieve_all(&mongodb, &viewer_id)
+            .await?
+            .into_iter()
+            .filter(|note| query.is_empty() || note.matches(query))
+            .collect(),
+        None => Vec::new(),
+    };
+
+    let markup = html! {
+        (DOCTYPE)
+        html.has-navbar-fixed-top lang=(locale.text("html-lang")?) {
+            head {
+                (headers())
+                title { (locale.text("page-title-my-notes")?) " – " (locale.text("page-title-index")?) }
+            }
+            body {
+                (tracking_code.0)
+                nav.navbar.has-shadow.is-fixed-top role="navigation" aria-label="main navigation" {
+                    div.navbar-brand {
+                        (home_button(&locale)?)
+                    }
+                    div.navbar-menu {
+                        div.navbar-end {
+                            form.navbar-item action="/search" method="GET" {
+                                (AccountSearch::new(wargaming::Realm::Europe, &locale).try_into_markup()?)
+                            }
+                        }
+                    }
+                }
+
+                section.section {
+                    div.container {
+                        h1.title { (locale.text("page-title-my-notes")?) }
+                        p.subtitle."is-6".has-text-grey { (locale.text("message-notes-private")?) }
+
+                        form."mb-5" action="/my/notes" method="GET" {
+                            div.field.has-addons {
+                                div.control.is-expanded {
+                                    input.input type="search" name="q" value=(query) placeholder=(locale.text("title-search-notes")?);
+                                }
+                                div.control {
+                                    button.button.is-info type="submit" {
+                                        span.icon { i.fa-solid.fa-magnifying-glass {} }
+                                    }
+                                }
+                            }
+                        }
+
+                        @if notes.is_empty() {
+                            p.has-text-grey { (locale.text("message-no-notes")?) }
+                        }
+
+                        @for note in &notes {
+                            div.box {
+                                div.level.is-mobile."mb-2" {
+                                    div.level-left {
+                                        div.level-item {
+                                            a href=(format!("/{}/{}", note.realm, note.account_id)) {
+                                                strong { (note.realm.to_emoji()) " " (note.nickname) }
+                                            }
+                                        }
+                                    }
+                                    div.level-right {
+                                        div.level-item."is-size-7".has-text-grey {
+                                            (datetime(note.updated_at, Tense::Past))
+                                        }
+                                    }
+                                }
+                                @if !note.tags.is_empty() {
+                                    div.tags."mb-1" {
+                                        @for tag in &note.tags {
+                                            span.tag.is-info.is-light { (tag) }
+                                        }
+                                    }
+                                }
+                                @if !note.text.is_empty() {
+                                    p style="white-space: pre-line" { (note.text) }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                (footer(&locale)?)
+            }
+        }
+    };
+
+    Ok(Html(markup.into_string())
+        .with_header("Cache-Control", "private, no-cache")
+        .into_response())
+}
@@ -6,36 +6,73 @@ use std::time;
 use std::time::Instant;
 
 use chrono_humanize::Tense;
+use fred::pool::RedisPool;
 use maud::{html, Markup, PreEscaped, DOCTYPE};
+use poem::http::StatusCode;
 use poem::i18n::Locale;
 use poem::web::cookie::CookieJar;
-use poem::web::{Data, Form, Html, Path, RealIp, Redirect};
+use poem::web::{Data, Form, Html, Path, Query, RealIp, Redirect};
 use poem::{handler, IntoResponse, Response};
 use statrs::distribution::ContinuousCDF;
 use statrs::statistics::Distribution;
 
 use self::damage_item::DamageItem;
-use self::display_preferences::UpdateDisplayPreferences;
+use self::display_preferences::{PeriodParams, UpdateDisplayPreferences};
+use self::expected_gold::ExpectedGold;
+use self::grind::{GrindKind, GrindProgress, GrindTargets, UpdateGrindTarget};
+use self::layout::{Layout, LayoutParams};
+use self::life_time_stats::LifeTimeStats;
+use self::note::UpdateNote;
 use self::partials::*;
 use self::path::PathSegments;
 use self::percentage_item::PercentageItem;
+use self::pivot::{victory_ratio_interval, PivotRow};
+use self::session_goal::{GoalKind, SessionGoals, UpdateSessionGoal};
+use self::tier_stats::TierStats;
+use self::vehicle_targets::{UpdateVehicleTarget, VehicleTargets};
 use self::view_model::ViewModel;
+use crate::crawler::fast_lane::FastLane;
+use crate::database::mongodb::traits::Upsert;
 use crate::helpers::time::{from_days, from_hours, from_months, from_years};
 use crate::math::traits::*;
 use crate::prelude::*;
-use crate::tankopedia::get_vehicle;
+use crate::tankopedia::cache::HistoricalTankopediaCache;
+use crate::tankopedia::HistoricalTankopedia;
 use crate::wargaming::cache::account::{AccountInfoCache, AccountTanksCache};
 use crate::web::partials::*;
+use crate::web::realm_host::RealmHost;
+use crate::web::search_bot::SearchBot;
 use crate::web::views::player::display_preferences::DisplayPreferences;
-use crate::web::{cookies, TrackingCode};
+use crate::web::viewer_id::ViewerId;
+use crate::web::views::r#static::{NAVBAR_JS, TABLE_JS, TIMELINE_JS};
+use crate::web::views::search;
+use crate::web::views::search::resolve_exact_match;
+use crate::web::error::WebError;
+use crate::web::TrackingCode;
 use crate::{database, wargaming};
 
+pub mod at;
 mod damage_item;
-mod display_preferences;
+pub mod display_preferences;
+mod expected_gold;
+pub mod feed;
+pub mod garage_diff;
+mod grind;
+mod layout;
+mod life_time_stats;
+mod note;
+pub mod overlay;
 mod partials;
 mod path;
 mod percentage_item;
-mod stats_delta;
+mod pivot;
+pub mod records;
+mod session_delta;
+mod session_goal;
+pub mod stats_delta;
+mod tier_stats;
+mod vehicle_targets;
+mod victory_ratio_history;
 mod view_constants;
 mod view_model;
 
@@ -51,16 +88,161 @@ pub async fn post(
     path: Path<PathSegments>,
     Form(update_preferences): Form<UpdateDisplayPreferences>,
     cookies: &CookieJar,
-) -> poem::Result<Redirect> {
-    let cookie_preferences = UpdateDisplayPreferences::from(cookies);
-    cookies::Builder::new(UpdateDisplayPreferences::COOKIE_NAME)
-        .value(DisplayPreferences::from(cookie_preferences + update_preferences))
-        .expires_in(Duration::weeks(4))
-        .set_path("/")
-        .add_to(cookies);
+) -> Result<Redirect, WebError> {
+    update_preferences.save(cookies);
     Ok(Redirect::see_other(format!("/{}/{}", path.realm, path.account_id)))
 }
 
+#[instrument(
+    skip_all,
+    level = "info",
+    fields(realm = ?path.realm, account_id = path.account_id),
+)]
+#[handler]
+pub async fn post_vehicle_target(
+    path: Path<PathSegments>,
+    Form(update): Form<UpdateVehicleTarget>,
+    cookies: &CookieJar,
+) -> Result<Redirect, WebError> {
+    let mut targets = VehicleTargets::from(cookies);
+    targets.update(&update).map_err(WebError::BadRequest)?;
+    targets.save(cookies);
+    Ok(Redirect::see_other(format!("/{}/{}", path.realm, path.account_id)))
+}
+
+#[instrument(
+    skip_all,
+    level = "info",
+    fields(realm = ?path.realm, account_id = path.account_id),
+)]
+#[handler]
+pub async fn post_session_goal(
+    path: Path<PathSegments>,
+    Form(update): Form<UpdateSessionGoal>,
+    cookies: &CookieJar,
+) -> Result<Redirect, WebError> {
+    let mut goals = SessionGoals::from(cookies);
+    goals
+        .update(path.realm, path.account_id, &update)
+        .map_err(WebError::BadRequest)?;
+    goals.save(cookies);
+    Ok(Redirect::see_other(format!("/{}/{}", path.realm, path.account_id)))
+}
+
+#[instrument(
+    skip_all,
+    level = "info",
+    fields(realm = ?path.realm, account_id = path.account_id),
+)]
+#[handler]
+pub async fn post_grind_target(
+    path: Path<PathSegments>,
+    Form(update): Form<UpdateGrindTarget>,
+    cookies: &CookieJar,
+) -> Result<Redirect, WebError> {
+    let mut targets = GrindTargets::from(cookies);
+    targets
+        .update(path.realm, path.account_id, &update)
+        .map_err(WebError::BadRequest)?;
+    targets.save(cookies);
+    Ok(Redirect::see_other(format!("/{}/{}", path.realm, path.account_id)))
+}
+
+/// Saves or deletes the viewer's private note on the account.
+#[instrument(
+    skip_all,
+    level = "info",
+    fields(realm = ?path.realm, account_id = path.account_id),
+)]
+#[handler]
+pub async fn post_note(
+    path: Path<PathSegments>,
+    Form(update): Form<UpdateNote>,
+    cookies: &CookieJar,
+    mongodb: Data<&mongodb::Database>,
+) -> Result<Redirect, WebError> {
+    let ViewerId(viewer_id) = ViewerId::get_or_issue(cookies);
+    match update
+        .into_note(viewer_id.clone(), path.realm, path.account_id)
+        .map_err(WebError::BadRequest)?
+    {
+        Some(note) => note.upsert(&mongodb).await?,
+        None => database::AccountNote::delete(&mongodb, &viewer_id, path.realm, path.account_id)
+            .await?,
+    }
+    Ok(Redirect::see_other(format!("/{}/{}", path.realm, path.account_id)))
+}
+
+/// Pushes the account into the crawler's fast lane on the viewer's request.
+#[instrument(
+    skip_all,
+    level = "info",
+    fields(realm = ?path.realm, account_id = path.account_id),
+)]
+#[handler]
+pub async fn post_refresh(
+    path: Path<PathSegments>,
+    fast_lane: Data<&FastLane>,
+    mongodb: Data<&mongodb::Database>,
+    real_ip: RealIp,
+) -> Result<StatusCode, WebError> {
+    // The crawler's stream skips the unknown accounts anyway.
+    let account = database::Account::retrieve(&mongodb, path.realm, path.account_id).await?;
+    if let Some(account) = account {
+        if let Err(error) = fast_lane.push(&account).await {
+            warn!("failed to push the account into the fast lane: {:#}", error);
+        }
+    }
+    let details = format!("{}/{}", path.realm, path.account_id);
+    database::AuditLogEntry::web(real_ip.0, "refresh", details)
+        .insert(&mongodb)
+        .await?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+/// Redirects the realm subdomain account URL to the canonical one.
+#[instrument(skip_all, level = "info", fields(realm = ?realm_host.realm, account_id = account_id))]
+#[handler]
+pub async fn get_by_realm_host(
+    realm_host: RealmHost,
+    Path(account_id): Path<wargaming::AccountId>,
+) -> Redirect {
+    Redirect::permanent(realm_host.canonical_url(account_id))
+}
+
+/// Redirects `/{realm}/@{nickname}` to the canonical account URL.
+/// The nickname must match exactly, apart from the case.
+#[instrument(skip_all, level = "info", fields(realm = ?realm, nickname = nickname))]
+#[handler]
+pub async fn get_by_nickname(
+    Path((realm, nickname)): Path<(wargaming::Realm, String)>,
+    api: Data<&wargaming::WargamingApi>,
+    mongodb: Data<&mongodb::Database>,
+) -> Result<Redirect, WebError> {
+    let nickname = nickname.strip_prefix('@').unwrap_or(&nickname).to_string();
+    let nickname = search::models::Query::try_from(nickname).map_err(WebError::BadRequest)?.0;
+    let account_id = match database::NicknameResolution::retrieve(&mongodb, realm, &nickname)
+        .await?
+    {
+        Some(account_id) => account_id,
+        None => {
+            let found_accounts = api.search_accounts(realm, &nickname).await?;
+            let account_id =
+                resolve_exact_match(&found_accounts, &nickname).ok_or(WebError::NotFound)?;
+            database::NicknameResolution {
+                realm,
+                nickname,
+                account_id,
+                resolved_at: now(),
+            }
+            .upsert(&mongodb)
+            .await?;
+            account_id
+        }
+    };
+    Ok(Redirect::permanent(format!("/{realm}/{account_id}")))
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(
     skip_all,
@@ -70,170 +252,140 @@ pub async fn post(
 #[handler]
 pub async fn get(
     path: Path<PathSegments>,
+    Query(layout_params): Query<LayoutParams>,
+    Query(period_params): Query<PeriodParams>,
     cookies: &CookieJar,
     mongodb: Data<&mongodb::Database>,
     info_cache: Data<&AccountInfoCache>,
     tanks_cache: Data<&AccountTanksCache>,
+    tankopedia_cache: Data<&HistoricalTankopediaCache>,
+    fast_lane: Data<&FastLane>,
+    redis: Data<&RedisPool>,
     tracking_code: Data<&TrackingCode>,
     real_ip: RealIp,
+    SearchBot(is_search_bot): SearchBot,
     locale: Locale,
-) -> poem::Result<Response> {
+) -> Result<Response, WebError> {
     let start_instant = Instant::now();
 
-    let view_model =
-        ViewModel::new(real_ip.0, path, cookies, &mongodb, &info_cache, &tanks_cache).await?;
-
-    let vehicles_thead = html! {
-        tr {
-            th {
-                span.icon-text.is-flex-wrap-nowrap {
-                    span.icon { i.fas.fa-truck-monster {} }
-                    span { (locale.text("title-vehicle")?) }
-                }
-            }
-
-            th.has-text-centered { (locale.text("title-type")?) }
-
-            th.has-text-right {
-                a data-sort="battles" {
-                    span.icon-text.is-flex-wrap-nowrap {
-                        span { (locale.text("title-battles")?) }
-                    }
-                }
-            }
-
-            th {
-                a data-sort="wins" {
-                    span.icon-text.is-flex-wrap-nowrap {
-                        span { (locale.text("title-wins")?) }
-                    }
-                }
-            }
-
-            th.has-text-right {
-                a data-sort="win-rate" {
-                    span.icon-text.is-flex-wrap-nowrap {
-                        span { (locale.text("title-victory-ratio")?) }
-                    }
-                }
-            }
-
-            th {
-                a data-sort="victory-probability" {
-                    span.icon-text.is-flex-wrap-nowrap {
-                        span {
-                            (locale.text("title-victory-probability")?)
-                        }
-                    }
-                }
-            }
-
-            th {
-                a data-sort="target-victory-ratio-probability" {
-                    span.icon-text.is-flex-wrap-nowrap {
-                        span { (locale.text("title-target-victory-ratio-probability")?) }
-                    }
-                }
-            }
-
-            th {
-                a data-sort="frags-per-battle" {
-                    span.icon-text.is-flex-wrap-nowrap {
-                        span { (locale.text("title-frags-per-battle")?) }
-                    }
-                }
-            }
-
-            th {
-                a data-sort="posterior-gold" {
-                    span.icon-text.is-flex-wrap-nowrap {
-                        span {
-                            abbr title=(locale.text("title-posterior-gold-abbr")?) {
-                                (locale.text("title-posterior-gold")?)
-                            }
-                        }
-                    }
-                }
-            }
-
-            th {
-                a data-sort="damage-ratio" {
-                    span.icon-text.is-flex-wrap-nowrap {
-                        span { (locale.text("title-damage-ratio")?) }
-                    }
-                }
-            }
-
-            th.has-text-left {
-                a data-sort="damage-dealt" {
-                    span.icon-text.is-flex-wrap-nowrap {
-                        span { (locale.text("title-damage-dealt")?) }
-                    }
-                }
-            }
-
-            th.has-text-left {
-                a data-sort="damage-per-battle" {
-                    span.icon-text.is-flex-wrap-nowrap {
-                        span { (locale.text("title-damage-dealt-per-battle")?) }
-                    }
-                }
-            }
-
-            th.has-text-left {
-                a data-sort="accuracy" {
-                    span.icon-text.is-flex-wrap-nowrap {
-                        span { (locale.text("title-hits")?) }
-                    }
-                }
-            }
-
-            th.has-text-right {
-                a data-sort="survived-battles" {
-                    span.icon-text.is-flex-wrap-nowrap {
-                        span { (locale.text("title-survived")?) }
-                    }
-                }
-            }
-
-            th {
-                a data-sort="survival-rate" {
-                    span.icon-text.is-flex-wrap-nowrap {
-                        span { (locale.text("title-survival-ratio")?) }
-                    }
-                }
-            }
-
-            th {
-                span.icon-text.is-flex-wrap-nowrap {
-                    span.icon { i.fas.fa-truck-monster {} }
-                    span { (locale.text("title-vehicle")?) }
-                }
-            }
+    // The period tabs are plain links, which persist the period and get back to the clean URL.
+    if period_params.period.is_some() {
+        UpdateDisplayPreferences::from(period_params).save(cookies);
+        let mut url = format!("/{}/{}", path.realm, path.account_id);
+        if let Some(layout) = layout_params.layout {
+            url = format!("{}?layout={}", url, layout.as_str());
         }
+        return Ok(Redirect::see_other(url).into_response());
+    }
+
+    let view_model = ViewModel::new(
+        real_ip.0,
+        path,
+        cookies,
+        &mongodb,
+        &info_cache,
+        &tanks_cache,
+        &tankopedia_cache,
+        &fast_lane,
+        &redis,
+    )
+    .await?;
+    // The search engines get the script-free compact page, which is cheaper to render and index.
+    let layout = if is_search_bot {
+        Layout::Compact
+    } else {
+        layout_params.layout.unwrap_or_default()
     };
+    let is_compact = layout == Layout::Compact;
+
+    let vehicles_thead =
+        render_vehicles_thead(&locale, view_model.preferences.show_extra_columns)?;
     let markup = html! {
         (DOCTYPE)
         html.has-navbar-fixed-bottom lang=(locale.text("html-lang")?) {
             head {
-                script type="module" defer { (r##"
-                    'use strict';
+                @if !is_search_bot {
+                    script type="module" defer { (PreEscaped(format!(r##"
+                        'use strict';
                     
-                    import { initSortableTable } from '/static/table.js?v5';
+                        import {{ initSortableTable }} from '{}';
                     
-                    (function () {
-                        const vehicles = document.getElementById('vehicles');
-                        if (vehicles != null) {
-                            initSortableTable(vehicles, 'battles');
+                        (function () {{
+                            const vehicles = document.getElementById('vehicles');
+                            if (vehicles != null) {{
+                                initSortableTable(vehicles, 'battles');
+                            }}
+                        }})();
+                    "##, TABLE_JS.href()))) }
+
+                    script type="module" defer { (PreEscaped(format!(r##"
+                        'use strict';
+                        import {{ init }} from '{}';
+                        init();
+                    "##, NAVBAR_JS.href()))) }
+
+                    script type="module" defer { (PreEscaped(r##"
+                        'use strict';
+                        const freshness = document.getElementById('freshness');
+                        const refreshButton = document.getElementById('refresh-button');
+                        if (refreshButton != null) {
+                            refreshButton.addEventListener('click', async () => {
+                                refreshButton.classList.add('is-loading');
+                                await fetch(freshness.dataset.refreshUrl, {method: 'POST'});
+                                for (let i = 0; i < 60; i++) {
+                                    await new Promise((resolve) => setTimeout(resolve, 5000));
+                                    const response = await fetch(freshness.dataset.url);
+                                    if (!response.ok) {
+                                        continue;
+                                    }
+                                    const {last_snapshot_time} = await response.json();
+                                    if (last_snapshot_time > Number(freshness.dataset.lastSnapshotTime)) {
+                                        window.location.reload();
+                                        return;
+                                    }
+                                }
+                                refreshButton.classList.remove('is-loading');
+                            });
+                        }
+                    "##)) }
+
+                    script type="module" defer { (PreEscaped(r##"
+                        'use strict';
+                        for (const row of document.querySelectorAll('[data-pivot-toggle]')) {
+                            row.addEventListener('click', () => {
+                                const group = row.dataset.pivotToggle;
+                                for (const child of document.querySelectorAll(`[data-pivot-group="${group}"]`)) {
+                                    child.classList.toggle('is-hidden');
+                                }
+                            });
                         }
-                    })();
-                "##) }
+                    "##)) }
+
+                    @if !is_compact {
+                        script type="module" defer { (PreEscaped(format!(r##"
+                            'use strict';
+                            import {{ initTimeline }} from '{}';
+                            initTimeline(document.getElementById('timeline'));
+                        "##, TIMELINE_JS.href()))) }
+                    }
+
+                    @if layout_params.layout.is_none() && view_model.preferences.compact_on_narrow_screens {
+                        script {
+                            (PreEscaped(r##"
+                                'use strict';
+                                if (window.matchMedia('(max-width: 768px)').matches) {
+                                    const url = new URL(window.location);
+                                    url.searchParams.set('layout', 'compact');
+                                    window.location.replace(url);
+                                }
+                            "##))
+                        }
+                    }
+                }
 
-                script type="module" defer { (r##"
-                    'use strict';
-                    import { init } from '/static/navbar.js?v1';
-                    init();
-                "##) }
+                @if is_compact {
+                    style { "@media print { .navbar { display: none !important; } }" }
+                }
 
                 (headers())
                 link rel="canonical" href=(format!("/{}/{}", view_model.realm, view_model.actual_info.id));
@@ -273,6 +425,16 @@ pub async fn get(
                                 }
                             }
                         }
+
+                        @if view_model.is_linked {
+                            a.navbar-item href=(format!("/{}/{}/linked", view_model.realm, view_model.actual_info.id)) title=(locale.text("title-linked-accounts")?) {
+                                span.icon { i.fa-solid.fa-link {} }
+                            }
+                        }
+
+                        a.navbar-item href=(format!("/{}/{}/records", view_model.realm, view_model.actual_info.id)) title=(locale.text("page-title-personal-bests")?) {
+                            span.icon { i.fa-solid.fa-trophy {} }
+                        }
                     }
                     div.navbar-menu.is-active {
                         div.navbar-end {
@@ -288,7 +450,83 @@ pub async fn get(
                 }
 
                 section.section.has-background-info-light."pt-5" {
-                    p.subtitle.has-text-weight-medium { (view_model.realm.to_emoji()) (PreEscaped("&nbsp;")) (view_model.actual_info.nickname) }
+                    p.subtitle.has-text-weight-medium {
+                        (view_model.realm.to_emoji()) (PreEscaped("&nbsp;")) (view_model.actual_info.nickname)
+                        @if let Some(level) = view_model.activity_level {
+                            " " (activity_tag(level, &locale)?)
+                        }
+                    }
+                    div."mb-3" {
+                        @if let Some(note) = &view_model.note {
+                            div.tags."mb-1" {
+                                @for tag in &note.tags {
+                                    span.tag.is-info.is-light { (tag) }
+                                }
+                            }
+                            @if !note.text.is_empty() {
+                                p."is-size-7"."mb-1" style="white-space: pre-line" { (note.text) }
+                            }
+                        }
+                        details {
+                            summary."is-size-7".has-text-grey {
+                                span.icon-text {
+                                    span.icon { i.fa-solid.fa-note-sticky {} }
+                                    span { (locale.text("title-edit-note")?) }
+                                }
+                            }
+                            form."mt-2" method="post" action=(format!("/{}/{}/note", view_model.realm, view_model.actual_info.id)) {
+                                input type="hidden" name="nickname" value=(view_model.actual_info.nickname);
+                                div.field {
+                                    div.control {
+                                        input.input.is-small type="text" name="tags" maxlength="350"
+                                            placeholder=(locale.text("title-note-tags-placeholder")?)
+                                            value=[view_model.note.as_ref().map(|note| note.tags.join(", "))];
+                                    }
+                                }
+                                div.field {
+                                    div.control {
+                                        textarea.textarea.is-small name="text" rows="2" maxlength=(database::AccountNote::MAX_TEXT_LEN)
+                                            placeholder=(locale.text("title-note-text-placeholder")?) {
+                                            @if let Some(note) = &view_model.note { (note.text) }
+                                        }
+                                    }
+                                }
+                                div.field.is-grouped {
+                                    div.control {
+                                        button.button.is-small.is-info type="submit" { (locale.text("title-save-note")?) }
+                                    }
+                                    div.control {
+                                        a.button.is-small.is-light href="/my/notes" { (locale.text("page-title-my-notes")?) }
+                                    }
+                                }
+                                p.help { (locale.text("message-notes-private")?) }
+                            }
+                        }
+                    }
+                    @if let Some(since) = view_model.live_data_unavailable_since {
+                        div.notification.is-warning."mb-3" {
+                            span.icon-text {
+                                span.icon { i.fa-solid.fa-triangle-exclamation {} }
+                                span { (locale.text("message-live-data-unavailable")?) " " (datetime(since, Tense::Past)) }
+                            }
+                        }
+                    }
+                    p."is-size-7".has-text-grey."mb-3"
+                        id="freshness"
+                        data-last-snapshot-time=(view_model.last_snapshot_time.map_or(0, |time| time.timestamp_millis()))
+                        data-url=(format!("/api/v1/{}/{}/freshness", view_model.realm, view_model.actual_info.id))
+                        data-refresh-url=(format!("/{}/{}/refresh", view_model.realm, view_model.actual_info.id))
+                    {
+                        @match view_model.last_snapshot_time {
+                            Some(last_snapshot_time) => { (locale.text("title-data-as-of")?) " " (datetime(last_snapshot_time, Tense::Past)) },
+                            None => (locale.text("title-no-data-yet")?),
+                        }
+                        @if view_model.is_stale() {
+                            button.button.is-small.is-rounded.is-light."ml-2" type="button" id="refresh-button" title=(locale.text("title-refresh")?) {
+                                span.icon { i.fa-solid.fa-rotate {} }
+                            }
+                        }
+                    }
 
                     div.container {
                         div.columns.is-multiline {
@@ -311,7 +549,7 @@ pub async fn get(
                                                     p.title title=(rating) { (rating) }
                                                 }
                                             }
-                                            @if !view_model.rating_snapshots.is_empty() {
+                                            @if !is_compact && !view_model.rating_snapshots.is_empty() {
                                                 div.level-item.has-text-centered {
                                                     div id="rating-chart" {}
                                                 }
@@ -349,6 +587,11 @@ pub async fn get(
                                                     }
                                                 }
                                             }
+                                            @if !is_compact && view_model.victory_ratio_history.len() >= 2 {
+                                                div.level-item.has-text-centered {
+                                                    div id="victory-ratio-chart" title=(locale.text("title-victory-ratio-history")?) {}
+                                                }
+                                            }
                                         }
                                     }
                                 }
@@ -392,33 +635,208 @@ pub async fn get(
                                     }
                                 }
                             }
-                        }
-                    }
-                }
-
-                section.section."pt-5" {
-                    nav.tabs.is-boxed.has-text-weight-medium {
-                        div.container {
-                            ul {
-                                (render_period_li(view_model.preferences.period, from_hours(2), &locale.text("title-period-2-hours")?)?)
-                                (render_period_li(view_model.preferences.period, from_hours(6), &locale.text("title-period-6-hours")?)?)
-                                (render_period_li(view_model.preferences.period, from_hours(12), &locale.text("title-period-12-hours")?)?)
-                                (render_period_li(view_model.preferences.period, from_days(1), &locale.text("title-period-24-hours")?)?)
-                                (render_period_li(view_model.preferences.period, from_days(2), &locale.text("title-period-2-days")?)?)
-                                (render_period_li(view_model.preferences.period, from_days(3), &locale.text("title-period-3-days")?)?)
-                                (render_period_li(view_model.preferences.period, from_days(7), &locale.text("title-period-1-week")?)?)
-                                (render_period_li(view_model.preferences.period, from_days(14), &locale.text("title-period-2-weeks")?)?)
-                                (render_period_li(view_model.preferences.period, from_days(21), &locale.text("title-period-3-weeks")?)?)
-                                (render_period_li(view_model.preferences.period, from_months(1), &locale.text("title-period-1-month")?)?)
-                                (render_period_li(view_model.preferences.period, from_months(2), &locale.text("title-period-2-months")?)?)
-                                (render_period_li(view_model.preferences.period, from_months(3), &locale.text("title-period-3-months")?)?)
-                                (render_period_li(view_model.preferences.period, from_months(6), &locale.text("title-period-6-months")?)?)
-                                (render_period_li(view_model.preferences.period, from_years(1), &locale.text("title-period-1-year")?)?)
-                            }
-                        }
-                    }
 
-                    div.container {
+                            @if let Some(session_delta) = &view_model.session_delta {
+                                div.column."is-6-tablet"."is-5-desktop"."is-4-widescreen" {
+                                    div.card {
+                                        header.card-header {
+                                            p.card-header-title {
+                                                span.icon-text.is-flex-wrap-nowrap {
+                                                    span.icon.has-text-primary { i.fa-solid.fa-clock-rotate-left {} }
+                                                    span { (locale.text("title-last-session")?) }
+                                                }
+                                            }
+                                        }
+                                        div.card-content {
+                                            div.level.is-mobile {
+                                                @let (last, previous) = (&session_delta.last, &session_delta.previous);
+                                                div.level-item.has-text-centered {
+                                                    div {
+                                                        p.heading { (locale.text("title-battles")?) }
+                                                        p.title {
+                                                            (last.n_battles)
+                                                            (render_change_arrow(last.n_battles as f64, previous.n_battles as f64))
+                                                        }
+                                                        p."is-size-7".has-text-grey title=(locale.text("title-previous-session")?) {
+                                                            (previous.n_battles)
+                                                        }
+                                                    }
+                                                }
+                                                div.level-item.has-text-centered {
+                                                    div {
+                                                        p.heading { (locale.text("title-victory-ratio")?) }
+                                                        p.title {
+                                                            (render_percentage(last.victory_ratio()))
+                                                            (render_change_arrow(last.victory_ratio(), previous.victory_ratio()))
+                                                        }
+                                                        p."is-size-7".has-text-grey title=(locale.text("title-previous-session")?) {
+                                                            (render_percentage(previous.victory_ratio()))
+                                                        }
+                                                    }
+                                                }
+                                                div.level-item.has-text-centered {
+                                                    div {
+                                                        p.heading { (locale.text("title-average-damage")?) }
+                                                        p.title {
+                                                            (Float::from(last.average_damage_dealt()).precision(0))
+                                                            (render_change_arrow(last.average_damage_dealt(), previous.average_damage_dealt()))
+                                                        }
+                                                        p."is-size-7".has-text-grey title=(locale.text("title-previous-session")?) {
+                                                            (Float::from(previous.average_damage_dealt()).precision(0))
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            div.column."is-6-tablet"."is-5-desktop"."is-4-widescreen" {
+                                div.card {
+                                    header.card-header {
+                                        p.card-header-title {
+                                            span.icon-text.is-flex-wrap-nowrap {
+                                                span.icon.has-text-success { i.fa-solid.fa-flag-checkered {} }
+                                                span { (locale.text("title-session-goal")?) }
+                                            }
+                                        }
+                                    }
+                                    div.card-content {
+                                        @let goal_url = format!("/{}/{}/session-goal", view_model.realm, view_model.actual_info.id);
+                                        @if let Some(progress) = &view_model.session_goal {
+                                            @let title = match progress.goal.kind {
+                                                GoalKind::Wins => locale.text("title-goal-wins")?,
+                                                GoalKind::Battles => locale.text("title-goal-battles")?,
+                                                GoalKind::Rating => locale.text("title-goal-rating")?,
+                                            };
+                                            p.heading { (title) ": " (progress.current) " / " (progress.goal.target) }
+                                            @if progress.is_reached() {
+                                                p.has-text-success.has-text-weight-medium {
+                                                    span.icon-text {
+                                                        span.icon { i.fa-solid.fa-trophy {} }
+                                                        span { (locale.text("title-goal-reached")?) }
+                                                    }
+                                                }
+                                            } @else {
+                                                progress.progress.is-success value=(progress.current.max(0)) max=(progress.goal.target) {}
+                                                form method="post" action=(goal_url) {
+                                                    input type="hidden" name="kind" value=(progress.goal.kind.as_str());
+                                                    input type="hidden" name="target" value="";
+                                                    button.button.is-small.is-rounded type="submit" { (locale.text("title-reset-goal")?) }
+                                                }
+                                            }
+                                        } @else {
+                                            form method="post" action=(goal_url) {
+                                                div.field.has-addons {
+                                                    div.control {
+                                                        div.select.is-small {
+                                                            select name="kind" {
+                                                                option value="wins" { (locale.text("title-goal-wins")?) }
+                                                                option value="battles" { (locale.text("title-goal-battles")?) }
+                                                                option value="rating" { (locale.text("title-goal-rating")?) }
+                                                            }
+                                                        }
+                                                    }
+                                                    div.control {
+                                                        input.input.is-small type="number" name="target" min="1" required;
+                                                    }
+                                                    div.control {
+                                                        button.button.is-small.is-success type="submit" { (locale.text("title-set-goal")?) }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            @if !view_model.grind_progress.is_empty() {
+                                div.column."is-6-tablet"."is-5-desktop"."is-4-widescreen" {
+                                    div.card {
+                                        header.card-header {
+                                            p.card-header-title {
+                                                span.icon-text.is-flex-wrap-nowrap {
+                                                    span.icon.has-text-info { i.fa-solid.fa-person-digging {} }
+                                                    span { (locale.text("title-grind")?) }
+                                                }
+                                            }
+                                        }
+                                        div.card-content {
+                                            @for progress in &view_model.grind_progress {
+                                                (render_grind_progress(progress, &view_model.tankopedia, &locale)?)
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                @if !is_compact {
+                    section.section."pt-5"."pb-0" {
+                        div.container {
+                            div.card
+                                id="timeline"
+                                data-url=(format!("/api/v1/{}/{}/timeline", view_model.realm, view_model.actual_info.id))
+                            {
+                                header.card-header {
+                                    p.card-header-title {
+                                        span.icon-text.is-flex-wrap-nowrap {
+                                            span.icon.has-text-info { i.fa-solid.fa-chart-column {} }
+                                            span { (locale.text("title-timeline")?) }
+                                        }
+                                    }
+                                }
+                                div.card-content {
+                                    div.tabs.is-small."mb-2" {
+                                        ul {
+                                            li.is-active { a data-metric="win_rate" { (locale.text("title-victory-ratio")?) } }
+                                            li { a data-metric="battles" { (locale.text("title-battles")?) } }
+                                            li { a data-metric="average_damage" { (locale.text("title-average-damage")?) } }
+                                            li { a data-metric="survival_rate" { (locale.text("title-survival-ratio")?) } }
+                                            li { a data-metric="accuracy" { (locale.text("title-hits")?) } }
+                                        }
+                                    }
+                                    div.tabs.is-small.is-toggle {
+                                        ul {
+                                            li { a data-period="1w" { (locale.text("title-period-1-week")?) } }
+                                            li.is-active { a data-period="1m" { (locale.text("title-period-1-month")?) } }
+                                            li { a data-period="3m" { (locale.text("title-period-3-months")?) } }
+                                            li { a data-period="1y" { (locale.text("title-period-1-year")?) } }
+                                        }
+                                    }
+                                    div.timeline-chart {}
+                                }
+                            }
+                        }
+                    }
+                }
+
+                section.section."pt-5" {
+                    nav.tabs.is-boxed.has-text-weight-medium {
+                        div.container {
+                            ul {
+                                (render_period_li(view_model.preferences.period, layout_params.layout, from_hours(2), &locale.text("title-period-2-hours")?)?)
+                                (render_period_li(view_model.preferences.period, layout_params.layout, from_hours(6), &locale.text("title-period-6-hours")?)?)
+                                (render_period_li(view_model.preferences.period, layout_params.layout, from_hours(12), &locale.text("title-period-12-hours")?)?)
+                                (render_period_li(view_model.preferences.period, layout_params.layout, from_days(1), &locale.text("title-period-24-hours")?)?)
+                                (render_period_li(view_model.preferences.period, layout_params.layout, from_days(2), &locale.text("title-period-2-days")?)?)
+                                (render_period_li(view_model.preferences.period, layout_params.layout, from_days(3), &locale.text("title-period-3-days")?)?)
+                                (render_period_li(view_model.preferences.period, layout_params.layout, from_days(7), &locale.text("title-period-1-week")?)?)
+                                (render_period_li(view_model.preferences.period, layout_params.layout, from_days(14), &locale.text("title-period-2-weeks")?)?)
+                                (render_period_li(view_model.preferences.period, layout_params.layout, from_days(21), &locale.text("title-period-3-weeks")?)?)
+                                (render_period_li(view_model.preferences.period, layout_params.layout, from_months(1), &locale.text("title-period-1-month")?)?)
+                                (render_period_li(view_model.preferences.period, layout_params.layout, from_months(2), &locale.text("title-period-2-months")?)?)
+                                (render_period_li(view_model.preferences.period, layout_params.layout, from_months(3), &locale.text("title-period-3-months")?)?)
+                                (render_period_li(view_model.preferences.period, layout_params.layout, from_months(6), &locale.text("title-period-6-months")?)?)
+                                (render_period_li(view_model.preferences.period, layout_params.layout, from_years(1), &locale.text("title-period-1-year")?)?)
+                            }
+                        }
+                    }
+
+                    div.container {
                         @if view_model.stats_delta.rating.n_battles != 0 {
                             div.columns.is-multiline.has-background-warning-light id="rating-columns" {
                                 div.column."is-5-tablet"."is-4-desktop"."is-3-widescreen" {
@@ -749,6 +1167,181 @@ pub async fn get(
                                     }
                                 }
 
+                                @let tier_stats = TierStats::new(&view_model.stats_delta.tanks, &view_model.tankopedia);
+                                @if let Some(average_tier) = tier_stats.average_tier() {
+                                    div.column."is-4-tablet"."is-3-desktop"."is-3-widescreen" {
+                                        div.card {
+                                            header.card-header {
+                                                p.card-header-title {
+                                                    span.icon-text.is-flex-wrap-nowrap {
+                                                        span.icon.has-text-link { i.fa-solid.fa-layer-group {} }
+                                                        span { (locale.text("title-average-tier")?) }
+                                                    }
+                                                }
+                                                p.card-header-icon {
+                                                    a.icon.has-text-grey-light href="#random-columns" { i.fa-solid.fa-dice {} }
+                                                }
+                                            }
+                                            div.card-content {
+                                                div.level.is-mobile {
+                                                    div.level-item.has-text-centered {
+                                                        div {
+                                                            p.heading { (locale.text("title-average-masculine")?) }
+                                                            p.title { (Float::from(average_tier).precision(1)) }
+                                                        }
+                                                    }
+                                                    div.level-item.has-text-centered {
+                                                        div.tier-bars {
+                                                            @let max_n_battles = tier_stats.max_n_battles();
+                                                            @for (n_battles, tier) in tier_stats.n_battles.iter().zip(1_u8..) {
+                                                                div.tier-bar title=(format!("{}: {}", TIER_MARKUP.get(&tier).copied().unwrap_or_default(), n_battles)) {
+                                                                    div.has-background-link style=(format!("height: {}%", 100 * n_battles / max_n_battles)) {}
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+
+                                @let gold = ExpectedGold::total(&view_model.stats_delta.tanks, &view_model.tankopedia, view_model.preferences.confidence_level)?;
+                                @if gold.mean != 0.0 {
+                                    div.column."is-6-tablet"."is-4-desktop"."is-3-widescreen" {
+                                        div.card {
+                                            header.card-header {
+                                                p.card-header-title {
+                                                    span.icon-text.is-flex-wrap-nowrap {
+                                                        span.icon.has-text-warning-dark { i.fas.fa-coins {} }
+                                                        span {
+                                                            abbr title=(locale.text("title-posterior-gold-abbr")?) {
+                                                                (locale.text("title-posterior-gold")?)
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                                p.card-header-icon {
+                                                    a.icon.has-text-grey-light href="#random-columns" { i.fa-solid.fa-dice {} }
+                                                }
+                                            }
+                                            div.card-content {
+                                                div.level.is-mobile {
+                                                    div.level-item.has-text-centered {
+                                                        div {
+                                                            p.heading { (locale.text("title-total")?) }
+                                                            p.title { (Float::from(gold.mean).precision(0)) }
+                                                            p."is-size-7".has-text-grey title=(locale.text("title-credible-interval")?) {
+                                                                (Float::from(gold.lower).precision(0)) "–" (Float::from(gold.upper).precision(0))
+                                                            }
+                                                        }
+                                                    }
+                                                    @let n_battles = view_model.stats_delta.tanks.iter().map(|tank| tank.stats.n_battles).sum::<u32>() as f64;
+                                                    div.level-item.has-text-centered {
+                                                        div {
+                                                            p.heading { (locale.text("title-per-battle")?) }
+                                                            p.title { (Float::from(gold.mean / n_battles).precision(1)) }
+                                                            p."is-size-7".has-text-grey title=(locale.text("title-credible-interval")?) {
+                                                                (Float::from(gold.lower / n_battles).precision(1)) "–" (Float::from(gold.upper / n_battles).precision(1))
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+
+                                @let life_time_stats = LifeTimeStats::new(&view_model.stats_delta.tanks);
+                                @if let Some(hours) = life_time_stats.hours() {
+                                    div.column."is-6-tablet"."is-6-desktop"."is-5-widescreen" {
+                                        div.card {
+                                            header.card-header {
+                                                p.card-header-title {
+                                                    span.icon-text.is-flex-wrap-nowrap {
+                                                        span.icon.has-text-primary { i.fa-solid.fa-stopwatch {} }
+                                                        span { (locale.text("title-battle-life-time")?) }
+                                                    }
+                                                }
+                                                p.card-header-icon {
+                                                    a.icon.has-text-grey-light href="#random-columns" { i.fa-solid.fa-dice {} }
+                                                }
+                                            }
+                                            div.card-content {
+                                                div.level.is-mobile {
+                                                    div.level-item.has-text-centered {
+                                                        div {
+                                                            p.heading { (locale.text("title-hours")?) }
+                                                            p.title { (Float::from(hours).precision(1)) }
+                                                        }
+                                                    }
+                                                    div.level-item.has-text-centered {
+                                                        div {
+                                                            p.heading { (locale.text("title-battles-per-hour")?) }
+                                                            p.title { (Float::from(life_time_stats.battles_per_hour().unwrap_or_default()).precision(1)) }
+                                                        }
+                                                    }
+                                                    div.level-item.has-text-centered {
+                                                        div {
+                                                            p.heading { (locale.text("title-wins-per-hour")?) }
+                                                            p.title { (Float::from(life_time_stats.wins_per_hour().unwrap_or_default()).precision(1)) }
+                                                        }
+                                                    }
+                                                    div.level-item.has-text-centered {
+                                                        div {
+                                                            p.heading { (locale.text("title-damage-per-minute")?) }
+                                                            p.title { (Float::from(life_time_stats.damage_per_minute().unwrap_or_default()).precision(0)) }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+
+                                @if let Some(hours) = life_time_stats.hours() {
+                                    @let known_tanks: Vec<_> = LifeTimeStats::known_tanks(&view_model.stats_delta.tanks).copied().collect();
+                                    @let gold = ExpectedGold::total(&known_tanks, &view_model.tankopedia, view_model.preferences.confidence_level)?;
+                                    div.column."is-6-tablet"."is-4-desktop"."is-3-widescreen" {
+                                        div.card {
+                                            header.card-header {
+                                                p.card-header-title {
+                                                    span.icon-text.is-flex-wrap-nowrap {
+                                                        span.icon.has-text-success { i.fa-solid.fa-sack-dollar {} }
+                                                        span { (locale.text("title-income-per-hour")?) }
+                                                    }
+                                                }
+                                                p.card-header-icon {
+                                                    a.icon.has-text-grey-light href="#random-columns" { i.fa-solid.fa-dice {} }
+                                                }
+                                            }
+                                            div.card-content {
+                                                div.level.is-mobile {
+                                                    div.level-item.has-text-centered {
+                                                        div {
+                                                            p.heading { (locale.text("title-xp")?) }
+                                                            p.title { (Float::from(life_time_stats.xp_per_hour().unwrap_or_default()).precision(0)) }
+                                                        }
+                                                    }
+                                                    div.level-item.has-text-centered {
+                                                        div {
+                                                            p.heading {
+                                                                abbr title=(locale.text("title-posterior-gold-abbr")?) {
+                                                                    (locale.text("title-posterior-gold")?)
+                                                                }
+                                                            }
+                                                            p.title { (Float::from(gold.mean / hours).precision(1)) }
+                                                            p."is-size-7".has-text-grey title=(locale.text("title-credible-interval")?) {
+                                                                (Float::from(gold.lower / hours).precision(1)) "–" (Float::from(gold.upper / hours).precision(1))
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+
                                 @if view_model.stats_delta.random.n_shots != 0 {
                                     div.column."is-4-tablet"."is-3-desktop"."is-3-widescreen" {
                                         div.card {
@@ -793,11 +1386,16 @@ pub async fn get(
                                     table.table.is-hoverable.is-striped.is-fullwidth id="vehicles" {
                                         thead { (vehicles_thead) }
                                         tbody {
-                                            @for tank in &view_model.stats_delta.tanks {
+                                            @for tank in layout.select_tanks(&view_model.stats_delta.tanks) {
                                                 (render_tank_tr(
                                                     tank,
-                                                    view_model.preferences.target_victory_ratio,
-                                                    view_model.preferences.confidence_level,
+                                                    &view_model.tankopedia,
+                                                    &view_model.preferences,
+                                                    &view_model.vehicle_targets,
+                                                    view_model.grind_progress.iter().find(|progress| progress.tank_id == tank.tank_id),
+                                                    view_model.stats_delta.garage_diff.is_new(tank.tank_id),
+                                                    view_model.realm,
+                                                    view_model.actual_info.id,
                                                     &locale,
                                                 )?)
                                             }
@@ -807,6 +1405,39 @@ pub async fn get(
                                         }
                                     }
                                 }
+                                @if !view_model.stats_delta.garage_diff.gone.is_empty() {
+                                    div.tags {
+                                        span.tag.is-warning.is-light { (locale.text("title-no-longer-played")?) }
+                                        @for tank_id in &view_model.stats_delta.garage_diff.gone {
+                                            span.tag { (vehicle_title(&view_model.tankopedia.get_vehicle(*tank_id), &locale)?) }
+                                        }
+                                    }
+                                }
+                            }
+
+                            @if !is_compact {
+                                div.columns {
+                                    div.column."is-6" {
+                                        (render_pivot_card(
+                                            "nation",
+                                            &PivotRow::by_nation(&view_model.stats_delta.tanks, &view_model.tankopedia),
+                                            |nation| render_nation(nation, &locale),
+                                            &view_model.tankopedia,
+                                            view_model.preferences.confidence_level,
+                                            &locale,
+                                        )?)
+                                    }
+                                    div.column."is-6" {
+                                        (render_pivot_card(
+                                            "type",
+                                            &PivotRow::by_type(&view_model.stats_delta.tanks, &view_model.tankopedia),
+                                            |tank_type| render_tank_type(tank_type, &locale),
+                                            &view_model.tankopedia,
+                                            view_model.preferences.confidence_level,
+                                            &locale,
+                                        )?)
+                                    }
+                                }
                             }
                         }
                     }
@@ -894,91 +1525,531 @@ pub async fn get(
                                 }
                             }
                         }
+
+                        form.navbar-item method="post" {
+                            input type="hidden" name="show_extra_columns" value=(maud::display(!view_model.preferences.show_extra_columns));
+                            button.button.is-small.is-rounded.is-info[view_model.preferences.show_extra_columns] title=(locale.text("navbar-item-extra-columns")?) {
+                                span.icon { i.fa-solid.fa-table-columns {} }
+                            }
+                        }
+
+                        form.navbar-item method="post" {
+                            input type="hidden" name="compact_on_narrow_screens" value=(maud::display(!view_model.preferences.compact_on_narrow_screens));
+                            button.button.is-small.is-rounded.is-info[view_model.preferences.compact_on_narrow_screens] title=(locale.text("navbar-item-compact-on-narrow-screens")?) {
+                                span.icon { i.fa-solid.fa-mobile-screen {} }
+                            }
+                        }
+
+                        div.navbar-item {
+                            @if is_compact {
+                                a.button.is-small.is-rounded href="?layout=full" title=(locale.text("navbar-item-full-layout")?) {
+                                    span.icon { i.fa-solid.fa-expand {} }
+                                }
+                            } @else {
+                                a.button.is-small.is-rounded href="?layout=compact" title=(locale.text("navbar-item-compact-layout")?) {
+                                    span.icon { i.fa-solid.fa-compress {} }
+                                }
+                            }
+                        }
                     }
                 }
 
-                @if !view_model.rating_snapshots.is_empty() {
+                @if !is_compact {
                     script src="https://cdn.jsdelivr.net/npm/apexcharts" {}
-                    script defer {
-                        (PreEscaped("
-                            'use strict';
-                            const mode = (window.matchMedia && window.matchMedia('(prefers-color-scheme: dark)').matches) ? 'dark' : 'light';
-                            new ApexCharts(document.getElementById('rating-chart'), {
-                                chart: {
-                                    type: 'line',
-                                    width: 100,
-                                    height: 57,
-                                    sparkline: {enabled: true},
-                                    animations: {enabled: false},
-                                    background: 'transparent',
-                                },
-                                colors: ['hsl(204, 71%, 39%)'],
-                                series: [{name: '', data: [
-                        "))
-                        @for snapshot in &view_model.rating_snapshots {
-                            "[" (snapshot.date.timestamp_millis()) "," (snapshot.close_rating.display_rating()) "],"
+                    @if !view_model.rating_snapshots.is_empty() {
+                        script defer {
+                            (PreEscaped("
+                                'use strict';
+                                const mode = (window.matchMedia && window.matchMedia('(prefers-color-scheme: dark)').matches) ? 'dark' : 'light';
+                                new ApexCharts(document.getElementById('rating-chart'), {
+                                    chart: {
+                                        type: 'line',
+                                        width: 100,
+                                        height: 57,
+                                        sparkline: {enabled: true},
+                                        animations: {enabled: false},
+                                        background: 'transparent',
+                                    },
+                                    colors: ['hsl(204, 71%, 39%)'],
+                                    series: [{name: '', data: [
+                            "))
+                            @for snapshot in &view_model.rating_snapshots {
+                                "[" (snapshot.date.timestamp_millis()) "," (snapshot.close_rating.display_rating()) "],"
+                            }
+                            (PreEscaped("]}],
+                                    xaxis: {type: 'datetime'},
+                                    tooltip: {
+                                        fixed: {enabled: true, offsetY: 70},
+                                        marker: {show: false},
+                                        x: {format: 'MMM d'},
+                                    },
+                                    stroke: {width: 3, curve: 'straight'},
+                                    annotations: {yaxis: [
+                                        {y: 5000, borderColor: 'hsl(217, 71%, 53%)'},
+                                        {y: 4000, borderColor: 'hsl(141, 71%, 48%)'},
+                                        {y: 3000, borderColor: 'hsl(48, 100%, 67%)'},
+                                    ]},
+                                    theme: {mode: mode},
+                                }).render();
+                            "))
+                        }
+                    }
+                    @if view_model.victory_ratio_history.len() >= 2 {
+                        script defer {
+                            (PreEscaped("
+                                'use strict';
+                                new ApexCharts(document.getElementById('victory-ratio-chart'), {
+                                    chart: {
+                                        type: 'line',
+                                        width: 100,
+                                        height: 57,
+                                        sparkline: {enabled: true},
+                                        animations: {enabled: false},
+                                        background: 'transparent',
+                                    },
+                                    colors: ['hsl(204, 86%, 53%)'],
+                                    series: [{name: '', data: [
+                            "))
+                            @for ratio in &view_model.victory_ratio_history {
+                                "[" (ratio.last_battle_time.timestamp_millis()) "," (format!("{:.2}", ratio.victory_ratio * 100.0)) "],"
+                            }
+                            (PreEscaped("]}],
+                                    xaxis: {type: 'datetime'},
+                                    yaxis: {labels: {formatter: (value) => value.toFixed(2) + '%'}},
+                                    tooltip: {
+                                        fixed: {enabled: true, offsetY: 70},
+                                        marker: {show: false},
+                                        x: {format: 'MMM d'},
+                                    },
+                                    stroke: {width: 3, curve: 'straight'},
+                                    theme: {mode: (window.matchMedia && window.matchMedia('(prefers-color-scheme: dark)').matches) ? 'dark' : 'light'},
+                                }).render();
+                            "))
                         }
-                        (PreEscaped("]}],
-                                xaxis: {type: 'datetime'},
-                                tooltip: {
-                                    fixed: {enabled: true, offsetY: 70},
-                                    marker: {show: false},
-                                    x: {format: 'MMM d'},
-                                },
-                                stroke: {width: 3, curve: 'straight'},
-                                annotations: {yaxis: [
-                                    {y: 5000, borderColor: 'hsl(217, 71%, 53%)'},
-                                    {y: 4000, borderColor: 'hsl(141, 71%, 48%)'},
-                                    {y: 3000, borderColor: 'hsl(48, 100%, 67%)'},
-                                ]},
-                                theme: {mode: mode},
-                            }).render();
-                        "))
                     }
                 }
             }
         }
     };
 
+    // The fallback page should not outlive the upstream outage,
+    // and the shared caches must not serve the search engines' page to the viewers.
+    let cache_control = match (view_model.live_data_unavailable_since, is_search_bot) {
+        (Some(_), _) => "no-store",
+        (None, true) => "private, max-age=300",
+        (None, false) => "public, max-age=30, stale-while-revalidate=3600",
+    };
     let response = Html(markup.into_string())
-        .with_header("Cache-Control", "public, max-age=30, stale-while-revalidate=3600")
+        .with_header("Cache-Control", cache_control)
         .into_response();
     info!(elapsed = ?start_instant.elapsed(), "finished");
     Ok(response)
 }
 
-fn render_tank_tr(
-    snapshot: &database::TankSnapshot,
-    target_victory_ratio: f64,
+/// Renders the period delta grouped by the key, each group expands into its vehicles.
+fn render_pivot_card<K: Ord + Copy>(
+    id: &str,
+    rows: &[PivotRow<K>],
+    render_key: impl Fn(K) -> Result<Markup>,
+    tankopedia: &HistoricalTankopedia,
     confidence_level: f64,
     locale: &Locale,
 ) -> Result<Markup> {
-    let vehicle = get_vehicle(snapshot.tank_id);
-    let posterior_victory_ratio_distribution =
-        snapshot.stats.posterior_victory_ratio_distribution()?;
-    let posterior_victory_ratio = posterior_victory_ratio_distribution.mean().unwrap();
-    let thumbs_down_probability = posterior_victory_ratio_distribution.cdf(target_victory_ratio);
-
     let markup = html! {
-        tr
-            .has-background-danger-light[thumbs_down_probability > confidence_level]
-            .has-background-success-light[(1.0 - thumbs_down_probability > confidence_level)]
-        {
+        div.card {
+            header.card-header {
+                p.card-header-title {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span.icon.has-text-info { i.fa-solid.fa-table-cells {} }
+                        span { (locale.text(format!("title-by-{id}"))?) }
+                    }
+                }
+            }
+            div.card-content {
+                div.table-container {
+                    table.table.is-hoverable.is-fullwidth {
+                        thead {
+                            tr {
+                                th {}
+                                th.has-text-right { (locale.text("title-battles")?) }
+                                th.has-text-right { (locale.text("title-victory-ratio")?) }
+                                th.has-text-right { (locale.text("title-average-damage")?) }
+                            }
+                        }
+                        @for (i, row) in rows.iter().enumerate() {
+                            @let group = format!("{id}-{i}");
+                            tbody {
+                                tr.is-clickable data-pivot-toggle=(group) {
+                                    th.is-white-space-nowrap {
+                                        span.icon.has-text-grey-light { i.fa-solid.fa-caret-right {} }
+                                        (render_key(row.key)?)
+                                    }
+                                    (render_pivot_stats_tds(&row.stats, confidence_level, locale)?)
+                                }
+                                @for tank in &row.tanks {
+                                    tr.is-hidden data-pivot-group=(group) {
+                                        (vehicle_th(&tankopedia.get_vehicle(tank.tank_id), locale)?)
+                                        (render_pivot_stats_tds(&tank.stats, confidence_level, locale)?)
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+    Ok(markup)
+}
+
+fn render_pivot_stats_tds(
+    stats: &database::RandomStatsSnapshot,
+    confidence_level: f64,
+    locale: &Locale,
+) -> Result<Markup> {
+    let (lower, upper) = victory_ratio_interval(stats, confidence_level)?;
+    let markup = html! {
+        td.has-text-right { (stats.n_battles) }
+        td.has-text-right.is-white-space-nowrap {
+            strong { (render_percentage(stats.victory_ratio())) }
+            p."is-size-7".has-text-grey title=(locale.text("title-credible-interval")?) {
+                (Float::from(lower * 100.0).precision(1)) "–" (Float::from(upper * 100.0).precision(1)) "%"
+            }
+        }
+        td.has-text-right { (Float::from(stats.average_damage_dealt()).precision(0)) }
+    };
+    Ok(markup)
+}
+
+/// Vehicle table header, which is also repeated as the footer.
+fn render_vehicles_thead(locale: &Locale, show_extra_columns: bool) -> Result<Markup, WebError> {
+    let markup = html! {
+        tr {
+            th {
+                span.icon-text.is-flex-wrap-nowrap {
+                    span.icon { i.fas.fa-truck-monster {} }
+                    span { (locale.text("title-vehicle")?) }
+                }
+            }
+
+            th.has-text-centered { (locale.text("title-type")?) }
+
+            th.has-text-centered {
+                a data-sort="tier" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span { (locale.text("title-tier")?) }
+                    }
+                }
+            }
+
+            th.has-text-right {
+                a data-sort="battles" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span { (locale.text("title-battles")?) }
+                    }
+                }
+            }
+
+            th {
+                a data-sort="wins" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span { (locale.text("title-wins")?) }
+                    }
+                }
+            }
+
+            th.has-text-right {
+                a data-sort="win-rate" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span { (locale.text("title-victory-ratio")?) }
+                    }
+                }
+            }
+
+            th {
+                a data-sort="victory-probability" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span {
+                            (locale.text("title-victory-probability")?)
+                        }
+                    }
+                }
+            }
+
+            th {
+                a data-sort="target-victory-ratio-probability" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span { (locale.text("title-target-victory-ratio-probability")?) }
+                    }
+                }
+            }
+
+            th {
+                a data-sort="frags-per-battle" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span { (locale.text("title-frags-per-battle")?) }
+                    }
+                }
+            }
+
+            th {
+                a data-sort="posterior-gold" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span {
+                            abbr title=(locale.text("title-posterior-gold-abbr")?) {
+                                (locale.text("title-posterior-gold")?)
+                            }
+                        }
+                    }
+                }
+            }
+
+            th {
+                a data-sort="damage-ratio" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span { (locale.text("title-damage-ratio")?) }
+                    }
+                }
+            }
+
+            th.has-text-left {
+                a data-sort="damage-dealt" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span { (locale.text("title-damage-dealt")?) }
+                    }
+                }
+            }
+
+            th.has-text-left {
+                a data-sort="damage-per-battle" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span { (locale.text("title-damage-dealt-per-battle")?) }
+                    }
+                }
+            }
+
+            th.has-text-right {
+                a data-sort="xp-per-battle" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span { (locale.text("title-xp-per-battle")?) }
+                    }
+                }
+            }
+
+            @if show_extra_columns {
+                th.has-text-right {
+                    a data-sort="spotted-per-battle" {
+                        span.icon-text.is-flex-wrap-nowrap {
+                            span { (locale.text("title-spotted-per-battle")?) }
+                        }
+                    }
+                }
+
+                th.has-text-right {
+                    a data-sort="damage-blocked-per-battle" {
+                        span.icon-text.is-flex-wrap-nowrap {
+                            span { (locale.text("title-damage-blocked-per-battle")?) }
+                        }
+                    }
+                }
+
+                th.has-text-right {
+                    a data-sort="damage-assisted-per-battle" {
+                        span.icon-text.is-flex-wrap-nowrap {
+                            span { (locale.text("title-damage-assisted-per-battle")?) }
+                        }
+                    }
+                }
+            }
+
+            th.has-text-left {
+                a data-sort="damage-per-minute" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span { (locale.text("title-damage-per-minute")?) }
+                    }
+                }
+            }
+
+            th.has-text-right {
+                a data-sort="battles-per-hour" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span { (locale.text("title-battles-per-hour")?) }
+                    }
+                }
+            }
+
+            th.has-text-right {
+                a data-sort="wins-per-hour" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span { (locale.text("title-wins-per-hour")?) }
+                    }
+                }
+            }
+
+            th.has-text-right {
+                a data-sort="xp-per-hour" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span { (locale.text("title-xp-per-hour")?) }
+                    }
+                }
+            }
+
+            th.has-text-left {
+                a data-sort="accuracy" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span { (locale.text("title-hits")?) }
+                    }
+                }
+            }
+
+            th.has-text-right {
+                a data-sort="survived-battles" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span { (locale.text("title-survived")?) }
+                    }
+                }
+            }
+
+            th {
+                a data-sort="survival-rate" {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span { (locale.text("title-survival-ratio")?) }
+                    }
+                }
+            }
+
+            th {
+                span.icon-text.is-flex-wrap-nowrap {
+                    span.icon { i.fas.fa-truck-monster {} }
+                    span { (locale.text("title-vehicle")?) }
+                }
+            }
+        }
+    };
+    Ok(markup)
+}
+
+fn render_grind_progress(
+    progress: &GrindProgress,
+    tankopedia: &HistoricalTankopedia,
+    locale: &Locale,
+) -> Result<Markup> {
+    let vehicle = tankopedia.get_vehicle(progress.tank_id);
+    let title = match progress.target.kind {
+        GrindKind::Battles => locale.text("title-goal-battles")?,
+        GrindKind::Masteries => locale.text("title-grind-masteries")?,
+    };
+    let markup = html! {
+        div."mb-4" {
+            p.heading { (vehicle.name) " – " (title) ": " (progress.current) " / " (progress.target.target) }
+            @if progress.is_reached() {
+                p.has-text-success.has-text-weight-medium {
+                    span.icon-text {
+                        span.icon { i.fa-solid.fa-trophy {} }
+                        span { (locale.text("title-goal-reached")?) }
+                    }
+                }
+            } @else {
+                progress.progress.is-info."mb-1" value=(progress.current) max=(progress.target.target) {}
+                p."is-size-7".has-text-grey {
+                    (locale.text("title-grind-in-period")?) ": +" (progress.period_delta) ", "
+                    (locale.text("title-grind-remaining")?) ": " (progress.remaining())
+                }
+            }
+        }
+    };
+    Ok(markup)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_tank_tr(
+    snapshot: &database::TankSnapshot,
+    tankopedia: &HistoricalTankopedia,
+    preferences: &DisplayPreferences,
+    vehicle_targets: &VehicleTargets,
+    grind_progress: Option<&GrindProgress>,
+    is_new: bool,
+    realm: wargaming::Realm,
+    account_id: wargaming::AccountId,
+    locale: &Locale,
+) -> Result<Markup> {
+    let target_victory_ratio = vehicle_targets
+        .get(snapshot.tank_id)
+        .unwrap_or(preferences.target_victory_ratio);
+    let confidence_level = preferences.confidence_level;
+    let vehicle = tankopedia.get_vehicle(snapshot.tank_id);
+    let posterior_victory_ratio_distribution =
+        snapshot.stats.posterior_victory_ratio_distribution()?;
+    let posterior_victory_ratio = posterior_victory_ratio_distribution.mean().unwrap();
+    let thumbs_down_probability = posterior_victory_ratio_distribution.cdf(target_victory_ratio);
+
+    let markup = html! {
+        tr
+            .has-background-danger-light[thumbs_down_probability > confidence_level]
+            .has-background-success-light[(1.0 - thumbs_down_probability > confidence_level)]
+        {
             @let vehicle_th = vehicle_th(&vehicle, locale)?;
-            (vehicle_th)
+            th.is-white-space-nowrap {
+                (vehicle_title(&vehicle, locale)?)
+                @if is_new {
+                    " "
+                    span.tag.is-success.is-light {
+                        (locale.text("title-new-in-garage")?)
+                    }
+                }
+            }
 
             td.has-text-centered.is-white-space-nowrap {
-                @match vehicle.type_ {
-                    wargaming::TankType::Light => (locale.text("tank-type-light")?),
-                    wargaming::TankType::Medium => (locale.text("tank-type-medium")?),
-                    wargaming::TankType::Heavy => (locale.text("tank-type-heavy")?),
-                    wargaming::TankType::AT => (locale.text("tank-type-at")?),
-                    wargaming::TankType::Unknown => "",
+                (render_tank_type(vehicle.type_, locale)?)
+            }
+
+            td.has-text-centered data-sort="tier" data-value=(vehicle.tier) {
+                @if let Some(tier) = TIER_MARKUP.get(&vehicle.tier) {
+                    strong { (tier) }
                 }
             }
 
             td.has-text-right data-sort="battles" data-value=(snapshot.stats.n_battles) {
                 (snapshot.stats.n_battles)
+                @if let Some(progress) = grind_progress {
+                    progress.progress.is-small.is-info."mb-0"
+                        value=(progress.current)
+                        max=(progress.target.target)
+                        title=(format!("{} / {}", progress.current, progress.target.target)) {}
+                }
+                @let grind_kind = grind_progress.map(|progress| progress.target.kind);
+                details {
+                    summary."is-size-7".has-text-grey title=(locale.text("title-grind-target")?) {
+                        span.icon { i.fa-solid.fa-person-digging {} }
+                    }
+                    form method="post" action=(format!("/{realm}/{account_id}/grind-target")) {
+                        input type="hidden" name="tank_id" value=(snapshot.tank_id);
+                        div.field.has-addons {
+                            div.control {
+                                div.select.is-small {
+                                    select name="kind" {
+                                        option value=(GrindKind::Battles.as_str()) selected[grind_kind == Some(GrindKind::Battles)] {
+                                            (locale.text("title-goal-battles")?)
+                                        }
+                                        option value=(GrindKind::Masteries.as_str()) selected[grind_kind == Some(GrindKind::Masteries)] {
+                                            (locale.text("title-grind-masteries")?)
+                                        }
+                                    }
+                                }
+                            }
+                            div.control {
+                                input.input.is-small
+                                    name="target"
+                                    type="number"
+                                    min="1"
+                                    value=[grind_progress.map(|progress| progress.target.target)];
+                            }
+                            div.control {
+                                button.button.is-small.is-link { span.icon { i.fa-solid.fa-arrow-right {} } }
+                            }
+                        }
+                    }
+                }
             }
 
             td data-sort="wins" data-value=(snapshot.stats.n_wins) {
@@ -1018,6 +2089,32 @@ fn render_tank_tr(
                         span.has-text-grey { "%" }
                     }
                 }
+                @let vehicle_target = vehicle_targets.get_percentage(snapshot.tank_id);
+                details {
+                    summary."is-size-7".has-text-grey title=(locale.text("title-vehicle-target")?) {
+                        span.icon { i.fa-solid.fa-bullseye {} }
+                        (Float::from(target_victory_ratio * 100.0).precision(2)) "%"
+                        @if vehicle_target.is_some() { " *" }
+                    }
+                    form method="post" action=(format!("/{realm}/{account_id}/vehicle-target")) {
+                        input type="hidden" name="tank_id" value=(snapshot.tank_id);
+                        div.field.has-addons {
+                            div.control {
+                                input.input.is-small
+                                    name="target_victory_ratio_percentage"
+                                    type="number"
+                                    min="0.01"
+                                    max="99.99"
+                                    step="any"
+                                    placeholder=(preferences.target_victory_ratio_percentage)
+                                    value=[vehicle_target];
+                            }
+                            div.control {
+                                button.button.is-small.is-link { span.icon { i.fa-solid.fa-arrow-right {} } }
+                            }
+                        }
+                    }
+                }
             }
 
             @let frags_per_battle = snapshot.stats.frags_per_battle();
@@ -1028,11 +2125,14 @@ fn render_tank_tr(
                 }
             }
 
-            @let posterior_gold = posterior_victory_ratio_distribution.mean().unwrap() * (vehicle.tier as f64) + 10.0;
-            td.is-white-space-nowrap data-sort="posterior-gold" data-value=(posterior_gold) {
+            @let gold = ExpectedGold::per_battle(&snapshot.stats, vehicle.tier, confidence_level)?;
+            td.is-white-space-nowrap data-sort="posterior-gold" data-value=(gold.mean) {
                 span.icon-text.is-flex-wrap-nowrap {
                     span.icon.has-text-warning-dark { i.fas.fa-coins {} }
-                    strong { (Float::from(posterior_gold).precision(1)) }
+                    strong { (Float::from(gold.mean).precision(1)) }
+                }
+                p."is-size-7".has-text-grey title=(locale.text("title-credible-interval")?) {
+                    (Float::from(gold.lower).precision(1)) "–" (Float::from(gold.upper).precision(1))
                 }
             }
 
@@ -1059,6 +2159,64 @@ fn render_tank_tr(
                 }
             }
 
+            @let xp_per_battle = snapshot.stats.xp_per_battle();
+            td.has-text-right data-sort="xp-per-battle" data-value=(xp_per_battle) {
+                (format!("{xp_per_battle:.0}"))
+            }
+
+            @if preferences.show_extra_columns {
+                @let spotted_per_battle = snapshot.stats.spotted_per_battle();
+                td.has-text-right data-sort="spotted-per-battle" data-value=(spotted_per_battle) {
+                    (render_float(spotted_per_battle, 1))
+                }
+
+                @let damage_blocked_per_battle = snapshot.stats.damage_blocked_per_battle();
+                td.has-text-right data-sort="damage-blocked-per-battle" data-value=(damage_blocked_per_battle) {
+                    @if snapshot.stats.damage_blocked != 0 {
+                        (format!("{damage_blocked_per_battle:.0}"))
+                    }
+                }
+
+                @let damage_assisted_per_battle = snapshot.stats.damage_assisted_per_battle();
+                td.has-text-right data-sort="damage-assisted-per-battle" data-value=(damage_assisted_per_battle) {
+                    @if snapshot.stats.damage_assisted != 0 {
+                        (format!("{damage_assisted_per_battle:.0}"))
+                    }
+                }
+            }
+
+            @let life_time_stats = LifeTimeStats::from(snapshot);
+            @let damage_per_minute = life_time_stats.damage_per_minute();
+            td.has-text-left data-sort="damage-per-minute" data-value=(damage_per_minute.unwrap_or_default()) {
+                @if let Some(damage_per_minute) = damage_per_minute {
+                    span.icon-text.is-flex-wrap-nowrap {
+                        span.icon.has-text-grey-light { i.fa-solid.fa-stopwatch {} }
+                        (format!("{damage_per_minute:.0}"))
+                    }
+                }
+            }
+
+            @let battles_per_hour = life_time_stats.battles_per_hour();
+            td.has-text-right data-sort="battles-per-hour" data-value=(battles_per_hour.unwrap_or_default()) {
+                @if let Some(battles_per_hour) = battles_per_hour {
+                    (render_float(battles_per_hour, 1))
+                }
+            }
+
+            @let wins_per_hour = life_time_stats.wins_per_hour();
+            td.has-text-right data-sort="wins-per-hour" data-value=(wins_per_hour.unwrap_or_default()) {
+                @if let Some(wins_per_hour) = wins_per_hour {
+                    (render_float(wins_per_hour, 1))
+                }
+            }
+
+            @let xp_per_hour = life_time_stats.xp_per_hour();
+            td.has-text-right data-sort="xp-per-hour" data-value=(xp_per_hour.unwrap_or_default()) {
+                @if let Some(xp_per_hour) = xp_per_hour {
+                    (format!("{xp_per_hour:.0}"))
+                }
+            }
+
             @let accuracy = snapshot.stats.accuracy();
             td.has-text-left data-sort="accuracy" data-value=(accuracy) {
                 span.icon-text.is-flex-wrap-nowrap {
@@ -1090,16 +2248,77 @@ fn render_tank_tr(
 
 fn render_period_li(
     period: time::Duration,
+    layout: Option<Layout>,
     new_period: time::Duration,
     text: &str,
 ) -> Result<Markup> {
+    let mut href = format!("?period={}", new_period.as_secs());
+    if let Some(layout) = layout {
+        href = format!("{}&layout={}", href, layout.as_str());
+    }
     let markup = html! {
         li.is-active[period == new_period] {
-            form method="POST" {
-                input type="hidden" name="period" value=(new_period.as_secs());
-                a onclick="this.parentNode.submit()" { (text) }
-            }
+            a href=(href) aria-current=[(period == new_period).then_some("page")] { (text) }
         }
     };
     Ok(markup)
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::*;
+
+    use super::*;
+    use crate::web::test::{create_test_app, create_test_locale, get_markup, MockApi};
+
+    #[tokio::test]
+    async fn get_unknown_account_inserts_stub_ok() -> Result {
+        let account_id = thread_rng().gen_range(1_000_000_000..2_000_000_000);
+        let mock_api = MockApi::default().with_account(account_id, "NeverCrawled");
+        let Some(app) = create_test_app(mock_api).await? else { return Ok(()) };
+
+        get_markup(&app.client, &format!("/eu/{account_id}"), "en").await?;
+        let account =
+            database::Account::retrieve(&app.mongodb, wargaming::Realm::Europe, account_id)
+                .await?
+                .context("the stub is not inserted")?;
+        assert_eq!(account.last_battle_time, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_ok() -> Result {
+        let account_id = thread_rng().gen_range(1_000_000_000..2_000_000_000);
+        let mock_api = MockApi::default().with_account(account_id, "Mocked");
+        let Some(app) = create_test_app(mock_api).await? else { return Ok(()) };
+
+        let markup = get_markup(&app.client, &format!("/eu/{account_id}"), "en").await?;
+        assert!(markup.contains(r#"<html class="has-navbar-fixed-bottom" lang="en">"#));
+        assert!(markup.contains(&format!(r#"<link rel="canonical" href="/eu/{account_id}">"#)));
+        assert!(markup.contains("<title>🇪🇺 Mocked – "));
+        assert!(markup.contains("<span>100</span>"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn render_vehicles_thead_ok() -> Result {
+        let locale = create_test_locale("en").await?;
+        let markup = render_vehicles_thead(&locale, false)?.into_string();
+        assert!(markup.starts_with("<tr>"));
+        assert!(markup.contains(r#"data-sort="battles""#));
+        assert!(!markup.contains(r#"data-sort="spotted-per-battle""#));
+
+        let extra_markup = render_vehicles_thead(&locale, true)?.into_string();
+        assert_eq!(extra_markup.matches("<th").count(), markup.matches("<th").count() + 3);
+        assert!(extra_markup.contains(r#"data-sort="spotted-per-battle""#));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn render_vehicles_thead_localized_ok() -> Result {
+        let en = render_vehicles_thead(&create_test_locale("en").await?, false)?;
+        let ru = render_vehicles_thead(&create_test_locale("ru").await?, false)?;
+        assert_ne!(en.into_string(), ru.into_string());
+        Ok(())
+    }
+}
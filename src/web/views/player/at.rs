@@ -0,0 +1,191 @@
+//! Account profile as of a past date, reconstructed from the account snapshots.
+
+use chrono::NaiveDate;
+use futures::future::try_join3;
+use maud::{html, Markup, DOCTYPE};
+use poem::i18n::Locale;
+use poem::web::{Data, Html, Path};
+use poem::{handler, IntoResponse, Response};
+
+use crate::math::traits::*;
+use crate::prelude::*;
+use crate::wargaming::cache::account::AccountInfoCache;
+use crate::web::error::WebError;
+use crate::web::partials::*;
+use crate::web::TrackingCode;
+use crate::{database, wargaming};
+
+#[instrument(
+    skip_all,
+    level = "info",
+    fields(realm = ?realm, account_id = account_id, date = ?date),
+)]
+#[handler]
+pub async fn get(
+    Path((realm, account_id, date)): Path<(wargaming::Realm, wargaming::AccountId, NaiveDate)>,
+    mongodb: Data<&mongodb::Database>,
+    info_cache: Data<&AccountInfoCache>,
+    tracking_code: Data<&TrackingCode>,
+    locale: Locale,
+) -> Result<Response, WebError> {
+    let info = info_cache
+        .get(realm, account_id)
+        .await?
+        .ok_or(WebError::NotFound)?;
+
+    let (start, end) = day_bounds(date)?;
+    let before = end - Duration::milliseconds(1);
+    let (snapshot, previous_time, next_time) = try_join3(
+        database::AccountSnapshot::retrieve_latest(&mongodb, realm, account_id, before),
+        database::AccountSnapshot::retrieve_previous_time(&mongodb, realm, account_id, start),
+        database::AccountSnapshot::retrieve_next_time(&mongodb, realm, account_id, end),
+    )
+    .await?;
+
+    let markup = html! {
+        (DOCTYPE)
+        html.has-navbar-fixed-top lang=(locale.text("html-lang")?) {
+            head {
+                (headers())
+                title {
+                    (realm.to_emoji()) " " (info.nickname) " – " (maud::display(date)) " – " (locale.text("page-title-as-of")?)
+                }
+            }
+            body {
+                (tracking_code.0)
+                nav.navbar.has-shadow.is-fixed-top role="navigation" aria-label="main navigation" {
+                    div.navbar-brand {
+                        (home_button(&locale)?)
+                    }
+                    div.navbar-menu {
+                        div.navbar-end {
+                            form.navbar-item action="/search" method="GET" {
+                                (AccountSearch::new(realm, &locale).try_into_markup()?)
+                            }
+                        }
+                    }
+                }
+
+                section.section {
+                    div.container {
+                        nav.level.is-mobile {
+                            div.level-left {
+                                div.level-item {
+                                    (date_link(realm, account_id, previous_time, "fa-chevron-left", &locale.text("title-previous-date")?))
+                                }
+                            }
+                            div.level-item.has-text-centered {
+                                div {
+                                    p.heading { (locale.text("page-title-as-of")?) }
+                                    p.title {
+                                        a href=(format!("/{realm}/{account_id}")) { (info.nickname) }
+                                    }
+                                    p.subtitle { (maud::display(date)) }
+                                }
+                            }
+                            div.level-right {
+                                div.level-item {
+                                    (date_link(realm, account_id, next_time, "fa-chevron-right", &locale.text("title-next-date")?))
+                                }
+                            }
+                        }
+
+                        div.card {
+                            div.card-content {
+                                @match &snapshot {
+                                    Some(snapshot) => (render_snapshot(snapshot, &locale)?),
+                                    None => p.has-text-grey { (locale.text("message-no-data-as-of")?) },
+                                }
+                            }
+                        }
+                    }
+                }
+
+                (footer(&locale)?)
+            }
+        }
+    };
+
+    Ok(Html(markup.into_string())
+        .with_header("Cache-Control", "public, max-age=60, stale-while-revalidate=3600")
+        .into_response())
+}
+
+/// Returns the start of the day and the start of the next day.
+fn day_bounds(date: NaiveDate) -> Result<(DateTime, DateTime)> {
+    let start = date
+        .and_hms_opt(0, 0, 0)
+        .ok_or_else(|| anyhow!("invalid date: {date}"))?;
+    let start = Utc.from_utc_datetime(&start);
+    Ok((start, start + Duration::days(1)))
+}
+
+fn date_link(
+    realm: wargaming::Realm,
+    account_id: wargaming::AccountId,
+    time: Option<DateTime>,
+    icon: &str,
+    title: &str,
+) -> Markup {
+    html! {
+        @if let Some(time) = time {
+            a.button.is-rounded href=(format!("/{realm}/{account_id}/at/{}", time.date_naive())) title=(title) {
+                span.icon { i.fa-solid.(icon) {} }
+            }
+        } @else {
+            button.button.is-rounded disabled title=(title) {
+                span.icon { i.fa-solid.(icon) {} }
+            }
+        }
+    }
+}
+
+fn render_snapshot(snapshot: &database::AccountSnapshot, locale: &Locale) -> Result<Markup> {
+    let random = &snapshot.random_stats;
+    let markup = html! {
+        div.level.is-mobile {
+            div.level-item.has-text-centered {
+                div {
+                    p.heading { (locale.text("title-battles")?) }
+                    p.title { (random.n_battles) }
+                }
+            }
+            div.level-item.has-text-centered {
+                div {
+                    p.heading { (locale.text("title-victory-ratio")?) }
+                    p.title {
+                        @if random.n_battles == 0 {
+                            "–"
+                        } @else {
+                            (Float::from(random.victory_ratio() * 100.0).precision(2))
+                            span.has-text-grey-light { "%" }
+                        }
+                    }
+                }
+            }
+            div.level-item.has-text-centered {
+                div {
+                    p.heading { (locale.text("title-vehicles-played")?) }
+                    p.title { (snapshot.tank_last_battle_times.len()) }
+                }
+            }
+        }
+        p.has-text-grey.has-text-centered {
+            (locale.text("title-last-played")?) ": " (maud::display(snapshot.last_battle_time.format("%Y-%m-%d %H:%M UTC")))
+        }
+    };
+    Ok(markup)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_bounds_ok() -> Result {
+        let (start, end) = day_bounds(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap())?;
+        assert_eq!(start, Utc.with_ymd_and_hms(2022, 12, 31, 0, 0, 0).unwrap());
+        assert_eq!(end, Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap());
+        Ok(())
+    }
+}
@@ -4,22 +4,60 @@ use std::time;
 use poem::web::cookie::{Cookie, CookieJar};
 use serde::{Deserialize, Serialize};
 
+use crate::helpers::period::Period;
+use crate::prelude::*;
+use crate::web::cookies;
+
 /// Form & cookie.
 #[serde_with::serde_as]
 #[derive(Deserialize, Default)]
 pub struct UpdateDisplayPreferences {
     #[serde(default)]
-    #[serde_as(as = "Option<serde_with::DurationSeconds>")]
+    #[serde_as(as = "Option<Period>")]
     pub period: Option<time::Duration>,
 
     #[serde(default)]
     pub confidence_level_percentage: Option<f64>,
 
     pub target_victory_ratio_percentage: Option<f64>,
+
+    #[serde(default)]
+    pub show_extra_columns: Option<bool>,
+
+    #[serde(default)]
+    pub compact_on_narrow_screens: Option<bool>,
 }
 
 impl UpdateDisplayPreferences {
     pub const COOKIE_NAME: &'static str = "display-preferences";
+
+    /// Merges the update into the cookie preferences and saves them.
+    pub fn save(self, jar: &CookieJar) {
+        let preferences = DisplayPreferences::from(Self::from(jar) + self);
+        cookies::Builder::new(Self::COOKIE_NAME)
+            .value(preferences)
+            .expires_in(Duration::weeks(4))
+            .set_path("/")
+            .add_to(jar);
+    }
+}
+
+/// Query of the period tab links, which persists the period into the preferences.
+#[serde_with::serde_as]
+#[derive(Deserialize)]
+pub struct PeriodParams {
+    #[serde(default)]
+    #[serde_as(as = "Option<Period>")]
+    pub period: Option<time::Duration>,
+}
+
+impl From<PeriodParams> for UpdateDisplayPreferences {
+    fn from(params: PeriodParams) -> Self {
+        Self {
+            period: params.period,
+            ..Default::default()
+        }
+    }
 }
 
 impl From<Cookie> for UpdateDisplayPreferences {
@@ -50,6 +88,10 @@ impl Add<UpdateDisplayPreferences> for UpdateDisplayPreferences {
             target_victory_ratio_percentage: rhs
                 .target_victory_ratio_percentage
                 .or(self.target_victory_ratio_percentage),
+            show_extra_columns: rhs.show_extra_columns.or(self.show_extra_columns),
+            compact_on_narrow_screens: rhs
+                .compact_on_narrow_screens
+                .or(self.compact_on_narrow_screens),
         }
     }
 }
@@ -58,7 +100,7 @@ impl Add<UpdateDisplayPreferences> for UpdateDisplayPreferences {
 #[serde_with::serde_as]
 #[derive(Serialize)]
 pub struct DisplayPreferences {
-    #[serde_as(as = "serde_with::DurationSeconds")]
+    #[serde_as(as = "Period")]
     pub period: time::Duration,
 
     pub confidence_level_percentage: f64,
@@ -68,6 +110,12 @@ pub struct DisplayPreferences {
     pub target_victory_ratio_percentage: f64,
 
     pub target_victory_ratio: f64,
+
+    /// Show the spotting, blocked, and assisted damage columns in the vehicles table.
+    pub show_extra_columns: bool,
+
+    /// Switch to the compact layout on the narrow viewports, unless the layout is explicit.
+    pub compact_on_narrow_screens: bool,
 }
 
 impl From<UpdateDisplayPreferences> for DisplayPreferences {
@@ -84,6 +132,8 @@ impl From<UpdateDisplayPreferences> for DisplayPreferences {
             confidence_level: confidence_level_percentage / 100.0,
             target_victory_ratio_percentage,
             target_victory_ratio: target_victory_ratio_percentage / 100.0,
+            show_extra_columns: update.show_extra_columns.unwrap_or_default(),
+            compact_on_narrow_screens: update.compact_on_narrow_screens.unwrap_or_default(),
         }
     }
 }
@@ -0,0 +1,85 @@
+use statrs::distribution::ContinuousCDF;
+use statrs::statistics::Distribution;
+
+use crate::database;
+use crate::math::traits::*;
+use crate::prelude::*;
+use crate::tankopedia::HistoricalTankopedia;
+
+/// Expected gold booster yield: 10 plus the tier for a victory,
+/// with the credible interval from the posterior victory ratio distribution.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ExpectedGold {
+    pub mean: f64,
+    pub lower: f64,
+    pub upper: f64,
+}
+
+impl ExpectedGold {
+    /// Expected yield per battle on the specific tank.
+    pub fn per_battle(
+        stats: &database::RandomStatsSnapshot,
+        tier: wargaming::Tier,
+        confidence_level: f64,
+    ) -> Result<Self> {
+        let distribution = stats.posterior_victory_ratio_distribution()?;
+        let alpha = 1.0 - confidence_level;
+        let tier = tier as f64;
+        Ok(Self {
+            mean: 10.0 + tier * distribution.mean().unwrap(),
+            lower: 10.0 + tier * distribution.inverse_cdf(alpha / 2.0),
+            upper: 10.0 + tier * distribution.inverse_cdf(1.0 - alpha / 2.0),
+        })
+    }
+
+    /// Total expected yield over the battles played on all the tanks.
+    ///
+    /// The interval is the sum of the per-tank intervals, which makes it conservative.
+    pub fn total(
+        tanks: &[database::TankSnapshot],
+        tankopedia: &HistoricalTankopedia,
+        confidence_level: f64,
+    ) -> Result<Self> {
+        let mut total = Self {
+            mean: 0.0,
+            lower: 0.0,
+            upper: 0.0,
+        };
+        for tank in tanks {
+            let tier = tankopedia.get_vehicle(tank.tank_id).tier;
+            let per_battle = Self::per_battle(&tank.stats, tier, confidence_level)?;
+            let n_battles = tank.stats.n_battles as f64;
+            total.mean += n_battles * per_battle.mean;
+            total.lower += n_battles * per_battle.lower;
+            total.upper += n_battles * per_battle.upper;
+        }
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn per_battle_ok() -> Result {
+        let stats = database::RandomStatsSnapshot {
+            n_battles: 100,
+            n_wins: 60,
+            ..Default::default()
+        };
+        let gold = ExpectedGold::per_battle(&stats, 10, 0.9)?;
+        assert!(gold.lower < gold.mean);
+        assert!(gold.mean < gold.upper);
+        assert!(gold.lower > 10.0);
+        assert!(gold.upper < 20.0);
+        Ok(())
+    }
+
+    #[test]
+    fn total_empty_ok() -> Result {
+        let gold = ExpectedGold::total(&[], &HistoricalTankopedia::default(), 0.9)?;
+        assert_eq!(gold.mean, 0.0);
+        Ok(())
+    }
+}
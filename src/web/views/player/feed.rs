@@ -0,0 +1,216 @@
+//! Atom feed of the weekly summaries, so that the players could subscribe in a reader
+//! or wire it to an email automation.
+
+use maud::{html, PreEscaped};
+use poem::i18n::Locale;
+use poem::web::{Data, Path};
+use poem::{handler, IntoResponse, Response};
+
+use crate::database::{AccountSnapshot, Granularity, TankSnapshot};
+use crate::math::traits::*;
+use crate::prelude::*;
+use crate::tankopedia::get_vehicle;
+use crate::wargaming::cache::account::AccountInfoCache;
+use crate::web::error::WebError;
+use crate::web::partials::Float;
+use crate::{database, wargaming};
+
+/// Number of the most recent complete weeks in the feed.
+const N_WEEKS: usize = 8;
+
+const BASE_URL: &str = "https://yastati.st";
+
+#[instrument(skip_all, level = "info", fields(realm = ?realm, account_id = account_id))]
+#[handler]
+pub async fn get(
+    Path((realm, account_id)): Path<(wargaming::Realm, wargaming::AccountId)>,
+    mongodb: Data<&mongodb::Database>,
+    info_cache: Data<&AccountInfoCache>,
+    locale: Locale,
+) -> Result<Response, WebError> {
+    let info = info_cache
+        .get(realm, account_id)
+        .await?
+        .ok_or(WebError::NotFound)?;
+    let summaries = WeeklySummary::retrieve_recent(&mongodb, realm, account_id, now()).await?;
+
+    let url = format!("{BASE_URL}/{realm}/{account_id}");
+    let updated = summaries.first().map_or_else(now, |summary| summary.end);
+    let feed = html! {
+        (PreEscaped(r#"<?xml version="1.0" encoding="utf-8"?>"#))
+        feed xmlns="http://www.w3.org/2005/Atom" xml:lang=(locale.text("html-lang")?) {
+            title { (info.nickname) " – " (locale.text("title-weekly-summary")?) }
+            link href=(url) rel="alternate" {}
+            id { (url) }
+            updated { (updated.to_rfc3339()) }
+            author { name { (info.nickname) } }
+            @for summary in &summaries {
+                @let start = summary.start.format("%Y-%m-%d").to_string();
+                entry {
+                    title { (locale.text("title-period-1-week")?) " " (start) }
+                    id { (url) "#week-" (start) }
+                    link href=(url) rel="alternate" {}
+                    updated { (summary.end.to_rfc3339()) }
+                    content type="text" {
+                        (locale.text("title-battles")?) ": " (summary.stats.n_battles) ". "
+                        (locale.text("title-victory-ratio")?) ": "
+                        (Float::from(100.0 * summary.stats.victory_ratio()).precision(2)) "%. "
+                        (locale.text("title-victory-ratio-change")?) ": "
+                        (format!("{:+.2}", 100.0 * summary.victory_ratio_change)) "%."
+                        @if let Some(best_tank) = &summary.best_tank {
+                            " " (locale.text("title-best-vehicle")?) ": "
+                            (get_vehicle(best_tank.tank_id).name) " ("
+                            (best_tank.stats.n_wins) "/" (best_tank.stats.n_battles) ")."
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    Ok(feed
+        .into_string()
+        .with_content_type("application/atom+xml; charset=utf-8")
+        .with_header("Cache-Control", "public, max-age=3600")
+        .into_response())
+}
+
+/// Account's random battles within a complete week.
+struct WeeklySummary {
+    start: DateTime,
+    end: DateTime,
+    stats: database::RandomStatsSnapshot,
+
+    /// Change of the overall victory ratio over the week.
+    victory_ratio_change: f64,
+
+    /// The week's tank delta with the most wins.
+    best_tank: Option<TankSnapshot>,
+}
+
+impl WeeklySummary {
+    /// Retrieves the summaries of the played weeks, the most recent first.
+    ///
+    /// Stops at the first week, which started before the account got crawled,
+    /// since there's no baseline to subtract.
+    async fn retrieve_recent(
+        db: &mongodb::Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+        now: DateTime,
+    ) -> Result<Vec<Self>> {
+        let mut summaries = Vec::new();
+        let mut end = Granularity::Weekly.bucket_start(now);
+        let Some(mut end_snapshot) =
+            AccountSnapshot::retrieve_latest(db, realm, account_id, end).await?
+        else {
+            return Ok(summaries);
+        };
+        for _ in 0..N_WEEKS {
+            let start = end - Duration::days(7);
+            let Some(start_snapshot) =
+                AccountSnapshot::retrieve_latest(db, realm, account_id, start).await?
+            else {
+                break;
+            };
+            if end_snapshot.random_stats.n_battles > start_snapshot.random_stats.n_battles {
+                let best_tank = Self::retrieve_best_tank(db, &start_snapshot, &end_snapshot).await?;
+                summaries.push(Self {
+                    start,
+                    end,
+                    stats: end_snapshot.random_stats - start_snapshot.random_stats,
+                    victory_ratio_change: end_snapshot.random_stats.victory_ratio()
+                        - start_snapshot.random_stats.victory_ratio(),
+                    best_tank,
+                });
+            }
+            end = start;
+            end_snapshot = start_snapshot;
+        }
+        Ok(summaries)
+    }
+
+    async fn retrieve_best_tank(
+        db: &mongodb::Database,
+        start_snapshot: &AccountSnapshot,
+        end_snapshot: &AccountSnapshot,
+    ) -> Result<Option<TankSnapshot>> {
+        let tank_ids: Vec<wargaming::TankId> = end_snapshot
+            .tank_last_battle_times
+            .iter()
+            .filter(|tank| tank.last_battle_time > start_snapshot.last_battle_time)
+            .map(|tank| tank.tank_id)
+            .collect();
+        let (realm, account_id) = (end_snapshot.realm, end_snapshot.account_id);
+        let actual = TankSnapshot::retrieve_latest_tank_snapshots(
+            db,
+            realm,
+            account_id,
+            end_snapshot.last_battle_time + Duration::seconds(1),
+            &tank_ids,
+        )
+        .await?;
+        let previous = TankSnapshot::retrieve_latest_tank_snapshots(
+            db,
+            realm,
+            account_id,
+            start_snapshot.last_battle_time + Duration::seconds(1),
+            &tank_ids,
+        )
+        .await?;
+        Ok(best_tank(actual, previous))
+    }
+}
+
+/// Subtracts the previous snapshots and picks the tank with the most wins,
+/// then with the most battles.
+fn best_tank(actual: Vec<TankSnapshot>, previous: Vec<TankSnapshot>) -> Option<TankSnapshot> {
+    let previous: AHashMap<wargaming::TankId, TankSnapshot> = previous
+        .into_iter()
+        .map(|snapshot| (snapshot.tank_id, snapshot))
+        .collect();
+    actual
+        .into_iter()
+        .map(|snapshot| match previous.get(&snapshot.tank_id) {
+            Some(previous) => snapshot - *previous,
+            None => snapshot,
+        })
+        .filter(|delta| delta.stats.n_battles != 0)
+        .max_by_key(|delta| (delta.stats.n_wins, delta.stats.n_battles))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(tank_id: wargaming::TankId, n_battles: u32, n_wins: u32) -> TankSnapshot {
+        TankSnapshot {
+            realm: wargaming::Realm::Europe,
+            last_battle_time: Utc.timestamp_opt(1660000000, 0).unwrap(),
+            account_id: 1,
+            tank_id,
+            battle_life_time: Duration::zero(),
+            stats: database::RandomStatsSnapshot {
+                n_battles,
+                n_wins,
+                ..Default::default()
+            },
+            n_masteries: 0,
+        }
+    }
+
+    #[test]
+    fn best_tank_ok() {
+        let actual = vec![snapshot(1, 110, 60), snapshot(2, 20, 12), snapshot(3, 5, 3)];
+        let previous = vec![snapshot(1, 100, 55), snapshot(2, 10, 5)];
+        let best_tank = best_tank(actual, previous).unwrap();
+        assert_eq!(best_tank.tank_id, 2);
+        assert_eq!(best_tank.stats.n_battles, 10);
+        assert_eq!(best_tank.stats.n_wins, 7);
+    }
+
+    #[test]
+    fn best_tank_none() {
+        assert!(best_tank(vec![snapshot(1, 10, 5)], vec![snapshot(1, 10, 5)]).is_none());
+    }
+}
@@ -0,0 +1,46 @@
+use std::collections::BTreeSet;
+
+use crate::wargaming;
+
+/// Vehicles, which appeared in or disappeared from the account's tank list
+/// between the period start and now.
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct GarageDiff {
+    /// Played for the first time within the period.
+    pub new: Vec<wargaming::TankId>,
+
+    /// Present at the period start, but missing from the actual list.
+    pub gone: Vec<wargaming::TankId>,
+}
+
+impl GarageDiff {
+    pub fn new(
+        actual: impl IntoIterator<Item = wargaming::TankId>,
+        previous: impl IntoIterator<Item = wargaming::TankId>,
+    ) -> Self {
+        let actual: BTreeSet<_> = actual.into_iter().collect();
+        let previous: BTreeSet<_> = previous.into_iter().collect();
+        Self {
+            new: actual.difference(&previous).copied().collect(),
+            gone: previous.difference(&actual).copied().collect(),
+        }
+    }
+
+    pub fn is_new(&self, tank_id: wargaming::TankId) -> bool {
+        self.new.binary_search(&tank_id).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_ok() {
+        let diff = GarageDiff::new([3, 1, 2], [2, 4, 1]);
+        assert_eq!(diff.new, [3]);
+        assert_eq!(diff.gone, [4]);
+        assert!(diff.is_new(3));
+        assert!(!diff.is_new(1));
+    }
+}
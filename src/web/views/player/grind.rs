@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+
+use poem::web::cookie::CookieJar;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, NoneAsEmptyString};
+
+use crate::prelude::*;
+use crate::web::cookies;
+use crate::{database, wargaming};
+
+/// Vehicles, which the viewer is grinding, keyed by `{realm}/{account_id}/{tank_id}`.
+///
+/// Stored in the signed cookie.
+#[derive(Serialize, Deserialize, Default)]
+pub struct GrindTargets(BTreeMap<String, GrindTarget>);
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum GrindKind {
+    /// Total battles on the vehicle.
+    Battles,
+
+    /// Total Ace Tanker badges on the vehicle.
+    Masteries,
+}
+
+impl GrindKind {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Battles => "battles",
+            Self::Masteries => "masteries",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GrindTarget {
+    pub kind: GrindKind,
+    pub target: u32,
+}
+
+/// Form, which sets or resets the vehicle's grind target.
+#[serde_as]
+#[derive(Deserialize)]
+pub struct UpdateGrindTarget {
+    pub tank_id: wargaming::TankId,
+    pub kind: GrindKind,
+
+    /// Empty resets the target.
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub target: Option<u32>,
+}
+
+pub struct GrindProgress {
+    pub tank_id: wargaming::TankId,
+    pub target: GrindTarget,
+
+    /// All-time value as of now.
+    pub current: u32,
+
+    /// Gained within the display period.
+    pub period_delta: u32,
+}
+
+impl GrindProgress {
+    /// Evaluates the target against the actual tank and its delta within the display period.
+    /// The tank is missing, when it's never been played.
+    pub fn new(
+        tank_id: wargaming::TankId,
+        target: GrindTarget,
+        actual: Option<&database::TankSnapshot>,
+        delta: Option<&database::TankSnapshot>,
+    ) -> Self {
+        let value = |snapshot: &database::TankSnapshot| match target.kind {
+            GrindKind::Battles => snapshot.stats.n_battles,
+            GrindKind::Masteries => snapshot.n_masteries,
+        };
+        Self {
+            tank_id,
+            target,
+            current: actual.map_or(0, value),
+            period_delta: delta.map_or(0, value),
+        }
+    }
+
+    pub const fn is_reached(&self) -> bool {
+        self.current >= self.target.target
+    }
+
+    pub const fn remaining(&self) -> u32 {
+        self.target.target.saturating_sub(self.current)
+    }
+}
+
+impl GrindTargets {
+    pub const COOKIE_NAME: &'static str = "grind-targets";
+
+    /// Keeps the cookie well within the size limit.
+    const MAX_LEN: usize = 50;
+
+    fn key(
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+        tank_id: wargaming::TankId,
+    ) -> String {
+        format!("{realm}/{account_id}/{tank_id}")
+    }
+
+    /// Targets of the account's vehicles.
+    pub fn get_all(
+        &self,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+    ) -> Vec<(wargaming::TankId, GrindTarget)> {
+        let prefix = format!("{realm}/{account_id}/");
+        self.0
+            .iter()
+            .filter_map(|(key, target)| {
+                let tank_id = key.strip_prefix(&prefix)?.parse().ok()?;
+                Some((tank_id, *target))
+            })
+            .collect()
+    }
+
+    pub fn update(
+        &mut self,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+        update: &UpdateGrindTarget,
+    ) -> Result {
+        let key = Self::key(realm, account_id, update.tank_id);
+        match update.target {
+            Some(target) if target >= 1 => {
+                if self.0.len() >= Self::MAX_LEN && !self.0.contains_key(&key) {
+                    bail!("at most {} grind targets are allowed", Self::MAX_LEN);
+                }
+                let target = GrindTarget {
+                    kind: update.kind,
+                    target,
+                };
+                self.0.insert(key, target);
+            }
+            Some(target) => bail!("{} is an invalid grind target", target),
+            None => {
+                self.0.remove(&key);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn save(&self, jar: &CookieJar) {
+        let cookie = cookies::Builder::new(Self::COOKIE_NAME)
+            .value(self)
+            .expires_in(Duration::weeks(52))
+            .set_path("/")
+            .build();
+        jar.signed().add(cookie);
+    }
+}
+
+impl From<&CookieJar> for GrindTargets {
+    fn from(jar: &CookieJar) -> Self {
+        jar.signed()
+            .get(Self::COOKIE_NAME)
+            .and_then(|cookie| cookie.value().ok())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update(tank_id: wargaming::TankId, target: Option<u32>) -> UpdateGrindTarget {
+        UpdateGrindTarget {
+            tank_id,
+            kind: GrindKind::Battles,
+            target,
+        }
+    }
+
+    fn tank(n_battles: u32, n_masteries: u32) -> database::TankSnapshot {
+        database::TankSnapshot {
+            realm: wargaming::Realm::Europe,
+            last_battle_time: Utc.timestamp_opt(1660000000, 0).unwrap(),
+            account_id: 1,
+            tank_id: 1,
+            battle_life_time: Duration::zero(),
+            stats: database::RandomStatsSnapshot {
+                n_battles,
+                ..Default::default()
+            },
+            n_masteries,
+        }
+    }
+
+    #[test]
+    fn update_ok() -> Result {
+        let mut targets = GrindTargets::default();
+        targets.update(wargaming::Realm::Europe, 1, &update(42, Some(1000)))?;
+        targets.update(wargaming::Realm::Europe, 11, &update(43, Some(500)))?;
+        let target = GrindTarget {
+            kind: GrindKind::Battles,
+            target: 1000,
+        };
+        assert_eq!(targets.get_all(wargaming::Realm::Europe, 1), [(42, target)]);
+        assert!(targets.get_all(wargaming::Realm::Russia, 1).is_empty());
+
+        assert!(targets
+            .update(wargaming::Realm::Europe, 1, &update(42, Some(0)))
+            .is_err());
+
+        targets.update(wargaming::Realm::Europe, 1, &update(42, None))?;
+        assert!(targets.get_all(wargaming::Realm::Europe, 1).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn progress_ok() {
+        let target = GrindTarget {
+            kind: GrindKind::Masteries,
+            target: 10,
+        };
+        let progress = GrindProgress::new(1, target, Some(&tank(500, 7)), Some(&tank(20, 2)));
+        assert_eq!(progress.current, 7);
+        assert_eq!(progress.period_delta, 2);
+        assert_eq!(progress.remaining(), 3);
+        assert!(!progress.is_reached());
+
+        let progress = GrindProgress::new(1, target, None, None);
+        assert_eq!(progress.current, 0);
+        assert_eq!(progress.remaining(), 10);
+    }
+}
@@ -0,0 +1,63 @@
+use std::cmp::Reverse;
+
+use serde::Deserialize;
+
+use crate::database;
+
+/// Player page layout, selected with the `layout` query parameter.
+#[derive(Deserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Layout {
+    #[default]
+    Full,
+
+    /// Condensed chart-free variant for the mobile data and printing:
+    /// the summary cards and the most played vehicles only.
+    Compact,
+}
+
+#[derive(Deserialize)]
+pub struct LayoutParams {
+    #[serde(default)]
+    pub layout: Option<Layout>,
+}
+
+impl Layout {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Compact => "compact",
+        }
+    }
+
+    /// Number of the most played vehicles, which the compact layout shows.
+    const N_COMPACT_TANKS: usize = 10;
+
+    /// Selects the vehicles to display in the table.
+    pub fn select_tanks(self, tanks: &[database::TankSnapshot]) -> Vec<&database::TankSnapshot> {
+        match self {
+            Self::Full => tanks.iter().collect(),
+            Self::Compact => {
+                let mut tanks: Vec<_> = tanks.iter().collect();
+                tanks.sort_by_key(|tank| Reverse(tank.stats.n_battles));
+                tanks.truncate(Self::N_COMPACT_TANKS);
+                tanks
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn deserialize_ok() -> Result {
+        let params: LayoutParams = serde_json::from_str(r#"{"layout":"compact"}"#)?;
+        assert_eq!(params.layout, Some(Layout::Compact));
+        let params: LayoutParams = serde_json::from_str("{}")?;
+        assert_eq!(params.layout, None);
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use crate::database;
+use crate::prelude::*;
+
+/// Battle life time based metrics, which account for how long the battles actually lasted.
+///
+/// The battle life time is the time spent alive in battles. For the tank deltas,
+/// it's the difference between the snapshots, just like the rest of the statistics.
+#[derive(Copy, Clone)]
+pub struct LifeTimeStats {
+    pub battle_life_time: Duration,
+    pub n_battles: u32,
+    pub n_wins: u32,
+    pub damage_dealt: u64,
+    pub xp: u64,
+}
+
+impl Default for LifeTimeStats {
+    fn default() -> Self {
+        Self {
+            battle_life_time: Duration::zero(),
+            n_battles: 0,
+            n_wins: 0,
+            damage_dealt: 0,
+            xp: 0,
+        }
+    }
+}
+
+impl From<&database::TankSnapshot> for LifeTimeStats {
+    fn from(tank: &database::TankSnapshot) -> Self {
+        Self {
+            battle_life_time: tank.battle_life_time,
+            n_battles: tank.stats.n_battles,
+            n_wins: tank.stats.n_wins,
+            damage_dealt: tank.stats.damage_dealt,
+            xp: tank.stats.xp,
+        }
+    }
+}
+
+impl LifeTimeStats {
+    /// Sums up the tanks with the known battle life time.
+    pub fn new(tanks: &[database::TankSnapshot]) -> Self {
+        Self::known_tanks(tanks)
+            .map(Self::from)
+            .fold(Self::default(), |total, stats| Self {
+                battle_life_time: total.battle_life_time + stats.battle_life_time,
+                n_battles: total.n_battles + stats.n_battles,
+                n_wins: total.n_wins + stats.n_wins,
+                damage_dealt: total.damage_dealt + stats.damage_dealt,
+                xp: total.xp + stats.xp,
+            })
+    }
+
+    /// The tanks with the known battle life time, which the totals are made of.
+    pub fn known_tanks(
+        tanks: &[database::TankSnapshot],
+    ) -> impl Iterator<Item = &database::TankSnapshot> {
+        tanks
+            .iter()
+            .filter(|tank| tank.battle_life_time > Duration::zero())
+    }
+
+    pub fn battles_per_hour(&self) -> Option<f64> {
+        self.hours().map(|hours| self.n_battles as f64 / hours)
+    }
+
+    pub fn wins_per_hour(&self) -> Option<f64> {
+        self.hours().map(|hours| self.n_wins as f64 / hours)
+    }
+
+    pub fn xp_per_hour(&self) -> Option<f64> {
+        self.hours().map(|hours| self.xp as f64 / hours)
+    }
+
+    pub fn damage_per_minute(&self) -> Option<f64> {
+        self.hours().map(|hours| self.damage_dealt as f64 / hours / 60.0)
+    }
+
+    /// Battle life time in hours, or `None` if it's unknown.
+    pub fn hours(&self) -> Option<f64> {
+        let seconds = self.battle_life_time.num_seconds();
+        (seconds > 0).then(|| seconds as f64 / 3600.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn per_hour_ok() {
+        let stats = LifeTimeStats {
+            battle_life_time: Duration::minutes(30),
+            n_battles: 6,
+            n_wins: 4,
+            damage_dealt: 9000,
+            xp: 3000,
+        };
+        assert_eq!(stats.battles_per_hour(), Some(12.0));
+        assert_eq!(stats.xp_per_hour(), Some(6000.0));
+        assert_eq!(stats.wins_per_hour(), Some(8.0));
+        assert_eq!(stats.damage_per_minute(), Some(300.0));
+    }
+
+    #[test]
+    fn unknown_life_time_ok() {
+        let stats = LifeTimeStats {
+            battle_life_time: Duration::seconds(-5),
+            n_battles: 1,
+            ..Default::default()
+        };
+        assert_eq!(stats.battles_per_hour(), None);
+        assert_eq!(stats.damage_per_minute(), None);
+        assert_eq!(stats.xp_per_hour(), None);
+    }
+}
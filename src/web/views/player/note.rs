@@ -0,0 +1,70 @@
+use serde::Deserialize;
+
+use crate::prelude::*;
+use crate::{database, wargaming};
+
+/// Form, which updates the viewer's note on the account.
+#[derive(Deserialize)]
+pub struct UpdateNote {
+    /// Current nickname, which is shown on the notes page.
+    pub nickname: String,
+
+    /// Comma-separated tags.
+    #[serde(default)]
+    pub tags: String,
+
+    #[serde(default)]
+    pub text: String,
+}
+
+impl UpdateNote {
+    /// Validates the form and builds the note, [`None`] means the note should be deleted.
+    pub fn into_note(
+        self,
+        viewer_id: String,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+    ) -> Result<Option<database::AccountNote>> {
+        let tags = database::AccountNote::parse_tags(&self.tags)?;
+        let text = self.text.trim();
+        let max_len = database::AccountNote::MAX_TEXT_LEN;
+        if text.chars().count() > max_len {
+            bail!("a note must be at most {} characters long", max_len);
+        }
+        if tags.is_empty() && text.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(database::AccountNote {
+            viewer_id,
+            realm,
+            account_id,
+            nickname: self.nickname,
+            tags,
+            text: text.to_string(),
+            updated_at: now(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_note_ok() -> Result {
+        let update = UpdateNote {
+            nickname: "eigenein".to_string(),
+            tags: " , ".to_string(),
+            text: " ".to_string(),
+        };
+        assert!(update.into_note("viewer".to_string(), wargaming::Realm::Europe, 1)?.is_none());
+
+        let update = UpdateNote {
+            nickname: "eigenein".to_string(),
+            tags: String::new(),
+            text: "x".repeat(1001),
+        };
+        assert!(update.into_note("viewer".to_string(), wargaming::Realm::Europe, 1).is_err());
+        Ok(())
+    }
+}
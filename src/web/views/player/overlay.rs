@@ -0,0 +1,99 @@
+//! Minimal auto-refreshing overlay with the ongoing session statistics,
+//! meant to be added as a browser source to the streaming software.
+
+use futures::future::try_join;
+use maud::{html, DOCTYPE};
+use poem::i18n::Locale;
+use poem::web::{Data, Html, Path};
+use poem::{handler, IntoResponse, Response};
+
+use crate::crawler::fast_lane::FastLane;
+use crate::math::traits::*;
+use crate::prelude::*;
+use crate::wargaming::cache::account::AccountInfoCache;
+use crate::web::error::WebError;
+use crate::web::partials::Float;
+use crate::web::views::player::session_delta::current_session;
+use crate::{database, wargaming};
+
+/// Maximum number of the snapshots to look through for the session start.
+const MAX_SNAPSHOTS: i64 = 100;
+
+const REFRESH_SECS: u64 = 30;
+
+#[instrument(skip_all, level = "info", fields(realm = ?realm, account_id = account_id))]
+#[handler]
+pub async fn get(
+    Path((realm, account_id)): Path<(wargaming::Realm, wargaming::AccountId)>,
+    mongodb: Data<&mongodb::Database>,
+    info_cache: Data<&AccountInfoCache>,
+    fast_lane: Data<&FastLane>,
+    locale: Locale,
+) -> Result<Response, WebError> {
+    let (info, snapshots) = try_join(
+        info_cache.get(realm, account_id),
+        database::AccountSnapshot::retrieve_recent(&mongodb, realm, account_id, MAX_SNAPSHOTS),
+    )
+    .await?;
+    let info = info.ok_or(WebError::NotFound)?;
+
+    // Keep the snapshots coming while the overlay is on air.
+    let account = match database::Account::retrieve(&mongodb, realm, account_id).await? {
+        Some(account) => account,
+        None => {
+            database::Account::ensure_exists(&mongodb, realm, account_id)
+                .await
+                .context("failed to ensure the account existence")?;
+            database::Account::new(realm, account_id)
+        }
+    };
+    if let Err(error) = fast_lane.push(&account).await {
+        warn!("failed to push the account into the fast lane: {:#}", error);
+    }
+
+    let session = current_session(
+        &snapshots,
+        info.stats.random.into(),
+        info.last_battle_time,
+        now(),
+    );
+
+    let markup = html! {
+        (DOCTYPE)
+        html lang=(locale.text("html-lang")?) {
+            head {
+                meta charset="utf-8";
+                meta http-equiv="refresh" content=(REFRESH_SECS);
+                title { (info.nickname) }
+                style {
+                    "html, body { background: transparent; margin: 0; }"
+                    "body { color: white; font: bold 24px sans-serif; text-shadow: 0 0 4px black; }"
+                    ".overlay { display: flex; gap: 1.5em; padding: 0.5em; }"
+                    ".heading { font-size: 0.5em; text-transform: uppercase; }"
+                }
+            }
+            body {
+                div.overlay {
+                    div {
+                        div.heading { (locale.text("title-battles")?) }
+                        div { (session.n_battles) }
+                    }
+                    @if session.n_battles != 0 {
+                        div {
+                            div.heading { (locale.text("title-victory-ratio")?) }
+                            div { (Float::from(session.victory_ratio() * 100.0).precision(1)) "%" }
+                        }
+                        div {
+                            div.heading { (locale.text("title-average-damage")?) }
+                            div { (Float::from(session.average_damage_dealt()).precision(0)) }
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    Ok(Html(markup.into_string())
+        .with_header("Cache-Control", "no-cache")
+        .into_response())
+}
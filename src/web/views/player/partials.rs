@@ -1,6 +1,8 @@
 use maud::{html, Markup};
+use poem::i18n::Locale;
 
-use crate::web::partials::Float;
+use crate::prelude::*;
+use crate::web::partials::{nation_flag_class, Float};
 
 pub fn render_percentage(value: f64) -> Markup {
     html! {
@@ -8,3 +10,48 @@ pub fn render_percentage(value: f64) -> Markup {
         span.has-text-grey-light { "%" }
     }
 }
+
+/// Renders the arrow which shows whether the value has grown or declined.
+pub fn render_change_arrow(value: f64, previous_value: f64) -> Markup {
+    html! {
+        @if value > previous_value {
+            span.icon.has-text-success { i.fa-solid.fa-arrow-up {} }
+        } @else if value < previous_value {
+            span.icon.has-text-danger { i.fa-solid.fa-arrow-down {} }
+        }
+    }
+}
+
+pub fn render_tank_type(tank_type: wargaming::TankType, locale: &Locale) -> Result<Markup> {
+    let markup = html! {
+        @match tank_type {
+            wargaming::TankType::Light => (locale.text("tank-type-light")?),
+            wargaming::TankType::Medium => (locale.text("tank-type-medium")?),
+            wargaming::TankType::Heavy => (locale.text("tank-type-heavy")?),
+            wargaming::TankType::AT => (locale.text("tank-type-at")?),
+            wargaming::TankType::Unknown => "",
+        }
+    };
+    Ok(markup)
+}
+
+pub fn render_nation(nation: wargaming::Nation, locale: &Locale) -> Result<Markup> {
+    let key = match nation {
+        wargaming::Nation::China => "nation-china",
+        wargaming::Nation::Europe => "nation-europe",
+        wargaming::Nation::France => "nation-france",
+        wargaming::Nation::Germany => "nation-germany",
+        wargaming::Nation::Japan => "nation-japan",
+        wargaming::Nation::Other => "nation-other",
+        wargaming::Nation::Uk => "nation-uk",
+        wargaming::Nation::Usa => "nation-usa",
+        wargaming::Nation::Ussr => "nation-ussr",
+    };
+    let markup = html! {
+        span.icon-text.is-flex-wrap-nowrap {
+            span.flag-icon.(nation_flag_class(nation)) {}
+            span."ml-1" { (locale.text(key)?) }
+        }
+    };
+    Ok(markup)
+}
@@ -0,0 +1,109 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+use statrs::distribution::ContinuousCDF;
+
+use crate::database;
+use crate::math::traits::*;
+use crate::prelude::*;
+use crate::tankopedia::HistoricalTankopedia;
+
+/// Period delta of the vehicles, which share the same nation or type.
+pub struct PivotRow<'a, K> {
+    pub key: K,
+    pub stats: database::RandomStatsSnapshot,
+    pub tanks: Vec<&'a database::TankSnapshot>,
+}
+
+impl<'a> PivotRow<'a, wargaming::Nation> {
+    pub fn by_nation(
+        tanks: &'a [database::TankSnapshot],
+        tankopedia: &HistoricalTankopedia,
+    ) -> Vec<Self> {
+        Self::group_by(tanks, |tank_id| tankopedia.get_vehicle(tank_id).nation)
+    }
+}
+
+impl<'a> PivotRow<'a, wargaming::TankType> {
+    pub fn by_type(
+        tanks: &'a [database::TankSnapshot],
+        tankopedia: &HistoricalTankopedia,
+    ) -> Vec<Self> {
+        Self::group_by(tanks, |tank_id| tankopedia.get_vehicle(tank_id).type_)
+    }
+}
+
+impl<'a, K: Ord + Copy> PivotRow<'a, K> {
+    /// Groups the tanks by the key, the most played group first.
+    fn group_by(
+        tanks: &'a [database::TankSnapshot],
+        key: impl Fn(wargaming::TankId) -> K,
+    ) -> Vec<Self> {
+        let mut groups: BTreeMap<K, Vec<&database::TankSnapshot>> = BTreeMap::new();
+        for tank in tanks.iter().filter(|tank| tank.stats.n_battles != 0) {
+            groups.entry(key(tank.tank_id)).or_default().push(tank);
+        }
+        let mut rows: Vec<Self> = groups
+            .into_iter()
+            .map(|(key, mut tanks)| {
+                tanks.sort_unstable_by_key(|tank| Reverse(tank.stats.n_battles));
+                Self {
+                    key,
+                    stats: tanks.iter().map(|tank| tank.stats).sum(),
+                    tanks,
+                }
+            })
+            .collect();
+        rows.sort_by_key(|row| Reverse(row.stats.n_battles));
+        rows
+    }
+}
+
+/// Credible interval of the posterior victory ratio.
+pub fn victory_ratio_interval(
+    stats: &database::RandomStatsSnapshot,
+    confidence_level: f64,
+) -> Result<(f64, f64)> {
+    let distribution = stats.posterior_victory_ratio_distribution()?;
+    let alpha = 1.0 - confidence_level;
+    Ok((distribution.inverse_cdf(alpha / 2.0), distribution.inverse_cdf(1.0 - alpha / 2.0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tank(tank_id: wargaming::TankId, n_battles: u32, n_wins: u32) -> database::TankSnapshot {
+        database::TankSnapshot {
+            realm: wargaming::Realm::Europe,
+            last_battle_time: Utc.timestamp_opt(0, 0).unwrap(),
+            account_id: 1,
+            tank_id,
+            battle_life_time: Duration::zero(),
+            stats: database::RandomStatsSnapshot {
+                n_battles,
+                n_wins,
+                ..Default::default()
+            },
+            n_masteries: 0,
+        }
+    }
+
+    #[test]
+    fn group_by_ok() -> Result {
+        let tanks = [tank(1, 10, 5), tank(2, 0, 0), tank(3, 30, 20), tank(4, 5, 5)];
+        let rows = PivotRow::group_by(&tanks, |tank_id| tank_id % 2);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].key, 1);
+        assert_eq!(rows[0].stats.n_battles, 40);
+        assert_eq!(rows[0].stats.n_wins, 25);
+        assert_eq!(rows[0].tanks[0].tank_id, 3);
+        assert_eq!(rows[1].key, 0);
+        assert_eq!(rows[1].tanks.len(), 1);
+
+        let (lower, upper) = victory_ratio_interval(&rows[0].stats, 0.9)?;
+        assert!(lower < rows[0].stats.victory_ratio());
+        assert!(upper > rows[0].stats.victory_ratio());
+        Ok(())
+    }
+}
@@ -0,0 +1,195 @@
+//! Account's personal bests.
+//!
+//! The records are computed on the first view, and then one of the web replicas
+//! recomputes the recently viewed ones nightly, so that the page only reads a single document.
+
+use chrono::DurationRound;
+use fred::pool::RedisPool;
+use maud::{html, Markup, DOCTYPE};
+use poem::i18n::Locale;
+use poem::web::{Data, Html, Path};
+use poem::{handler, IntoResponse, Response};
+use tokio::time::sleep;
+
+use crate::database::mongodb::traits::Upsert;
+use crate::database::{AccountRecords, AccountSnapshot, DayRecord};
+use crate::helpers::lock::Lock;
+use crate::prelude::*;
+use crate::wargaming::cache::account::AccountInfoCache;
+use crate::web::error::WebError;
+use crate::web::partials::*;
+use crate::web::TrackingCode;
+
+const INTERVAL: time::Duration = time::Duration::from_secs(3600);
+
+const LOCK_TTL: time::Duration = time::Duration::from_secs(60);
+
+/// The nightly job stops recomputing the records, which nobody has looked at for a week.
+const N_VIEWED_DAYS: i64 = 7;
+
+/// Maximum number of the records to recompute at once.
+const BATCH_SIZE: i64 = 1000;
+
+#[instrument(skip_all, level = "info", fields(realm = ?realm, account_id = account_id))]
+#[handler]
+pub async fn get(
+    Path((realm, account_id)): Path<(wargaming::Realm, wargaming::AccountId)>,
+    mongodb: Data<&mongodb::Database>,
+    info_cache: Data<&AccountInfoCache>,
+    tracking_code: Data<&TrackingCode>,
+    locale: Locale,
+) -> Result<Response, WebError> {
+    let info = info_cache
+        .get(realm, account_id)
+        .await?
+        .ok_or(WebError::NotFound)?;
+    let now = now();
+    let records = match AccountRecords::retrieve(&mongodb, realm, account_id).await? {
+        Some(records) if records.is_fresh(now) => records,
+        _ => compute(&mongodb, realm, account_id, now).await?,
+    };
+
+    let markup = html! {
+        (DOCTYPE)
+        html.has-navbar-fixed-top lang=(locale.text("html-lang")?) {
+            head {
+                (headers())
+                title { (info.nickname) " – " (locale.text("page-title-personal-bests")?) }
+            }
+            body {
+                (tracking_code.0)
+                nav.navbar.has-shadow.is-fixed-top role="navigation" aria-label="main navigation" {
+                    div.navbar-brand {
+                        (home_button(&locale)?)
+                    }
+                    div.navbar-menu {
+                        div.navbar-end {
+                            form.navbar-item action="/search" method="GET" {
+                                (AccountSearch::new(realm, &locale).try_into_markup()?)
+                            }
+                        }
+                    }
+                }
+
+                section.section {
+                    div.container {
+                        h1.title {
+                            a href=(format!("/{realm}/{account_id}")) { (realm.to_emoji()) " " (info.nickname) }
+                            " – " (locale.text("page-title-personal-bests")?)
+                        }
+                        p.subtitle."is-6".has-text-grey { (locale.text("message-personal-bests")?) }
+
+                        div.columns.is-multiline {
+                            div.column."is-4" {
+                                (render_day_record(
+                                    &locale,
+                                    "title-best-day-victory-ratio",
+                                    records.best_victory_ratio.as_ref(),
+                                    |value| html! { (Float::from(100.0 * value).precision(2)) "%" },
+                                )?)
+                            }
+                            div.column."is-4" {
+                                (render_day_record(
+                                    &locale,
+                                    "title-best-day-damage",
+                                    records.best_damage.as_ref(),
+                                    |value| html! { (Float::from(value).precision(0)) },
+                                )?)
+                            }
+                            div.column."is-4" {
+                                div.box {
+                                    p.heading { (locale.text("title-longest-streak-days")?) }
+                                    @if let Some(streak) = records.longest_streak {
+                                        p.title { (streak.n_days) }
+                                        p."is-size-7".has-text-grey { (streak.start.format("%Y-%m-%d").to_string()) }
+                                    } @else {
+                                        p.title.has-text-grey { "–" }
+                                        p."is-size-7".has-text-grey { (locale.text("title-no-data-yet")?) }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                (footer(&locale)?)
+            }
+        }
+    };
+
+    Ok(Html(markup.into_string())
+        .with_header("Cache-Control", "public, max-age=3600")
+        .into_response())
+}
+
+fn render_day_record(
+    locale: &Locale,
+    title_key: &str,
+    record: Option<&DayRecord>,
+    render_value: impl Fn(f64) -> Markup,
+) -> Result<Markup> {
+    let markup = html! {
+        div.box {
+            p.heading { (locale.text(title_key)?) }
+            @if let Some(record) = record {
+                p.title { (render_value(record.value)) }
+                p."is-size-7".has-text-grey {
+                    (record.date.format("%Y-%m-%d").to_string()) " · "
+                    (locale.text("title-battles")?) ": " (record.n_battles)
+                }
+            } @else {
+                p.title.has-text-grey { "–" }
+                p."is-size-7".has-text-grey { (locale.text("title-no-data-yet")?) }
+            }
+        }
+    };
+    Ok(markup)
+}
+
+/// Computes the records from scratch and stores them.
+async fn compute(
+    db: &mongodb::Database,
+    realm: wargaming::Realm,
+    account_id: wargaming::AccountId,
+    now: DateTime,
+) -> Result<AccountRecords> {
+    let snapshots = AccountSnapshot::retrieve_daily_closing(db, realm, account_id).await?;
+    let records = AccountRecords::compute(realm, account_id, &snapshots, now);
+    records.upsert(db).await?;
+    Ok(records)
+}
+
+/// Recomputes the records forever.
+pub async fn run_updater(mongodb: mongodb::Database, redis: RedisPool) {
+    let lock = Lock::new(redis, "web:records", LOCK_TTL);
+    loop {
+        if let Err(error) = lock.run_exclusively(update(&mongodb)).await {
+            warn!("failed to recompute the records: {:#}", error);
+        }
+        sleep(INTERVAL).await;
+    }
+}
+
+/// Recomputes the recently viewed records, which were computed before the last midnight.
+#[instrument(skip_all, level = "debug")]
+async fn update(db: &mongodb::Database) -> Result {
+    let now = now();
+    let midnight = now.duration_trunc(Duration::days(1))?;
+    let viewed_since = now - Duration::days(N_VIEWED_DAYS);
+    loop {
+        let stale = AccountRecords::retrieve_stale(db, midnight, viewed_since, BATCH_SIZE).await?;
+        if stale.is_empty() {
+            break;
+        }
+        info!(n_records = stale.len(), "recomputing…");
+        for stale in stale {
+            let snapshots =
+                AccountSnapshot::retrieve_daily_closing(db, stale.realm, stale.account_id).await?;
+            let mut records =
+                AccountRecords::compute(stale.realm, stale.account_id, &snapshots, now);
+            records.viewed_at = stale.viewed_at;
+            records.upsert(db).await?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use crate::prelude::*;
+use crate::{database, wargaming};
+
+/// Inactivity longer than this separates the sessions.
+const INACTIVITY_GAP_HOURS: i64 = 2;
+
+/// Maximum number of the snapshots to look through for the session boundaries.
+const MAX_SNAPSHOTS: i64 = 100;
+
+/// Random battle statistics of the two most recent play sessions.
+pub struct SessionDelta {
+    pub last: database::RandomStatsSnapshot,
+    pub previous: database::RandomStatsSnapshot,
+}
+
+impl SessionDelta {
+    #[instrument(skip_all, level = "debug", fields(realm = ?realm, account_id = account_id))]
+    pub async fn retrieve(
+        from: &mongodb::Database,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+    ) -> Result<Option<Self>> {
+        let snapshots =
+            database::AccountSnapshot::retrieve_recent(from, realm, account_id, MAX_SNAPSHOTS)
+                .await?;
+        Ok(Self::from_snapshots(&snapshots))
+    }
+
+    /// Detects the session boundaries and subtracts the statistics.
+    ///
+    /// The snapshots must be sorted by the last battle time, oldest first.
+    /// A snapshot already contains the battles of the session it belongs to,
+    /// hence, the session delta is calculated against the last snapshot of the preceding session.
+    fn from_snapshots(snapshots: &[database::AccountSnapshot]) -> Option<Self> {
+        let gap = Duration::hours(INACTIVITY_GAP_HOURS);
+        let mut session_starts = snapshots
+            .windows(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[1].last_battle_time - pair[0].last_battle_time > gap)
+            .map(|(i, _)| i + 1)
+            .rev();
+        let last_start = session_starts.next()?;
+        let previous_start = session_starts.next()?;
+
+        let last_snapshot = snapshots.last()?;
+        let this = Self {
+            last: last_snapshot.random_stats - snapshots[last_start - 1].random_stats,
+            previous: snapshots[last_start - 1].random_stats
+                - snapshots[previous_start - 1].random_stats,
+        };
+        // Sessions of rating battles only have nothing to compare.
+        (this.last.n_battles != 0 && this.previous.n_battles != 0).then_some(this)
+    }
+}
+
+/// Random battle statistics of the ongoing play session, which are empty
+/// when the account has been inactive for a while.
+///
+/// Unlike [`SessionDelta`], the session ends with the actual statistics,
+/// which are not necessarily crawled yet.
+/// The snapshots must be sorted by the last battle time, oldest first.
+pub fn current_session(
+    snapshots: &[database::AccountSnapshot],
+    actual: database::RandomStatsSnapshot,
+    last_battle_time: DateTime,
+    now: DateTime,
+) -> database::RandomStatsSnapshot {
+    let gap = Duration::hours(INACTIVITY_GAP_HOURS);
+    if now - last_battle_time > gap {
+        return database::RandomStatsSnapshot::default();
+    }
+    let mut next_time = last_battle_time;
+    for snapshot in snapshots.iter().rev() {
+        if next_time - snapshot.last_battle_time > gap {
+            return actual - snapshot.random_stats;
+        }
+        next_time = snapshot.last_battle_time;
+    }
+    // The session started before the oldest snapshot or has never been crawled.
+    snapshots
+        .first()
+        .map_or_else(Default::default, |snapshot| actual - snapshot.random_stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(last_battle_time: DateTime, n_battles: u32) -> database::AccountSnapshot {
+        let random_stats = database::RandomStatsSnapshot {
+            n_battles,
+            ..Default::default()
+        };
+        database::AccountSnapshot::for_test(last_battle_time, random_stats)
+    }
+
+    #[test]
+    fn from_snapshots_ok() {
+        let start = Utc.timestamp_opt(1660000000, 0).unwrap();
+        let snapshots = [
+            snapshot(start, 10),
+            snapshot(start + Duration::days(1), 13),
+            snapshot(start + Duration::days(1) + Duration::minutes(10), 15),
+            snapshot(start + Duration::days(2), 16),
+            snapshot(start + Duration::days(2) + Duration::minutes(10), 20),
+        ];
+        let delta = SessionDelta::from_snapshots(&snapshots).unwrap();
+        assert_eq!(delta.last.n_battles, 5);
+        assert_eq!(delta.previous.n_battles, 5);
+    }
+
+    #[test]
+    fn from_snapshots_single_session_none() {
+        let start = Utc.timestamp_opt(1660000000, 0).unwrap();
+        let snapshots = [
+            snapshot(start, 10),
+            snapshot(start + Duration::minutes(10), 15),
+        ];
+        assert!(SessionDelta::from_snapshots(&snapshots).is_none());
+    }
+
+    #[test]
+    fn current_session_ok() {
+        let start = Utc.timestamp_opt(1660000000, 0).unwrap();
+        let snapshots = [
+            snapshot(start, 10),
+            snapshot(start + Duration::days(1), 13),
+            snapshot(start + Duration::days(1) + Duration::minutes(10), 15),
+        ];
+        let actual = database::RandomStatsSnapshot {
+            n_battles: 17,
+            ..Default::default()
+        };
+        let last_battle_time = start + Duration::days(1) + Duration::minutes(20);
+        let session = current_session(&snapshots, actual, last_battle_time, last_battle_time);
+        assert_eq!(session.n_battles, 7);
+        let session = current_session(
+            &snapshots,
+            actual,
+            last_battle_time,
+            last_battle_time + Duration::hours(3),
+        );
+        assert_eq!(session.n_battles, 0);
+    }
+}
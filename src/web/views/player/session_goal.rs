@@ -0,0 +1,208 @@
+use std::collections::BTreeMap;
+
+use poem::web::cookie::CookieJar;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, NoneAsEmptyString};
+
+use crate::prelude::*;
+use crate::web::cookies;
+use crate::{database, wargaming};
+
+/// Session goals of the viewed accounts, keyed by `{realm}/{account_id}`.
+///
+/// Stored in the signed cookie.
+#[derive(Serialize, Deserialize, Default)]
+pub struct SessionGoals(BTreeMap<String, SessionGoal>);
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum GoalKind {
+    /// Victories within the display period.
+    Wins,
+
+    /// Battles within the display period.
+    Battles,
+
+    /// Current rating to reach.
+    Rating,
+}
+
+impl GoalKind {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Wins => "wins",
+            Self::Battles => "battles",
+            Self::Rating => "rating",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SessionGoal {
+    pub kind: GoalKind,
+    pub target: i32,
+}
+
+/// Form, which sets or resets the session goal.
+#[serde_as]
+#[derive(Deserialize)]
+pub struct UpdateSessionGoal {
+    pub kind: GoalKind,
+
+    /// Empty resets the goal.
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub target: Option<i32>,
+}
+
+pub struct GoalProgress {
+    pub goal: SessionGoal,
+    pub current: i32,
+}
+
+impl GoalProgress {
+    pub const fn is_reached(&self) -> bool {
+        self.current >= self.goal.target
+    }
+}
+
+impl SessionGoal {
+    /// Evaluates the goal against the current period delta and the actual rating.
+    pub fn progress(
+        self,
+        delta: &database::RandomStatsSnapshot,
+        rating: wargaming::MmRating,
+    ) -> GoalProgress {
+        let current = match self.kind {
+            GoalKind::Wins => delta.n_wins as i32,
+            GoalKind::Battles => delta.n_battles as i32,
+            GoalKind::Rating => rating.display_rating(),
+        };
+        GoalProgress {
+            goal: self,
+            current,
+        }
+    }
+}
+
+impl SessionGoals {
+    pub const COOKIE_NAME: &'static str = "session-goals";
+
+    /// Keeps the cookie well within the size limit.
+    const MAX_LEN: usize = 20;
+
+    fn key(realm: wargaming::Realm, account_id: wargaming::AccountId) -> String {
+        format!("{realm}/{account_id}")
+    }
+
+    pub fn get(
+        &self,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+    ) -> Option<SessionGoal> {
+        self.0.get(&Self::key(realm, account_id)).copied()
+    }
+
+    pub fn update(
+        &mut self,
+        realm: wargaming::Realm,
+        account_id: wargaming::AccountId,
+        update: &UpdateSessionGoal,
+    ) -> Result {
+        let key = Self::key(realm, account_id);
+        match update.target {
+            Some(target) if target >= 1 => {
+                if self.0.len() >= Self::MAX_LEN && !self.0.contains_key(&key) {
+                    bail!("at most {} session goals are allowed", Self::MAX_LEN);
+                }
+                let goal = SessionGoal {
+                    kind: update.kind,
+                    target,
+                };
+                self.0.insert(key, goal);
+            }
+            Some(target) => bail!("{} is an invalid goal", target),
+            None => {
+                self.0.remove(&key);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn remove(&mut self, realm: wargaming::Realm, account_id: wargaming::AccountId) {
+        self.0.remove(&Self::key(realm, account_id));
+    }
+
+    pub fn save(&self, jar: &CookieJar) {
+        let cookie = cookies::Builder::new(Self::COOKIE_NAME)
+            .value(self)
+            .expires_in(Duration::weeks(52))
+            .set_path("/")
+            .build();
+        jar.signed().add(cookie);
+    }
+}
+
+impl From<&CookieJar> for SessionGoals {
+    fn from(jar: &CookieJar) -> Self {
+        jar.signed()
+            .get(Self::COOKIE_NAME)
+            .and_then(|cookie| cookie.value().ok())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_ok() -> Result {
+        let mut goals = SessionGoals::default();
+        let update = UpdateSessionGoal {
+            kind: GoalKind::Wins,
+            target: Some(10),
+        };
+        goals.update(wargaming::Realm::Europe, 1, &update)?;
+        assert_eq!(
+            goals.get(wargaming::Realm::Europe, 1),
+            Some(SessionGoal {
+                kind: GoalKind::Wins,
+                target: 10,
+            }),
+        );
+        assert_eq!(goals.get(wargaming::Realm::Russia, 1), None);
+
+        let update = UpdateSessionGoal {
+            kind: GoalKind::Wins,
+            target: Some(0),
+        };
+        assert!(goals.update(wargaming::Realm::Europe, 1, &update).is_err());
+
+        goals.remove(wargaming::Realm::Europe, 1);
+        assert_eq!(goals.get(wargaming::Realm::Europe, 1), None);
+        Ok(())
+    }
+
+    #[test]
+    fn progress_ok() {
+        let delta = database::RandomStatsSnapshot {
+            n_battles: 15,
+            n_wins: 10,
+            ..Default::default()
+        };
+        let goal = SessionGoal {
+            kind: GoalKind::Wins,
+            target: 10,
+        };
+        assert!(goal.progress(&delta, 0.0.into()).is_reached());
+
+        let goal = SessionGoal {
+            kind: GoalKind::Rating,
+            target: 4000,
+        };
+        let progress = goal.progress(&delta, 50.0.into());
+        assert_eq!(progress.current, 3500);
+        assert!(!progress.is_reached());
+    }
+}
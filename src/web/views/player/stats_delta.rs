@@ -4,12 +4,16 @@ use either::Either;
 use itertools::Itertools;
 
 use crate::prelude::*;
+use crate::web::views::player::garage_diff::GarageDiff;
 use crate::{database, wargaming};
 
 pub struct StatsDelta {
     pub random: database::RandomStatsSnapshot,
     pub rating: database::RatingStatsSnapshot,
     pub tanks: Vec<database::TankSnapshot>,
+
+    /// Empty, when there's no account snapshot as of the period start.
+    pub garage_diff: GarageDiff,
 }
 
 impl StatsDelta {
@@ -59,6 +63,13 @@ impl StatsDelta {
                 Some(account_snapshot) => account_snapshot,
                 None => return Ok(Either::Right(actual_tanks)),
             };
+        let garage_diff = GarageDiff::new(
+            actual_tanks.keys().copied(),
+            account_snapshot
+                .tank_last_battle_times
+                .iter()
+                .map(|item| item.tank_id),
+        );
         let tank_last_battle_times =
             account_snapshot
                 .tank_last_battle_times
@@ -83,6 +94,7 @@ impl StatsDelta {
             random: stats.random - account_snapshot.random_stats,
             rating: stats.rating - account_snapshot.rating_stats,
             tanks: database::TankSnapshot::subtract_collections(actual_tanks, snapshots),
+            garage_diff,
         }))
     }
 
@@ -115,6 +127,7 @@ impl StatsDelta {
             random: tanks_delta.iter().map(|tank| tank.stats).sum(),
             rating: rating_stats.into(),
             tanks: tanks_delta,
+            garage_diff: GarageDiff::default(),
         })
     }
 }
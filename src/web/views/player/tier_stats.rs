@@ -0,0 +1,59 @@
+use crate::database;
+use crate::tankopedia::HistoricalTankopedia;
+
+/// Tier breakdown of the battles played within the period.
+pub struct TierStats {
+    /// Number of battles per tier, from I to X.
+    pub n_battles: [u32; 10],
+}
+
+impl TierStats {
+    pub fn new(tanks: &[database::TankSnapshot], tankopedia: &HistoricalTankopedia) -> Self {
+        let mut n_battles = [0; 10];
+        for tank in tanks {
+            let tier = tankopedia.get_vehicle(tank.tank_id).tier as usize;
+            // Unknown vehicles have no tier.
+            if (1..=10).contains(&tier) {
+                n_battles[tier - 1] += tank.stats.n_battles;
+            }
+        }
+        Self { n_battles }
+    }
+
+    /// Battles-weighted average tier.
+    pub fn average_tier(&self) -> Option<f64> {
+        let (n_battles, weighted_sum) = self
+            .n_battles
+            .iter()
+            .zip(1..)
+            .fold((0, 0), |(n_battles, weighted_sum), (n, tier)| {
+                (n_battles + n, weighted_sum + n * tier)
+            });
+        (n_battles != 0).then(|| weighted_sum as f64 / n_battles as f64)
+    }
+
+    /// Number of battles on the most played tier, for scaling the bars.
+    pub fn max_n_battles(&self) -> u32 {
+        self.n_battles.iter().copied().max().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn average_tier_ok() {
+        let stats = TierStats {
+            n_battles: [0, 0, 0, 0, 0, 0, 0, 1, 0, 3],
+        };
+        assert_eq!(stats.average_tier(), Some(9.5));
+        assert_eq!(stats.max_n_battles(), 3);
+    }
+
+    #[test]
+    fn average_tier_empty_ok() {
+        let stats = TierStats { n_battles: [0; 10] };
+        assert_eq!(stats.average_tier(), None);
+    }
+}
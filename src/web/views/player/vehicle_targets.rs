@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+
+use poem::web::cookie::CookieJar;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, NoneAsEmptyString};
+
+use crate::prelude::*;
+use crate::web::cookies;
+
+/// Per-vehicle target victory ratios, which override the global preference.
+///
+/// Stored in the signed cookie as the percentages.
+#[derive(Serialize, Deserialize, Default)]
+pub struct VehicleTargets(BTreeMap<wargaming::TankId, f64>);
+
+/// Form, which sets or resets the vehicle's target victory ratio.
+#[serde_as]
+#[derive(Deserialize)]
+pub struct UpdateVehicleTarget {
+    pub tank_id: wargaming::TankId,
+
+    /// Empty resets the target to the global preference.
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub target_victory_ratio_percentage: Option<f64>,
+}
+
+impl VehicleTargets {
+    pub const COOKIE_NAME: &'static str = "vehicle-targets";
+
+    /// Keeps the cookie well within the size limit.
+    const MAX_LEN: usize = 100;
+
+    /// Target victory ratio of the vehicle, if overridden.
+    pub fn get(&self, tank_id: wargaming::TankId) -> Option<f64> {
+        self.0.get(&tank_id).map(|percentage| percentage / 100.0)
+    }
+
+    pub fn get_percentage(&self, tank_id: wargaming::TankId) -> Option<f64> {
+        self.0.get(&tank_id).copied()
+    }
+
+    pub fn update(&mut self, update: &UpdateVehicleTarget) -> Result {
+        match update.target_victory_ratio_percentage {
+            Some(percentage) => {
+                // NaN would be serialized as `null`, and the cookie would fail to parse.
+                if !percentage.is_finite() {
+                    bail!("{} is an invalid target", percentage);
+                }
+                if self.0.len() >= Self::MAX_LEN && !self.0.contains_key(&update.tank_id) {
+                    bail!("at most {} vehicle targets are allowed", Self::MAX_LEN);
+                }
+                self.0.insert(update.tank_id, percentage.clamp(0.01, 99.99));
+            }
+            None => {
+                self.0.remove(&update.tank_id);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn save(&self, jar: &CookieJar) {
+        let cookie = cookies::Builder::new(Self::COOKIE_NAME)
+            .value(self)
+            .expires_in(Duration::weeks(52))
+            .set_path("/")
+            .build();
+        jar.signed().add(cookie);
+    }
+}
+
+impl From<&CookieJar> for VehicleTargets {
+    fn from(jar: &CookieJar) -> Self {
+        jar.signed()
+            .get(Self::COOKIE_NAME)
+            .and_then(|cookie| cookie.value().ok())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use poem::web::cookie::CookieKey;
+
+    use super::*;
+
+    fn update(tank_id: wargaming::TankId, percentage: Option<f64>) -> UpdateVehicleTarget {
+        UpdateVehicleTarget {
+            tank_id,
+            target_victory_ratio_percentage: percentage,
+        }
+    }
+
+    #[test]
+    fn update_ok() -> Result {
+        let mut targets = VehicleTargets::default();
+        targets.update(&update(1, Some(60.0)))?;
+        targets.update(&update(2, Some(150.0)))?;
+        assert_eq!(targets.get(1), Some(0.6));
+        assert_eq!(targets.get_percentage(2), Some(99.99));
+
+        targets.update(&update(1, None))?;
+        assert_eq!(targets.get(1), None);
+        Ok(())
+    }
+
+    #[test]
+    fn update_non_finite_fails() {
+        let mut targets = VehicleTargets::default();
+        assert!(targets.update(&update(1, Some(f64::NAN))).is_err());
+        assert!(targets.update(&update(1, Some(f64::INFINITY))).is_err());
+        assert_eq!(targets.get(1), None);
+    }
+
+    #[test]
+    fn cookie_roundtrip_ok() -> Result {
+        let key = CookieKey::generate();
+        let jar = CookieJar::default();
+        let mut targets = VehicleTargets::default();
+        targets.update(&update(42, Some(55.0)))?;
+
+        let cookie = cookies::Builder::new(VehicleTargets::COOKIE_NAME)
+            .value(&targets)
+            .build();
+        jar.signed_with_key(&key).add(cookie);
+        let restored: VehicleTargets = jar
+            .signed_with_key(&key)
+            .get(VehicleTargets::COOKIE_NAME)
+            .unwrap()
+            .value()?;
+        assert_eq!(restored.get(42), Some(0.55));
+
+        // Tampered or unsigned cookies are ignored.
+        assert!(jar
+            .signed_with_key(&CookieKey::generate())
+            .get(VehicleTargets::COOKIE_NAME)
+            .is_none());
+        Ok(())
+    }
+}
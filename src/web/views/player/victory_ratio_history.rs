@@ -0,0 +1,73 @@
+//! Overall victory ratio over the last few months, for the chart.
+
+use fred::pool::RedisPool;
+use fred::prelude::*;
+use fred::types::RedisKey;
+use mongodb::bson;
+
+use crate::database::{AccountSnapshot, DailyVictoryRatio};
+use crate::prelude::*;
+use crate::wargaming;
+
+const PERIOD_DAYS: i64 = 90;
+
+const EXPIRE: Option<Expiration> = Some(Expiration::EX(3600));
+
+/// Retrieves the daily victory ratios from the cache or aggregates the account snapshots.
+#[instrument(skip_all, level = "debug", fields(realm = ?realm, account_id = account_id))]
+pub async fn retrieve(
+    db: &mongodb::Database,
+    redis: &RedisPool,
+    realm: wargaming::Realm,
+    account_id: wargaming::AccountId,
+) -> Result<Vec<DailyVictoryRatio>> {
+    let cache_key = cache_key(realm, account_id);
+    if let Some(blob) = redis.get::<Option<Vec<u8>>, _>(&cache_key).await? {
+        debug!("cache hit");
+        return decode(&blob);
+    }
+
+    let since = now() - Duration::days(PERIOD_DAYS);
+    let ratios =
+        AccountSnapshot::retrieve_daily_victory_ratios(db, realm, account_id, since).await?;
+    redis
+        .set::<(), _, _>(&cache_key, encode(&ratios)?.as_slice(), EXPIRE, None, false)
+        .await?;
+    Ok(ratios)
+}
+
+fn encode(ratios: &[DailyVictoryRatio]) -> Result<Vec<u8>> {
+    Ok(bson::to_vec(&bson::doc! { "root": bson::to_bson(ratios)? })?)
+}
+
+fn decode(blob: &[u8]) -> Result<Vec<DailyVictoryRatio>> {
+    let mut root = bson::from_slice::<bson::Document>(blob)
+        .context("failed to deserialize the victory ratio history cache")?;
+    Ok(bson::from_bson(root.remove("root").ok_or_else(|| anyhow!("no root"))?)?)
+}
+
+#[inline]
+fn cache_key(realm: wargaming::Realm, account_id: wargaming::AccountId) -> RedisKey {
+    RedisKey::from(format!("cache:1:a:vr:{}:{}", realm.to_str(), account_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_decode_ok() -> Result {
+        let ratios = vec![
+            DailyVictoryRatio {
+                last_battle_time: Utc.timestamp_opt(1_000, 0).unwrap(),
+                victory_ratio: 0.5,
+            },
+            DailyVictoryRatio {
+                last_battle_time: Utc.timestamp_opt(90_000, 0).unwrap(),
+                victory_ratio: 0.625,
+            },
+        ];
+        assert_eq!(decode(&encode(&ratios)?)?, ratios);
+        Ok(())
+    }
+}
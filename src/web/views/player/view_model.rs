@@ -1,25 +1,68 @@
 use std::collections::BTreeMap;
 use std::net::IpAddr;
 
+use fred::pool::RedisPool;
 use futures::future::try_join;
-use poem::error::{InternalServerError, NotFoundError};
 use poem::web::cookie::CookieJar;
 use poem::web::Path;
 use sentry::protocol::IpAddress;
 
+use crate::crawler::fast_lane::FastLane;
 use crate::prelude::*;
+use crate::tankopedia::cache::HistoricalTankopediaCache;
+use crate::tankopedia::HistoricalTankopedia;
 use crate::wargaming::cache::account::{AccountInfoCache, AccountTanksCache};
+use crate::web::error::WebError;
+use crate::web::viewer_id::ViewerId;
 use crate::web::views::player::display_preferences::DisplayPreferences;
+use crate::web::views::player::grind::{GrindProgress, GrindTargets};
 use crate::web::views::player::path::PathSegments;
+use crate::web::views::player::session_delta::SessionDelta;
+use crate::web::views::player::session_goal::{GoalProgress, SessionGoals};
 use crate::web::views::player::stats_delta::StatsDelta;
+use crate::web::views::player::vehicle_targets::VehicleTargets;
+use crate::web::views::player::victory_ratio_history;
+use crate::web::warm_up;
 use crate::{database, wargaming};
 
+/// Keeps the rating sparkline light for the long seasons.
+const MAX_RATING_POINTS: usize = 60;
+
+type ActualTanks = AHashMap<wargaming::TankId, database::TankSnapshot>;
+
 pub struct ViewModel {
     pub realm: wargaming::Realm,
     pub actual_info: wargaming::AccountInfo,
     pub stats_delta: StatsDelta,
     pub rating_snapshots: Vec<database::RatingSnapshot>,
+    pub session_delta: Option<SessionDelta>,
+    pub victory_ratio_history: Vec<database::DailyVictoryRatio>,
     pub preferences: DisplayPreferences,
+    pub vehicle_targets: VehicleTargets,
+
+    /// Vehicles as they were at the beginning of the period.
+    pub tankopedia: Arc<HistoricalTankopedia>,
+
+    /// Last battle time of the latest crawled snapshot, if any.
+    pub last_snapshot_time: Option<DateTime>,
+
+    /// Progress towards the viewer's goal, which is cleared from the cookie once reached.
+    pub session_goal: Option<GoalProgress>,
+
+    /// Whether the account is linked with the player's accounts in the other realms.
+    pub is_linked: bool,
+
+    /// Viewer's private note on the account.
+    pub note: Option<database::AccountNote>,
+
+    /// [`None`] means the account hasn't been crawled yet.
+    pub activity_level: Option<database::ActivityLevel>,
+
+    /// Viewer's grind targets on the account's vehicles.
+    pub grind_progress: Vec<GrindProgress>,
+
+    /// Set when the API is down, and the profile is the last crawled snapshot as of the time.
+    pub live_data_unavailable_since: Option<DateTime>,
 }
 
 impl ViewModel {
@@ -31,29 +74,78 @@ impl ViewModel {
         db: &mongodb::Database,
         info_cache: &AccountInfoCache,
         tanks_cache: &AccountTanksCache,
-    ) -> poem::Result<Self> {
-        let mut user =
-            Self::get_sentry_user(realm, account_id, ip_addr).map_err(poem::Error::from)?;
+        tankopedia_cache: &HistoricalTankopediaCache,
+        fast_lane: &FastLane,
+        redis: &RedisPool,
+    ) -> Result<Self, WebError> {
+        let mut user = Self::get_sentry_user(realm, account_id, ip_addr)?;
         sentry::configure_scope(|scope| scope.set_user(Some(user.clone())));
 
-        let (actual_info, actual_tanks) =
-            try_join(info_cache.get(realm, account_id), tanks_cache.get(realm, account_id)).await?;
-        let actual_info = actual_info.ok_or(NotFoundError)?;
+        let account = database::Account::retrieve(db, realm, account_id).await?;
+        let live_data =
+            try_join(info_cache.get(realm, account_id), tanks_cache.get(realm, account_id))
+                .await
+                .map_err(WebError::from);
+        let (actual_info, actual_tanks, live_data_unavailable_since) = match live_data {
+            Ok((actual_info, actual_tanks)) => {
+                (actual_info.ok_or(WebError::NotFound)?, actual_tanks, None)
+            }
+            Err(WebError::UpstreamApi(error)) => {
+                warn!("falling back to the last known snapshot: {:#}", error);
+                let last_known = match &account {
+                    Some(account) => Self::retrieve_last_known(db, account).await?,
+                    None => None,
+                };
+                let Some((info, tanks)) = last_known else {
+                    return Err(WebError::UpstreamApi(error));
+                };
+                let since = info.last_battle_time;
+                (info, tanks, Some(since))
+            }
+            Err(error) => return Err(error),
+        };
 
-        database::Account::ensure_exists(db, realm, account_id)
-            .await
-            .context("failed to ensure the account existence")?;
+        if account.is_none() {
+            database::Account::ensure_exists(db, realm, account_id)
+                .await
+                .context("failed to ensure the account existence")?;
+        }
+        // The crawler is already up-to-date, if it has seen the last battle.
+        let last_battle_time = account.as_ref().and_then(|account| account.last_battle_time);
+        if last_battle_time != Some(actual_info.last_battle_time) {
+            let crawled =
+                account.clone().unwrap_or_else(|| database::Account::new(realm, account_id));
+            if let Err(error) = fast_lane.push(&crawled).await {
+                warn!("failed to push the account into the fast lane: {:#}", error);
+            }
+        }
+        if let Err(error) = warm_up::record_view(redis, realm, account_id).await {
+            warn!("failed to record the account view: {:#}", error);
+        }
 
         // Now that we know the user's nickname, update the Sentry user.
         user.username = Some(actual_info.nickname.clone());
         sentry::configure_scope(|scope| scope.set_user(Some(user)));
 
         let preferences = DisplayPreferences::from(cookies);
-        let before =
-            Utc::now() - Duration::from_std(preferences.period).map_err(InternalServerError)?;
+        let vehicle_targets = VehicleTargets::from(cookies);
+        let before = Utc::now() - Duration::from_std(preferences.period).map_err(Error::from)?;
+        let grind_targets = GrindTargets::from(cookies).get_all(realm, account_id);
+        let grind_actual_tanks: Vec<Option<database::TankSnapshot>> = grind_targets
+            .iter()
+            .map(|(tank_id, _)| actual_tanks.get(tank_id).copied())
+            .collect();
         let stats_delta =
             StatsDelta::retrieve(db, realm, account_id, &actual_info.stats, actual_tanks, before)
                 .await?;
+        let grind_progress = grind_targets
+            .into_iter()
+            .zip(grind_actual_tanks)
+            .map(|((tank_id, target), actual)| {
+                let delta = stats_delta.tanks.iter().find(|tank| tank.tank_id == tank_id);
+                GrindProgress::new(tank_id, target, actual.as_ref(), delta)
+            })
+            .collect();
 
         let rating_snapshots = database::RatingSnapshot::retrieve_season(
             db,
@@ -62,16 +154,106 @@ impl ViewModel {
             actual_info.stats.rating.current_season,
         )
         .await?;
+        let rating_snapshots =
+            database::RatingSnapshot::downsample_to(rating_snapshots, MAX_RATING_POINTS);
+        let session_delta = SessionDelta::retrieve(db, realm, account_id).await?;
+        let victory_ratio_history =
+            victory_ratio_history::retrieve(db, redis, realm, account_id).await?;
+        let last_snapshot_time =
+            database::AccountSnapshot::retrieve_latest_time(db, realm, account_id).await?;
+        let mut session_goals = SessionGoals::from(cookies);
+        let session_goal = session_goals.get(realm, account_id).map(|goal| {
+            goal.progress(&stats_delta.random, actual_info.stats.rating.mm_rating)
+        });
+        if session_goal.as_ref().is_some_and(GoalProgress::is_reached) {
+            session_goals.remove(realm, account_id);
+            session_goals.save(cookies);
+        }
+        let is_linked = !database::AccountLink::retrieve_linked(db, realm, account_id)
+            .await?
+            .is_empty();
+        let note = match ViewerId::get(cookies) {
+            Some(ViewerId(viewer_id)) => {
+                database::AccountNote::retrieve(db, &viewer_id, realm, account_id).await?
+            }
+            None => None,
+        };
+        let tankopedia = tankopedia_cache.get(db, before).await?;
 
         Ok(Self {
             realm,
             actual_info,
             stats_delta,
             rating_snapshots,
+            session_delta,
+            victory_ratio_history,
             preferences,
+            vehicle_targets,
+            tankopedia,
+            last_snapshot_time,
+            session_goal,
+            is_linked,
+            note,
+            activity_level: account.and_then(|account| account.activity_level()),
+            grind_progress,
+            live_data_unavailable_since,
         })
     }
 
+    /// Reconstructs the account information and tanks from the latest crawled snapshot.
+    ///
+    /// [`None`] means the account has never been crawled, or it's been crawled before
+    /// the nickname got stored.
+    #[instrument(skip_all, fields(account_id = account.id))]
+    async fn retrieve_last_known(
+        db: &mongodb::Database,
+        account: &database::Account,
+    ) -> Result<Option<(wargaming::AccountInfo, ActualTanks)>> {
+        let (Some(nickname), Some(created_at)) = (&account.nickname, account.created_at) else {
+            return Ok(None);
+        };
+        let Some(snapshot) =
+            database::AccountSnapshot::retrieve_latest(db, account.realm, account.id, now()).await?
+        else {
+            return Ok(None);
+        };
+        let tank_ids: Vec<wargaming::TankId> = snapshot
+            .tank_last_battle_times
+            .iter()
+            .map(|tank| tank.tank_id)
+            .collect();
+        let tanks = database::TankSnapshot::retrieve_latest_tank_snapshots(
+            db,
+            account.realm,
+            account.id,
+            snapshot.last_battle_time + Duration::seconds(1),
+            &tank_ids,
+        )
+        .await?
+        .into_iter()
+        .map(|snapshot| (snapshot.tank_id, snapshot))
+        .collect();
+        let info = wargaming::AccountInfo {
+            id: account.id,
+            last_battle_time: snapshot.last_battle_time,
+            nickname: nickname.clone(),
+            created_at,
+            stats: wargaming::AccountInfoStats {
+                random: (&snapshot.random_stats).into(),
+                rating: (&snapshot.rating_stats).into(),
+            },
+        };
+        Ok(Some((info, tanks)))
+    }
+
+    /// Tells whether the account has played since the latest crawled snapshot.
+    pub fn is_stale(&self) -> bool {
+        match self.last_snapshot_time {
+            Some(time) => time < self.actual_info.last_battle_time,
+            None => true,
+        }
+    }
+
     /// Instantiates a Sentry user from the account.
     fn get_sentry_user(
         realm: wargaming::Realm,
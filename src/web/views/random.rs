@@ -3,11 +3,12 @@ use poem::{handler, IntoResponse};
 use rand::prelude::*;
 
 use crate::prelude::*;
+use crate::web::error::WebError;
 use crate::{database, wargaming};
 
 #[handler]
 #[instrument(skip_all, level = "info")]
-pub async fn get_random(db: Data<&mongodb::Database>) -> Result<impl IntoResponse> {
+pub async fn get_random(db: Data<&mongodb::Database>) -> Result<impl IntoResponse, WebError> {
     let realm = [wargaming::Realm::Russia, wargaming::Realm::Europe]
         .choose(&mut thread_rng())
         .unwrap();
@@ -0,0 +1,172 @@
+//! Realm's landing page: the tracked accounts and the battles by day.
+
+use chrono::DurationRound;
+use fred::pool::RedisPool;
+use maud::{html, PreEscaped, DOCTYPE};
+use poem::i18n::Locale;
+use poem::web::{Data, Html, Path};
+use poem::{handler, IntoResponse, Response};
+
+use crate::prelude::*;
+use crate::web::coverage;
+use crate::web::error::WebError;
+use crate::web::partials::*;
+use crate::web::TrackingCode;
+
+/// The hourly buckets are kept for 5 weeks, the first one is likely to be incomplete.
+const N_DAYS: i64 = 28;
+
+/// Sums up the hourly buckets into the days, the oldest first.
+fn daily_battles(hourly_battles: &[database::HourlyBattles]) -> Vec<(DateTime, i64)> {
+    let mut n_battles = AHashMap::<DateTime, i64>::default();
+    for item in hourly_battles {
+        *n_battles
+            .entry(item.hour.duration_trunc(Duration::days(1)).unwrap())
+            .or_default() += item.n_battles;
+    }
+    let mut n_battles: Vec<_> = n_battles.into_iter().collect();
+    n_battles.sort_unstable_by_key(|(day, _)| *day);
+    n_battles
+}
+
+#[instrument(skip_all, level = "info", fields(realm = ?realm))]
+#[handler]
+pub async fn get(
+    Path(realm): Path<wargaming::Realm>,
+    mongodb: Data<&mongodb::Database>,
+    redis: Data<&RedisPool>,
+    tracking_code: Data<&TrackingCode>,
+    locale: Locale,
+) -> Result<Response, WebError> {
+    let since = (now() - Duration::days(N_DAYS))
+        .duration_trunc(Duration::days(1))
+        .map_err(Error::from)?;
+    let hourly_battles = database::HourlyBattles::retrieve_since(&mongodb, realm, since).await?;
+    let daily_battles = daily_battles(&hourly_battles);
+
+    // The page must not break because of the counter.
+    let n_accounts = coverage::retrieve(&redis)
+        .await
+        .unwrap_or_else(|error| {
+            warn!("failed to retrieve the coverage: {:#}", error);
+            None
+        })
+        .and_then(|coverage| coverage.n_realm_accounts.get(&realm).copied());
+
+    let markup = html! {
+        (DOCTYPE)
+        html.has-navbar-fixed-top lang=(locale.text("html-lang")?) {
+            head {
+                (headers())
+                title { (realm.to_emoji()) " " (realm.to_str().to_uppercase()) " – " (locale.text("page-title-index")?) }
+            }
+            body {
+                (tracking_code.0)
+                nav.navbar.has-shadow.is-fixed-top role="navigation" aria-label="main navigation" {
+                    div.navbar-brand {
+                        (home_button(&locale)?)
+                    }
+                    div.navbar-menu {
+                        div.navbar-end {
+                            form.navbar-item action="/search" method="GET" {
+                                (AccountSearch::new(realm, &locale).try_into_markup()?)
+                            }
+                        }
+                    }
+                }
+
+                section.section {
+                    div.container {
+                        h1.title { (realm.to_emoji()) " " (realm.to_str().to_uppercase()) }
+
+                        div.columns {
+                            div.column."is-4" {
+                                div.box {
+                                    p.heading { (locale.text("title-accounts-tracked")?) }
+                                    @if let Some(n_accounts) = n_accounts {
+                                        p.title { (HumanFloat(n_accounts as f64)) }
+                                    } @else {
+                                        p.title.has-text-grey { "–" }
+                                    }
+                                }
+                                a.button.is-fullwidth href=(format!("/{realm}/activity")) {
+                                    span.icon { i.fa-solid.fa-calendar-week {} }
+                                    span { (locale.text("page-title-realm-activity")?) }
+                                }
+                            }
+                            div.column."is-8" {
+                                div.box {
+                                    p.heading { (locale.text("title-battles-per-day")?) }
+                                    @if daily_battles.is_empty() {
+                                        p.has-text-grey { (locale.text("title-no-data-yet")?) }
+                                    } @else {
+                                        div id="battles-chart" {}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                (footer(&locale)?)
+
+                @if !daily_battles.is_empty() {
+                    script src="https://cdn.jsdelivr.net/npm/apexcharts" {}
+                    script defer {
+                        (PreEscaped("
+                            'use strict';
+                            new ApexCharts(document.getElementById('battles-chart'), {
+                                chart: {
+                                    type: 'bar',
+                                    height: 200,
+                                    toolbar: {show: false},
+                                    animations: {enabled: false},
+                                    background: 'transparent',
+                                },
+                                colors: ['hsl(171, 100%, 41%)'],
+                                series: [{name: '', data: [
+                        "))
+                        @for (day, n_battles) in &daily_battles {
+                            "[" (day.timestamp_millis()) "," (n_battles) "],"
+                        }
+                        (PreEscaped("]}],
+                                xaxis: {type: 'datetime'},
+                                dataLabels: {enabled: false},
+                                tooltip: {x: {format: 'MMM d'}},
+                                theme: {mode: (window.matchMedia && window.matchMedia('(prefers-color-scheme: dark)').matches) ? 'dark' : 'light'},
+                            }).render();
+                        "))
+                    }
+                }
+            }
+        }
+    };
+
+    Ok(Html(markup.into_string())
+        .with_header("Cache-Control", "public, max-age=3600")
+        .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn daily_battles_ok() {
+        let item = |timestamp, n_battles| database::HourlyBattles {
+            realm: wargaming::Realm::Europe,
+            hour: Utc.timestamp_opt(timestamp, 0).unwrap(),
+            n_battles,
+        };
+        // 2022-08-09 13:00 and 2022-08-08 13:00 and 18:00 UTC.
+        let daily_battles =
+            daily_battles(&[item(1660050000, 1), item(1659963600, 10), item(1659981600, 5)]);
+        assert_eq!(
+            daily_battles,
+            [
+                (Utc.timestamp_opt(1659916800, 0).unwrap(), 15),
+                (Utc.timestamp_opt(1660003200, 0).unwrap(), 1),
+            ],
+        );
+    }
+}
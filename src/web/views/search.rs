@@ -1,5 +1,7 @@
 pub mod models;
 
+use std::collections::HashMap;
+
 use chrono_humanize::Tense;
 use maud::{html, Markup, DOCTYPE};
 use poem::i18n::Locale;
@@ -14,6 +16,7 @@ use crate::prelude::*;
 use crate::wargaming;
 use crate::wargaming::cache::account::info::AccountInfoCache;
 use crate::wargaming::{AccountInfo, Realm, WargamingApi};
+use crate::web::error::WebError;
 use crate::web::partials::*;
 use crate::web::TrackingCode;
 
@@ -25,17 +28,18 @@ pub async fn get(
     params: Query<QueryParams>,
     tracking_code: Data<&TrackingCode>,
     api: Data<&WargamingApi>,
+    mongodb: Data<&mongodb::Database>,
     account_info_cache: Data<&AccountInfoCache>,
     locale: Locale,
-) -> poem::Result<Response> {
+) -> Result<Response, WebError> {
     clear_user();
 
-    let account_ids: Vec<wargaming::AccountId> = api
-        .search_accounts(params.realm, &params.query.0)
-        .await?
-        .iter()
-        .map(|account| account.id)
-        .collect();
+    let found_accounts = api.search_accounts(params.realm, &params.query.0).await?;
+    if let Some(account_id) = resolve_exact_match(&found_accounts, &params.query.0) {
+        return Ok(Redirect::temporary(format!("/{}/{}", params.realm, account_id)).into_response());
+    }
+    let account_ids: Vec<wargaming::AccountId> =
+        found_accounts.iter().map(|account| account.id).collect();
     let mut accounts: Vec<AccountInfo> = api
         .get_account_info(params.realm, &account_ids)
         .await?
@@ -57,6 +61,12 @@ pub async fn get(
         account_info_cache.put(params.realm, exact_match).await?;
     }
     accounts.sort_unstable_by(|left, right| right.last_battle_time.cmp(&left.last_battle_time));
+    let activity_levels: HashMap<wargaming::AccountId, database::ActivityLevel> =
+        database::Account::retrieve_many(&mongodb, params.realm, &account_ids)
+            .await?
+            .into_iter()
+            .filter_map(|account| Some((account.id, account.activity_level()?)))
+            .collect();
 
     let markup = html! {
         (DOCTYPE)
@@ -104,7 +114,15 @@ pub async fn get(
                                 @if let Some(exact_match) = &exact_match {
                                     p.menu-label { (locale.text("title-exact-match")?) }
                                     ul.menu-list {
-                                        (account_item(params.realm, exact_match, &locale)?)
+                                        (account_item(params.realm, exact_match, activity_levels.get(&exact_match.id).copied(), &locale)?)
+                                    }
+                                    p."mt-2"."mb-4"."is-size-7" {
+                                        a href=(format!("/same-nickname?nickname={}&realm={}", params.query.0, params.realm)) {
+                                            span.icon-text {
+                                                span.icon { i.fa-solid.fa-earth-europe {} }
+                                                span { (locale.text("page-title-same-nickname")?) }
+                                            }
+                                        }
                                     }
                                 }
 
@@ -113,7 +131,7 @@ pub async fn get(
                                 }
                                 ul.menu-list {
                                     @for account in &accounts {
-                                        (account_item(params.realm, account, &locale)?)
+                                        (account_item(params.realm, account, activity_levels.get(&account.id).copied(), &locale)?)
                                     }
                                 }
                             }
@@ -129,12 +147,35 @@ pub async fn get(
     Ok(Html(markup.into_string()).into_response())
 }
 
-fn account_item(realm: Realm, account_info: &AccountInfo, locale: &Locale) -> Result<Markup> {
+/// Returns the account, which nickname is exactly the query, unless it's ambiguous.
+/// This allows jumping right onto the account page.
+pub fn resolve_exact_match(
+    accounts: &[wargaming::FoundAccount],
+    query: &str,
+) -> Option<wargaming::AccountId> {
+    let mut matches = accounts
+        .iter()
+        .filter(|account| account.nickname.to_lowercase() == query);
+    match (matches.next(), matches.next()) {
+        (Some(account), None) => Some(account.id),
+        _ => None,
+    }
+}
+
+fn account_item(
+    realm: Realm,
+    account_info: &AccountInfo,
+    activity_level: Option<database::ActivityLevel>,
+    locale: &Locale,
+) -> Result<Markup> {
     let markup = html! {
         li {
             a href=(format!("/{}/{}", realm, account_info.id)) {
                 p."is-size-5"."py-1" {
                     span.has-text-link { (account_info.nickname) }
+                    @if let Some(activity_level) = activity_level {
+                        " " (activity_tag(activity_level, locale)?)
+                    }
                 }
                 p."is-size-6"."py-1".has-text-grey {
                     (locale.text("title-last-played")?)
@@ -164,3 +205,74 @@ fn account_item(realm: Realm, account_info: &AccountInfo, locale: &Locale) -> Re
     };
     Ok(markup)
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::*;
+    use serde_json::json;
+
+    use super::*;
+    use crate::web::test::{create_test_app, get_markup, MockApi};
+
+    fn found(nickname: &str, id: wargaming::AccountId) -> wargaming::FoundAccount {
+        wargaming::FoundAccount {
+            nickname: nickname.to_string(),
+            id,
+        }
+    }
+
+    #[test]
+    fn resolve_exact_match_ok() {
+        let accounts = [found("eigenein", 1), found("EigeneinTwo", 2)];
+        assert_eq!(resolve_exact_match(&accounts, "eigenein"), Some(1));
+        assert_eq!(resolve_exact_match(&accounts, "eigeneint"), None);
+    }
+
+    #[test]
+    fn resolve_exact_match_ambiguous() {
+        let accounts = [found("Eigenein", 1), found("eigenein", 2)];
+        assert_eq!(resolve_exact_match(&accounts, "eigenein"), None);
+    }
+
+    #[tokio::test]
+    async fn get_ok() -> Result {
+        let account_id = thread_rng().gen_range(1_000_000_000..2_000_000_000);
+        let other_id = account_id + 1;
+        let info = |account_id: wargaming::AccountId, nickname: &str| {
+            json!({
+                "account_id": account_id,
+                "nickname": nickname,
+                "created_at": 1415225091,
+                "last_battle_time": 1635269048,
+                "statistics": { "all": { "battles": 9676, "wins": 5318 }, "rating": {} },
+            })
+        };
+        let mock_api = MockApi::default()
+            .with(
+                "/wotb/account/list/",
+                json!([
+                    { "account_id": account_id, "nickname": "Mocked_1" },
+                    { "account_id": other_id, "nickname": "Mocked_2" },
+                ]),
+            )
+            .with(
+                "/wotb/account/info/",
+                json!({
+                    account_id.to_string(): info(account_id, "Mocked_1"),
+                    other_id.to_string(): info(other_id, "Mocked_2"),
+                }),
+            );
+        let Some(app) = create_test_app(mock_api).await? else { return Ok(()) };
+
+        let markup = get_markup(&app.client, "/search?realm=eu&query=mocked", "en").await?;
+        assert!(markup.contains("<title>mocked – "));
+        assert!(markup.contains(r#"value="mocked""#));
+        assert_eq!(markup.matches(r#"<ul class="menu-list">"#).count(), 1);
+        for (account_id, nickname) in [(account_id, "Mocked_1"), (other_id, "Mocked_2")] {
+            assert!(markup.contains(&format!(r#"<a href="/eu/{account_id}">"#)));
+            assert!(markup.contains(&format!(r#"<span class="has-text-link">{nickname}</span>"#)));
+        }
+        assert!(markup.contains("<strong>9676</strong>"));
+        Ok(())
+    }
+}
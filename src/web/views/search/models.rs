@@ -20,6 +20,7 @@ impl TryFrom<String> for Query {
     type Error = Error;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
+        let value = value.trim();
         if value.len() < MIN_QUERY_LENGTH {
             bail!("query is too short")
         }
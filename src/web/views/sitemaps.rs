@@ -4,6 +4,7 @@ use poem::{handler, Body, IntoResponse, Response};
 
 use crate::database;
 use crate::prelude::*;
+use crate::web::error::WebError;
 
 const CACHE_CONTROL: &str = "no-cache";
 
@@ -12,7 +13,7 @@ const CACHE_CONTROL: &str = "no-cache";
 pub async fn get_sitemap(
     db: Data<&mongodb::Database>,
     Path(realm): Path<String>,
-) -> Result<impl IntoResponse> {
+) -> Result<impl IntoResponse, WebError> {
     let start_instant = Instant::now();
     let stream = database::AccountIdProjection::retrieve_recently_active(&db, &realm, 1000).await?;
     info!(elapsed_secs = ?start_instant.elapsed(), "stream ready");
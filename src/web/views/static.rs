@@ -1,7 +1,70 @@
-use poem::{handler, IntoResponse};
+use poem::web::{Path, Redirect};
+use poem::{handler, IntoResponse, Response};
+
+pub static THEME_CSS: Asset =
+    Asset::new("theme.css", "text/css", include_bytes!("static/theme.css"));
+pub static TABLE_JS: Asset =
+    Asset::new("table.js", "application/javascript", include_bytes!("static/table.js"));
+pub static NAVBAR_JS: Asset =
+    Asset::new("navbar.js", "application/javascript", include_bytes!("static/navbar.js"));
+pub static TIMELINE_JS: Asset =
+    Asset::new("timeline.js", "application/javascript", include_bytes!("static/timeline.js"));
+pub static ANNOUNCEMENTS_JS: Asset = Asset::new(
+    "announcements.js",
+    "application/javascript",
+    include_bytes!("static/announcements.js"),
+);
 
 struct Static(&'static str, &'static [u8]);
 
+/// Static asset which is served under the content-hashed path,
+/// so that it could be cached forever and yet get busted on any change.
+pub struct Asset {
+    name: &'static str,
+    content_type: &'static str,
+    content: &'static [u8],
+    fingerprint: u64,
+}
+
+impl Asset {
+    const fn new(name: &'static str, content_type: &'static str, content: &'static [u8]) -> Self {
+        Self {
+            name,
+            content_type,
+            content,
+            fingerprint: fingerprint(content),
+        }
+    }
+
+    #[must_use]
+    pub fn href(&self) -> String {
+        format!("/static/{:016x}/{}", self.fingerprint, self.name)
+    }
+
+    /// Serves the content only under the current fingerprint, and redirects the stale,
+    /// made-up or missing ones to it, so that they never get the content cached forever.
+    fn serve(&self, fingerprint: Option<Path<String>>) -> Response {
+        match fingerprint {
+            Some(Path(fingerprint)) if fingerprint == format!("{:016x}", self.fingerprint) => {
+                Static(self.content_type, self.content).into_response()
+            }
+            _ => Redirect::temporary(self.href()).into_response(),
+        }
+    }
+}
+
+/// FNV-1a, evaluated at compile time.
+const fn fingerprint(content: &[u8]) -> u64 {
+    let mut hash = 0xcbf29ce484222325_u64;
+    let mut i = 0;
+    while i < content.len() {
+        hash ^= content[i] as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+        i += 1;
+    }
+    hash
+}
+
 impl IntoResponse for Static {
     #[inline]
     fn into_response(self) -> poem::Response {
@@ -55,20 +118,32 @@ pub async fn get_apple_touch_icon() -> impl IntoResponse {
 
 #[inline]
 #[handler]
-pub async fn get_table_js() -> impl IntoResponse {
-    Static("application/javascript", include_bytes!("static/table.js"))
+pub async fn get_table_js(fingerprint: Option<Path<String>>) -> impl IntoResponse {
+    TABLE_JS.serve(fingerprint)
+}
+
+#[inline]
+#[handler]
+pub async fn get_navbar_js(fingerprint: Option<Path<String>>) -> impl IntoResponse {
+    NAVBAR_JS.serve(fingerprint)
 }
 
 #[inline]
 #[handler]
-pub async fn get_navbar_js() -> impl IntoResponse {
-    Static("application/javascript", include_bytes!("static/navbar.js"))
+pub async fn get_timeline_js(fingerprint: Option<Path<String>>) -> impl IntoResponse {
+    TIMELINE_JS.serve(fingerprint)
 }
 
 #[inline]
 #[handler]
-pub async fn get_theme_css() -> impl IntoResponse {
-    Static("text/css", include_bytes!("static/theme.css"))
+pub async fn get_announcements_js(fingerprint: Option<Path<String>>) -> impl IntoResponse {
+    ANNOUNCEMENTS_JS.serve(fingerprint)
+}
+
+#[inline]
+#[handler]
+pub async fn get_theme_css(fingerprint: Option<Path<String>>) -> impl IntoResponse {
+    THEME_CSS.serve(fingerprint)
 }
 
 #[inline]
@@ -130,3 +205,39 @@ pub async fn get_us_svg() -> impl IntoResponse {
 pub async fn get_xx_svg() -> impl IntoResponse {
     Static("image/svg+xml", include_bytes!("static/flags/xx.svg"))
 }
+
+#[cfg(test)]
+mod tests {
+    use poem::http::StatusCode;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::web::test::create_standalone_test_client;
+
+    #[test]
+    fn fingerprint_ok() {
+        assert_eq!(fingerprint(b""), 0xcbf29ce484222325);
+        assert_eq!(fingerprint(b"a"), 0xaf63dc4c8601ec8c);
+    }
+
+    #[tokio::test]
+    async fn get_fingerprinted_ok() -> Result {
+        let (_guard, client) = create_standalone_test_client().await?;
+        let response = client.get(THEME_CSS.href()).send().await;
+        response.assert_status(StatusCode::OK);
+        response.assert_header("Cache-Control", "public, max-age=31536000, immutable");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_mismatched_fingerprint_redirects_ok() -> Result {
+        let (_guard, client) = create_standalone_test_client().await?;
+        for path in ["/static/0000000000000000/theme.css", "/static/theme.css"] {
+            let response = client.get(path).send().await;
+            response.assert_status(StatusCode::TEMPORARY_REDIRECT);
+            response.assert_header("Location", THEME_CSS.href());
+            assert!(response.0.headers().get("Cache-Control").is_none());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+"use strict";
+
+const DISMISSED_COOKIE_NAME = "dismissed-announcements";
+
+function getDismissed() {
+    const cookie = document.cookie
+        .split("; ")
+        .find(cookie => cookie.startsWith(`${DISMISSED_COOKIE_NAME}=`));
+    return cookie ? decodeURIComponent(cookie.split("=")[1]).split(",") : [];
+}
+
+function dismiss(id) {
+    const dismissed = getDismissed().concat([id]).slice(-50);
+    const value = encodeURIComponent(dismissed.join(","));
+    document.cookie = `${DISMISSED_COOKIE_NAME}=${value}; max-age=31536000; path=/; samesite=lax`;
+}
+
+export async function init() {
+    const response = await fetch("/api/announcements");
+    if (!response.ok) {
+        return;
+    }
+    for (const announcement of (await response.json()).reverse()) {
+        const $notification = document.createElement("div");
+        $notification.classList.add("notification", `is-${announcement.severity}`, "is-radiusless", "mb-0");
+        $notification.innerHTML = announcement.html;
+
+        const $delete = document.createElement("button");
+        $delete.classList.add("delete");
+        $delete.addEventListener("click", () => {
+            dismiss(announcement.id);
+            $notification.remove();
+        });
+        $notification.prepend($delete);
+
+        document.body.prepend($notification);
+    }
+}
+
+document.addEventListener("DOMContentLoaded", init);
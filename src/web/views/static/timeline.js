@@ -0,0 +1,61 @@
+"use strict";
+
+const percentageMetrics = new Set(["win_rate", "survival_rate", "accuracy"]);
+
+export function initTimeline(container) {
+    const mode = (window.matchMedia && window.matchMedia("(prefers-color-scheme: dark)").matches) ? "dark" : "light";
+    const chart = new ApexCharts(container.querySelector(".timeline-chart"), {
+        chart: {
+            type: "bar",
+            height: 250,
+            animations: {enabled: false},
+            background: "transparent",
+            toolbar: {show: false},
+            zoom: {enabled: false},
+        },
+        colors: ["hsl(204, 71%, 39%)"],
+        series: [],
+        dataLabels: {enabled: false},
+        xaxis: {type: "datetime"},
+        noData: {text: "…"},
+        theme: {mode: mode},
+    });
+    chart.render();
+
+    const state = {metric: "win_rate", period: "1m"};
+
+    function update() {
+        fetch(`${container.dataset.url}?metric=${state.metric}&period=${state.period}`)
+            .then(response => response.json())
+            .then(timeline => {
+                const isPercentage = percentageMetrics.has(timeline.metric);
+                chart.updateOptions({
+                    yaxis: {
+                        labels: {
+                            formatter: value => (value == null) ? "" : (
+                                isPercentage ? `${(value * 100).toFixed(1)}%` : value.toFixed(0)
+                            ),
+                        },
+                    },
+                });
+                chart.updateSeries([{name: "", data: timeline.points}]);
+            })
+            .catch(error => console.warn(error));
+    }
+
+    function addListeners(key) {
+        const links = container.querySelectorAll(`[data-${key}]`);
+        links.forEach((a) => {
+            a.addEventListener("click", (event) => {
+                event.preventDefault();
+                state[key] = a.dataset[key];
+                links.forEach(other => other.parentElement.classList.toggle("is-active", other === a));
+                update();
+            });
+        });
+    }
+
+    addListeners("metric");
+    addListeners("period");
+    update();
+}
@@ -0,0 +1,155 @@
+//! Wargaming.net API availability by realm over the last day.
+
+use fred::pool::RedisPool;
+use futures::future::try_join_all;
+use maud::{html, DOCTYPE};
+use poem::i18n::Locale;
+use poem::web::{Data, Html, Json};
+use poem::{handler, IntoResponse, Response};
+use serde::Serialize;
+
+use crate::prelude::*;
+use crate::web::error::WebError;
+use crate::web::partials::*;
+use crate::web::server_status::{self, Sample, N_HOURS};
+use crate::web::TrackingCode;
+
+struct RealmStatus {
+    realm: wargaming::Realm,
+    latest: Option<Sample>,
+    hourly_availability: [Option<f64>; N_HOURS],
+}
+
+impl RealmStatus {
+    async fn retrieve_all(redis: &RedisPool) -> Result<Vec<Self>> {
+        let now = now();
+        try_join_all(wargaming::Realm::ALL.map(|realm| async move {
+            let samples = server_status::retrieve(redis, realm).await?;
+            Ok::<_, Error>(Self {
+                realm,
+                latest: server_status::latest(&samples, now),
+                hourly_availability: server_status::hourly_availability(&samples, now),
+            })
+        }))
+        .await
+    }
+}
+
+#[instrument(skip_all, level = "info")]
+#[handler]
+pub async fn get(
+    redis: Data<&RedisPool>,
+    tracking_code: Data<&TrackingCode>,
+    locale: Locale,
+) -> Result<Response, WebError> {
+    let statuses = RealmStatus::retrieve_all(&redis).await?;
+
+    let markup = html! {
+        (DOCTYPE)
+        html.has-navbar-fixed-top lang=(locale.text("html-lang")?) {
+            head {
+                (headers())
+                title { (locale.text("page-title-server-status")?) " – " (locale.text("page-title-index")?) }
+            }
+            body {
+                (tracking_code.0)
+                nav.navbar.has-shadow.is-fixed-top role="navigation" aria-label="main navigation" {
+                    div.navbar-brand {
+                        (home_button(&locale)?)
+                    }
+                    div.navbar-menu {
+                        div.navbar-end {
+                            form.navbar-item action="/search" method="GET" {
+                                (AccountSearch::new(wargaming::Realm::Europe, &locale).try_into_markup()?)
+                            }
+                        }
+                    }
+                }
+
+                section.section {
+                    div.container {
+                        h1.title { (locale.text("page-title-server-status")?) }
+                        p.subtitle."is-6".has-text-grey { (locale.text("message-server-status")?) }
+
+                        @for status in &statuses {
+                            div.box {
+                                div.level.is-mobile {
+                                    div.level-left {
+                                        div.level-item {
+                                            a href=(format!("/{}", status.realm)) {
+                                                strong { (status.realm.to_emoji()) " " (status.realm.to_str().to_uppercase()) }
+                                            }
+                                        }
+                                    }
+                                    div.level-right {
+                                        div.level-item {
+                                            @match status.latest {
+                                                Some(Sample { latency_millis: Some(latency_millis), .. }) => {
+                                                    span.tag.is-success { (locale.text("title-server-up")?) }
+                                                    span.has-text-grey."ml-2" { (latency_millis) " ms" }
+                                                },
+                                                Some(Sample { latency_millis: None, .. }) => {
+                                                    span.tag.is-danger { (locale.text("title-server-down")?) }
+                                                },
+                                                None => {
+                                                    span.tag { (locale.text("title-server-unknown")?) }
+                                                },
+                                            }
+                                        }
+                                    }
+                                }
+                                div.is-flex.is-align-items-flex-end style="height: 3rem; gap: 2px" title=(locale.text("title-availability-24h")?) {
+                                    @for availability in status.hourly_availability {
+                                        @match availability {
+                                            Some(availability) => {
+                                                div."is-flex-grow-1"
+                                                    .(if availability >= 0.99 { "has-background-success" } else if availability >= 0.9 { "has-background-warning" } else { "has-background-danger" })
+                                                    style=(format!("height: {:.0}%; min-height: 2px", availability * 100.0))
+                                                    title=(format!("{:.1}%", availability * 100.0)) {}
+                                            },
+                                            None => {
+                                                div."is-flex-grow-1".has-background-grey-lighter style="height: 2px" {}
+                                            },
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                (footer(&locale)?)
+            }
+        }
+    };
+
+    Ok(Html(markup.into_string())
+        .with_header("Cache-Control", "public, max-age=60")
+        .into_response())
+}
+
+#[derive(Serialize)]
+struct CurrentStatus {
+    realm: wargaming::Realm,
+
+    /// [`None`] means the status is unknown.
+    is_up: Option<bool>,
+
+    latency_millis: Option<u64>,
+}
+
+/// Current realm statuses for the footer widget.
+#[handler]
+#[instrument(skip_all, level = "info")]
+pub async fn get_current(redis: Data<&RedisPool>) -> Result<impl IntoResponse, WebError> {
+    let statuses: Vec<CurrentStatus> = RealmStatus::retrieve_all(&redis)
+        .await?
+        .into_iter()
+        .map(|status| CurrentStatus {
+            realm: status.realm,
+            is_up: status.latest.map(|sample| sample.latency_millis.is_some()),
+            latency_millis: status.latest.and_then(|sample| sample.latency_millis),
+        })
+        .collect();
+    Ok(Json(statuses).with_header("Cache-Control", "public, max-age=60"))
+}
@@ -0,0 +1,107 @@
+//! Keeps the API caches warm for the most viewed accounts, which smooths the latency spikes
+//! of the popular pages, when their cache entries expire.
+//!
+//! The page views are counted in the Redis sorted set. Its scores decay on every round,
+//! so that the recent views matter more than the old ones.
+
+use std::str::FromStr;
+
+use fred::pool::RedisPool;
+use fred::prelude::*;
+use futures::future::try_join;
+use futures::{stream, StreamExt};
+
+use crate::prelude::*;
+use crate::wargaming::cache::account::{AccountInfoCache, AccountTanksCache};
+
+const KEY: &str = "web:popular-accounts";
+
+/// Must stay below the cache expiration, so that the entries do not go cold between the rounds.
+const INTERVAL: time::Duration = time::Duration::from_secs(20);
+
+/// Score multiplier of every round, the views halve in about 7 minutes.
+const DECAY: f64 = 0.965;
+
+/// The accounts with the lower scores are forgotten.
+const MIN_SCORE: f64 = 0.1;
+
+/// Number of the accounts being warmed up at the same time.
+const CONCURRENCY: usize = 4;
+
+#[instrument(skip_all, level = "debug", fields(realm = ?realm, account_id = account_id))]
+pub async fn record_view(
+    redis: &RedisPool,
+    realm: wargaming::Realm,
+    account_id: wargaming::AccountId,
+) -> Result {
+    redis
+        .zincrby::<(), _, _>(KEY, 1.0, to_member(realm, account_id))
+        .await?;
+    Ok(())
+}
+
+/// Warms up the caches of the most viewed accounts forever.
+pub async fn run(
+    redis: RedisPool,
+    info_cache: AccountInfoCache,
+    tanks_cache: AccountTanksCache,
+    n_accounts: usize,
+) {
+    let mut interval = tokio::time::interval(INTERVAL);
+    loop {
+        interval.tick().await;
+        if let Err(error) = warm_up(&redis, &info_cache, &tanks_cache, n_accounts).await {
+            warn!("failed to warm up the caches: {:#}", error);
+        }
+    }
+}
+
+#[instrument(skip_all, level = "debug")]
+async fn warm_up(
+    redis: &RedisPool,
+    info_cache: &AccountInfoCache,
+    tanks_cache: &AccountTanksCache,
+    n_accounts: usize,
+) -> Result {
+    let members: Vec<String> = redis.zrevrange(KEY, 0, n_accounts as i64 - 1, false).await?;
+    let start_instant = Instant::now();
+    stream::iter(members.iter().filter_map(|member| from_member(member)))
+        .for_each_concurrent(CONCURRENCY, |(realm, account_id)| async move {
+            let result = try_join(
+                info_cache.refresh(realm, account_id),
+                tanks_cache.refresh(realm, account_id),
+            )
+            .await;
+            if let Err(error) = result {
+                warn!(?realm, account_id, "failed to warm up: {:#}", error);
+            }
+        })
+        .await;
+    debug!(n_accounts = members.len(), elapsed = ?start_instant.elapsed(), "warmed up");
+
+    redis.zunionstore::<(), _, _, _>(KEY, KEY, DECAY, None).await?;
+    redis.zremrangebyscore::<(), _, _, _>(KEY, f64::NEG_INFINITY, MIN_SCORE).await?;
+    Ok(())
+}
+
+fn to_member(realm: wargaming::Realm, account_id: wargaming::AccountId) -> String {
+    format!("{}:{}", realm.to_str(), account_id)
+}
+
+fn from_member(member: &str) -> Option<(wargaming::Realm, wargaming::AccountId)> {
+    let (realm, account_id) = member.split_once(':')?;
+    Some((wargaming::Realm::from_str(realm).ok()?, account_id.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn member_round_trip_ok() {
+        let member = to_member(wargaming::Realm::Asia, 42);
+        assert_eq!(member, "asia:42");
+        assert_eq!(from_member(&member), Some((wargaming::Realm::Asia, 42)));
+        assert_eq!(from_member("xx:42"), None);
+    }
+}